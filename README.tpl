@@ -4,7 +4,7 @@
 [![Crates.io](https://img.shields.io/crates/v/infobip_sdk)](https://crates.io/crates/infobip_sdk)
 ![Crates.io Downloads](https://img.shields.io/crates/d/infobip_sdk)
 ![Crates.io](https://img.shields.io/crates/l/infobip_sdk)
-![Minimum Rust Version](https://img.shields.io/badge/Rust-%3E%3D1.63-blue)
+![Minimum Rust Version](https://img.shields.io/badge/Rust-%3E%3D1.70-blue)
 
 
 {{readme}}
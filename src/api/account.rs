@@ -0,0 +1,97 @@
+//! Module with client and endpoint functions for the account and its API keys.
+//!
+//! Use [`AccountClient::require_scopes`] at startup to check the API key before sending anything,
+//! so a key without e.g. the WhatsApp scopes fails with a clear error instead of at the first
+//! message.
+
+use reqwest::Method;
+
+use crate::api::{build_http_client, build_response, send_no_body_request, SdkError, SdkResponse};
+use crate::configuration::Configuration;
+use crate::model::account::ApiKeyDetails;
+
+pub const PATH_GET_CURRENT_API_KEY: &str = "/settings/2/api-keys/current";
+
+/// Main asynchronous client for the account settings.
+#[derive(Clone, Debug)]
+pub struct AccountClient {
+    pub configuration: Configuration,
+    pub http_client: reqwest::Client,
+}
+
+impl AccountClient {
+    /// Builds and returns a new asynchronous `AccountClient` with a specified configuration.
+    /// The client gets its own connection pool, see `with_http_client` to share one.
+    pub fn with_configuration(configuration: Configuration) -> Self {
+        Self::with_http_client(build_http_client(&configuration), configuration)
+    }
+
+    /// Builds and returns a new asynchronous `AccountClient` that sends requests with the given
+    /// HTTP client. See `api::client` for sharing an HTTP client between clients.
+    pub fn with_http_client(http_client: reqwest::Client, configuration: Configuration) -> Self {
+        AccountClient {
+            configuration,
+            http_client,
+        }
+    }
+
+    /// Get the details of the API key used by the configuration, including the scopes it is
+    /// allowed to use.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::account::AccountClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let account_client = AccountClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = account_client.current_api_key().await?;
+    ///
+    /// println!("{:?}", response.body.scopes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn current_api_key(&self) -> Result<SdkResponse<ApiKeyDetails>, SdkError> {
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            Method::GET,
+            PATH_GET_CURRENT_API_KEY,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Checks that the API key of the configuration is allowed to use all `scopes`, and returns
+    /// its details. Fails with `SdkError::MissingScopes` listing the scopes it lacks.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::account::AccountClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let account_client = AccountClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// account_client
+    ///     .require_scopes(&["whatsapp:message:send", "whatsapp:manage"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn require_scopes(&self, scopes: &[&str]) -> Result<ApiKeyDetails, SdkError> {
+        let details = self.current_api_key().await?.body;
+
+        let missing = details.missing_scopes(scopes);
+        if missing.is_empty() {
+            Ok(details)
+        } else {
+            Err(SdkError::MissingScopes { missing })
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Cooperative cancellation of client calls, streams and polling loops, for graceful shutdown.
+//!
+//! All async client methods are cancellation safe: dropping their future aborts the HTTP request
+//! and leaves the client usable. A request aborted after it was sent may still have been
+//! accepted by the API, so look messages up by their IDs before sending them again, e.g. with
+//! `Configuration::with_generated_message_ids`.
+//!
+//! The helpers here turn a `CancellationToken` or a deadline into an `SdkError::Cancelled`
+//! result, so callers don't have to race futures themselves. Long-running loops, like
+//! `WhatsAppScheduler::run_until_cancelled`, take a token directly and stop between iterations.
+//!
+//! # Example
+//! ```no_run
+//! # use infobip_sdk::api::cancellation::{cancellable, CancellationToken};
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::api::SdkError;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::PreviewRequestBody;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+//! let shutdown = CancellationToken::new();
+//!
+//! let signal = shutdown.clone();
+//! tokio::spawn(async move {
+//!     tokio::signal::ctrl_c().await.ok();
+//!     signal.cancel();
+//! });
+//!
+//! match cancellable(&shutdown, sms_client.preview(PreviewRequestBody::new("Hello!"))).await {
+//!     Ok(response) => println!("{:?}", response.body),
+//!     Err(SdkError::Cancelled) => println!("shutting down"),
+//!     Err(error) => return Err(error.into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use futures_util::{Stream, StreamExt};
+use tokio::time::Instant;
+
+use crate::api::SdkError;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Runs the future until it completes or the token is cancelled, whichever comes first. Fails
+/// with `SdkError::Cancelled` if the token is cancelled first, dropping the future.
+pub async fn cancellable<T, F>(token: &CancellationToken, future: F) -> Result<T, SdkError>
+where
+    F: Future<Output = Result<T, SdkError>>,
+{
+    tokio::select! {
+        biased;
+        _ = token.cancelled() => Err(SdkError::Cancelled),
+        result = future => result,
+    }
+}
+
+/// Runs the future until it completes or the deadline passes, whichever comes first. Fails with
+/// `SdkError::Cancelled` if the deadline passes first, dropping the future.
+pub async fn with_deadline<T, F>(deadline: Instant, future: F) -> Result<T, SdkError>
+where
+    F: Future<Output = Result<T, SdkError>>,
+{
+    tokio::time::timeout_at(deadline, future)
+        .await
+        .unwrap_or(Err(SdkError::Cancelled))
+}
+
+/// Ends the stream when the token is cancelled, e.g. the streams of `SmsClient::logs_paged` and
+/// `SmsClient::inbound_stream`. Items the stream already fetched but did not yield are dropped.
+pub fn cancellable_stream<S: Stream>(
+    token: &CancellationToken,
+    stream: S,
+) -> impl Stream<Item = S::Item> {
+    stream.take_until(token.clone().cancelled_owned())
+}
@@ -0,0 +1,303 @@
+//! Sending text messages without knowing the channel they go over.
+//!
+//! [`MessageChannel`] is implemented by the SMS client and by the senders of the WhatsApp and
+//! Email clients, which hold the sender address those channels need. Application code can keep
+//! a `Box<dyn MessageChannel>` per channel and pick one at runtime, e.g. from the notification
+//! preferences of a user, without matching on the concrete clients.
+//!
+//! | Channel | Implemented by | Sender |
+//! |---|---|---|
+//! | SMS | `SmsClient` | Default sender of the account |
+//! | WhatsApp | `WhatsAppSender`, from `WhatsAppClient::sender` | Sender number |
+//! | Email | `EmailSender`, from `EmailClient::sender` | Sender address |
+//!
+//! # Example
+//! ```no_run
+//! # use std::collections::HashMap;
+//! # use infobip_sdk::api::channel::{ChannelMessage, MessageChannel};
+//! # use infobip_sdk::api::email::EmailClient;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::api::whatsapp::WhatsAppClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let configuration = Configuration::from_env_api_key()?;
+//! let mut channels: HashMap<&str, Box<dyn MessageChannel>> = HashMap::new();
+//! channels.insert("sms", Box::new(SmsClient::with_configuration(configuration.clone())));
+//! channels.insert(
+//!     "whatsapp",
+//!     Box::new(WhatsAppClient::with_configuration(configuration.clone()).sender("44444444444")),
+//! );
+//! channels.insert(
+//!     "email",
+//!     Box::new(EmailClient::with_configuration(configuration).sender("orders@company.com")),
+//! );
+//!
+//! // The preference of the user, e.g. read from a database.
+//! let channel = &channels["whatsapp"];
+//! let message = ChannelMessage::new("55555555555", "Your order has shipped.");
+//!
+//! let sent = channel.send(&message).await?;
+//! if let Some(message_id) = sent.message_id {
+//!     println!("{:?}", channel.get_status(&message_id).await?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use futures_util::future::BoxFuture;
+
+#[cfg(feature = "email")]
+use crate::api::email::EmailSender;
+#[cfg(feature = "sms")]
+use crate::api::sms::SmsClient;
+#[cfg(feature = "whatsapp")]
+use crate::api::whatsapp::WhatsAppSender;
+use crate::api::SdkError;
+use crate::configuration::Channel;
+#[cfg(feature = "email")]
+use crate::model::email;
+#[cfg(feature = "sms")]
+use crate::model::sms;
+use crate::model::status::StatusGroup;
+#[cfg(feature = "whatsapp")]
+use crate::model::whatsapp;
+
+/// A text message for any channel.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelMessage {
+    /// Phone number or email address of the recipient, depending on the channel.
+    pub to: String,
+
+    /// Text of the message.
+    pub text: String,
+
+    /// Subject of the message, used by channels that have one, like email.
+    pub subject: Option<String>,
+}
+
+impl ChannelMessage {
+    pub fn new(to: &str, text: &str) -> Self {
+        Self {
+            to: to.into(),
+            text: text.into(),
+            subject: None,
+        }
+    }
+
+    /// Sets the subject, used by channels that have one.
+    pub fn with_subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+}
+
+/// Status of a message, the same for all channels.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageStatus {
+    /// Group of the status, if it is a known group.
+    pub group: Option<StatusGroup>,
+
+    /// Name of the status, e.g. `DELIVERED_TO_HANDSET`.
+    pub name: Option<String>,
+
+    /// Human-readable description of the status.
+    pub description: Option<String>,
+}
+
+/// A message sent by a `MessageChannel`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SentMessage {
+    /// ID of the message, for `MessageChannel::get_status`.
+    pub message_id: Option<String>,
+
+    /// Status of the message when it was accepted.
+    pub status: Option<MessageStatus>,
+}
+
+/// Sends text messages over one channel. Object safe, so channels can be held as
+/// `Box<dyn MessageChannel>`. See the [module documentation](self).
+pub trait MessageChannel: Send + Sync {
+    /// Returns the channel messages are sent over.
+    fn channel(&self) -> Channel;
+
+    /// Sends a text message.
+    fn send<'a>(
+        &'a self,
+        message: &'a ChannelMessage,
+    ) -> BoxFuture<'a, Result<SentMessage, SdkError>>;
+
+    /// Returns the current status of a sent message, from the logs of the channel. `None` if the
+    /// message isn't in the logs, which only keep the messages of the last 48 hours.
+    fn get_status<'a>(
+        &'a self,
+        message_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<MessageStatus>, SdkError>>;
+}
+
+// Implements `From` of a channel status model for `MessageStatus`.
+macro_rules! impl_from_status {
+    ($($status:ty),+ $(,)?) => {
+        $(
+            impl From<$status> for MessageStatus {
+                fn from(status: $status) -> Self {
+                    Self {
+                        group: status.group(),
+                        name: status.name,
+                        description: status.description,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "sms")]
+impl_from_status!(sms::Status);
+
+#[cfg(feature = "whatsapp")]
+impl_from_status!(whatsapp::Status);
+
+#[cfg(feature = "email")]
+impl_from_status!(email::Status);
+
+#[cfg(feature = "sms")]
+impl MessageChannel for SmsClient {
+    fn channel(&self) -> Channel {
+        Channel::Sms
+    }
+
+    fn send<'a>(
+        &'a self,
+        message: &'a ChannelMessage,
+    ) -> BoxFuture<'a, Result<SentMessage, SdkError>> {
+        Box::pin(async move {
+            let response = self.send_text_to(&message.to, &message.text).await?;
+            let sent = response
+                .body
+                .messages
+                .into_iter()
+                .flatten()
+                .next()
+                .unwrap_or_default();
+
+            Ok(SentMessage {
+                message_id: sent.message_id,
+                status: sent.status.map(MessageStatus::from),
+            })
+        })
+    }
+
+    fn get_status<'a>(
+        &'a self,
+        message_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<MessageStatus>, SdkError>> {
+        Box::pin(async move {
+            let mut query_parameters = sms::LogsQueryParameters::new();
+            query_parameters.message_id = Some(message_id.to_string());
+            query_parameters.limit = Some(1);
+
+            let status = self
+                .logs(query_parameters)
+                .await?
+                .body
+                .results
+                .into_iter()
+                .flatten()
+                .find(|log| log.message_id.as_deref() == Some(message_id))
+                .and_then(|log| log.status);
+
+            Ok(status.map(MessageStatus::from))
+        })
+    }
+}
+
+#[cfg(feature = "whatsapp")]
+impl MessageChannel for WhatsAppSender {
+    fn channel(&self) -> Channel {
+        Channel::WhatsApp
+    }
+
+    fn send<'a>(
+        &'a self,
+        message: &'a ChannelMessage,
+    ) -> BoxFuture<'a, Result<SentMessage, SdkError>> {
+        Box::pin(async move {
+            let response = self
+                .send_text(&message.to, whatsapp::TextContent::new(&message.text))
+                .await?;
+
+            Ok(SentMessage {
+                message_id: response.body.message_id,
+                status: response.body.status.map(MessageStatus::from),
+            })
+        })
+    }
+
+    fn get_status<'a>(
+        &'a self,
+        message_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<MessageStatus>, SdkError>> {
+        Box::pin(async move {
+            let status = self.client().message_status(message_id).await?.body;
+
+            Ok(status.map(MessageStatus::from))
+        })
+    }
+}
+
+#[cfg(feature = "email")]
+impl MessageChannel for EmailSender {
+    fn channel(&self) -> Channel {
+        Channel::Email
+    }
+
+    fn send<'a>(
+        &'a self,
+        message: &'a ChannelMessage,
+    ) -> BoxFuture<'a, Result<SentMessage, SdkError>> {
+        Box::pin(async move {
+            let mut request_body = email::SendRequestBody::new(&message.to);
+            request_body.subject = message.subject.clone();
+            request_body.text = Some(message.text.clone());
+
+            let response = self.send(request_body).await?;
+            let sent = response
+                .body
+                .messages
+                .into_iter()
+                .flatten()
+                .next()
+                .unwrap_or_default();
+
+            Ok(SentMessage {
+                message_id: sent.message_id,
+                status: sent.status.map(MessageStatus::from),
+            })
+        })
+    }
+
+    fn get_status<'a>(
+        &'a self,
+        message_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<MessageStatus>, SdkError>> {
+        Box::pin(async move {
+            let mut query_parameters = email::LogsQueryParameters::new();
+            query_parameters.message_id = Some(message_id.to_string());
+
+            let status = self
+                .client()
+                .logs(query_parameters)
+                .await?
+                .body
+                .results
+                .into_iter()
+                .flatten()
+                .find(|log| log.message_id.as_deref() == Some(message_id))
+                .and_then(|log| log.status);
+
+            Ok(status.map(MessageStatus::from))
+        })
+    }
+}
@@ -0,0 +1,258 @@
+//! Failing fast during API outages. When a `CircuitBreaker` is set in the `Configuration`, every
+//! request of the clients goes through it:
+//!
+//! * While the circuit is closed, requests are sent as usual. Server errors (5xx), timeouts and
+//!   connection failures are counted, and other responses reset the count.
+//! * After `failure_threshold` failures in a row the circuit opens, and requests fail right away
+//!   with `SdkError::CircuitOpen`, without being sent, for the `open_for` duration.
+//! * Then the circuit is half-open: one request at a time is sent as a probe. A successful probe
+//!   closes the circuit, and a failed one opens it again.
+//!
+//! Clones of a breaker share its state, so clients using one configuration trip together. Pass a
+//! listener to `with_listener` to count transitions and rejected requests in your metrics.
+//!
+//! # Example
+//! ```no_run
+//! # use std::time::Duration;
+//! # use infobip_sdk::api::circuit_breaker::{CircuitBreaker, CircuitEvent};
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::api::SdkError;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::PreviewRequestBody;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let breaker = CircuitBreaker::new(5, Duration::from_secs(30)).with_listener(|event| {
+//!     if let CircuitEvent::Opened { .. } = event {
+//!         eprintln!("Infobip API unavailable, failing fast");
+//!     }
+//! });
+//! let configuration = Configuration::from_env_api_key()?.with_circuit_breaker(breaker);
+//! let sms_client = SmsClient::with_configuration(configuration);
+//!
+//! match sms_client.preview(PreviewRequestBody::new("Hello!")).await {
+//!     Err(SdkError::CircuitOpen { retry_after }) => println!("try again in {:?}", retry_after),
+//!     other => println!("{:?}", other?.body),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::StatusCode;
+
+use crate::api::SdkError;
+
+/// State of a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// Requests are sent.
+    Closed,
+    /// Requests fail without being sent.
+    Open,
+    /// One request at a time is sent to check whether the API recovered.
+    HalfOpen,
+}
+
+/// Something that happened in a [`CircuitBreaker`], passed to its listener.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitEvent {
+    /// The circuit opened after the given number of failures in a row.
+    Opened { failures: u32 },
+    /// The open period ended, and a probe request is sent.
+    HalfOpened,
+    /// A probe request succeeded, and requests are sent again.
+    Closed,
+    /// A request failed without being sent, as the circuit is open.
+    Rejected,
+}
+
+type Listener = Arc<dyn Fn(CircuitEvent) + Send + Sync>;
+
+#[derive(Debug)]
+struct Circuit {
+    state: CircuitState,
+    failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+/// Stops sending requests while the API is failing. See the [module documentation](self).
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    open_for: Duration,
+    circuit: Arc<Mutex<Circuit>>,
+    listener: Option<Listener>,
+}
+
+impl fmt::Debug for CircuitBreaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircuitBreaker")
+            .field("failure_threshold", &self.failure_threshold)
+            .field("open_for", &self.open_for)
+            .field("circuit", &self.circuit)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker that opens after `failure_threshold` failures in a row, at least
+    /// one, and stays open for `open_for`.
+    pub fn new(failure_threshold: u32, open_for: Duration) -> Self {
+        CircuitBreaker {
+            failure_threshold: failure_threshold.max(1),
+            open_for,
+            circuit: Arc::new(Mutex::new(Circuit {
+                state: CircuitState::Closed,
+                failures: 0,
+                opened_at: None,
+                probing: false,
+            })),
+            listener: None,
+        }
+    }
+
+    /// Calls `listener` on every transition and rejected request, e.g. to update metrics. The
+    /// listener is called while the state is locked, so it must not use the breaker.
+    pub fn with_listener<F>(mut self, listener: F) -> Self
+    where
+        F: Fn(CircuitEvent) + Send + Sync + 'static,
+    {
+        self.listener = Some(Arc::new(listener));
+        self
+    }
+
+    /// Returns the current state. An open circuit whose open period ended is reported as
+    /// half-open.
+    pub fn state(&self) -> CircuitState {
+        let circuit = self.circuit.lock().unwrap();
+        match circuit.state {
+            CircuitState::Open if self.remaining(&circuit).is_zero() => CircuitState::HalfOpen,
+            state => state,
+        }
+    }
+
+    /// Returns the number of failures in a row counted while closed.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.circuit.lock().unwrap().failures
+    }
+
+    /// Closes the circuit and resets the failure count, e.g. after fixing a configuration
+    /// problem that caused the failures.
+    pub fn reset(&self) {
+        let mut circuit = self.circuit.lock().unwrap();
+        circuit.state = CircuitState::Closed;
+        circuit.failures = 0;
+        circuit.opened_at = None;
+        circuit.probing = false;
+    }
+
+    // Checks whether a request may be sent, returning a permit to record its outcome with.
+    pub(crate) fn acquire(&self) -> Result<Permit<'_>, SdkError> {
+        let mut circuit = self.circuit.lock().unwrap();
+
+        if circuit.state == CircuitState::Open {
+            let remaining = self.remaining(&circuit);
+            if !remaining.is_zero() {
+                self.notify(CircuitEvent::Rejected);
+                return Err(SdkError::CircuitOpen {
+                    retry_after: remaining,
+                });
+            }
+            circuit.state = CircuitState::HalfOpen;
+            self.notify(CircuitEvent::HalfOpened);
+        }
+        if circuit.state == CircuitState::HalfOpen {
+            if circuit.probing {
+                self.notify(CircuitEvent::Rejected);
+                return Err(SdkError::CircuitOpen {
+                    retry_after: Duration::ZERO,
+                });
+            }
+            circuit.probing = true;
+        }
+
+        Ok(Permit {
+            breaker: self,
+            probe: circuit.state == CircuitState::HalfOpen,
+            recorded: false,
+        })
+    }
+
+    fn remaining(&self, circuit: &Circuit) -> Duration {
+        circuit.opened_at.map_or(Duration::ZERO, |opened_at| {
+            self.open_for.saturating_sub(opened_at.elapsed())
+        })
+    }
+
+    fn record(&self, failed: bool) {
+        let mut circuit = self.circuit.lock().unwrap();
+        circuit.probing = false;
+
+        if !failed {
+            circuit.failures = 0;
+            if circuit.state != CircuitState::Closed {
+                circuit.state = CircuitState::Closed;
+                circuit.opened_at = None;
+                self.notify(CircuitEvent::Closed);
+            }
+            return;
+        }
+
+        circuit.failures = circuit.failures.saturating_add(1);
+        let trips = match circuit.state {
+            CircuitState::Closed => circuit.failures >= self.failure_threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if trips {
+            circuit.state = CircuitState::Open;
+            circuit.opened_at = Some(Instant::now());
+            self.notify(CircuitEvent::Opened {
+                failures: circuit.failures,
+            });
+        }
+    }
+
+    fn notify(&self, event: CircuitEvent) {
+        if let Some(listener) = &self.listener {
+            listener(event);
+        }
+    }
+}
+
+/// Permission to send one request. Dropping it without recording an outcome, e.g. when the
+/// request is cancelled, frees the probe slot of a half-open circuit without changing its state.
+pub(crate) struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    recorded: bool,
+}
+
+impl Permit<'_> {
+    pub(crate) fn record_status(mut self, status: StatusCode) {
+        self.recorded = true;
+        self.breaker.record(status.is_server_error());
+    }
+
+    pub(crate) fn record_error(mut self, error: &SdkError) {
+        self.recorded = true;
+        let failed = match error {
+            SdkError::Reqwest(error) => error.is_timeout() || error.is_connect(),
+            _ => false,
+        };
+        self.breaker.record(failed);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.circuit.lock().unwrap().probing = false;
+        }
+    }
+}
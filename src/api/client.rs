@@ -0,0 +1,138 @@
+//! A single entry point for all channels.
+//!
+//! [`InfobipClient`] holds one configuration and one HTTP client, and hands out the channel
+//! clients built from them. Channel clients are created the first time they are used, and all of
+//! them share the connection pool of the HTTP client, instead of each holding its own.
+//!
+//! Sharing matters for applications using several channels: every `with_configuration`
+//! constructor builds a new `reqwest::Client`, which keeps its own idle connections and repeats
+//! DNS lookups and TLS handshakes to the API. Clones of a `reqwest::Client` share one pool, so
+//! channel clients can also share it without the facade, by passing clones to their
+//! `with_http_client` constructors. The timeout, proxy and compression of the configuration are
+//! only applied to HTTP clients the SDK builds, so set them on a client passed in.
+//!
+//! # Example
+//! ```no_run
+//! # use infobip_sdk::api::client::InfobipClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::PreviewRequestBody;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = InfobipClient::new(Configuration::from_env_api_key()?);
+//!
+//! let response = client.sms().preview(PreviewRequestBody::new("Hello!")).await?;
+//!
+//! println!("{:?}", response.body);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::OnceLock;
+
+#[cfg(feature = "account")]
+use crate::api::account::AccountClient;
+use crate::api::build_http_client;
+#[cfg(feature = "email")]
+use crate::api::email::EmailClient;
+#[cfg(feature = "sms")]
+use crate::api::sms::SmsClient;
+#[cfg(feature = "tfa")]
+use crate::api::tfa::TfaClient;
+#[cfg(feature = "whatsapp")]
+use crate::api::whatsapp::WhatsAppClient;
+use crate::configuration::Configuration;
+
+/// Client for all channels enabled by features, sharing one configuration and HTTP client.
+#[derive(Debug)]
+pub struct InfobipClient {
+    configuration: Configuration,
+    http_client: reqwest::Client,
+    #[cfg(feature = "account")]
+    account: OnceLock<AccountClient>,
+    #[cfg(feature = "sms")]
+    sms: OnceLock<SmsClient>,
+    #[cfg(feature = "tfa")]
+    tfa: OnceLock<TfaClient>,
+    #[cfg(feature = "whatsapp")]
+    whatsapp: OnceLock<WhatsAppClient>,
+    #[cfg(feature = "email")]
+    email: OnceLock<EmailClient>,
+}
+
+impl InfobipClient {
+    /// Builds and returns a new `InfobipClient` with the specified configuration.
+    pub fn new(configuration: Configuration) -> Self {
+        Self::with_http_client(build_http_client(&configuration), configuration)
+    }
+
+    /// Builds and returns a new `InfobipClient` that sends requests with the given HTTP client,
+    /// e.g. to share its connection pool with other clients. See `api::pool` for clients of many
+    /// accounts.
+    pub fn with_http_client(http_client: reqwest::Client, configuration: Configuration) -> Self {
+        InfobipClient {
+            http_client,
+            configuration,
+            #[cfg(feature = "account")]
+            account: OnceLock::new(),
+            #[cfg(feature = "sms")]
+            sms: OnceLock::new(),
+            #[cfg(feature = "tfa")]
+            tfa: OnceLock::new(),
+            #[cfg(feature = "whatsapp")]
+            whatsapp: OnceLock::new(),
+            #[cfg(feature = "email")]
+            email: OnceLock::new(),
+        }
+    }
+
+    /// Returns the configuration shared by the channel clients.
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Returns the HTTP client shared by the channel clients.
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.http_client
+    }
+
+    /// Returns the account client, e.g. to check the scopes of the API key at startup.
+    #[cfg(feature = "account")]
+    pub fn account(&self) -> &AccountClient {
+        self.account.get_or_init(|| {
+            AccountClient::with_http_client(self.http_client.clone(), self.configuration.clone())
+        })
+    }
+
+    /// Returns the SMS client.
+    #[cfg(feature = "sms")]
+    pub fn sms(&self) -> &SmsClient {
+        self.sms.get_or_init(|| {
+            SmsClient::with_http_client(self.http_client.clone(), self.configuration.clone())
+        })
+    }
+
+    /// Returns the 2FA client.
+    #[cfg(feature = "tfa")]
+    pub fn tfa(&self) -> &TfaClient {
+        self.tfa.get_or_init(|| {
+            TfaClient::with_http_client(self.http_client.clone(), self.configuration.clone())
+        })
+    }
+
+    /// Returns the WhatsApp client.
+    #[cfg(feature = "whatsapp")]
+    pub fn whatsapp(&self) -> &WhatsAppClient {
+        self.whatsapp.get_or_init(|| {
+            WhatsAppClient::with_http_client(self.http_client.clone(), self.configuration.clone())
+        })
+    }
+
+    /// Returns the Email client.
+    #[cfg(feature = "email")]
+    pub fn email(&self) -> &EmailClient {
+        self.email.get_or_init(|| {
+            EmailClient::with_http_client(self.http_client.clone(), self.configuration.clone())
+        })
+    }
+}
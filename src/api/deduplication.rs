@@ -0,0 +1,125 @@
+//! Client-side suppression of repeated SMS messages. When enabled in the `Configuration`,
+//! `SmsClient::send` remembers the destination and text of every message it sends, and refuses
+//! to send the same text to the same destination again until the window has passed. This keeps
+//! retries at the application layer from texting a customer twice.
+//!
+//! A request with a repeated message fails with `SdkError::DuplicateSuppressed` and nothing is
+//! sent. Messages rejected by the API are forgotten so they can be retried; messages whose
+//! request failed in transit are kept, as they may have been delivered.
+//!
+//! # Example
+//! ```no_run
+//! # use std::time::Duration;
+//! # use infobip_sdk::api::deduplication::DeduplicationWindow;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::api::SdkError;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let configuration = Configuration::from_env_api_key()?
+//!     .with_deduplication(DeduplicationWindow::new(Duration::from_secs(600)));
+//! let sms_client = SmsClient::with_configuration(configuration);
+//!
+//! let mut message = Message::new(vec![Destination::new("123456789012")]);
+//! message.text = Some("Your order has shipped.".to_string());
+//! let request_body = SendRequestBody::new(vec![message]);
+//!
+//! sms_client.send(request_body.clone()).await?;
+//!
+//! match sms_client.send(request_body).await {
+//!     Err(SdkError::DuplicateSuppressed { destinations }) => {
+//!         println!("already sent to {:?}", destinations)
+//!     }
+//!     other => panic!("unexpected result: {:?}", other),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+type Key = (String, u64);
+
+/// Remembers sent messages for a time to live. Clones share the same storage, so all clients
+/// using a configuration share one window.
+#[derive(Clone, Debug)]
+pub struct DeduplicationWindow {
+    ttl: Duration,
+    sent: Arc<Mutex<HashMap<Key, Instant>>>,
+}
+
+impl DeduplicationWindow {
+    /// Creates a window that suppresses repeats sent within `ttl` of the original message.
+    pub fn new(ttl: Duration) -> Self {
+        DeduplicationWindow {
+            ttl,
+            sent: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the time to live of remembered messages.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the number of remembered messages, including expired ones not yet cleaned up.
+    pub fn len(&self) -> usize {
+        self.sent.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no message is remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets all remembered messages.
+    pub fn clear(&self) {
+        self.sent.lock().unwrap().clear();
+    }
+
+    // Remembers the (destination, text) pairs as sent now, unless any of them was already sent
+    // within the window or repeats within the pairs. In that case nothing is remembered and the
+    // repeated destinations are returned.
+    pub(crate) fn reserve(&self, messages: &[(String, String)]) -> Result<(), Vec<String>> {
+        let now = Instant::now();
+        let mut sent = self.sent.lock().unwrap();
+        sent.retain(|_, sent_at| now.duration_since(*sent_at) < self.ttl);
+
+        let mut keys = HashMap::new();
+        let mut duplicates = Vec::new();
+        for (destination, text) in messages {
+            let key = key(destination, text);
+            if sent.contains_key(&key) || keys.insert(key, now).is_some() {
+                duplicates.push(destination.clone());
+            }
+        }
+
+        if !duplicates.is_empty() {
+            return Err(duplicates);
+        }
+        sent.extend(keys);
+
+        Ok(())
+    }
+
+    // Forgets the (destination, text) pairs, so they can be sent again.
+    pub(crate) fn release(&self, messages: &[(String, String)]) {
+        let mut sent = self.sent.lock().unwrap();
+        for (destination, text) in messages {
+            sent.remove(&key(destination, text));
+        }
+    }
+}
+
+fn key(destination: &str, text: &str) -> Key {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+
+    (destination.to_string(), hasher.finish())
+}
@@ -0,0 +1,475 @@
+//! Background sending of messages with batching, retries and persistence.
+//!
+//! A [`Dispatcher`] runs a task that receives messages through a [`DispatcherHandle`], keeps
+//! them in a [`QueueStore`], and sends them in batches with a [`BatchSender`], like the SMS
+//! client sending several messages in one request. A message is acknowledged to the caller once
+//! it is in the store, and removed from it only after its batch was sent, so with a store that
+//! persists items, like [`FileQueueStore`](crate::queue::FileQueueStore), messages are not lost
+//! when the process stops.
+//!
+//! Delivery is at least once: batches that fail with a retryable error are sent again with
+//! exponential backoff, waiting at least as long as the `Retry-After` header of a throttled
+//! response asks for, and a batch whose send was interrupted by a restart is sent again once
+//! its lease ends. Set message IDs on the messages to recognize repeated sends. Batches that fail
+//! with other errors, or too many times, are removed and reported as failed.
+//!
+//! # Example
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use infobip_sdk::api::dispatcher::{Dispatcher, DispatcherOptions};
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::{Destination, Message};
+//! # use infobip_sdk::queue::FileQueueStore;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+//! let store = Arc::new(FileQueueStore::open("outbox.json")?);
+//!
+//! let dispatcher = Dispatcher::spawn_with_store(
+//!     sms_client,
+//!     store,
+//!     DispatcherOptions::default(),
+//!     |outcome| println!("{:?}", outcome),
+//! );
+//!
+//! let mut message = Message::new(vec![Destination::new("41793026727")]);
+//! message.text = Some("Your order has shipped.".to_string());
+//! dispatcher.handle().send(message).await?;
+//!
+//! dispatcher.shutdown().await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use serde_derive::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::api::cancellation::CancellationToken;
+#[cfg(feature = "sms")]
+use crate::api::sms::SmsClient;
+#[cfg(feature = "whatsapp")]
+use crate::api::whatsapp::WhatsAppClient;
+use crate::api::SdkError;
+#[cfg(feature = "sms")]
+use crate::model::sms::{Message, SendRequestBody};
+#[cfg(feature = "whatsapp")]
+use crate::model::whatsapp::{FailoverMessage, SendTemplateRequestBody};
+use crate::queue::{MemoryQueueStore, QueueStore, QueuedItem, StoreError};
+
+/// Sends a batch of messages in one request.
+pub trait BatchSender<T>: Send + Sync {
+    fn send_batch(&self, batch: Vec<T>) -> BoxFuture<'_, Result<(), SdkError>>;
+}
+
+#[cfg(feature = "sms")]
+impl BatchSender<Message> for SmsClient {
+    fn send_batch(&self, batch: Vec<Message>) -> BoxFuture<'_, Result<(), SdkError>> {
+        Box::pin(async move {
+            self.send(SendRequestBody::new(batch)).await?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "whatsapp")]
+impl BatchSender<FailoverMessage> for WhatsAppClient {
+    fn send_batch(&self, batch: Vec<FailoverMessage>) -> BoxFuture<'_, Result<(), SdkError>> {
+        Box::pin(async move {
+            self.send_template(SendTemplateRequestBody::new(batch))
+                .await?;
+            Ok(())
+        })
+    }
+}
+
+/// A message waiting in the store of a dispatcher.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pending<T> {
+    pub message: T,
+
+    /// Number of failed attempts to send the message.
+    pub attempts: u32,
+}
+
+/// Settings of a [`Dispatcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DispatcherOptions {
+    /// Maximum number of messages sent in one batch. Defaults to 100.
+    pub batch_size: usize,
+
+    /// How often the store is checked for messages to send. A batch is also sent as soon as
+    /// `batch_size` messages were received. Defaults to one second.
+    pub flush_interval: Duration,
+
+    /// How long messages of a batch being sent are hidden from other sends. Must be longer than
+    /// a send can take, or messages may be sent twice. Defaults to one minute.
+    pub lease: Duration,
+
+    /// Delay before the first retry of a failed batch, doubled on each further retry. Defaults to
+    /// one second.
+    pub initial_backoff: Duration,
+
+    /// Longest delay between retries. Defaults to five minutes.
+    pub max_backoff: Duration,
+
+    /// Longest `Retry-After` of a response that is honored, so a bogus value can't hold a batch
+    /// back for good. Defaults to one hour.
+    pub max_retry_after: Duration,
+
+    /// Number of attempts after which a message is reported as failed. Defaults to 10.
+    pub max_attempts: u32,
+
+    /// Number of received messages that can wait to be stored before `send` waits. Defaults to
+    /// 1024.
+    pub channel_capacity: usize,
+}
+
+impl Default for DispatcherOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            flush_interval: Duration::from_secs(1),
+            lease: Duration::from_secs(60),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(300),
+            max_retry_after: Duration::from_secs(3600),
+            max_attempts: 10,
+            channel_capacity: 1024,
+        }
+    }
+}
+
+impl DispatcherOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the delay before the next attempt, after the given number of failed attempts.
+    fn backoff(&self, attempts: u32) -> Duration {
+        self.initial_backoff
+            .checked_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+
+    // Returns the delay before retrying after `error`: the backoff, or the `Retry-After` of the
+    // response if that is longer. `max_backoff` doesn't limit `Retry-After`, as sending earlier
+    // would only be throttled again, but `max_retry_after` does.
+    fn retry_delay(&self, attempts: u32, error: &SdkError) -> Duration {
+        let backoff = self.backoff(attempts);
+        error.retry_after().map_or(backoff, |retry_after| {
+            retry_after.min(self.max_retry_after).max(backoff)
+        })
+    }
+
+    // Returns when a batch retried after `delay` is due. A delay too long for a date is treated
+    // as `max_retry_after`, and as the latest date if that is too long as well.
+    fn retry_due_at(&self, now: DateTime<Utc>, delay: Duration) -> DateTime<Utc> {
+        let after = |delay: Duration| {
+            chrono::Duration::from_std(delay)
+                .ok()
+                .and_then(|delay| now.checked_add_signed(delay))
+        };
+
+        after(delay)
+            .or_else(|| after(self.max_retry_after))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+}
+
+/// What happened to a batch of messages, reported to the callback of the dispatcher.
+#[derive(Debug)]
+pub enum DispatchOutcome<T> {
+    /// The batch was sent. Holds the IDs returned by `DispatcherHandle::send`.
+    Sent { ids: Vec<String> },
+
+    /// Sending the batch failed and will be retried after `delay`.
+    Retrying {
+        ids: Vec<String>,
+        attempts: u32,
+        delay: Duration,
+        error: SdkError,
+    },
+
+    /// Sending the batch failed for good, and its messages were removed from the store.
+    Failed {
+        items: Vec<QueuedItem<Pending<T>>>,
+        error: SdkError,
+    },
+
+    /// The store could not be read or updated. The dispatcher tries again on the next flush.
+    StoreFailed(StoreError),
+}
+
+type Received<T> = (T, oneshot::Sender<Result<String, SdkError>>);
+
+/// Accepts messages for a [`Dispatcher`]. Clones send to the same dispatcher.
+#[derive(Debug)]
+pub struct DispatcherHandle<T> {
+    sender: mpsc::Sender<Received<T>>,
+}
+
+impl<T> Clone for DispatcherHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T> DispatcherHandle<T> {
+    /// Queues a message, returning its ID once it is in the store. Fails with
+    /// `SdkError::Cancelled` if the dispatcher was shut down, or `SdkError::Store` if the message
+    /// could not be stored.
+    pub async fn send(&self, message: T) -> Result<String, SdkError> {
+        let (acknowledge, acknowledged) = oneshot::channel();
+        self.sender
+            .send((message, acknowledge))
+            .await
+            .map_err(|_| SdkError::Cancelled)?;
+
+        acknowledged.await.map_err(|_| SdkError::Cancelled)?
+    }
+}
+
+/// Sends messages in the background. See the [module documentation](self).
+pub struct Dispatcher<T> {
+    handle: DispatcherHandle<T>,
+    token: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+impl<T> Dispatcher<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Starts a dispatcher that keeps messages in memory, calling `on_outcome` with the outcome
+    /// of every batch. Must be called within a Tokio runtime.
+    pub fn spawn<S, F>(sender: S, options: DispatcherOptions, on_outcome: F) -> Self
+    where
+        S: BatchSender<T> + 'static,
+        F: FnMut(DispatchOutcome<T>) + Send + 'static,
+    {
+        Self::spawn_with_store(
+            sender,
+            Arc::new(MemoryQueueStore::new()),
+            options,
+            on_outcome,
+        )
+    }
+
+    /// Starts a dispatcher that keeps messages in the given store. Messages left in the store by
+    /// an earlier run are sent as well.
+    pub fn spawn_with_store<S, F>(
+        sender: S,
+        store: Arc<dyn QueueStore<Pending<T>>>,
+        options: DispatcherOptions,
+        on_outcome: F,
+    ) -> Self
+    where
+        S: BatchSender<T> + 'static,
+        F: FnMut(DispatchOutcome<T>) + Send + 'static,
+    {
+        let (sender_half, receiver) = mpsc::channel(options.channel_capacity.max(1));
+        let token = CancellationToken::new();
+        let worker = Worker {
+            sender,
+            store,
+            options,
+            on_outcome,
+        };
+        let task = tokio::spawn(worker.run(receiver, token.clone()));
+
+        Self {
+            handle: DispatcherHandle {
+                sender: sender_half,
+            },
+            token,
+            task,
+        }
+    }
+
+    /// Returns a handle for queueing messages.
+    pub fn handle(&self) -> DispatcherHandle<T> {
+        self.handle.clone()
+    }
+
+    /// Stops accepting messages, sends the messages that are due, and waits for the task to
+    /// end. Messages waiting for a retry stay in the store for the next run.
+    pub async fn shutdown(self) {
+        self.token.cancel();
+        self.task.await.ok();
+    }
+}
+
+struct Worker<S, F, T> {
+    sender: S,
+    store: Arc<dyn QueueStore<Pending<T>>>,
+    options: DispatcherOptions,
+    on_outcome: F,
+}
+
+impl<S, F, T> Worker<S, F, T>
+where
+    S: BatchSender<T>,
+    F: FnMut(DispatchOutcome<T>),
+    T: Clone,
+{
+    async fn run(mut self, mut receiver: mpsc::Receiver<Received<T>>, token: CancellationToken) {
+        let mut interval = tokio::time::interval(self.options.flush_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut received = 0;
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = token.cancelled() => break,
+                message = receiver.recv() => match message {
+                    Some((message, acknowledge)) => {
+                        acknowledge.send(self.store(message)).ok();
+                        received += 1;
+                        if received < self.options.batch_size {
+                            continue;
+                        }
+                    }
+                    None => break,
+                },
+                _ = interval.tick() => {}
+            }
+
+            received = 0;
+            self.flush().await;
+        }
+
+        receiver.close();
+        while let Some((message, acknowledge)) = receiver.recv().await {
+            acknowledge.send(self.store(message)).ok();
+        }
+        self.flush().await;
+    }
+
+    fn store(&self, message: T) -> Result<String, SdkError> {
+        let id = Uuid::new_v4().to_string();
+        self.store
+            .push(QueuedItem {
+                id: id.clone(),
+                due_at: Utc::now(),
+                item: Pending {
+                    message,
+                    attempts: 0,
+                },
+            })
+            .map_err(SdkError::Store)?;
+
+        Ok(id)
+    }
+
+    // Sends batches of due messages until none are left.
+    async fn flush(&mut self) {
+        let batch_size = self.options.batch_size.max(1);
+
+        loop {
+            let now = Utc::now();
+            let lease_until =
+                now + chrono::Duration::from_std(self.options.lease).unwrap_or_default();
+            let batch = match self.store.lease_due(now, lease_until, batch_size) {
+                Ok(batch) => batch,
+                Err(error) => {
+                    (self.on_outcome)(DispatchOutcome::StoreFailed(error));
+                    return;
+                }
+            };
+            if batch.is_empty() {
+                return;
+            }
+            let is_last = batch.len() < batch_size;
+
+            let messages = batch.iter().map(|item| item.item.message.clone()).collect();
+            let result = self.sender.send_batch(messages).await;
+            let outcome = self.settle(batch, result);
+            let is_retrying = matches!(outcome, DispatchOutcome::Retrying { .. });
+            (self.on_outcome)(outcome);
+
+            // Later batches would likely fail the same way, so they wait for the next flush.
+            if is_last || is_retrying {
+                return;
+            }
+        }
+    }
+
+    // Removes a sent batch from the store, or schedules its retry.
+    fn settle(
+        &self,
+        batch: Vec<QueuedItem<Pending<T>>>,
+        result: Result<(), SdkError>,
+    ) -> DispatchOutcome<T> {
+        let ids: Vec<String> = batch.iter().map(|item| item.id.clone()).collect();
+        let attempts = batch
+            .iter()
+            .map(|item| item.item.attempts)
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let delay = match &result {
+            Err(error) if error.is_retryable() && attempts < self.options.max_attempts => {
+                Some(self.options.retry_delay(attempts, error))
+            }
+            _ => None,
+        };
+
+        let update = match delay {
+            Some(delay) => {
+                let due_at = self.options.retry_due_at(Utc::now(), delay);
+                batch.iter().try_for_each(|item| {
+                    self.store.replace(QueuedItem {
+                        id: item.id.clone(),
+                        due_at,
+                        item: Pending {
+                            message: item.item.message.clone(),
+                            attempts,
+                        },
+                    })
+                })
+            }
+            None => ids
+                .iter()
+                .try_for_each(|id| self.store.remove(id).map(|_| ())),
+        };
+        if let Err(error) = update {
+            return DispatchOutcome::StoreFailed(error);
+        }
+
+        match (result, delay) {
+            (Ok(()), _) => DispatchOutcome::Sent { ids },
+            (Err(error), Some(delay)) => {
+                log::debug!(
+                    "retrying batch of {} messages in {:?} after attempt {}: {}",
+                    ids.len(),
+                    delay,
+                    attempts,
+                    error
+                );
+                DispatchOutcome::Retrying {
+                    ids,
+                    attempts,
+                    delay,
+                    error,
+                }
+            }
+            (Err(error), None) => DispatchOutcome::Failed {
+                items: batch,
+                error,
+            },
+        }
+    }
+}
@@ -1,24 +1,25 @@
 //! Module with client and endpoint functions for the Email channel.
 
-use std::collections::HashMap;
 use std::io;
 
 use reqwest::multipart::Form;
 use reqwest::multipart::Part;
 use tokio::io::AsyncReadExt;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::api::{
-    build_api_error, send_multipart_request, send_no_body_request, send_valid_json_request,
-    SdkError, SdkResponse,
+    build_http_client, build_response, fill_platform_ids, send_multipart_request,
+    send_no_body_request, send_raw_request, send_valid_json_request, NoContent, SdkError,
+    SdkResponse,
 };
-use crate::configuration::Configuration;
+use crate::configuration::{Channel, Configuration};
 use crate::model::email::{
-    AddDomainRequestBody, AddDomainResponseBody, BulksQueryParameters, BulksResponseBody,
-    DeliveryReportsQueryParameters, DeliveryReportsResponseBody, DomainResponseBody,
-    DomainsQueryParameters, DomainsResponseBody, LogsQueryParameters, LogsResponseBody,
-    RescheduleQueryParameters, RescheduleRequestBody, RescheduleResponseBody,
-    ScheduledStatusQueryParameters, ScheduledStatusResponseBody, SendRequestBody, SendResponseBody,
+    inline_image_cid, AddDomainRequestBody, AddDomainResponseBody, BulkStatus,
+    BulksQueryParameters, BulksResponseBody, DeliveryReportsQueryParameters,
+    DeliveryReportsResponseBody, DomainResponseBody, DomainsQueryParameters, DomainsResponseBody,
+    LogsQueryParameters, LogsResponseBody, RescheduleQueryParameters, RescheduleRequestBody,
+    RescheduleResponseBody, ScheduledStatusQueryParameters, ScheduledStatusResponseBody,
+    SendRequestBody, SendResponseBody, TrackingReportsQueryParameters, TrackingReportsResponseBody,
     UpdateScheduledStatusQueryParameters, UpdateScheduledStatusRequestBody,
     UpdateScheduledStatusResponseBody, UpdateTrackingRequestBody, UpdateTrackingResponseBody,
     ValidateAddressRequestBody, ValidateAddressResponseBody,
@@ -26,11 +27,15 @@ use crate::model::email::{
 
 pub const PATH_ADD_DOMAIN: &str = "/email/1/domains";
 pub const PATH_DELETE_DOMAIN: &str = "/email/1/domains/{domainName}";
+pub const PATH_GET_BOUNCES: &str = "/email/1/reports/bounces";
 pub const PATH_GET_BULKS: &str = "/email/1/bulks";
+pub const PATH_GET_CLICKS: &str = "/email/1/reports/clicks";
+pub const PATH_GET_COMPLAINTS: &str = "/email/1/reports/complaints";
 pub const PATH_GET_DELIVERY_REPORTS: &str = "/email/1/reports";
 pub const PATH_GET_DOMAIN: &str = "/email/1/domains/{domainName}";
 pub const PATH_GET_DOMAINS: &str = "/email/1/domains";
 pub const PATH_GET_LOGS: &str = "/email/1/logs";
+pub const PATH_GET_OPENS: &str = "/email/1/reports/opens";
 pub const PATH_GET_SCHEDULED_STATUS: &str = "/email/1/bulks/status";
 pub const PATH_RESCHEDULE: &str = "/email/1/bulks";
 pub const PATH_SEND: &str = "/email/3/send";
@@ -39,16 +44,89 @@ pub const PATH_UPDATE_TRACKING: &str = "/email/1/domains/{domainName}/tracking";
 pub const PATH_VALIDATE: &str = "/email/2/validation";
 pub const PATH_VERIFY_DOMAIN: &str = "/email/1/domains/{domainName}/verify";
 
-async fn file_part(file_name: String) -> io::Result<Part> {
-    let mut file = tokio::fs::File::open(file_name.clone()).await?;
+/// Maximum total size in bytes of the attachments and inline images of an email.
+pub const MAX_ATTACHMENTS_SIZE: u64 = 20 * 1024 * 1024;
+
+const SNIFF_LENGTH: usize = 16;
+
+/// Name, content type and size of a file sent with an email.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttachmentInfo {
+    /// Path of the file.
+    pub path: String,
+
+    /// File name sent to the API, without directories.
+    pub file_name: String,
+
+    /// Content type, detected from the first bytes of the file or from its extension.
+    pub content_type: String,
+
+    /// Size of the file in bytes.
+    pub size: u64,
+}
+
+/// Reads the metadata of a file to be attached to an email, detecting its content type.
+pub async fn attachment_info(path: &str) -> io::Result<AttachmentInfo> {
+    let file = tokio::fs::File::open(path).await?;
+    let size = file.metadata().await?.len();
+
+    let mut head = Vec::with_capacity(SNIFF_LENGTH);
+    file.take(SNIFF_LENGTH as u64)
+        .read_to_end(&mut head)
+        .await?;
+
+    Ok(AttachmentInfo {
+        path: path.to_string(),
+        file_name: inline_image_cid(path),
+        content_type: content_type(path, &head),
+        size,
+    })
+}
+
+// Well-known types are detected from their magic bytes, everything else (including Office
+// documents, which are zip files) from the file extension.
+fn content_type(path: &str, head: &[u8]) -> String {
+    let sniffed = if head.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if head.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if head.starts_with(b"RIFF") && head.get(8..12) == Some(b"WEBP".as_slice()) {
+        Some("image/webp")
+    } else if head.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else {
+        None
+    };
+
+    sniffed
+        .map(str::to_string)
+        .or_else(|| mime_guess::from_path(path).first_raw().map(str::to_string))
+        .unwrap_or_else(|| "application/octet-stream".to_string())
+}
+
+async fn file_part(path: String) -> Result<Part, SdkError> {
+    let mut file = tokio::fs::File::open(&path).await?;
     let mut buffer = Vec::new();
     let count = file.read_to_end(&mut buffer).await?;
+    let content_type = content_type(&path, &buffer[..count.min(SNIFF_LENGTH)]);
 
-    Ok(Part::stream_with_length(buffer, count as u64).file_name(file_name))
+    // The file name doubles as the Content-ID of inline images, so send it without directories.
+    Ok(Part::stream_with_length(buffer, count as u64)
+        .file_name(inline_image_cid(&path))
+        .mime_str(&content_type)?)
 }
 
-async fn build_form(request_body: SendRequestBody) -> io::Result<Form> {
-    let mut form = Form::new().text("to", request_body.to.clone());
+async fn build_form(request_body: SendRequestBody) -> Result<Form, SdkError> {
+    let mut form = Form::new();
+
+    if !request_body.to.is_empty() {
+        form = form.text("to", request_body.to.clone());
+    }
+    for recipient in request_body.recipients.iter().flatten() {
+        form = form.text("to", serde_json::to_string(recipient)?);
+    }
 
     if let Some(from) = request_body.from {
         form = form.text("from", from);
@@ -132,10 +210,67 @@ async fn build_form(request_body: SendRequestBody) -> io::Result<Form> {
     if let Some(landing_page_id) = request_body.landing_page_id {
         form = form.text("landingPageId", landing_page_id);
     }
+    if let Some(application_id) = request_body.application_id {
+        form = form.text("applicationId", application_id);
+    }
+    if let Some(entity_id) = request_body.entity_id {
+        form = form.text("entityId", entity_id);
+    }
 
     Ok(form)
 }
 
+/// Sends emails from one sender address, so it doesn't have to be repeated in every request
+/// body. Created with `EmailClient::sender`.
+///
+/// # Example
+/// ```no_run
+/// # use infobip_sdk::api::email::EmailClient;
+/// # use infobip_sdk::configuration::Configuration;
+/// # use infobip_sdk::model::email::SendRequestBody;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+/// let sender = client.sender("Orders <orders@company.com>");
+///
+/// let mut request_body = SendRequestBody::new("someone@domain.com");
+/// request_body.subject = Some("Your order".to_string());
+/// request_body.text = Some("Your order has shipped.".to_string());
+///
+/// sender.send(request_body).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EmailSender {
+    client: EmailClient,
+    from: String,
+}
+
+impl EmailSender {
+    /// Returns the sender address.
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the client the sender sends with.
+    pub fn client(&self) -> &EmailClient {
+        &self.client
+    }
+
+    /// Sends an email from the sender address, replacing the `from` of the request body. See
+    /// `EmailClient::send`.
+    pub async fn send(
+        &self,
+        mut request_body: SendRequestBody,
+    ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        request_body.from = Some(self.from.clone());
+
+        self.client.send(request_body).await
+    }
+}
+
 /// Main asynchronous client for the Infobip Email channel.
 #[derive(Clone, Debug)]
 pub struct EmailClient {
@@ -145,10 +280,25 @@ pub struct EmailClient {
 
 impl EmailClient {
     /// Builds and returns a new asynchronous `EmailClient` with a specified configuration.
+    /// The client gets its own connection pool, see `with_http_client` to share one.
     pub fn with_configuration(configuration: Configuration) -> Self {
+        Self::with_http_client(build_http_client(&configuration), configuration)
+    }
+
+    /// Builds and returns a new asynchronous `EmailClient` that sends requests with the given HTTP
+    /// client. See `api::client` for sharing an HTTP client between channel clients.
+    pub fn with_http_client(http_client: reqwest::Client, configuration: Configuration) -> Self {
         EmailClient {
-            configuration,
-            http_client: reqwest::Client::new(),
+            configuration: configuration.for_channel(Channel::Email),
+            http_client,
+        }
+    }
+
+    /// Returns a handle that sends emails from the given sender address.
+    pub fn sender(&self, from: &str) -> EmailSender {
+        EmailSender {
+            client: self.clone(),
+            from: from.to_string(),
         }
     }
 
@@ -169,7 +319,10 @@ impl EmailClient {
     /// request_body.from = Some("someone@company.com".to_string());
     /// request_body.subject = Some("Test subject".to_string());
     /// request_body.text = Some("Hello world!".to_string());
-    /// request_body.attachments = Some(vec!["path/to/attachment".to_string()]);
+    /// request_body.add_attachment("path/to/attachment.pdf");
+    ///
+    /// let logo_cid = request_body.add_inline_image("path/to/logo.png");
+    /// request_body.html = Some(format!("<img src=\"cid:{logo_cid}\"><p>Hello world!</p>"));
     ///
     /// let response = client.send(request_body).await?;
     ///
@@ -179,9 +332,11 @@ impl EmailClient {
     /// ```
     pub async fn send(
         &self,
-        request_body: SendRequestBody,
+        mut request_body: SendRequestBody,
     ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
         request_body.validate()?;
+        self.check_attachments(&request_body).await?;
+        fill_platform_ids(&mut request_body, &self.configuration);
 
         let form = build_form(request_body).await?;
 
@@ -194,17 +349,63 @@ impl EmailClient {
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        build_response(response).await
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
+    /// Reads the attachments and inline images of a request body, checking that they exist and
+    /// that their total size is within [`MAX_ATTACHMENTS_SIZE`]. `send` calls it before uploading
+    /// anything.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::SendRequestBody;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = SendRequestBody::new("someone@domain.com");
+    /// request_body.add_attachment("path/to/report.pdf");
+    ///
+    /// for attachment in client.check_attachments(&request_body).await? {
+    ///     println!("{}: {} ({} bytes)", attachment.file_name, attachment.content_type, attachment.size);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_attachments(
+        &self,
+        request_body: &SendRequestBody,
+    ) -> Result<Vec<AttachmentInfo>, SdkError> {
+        let paths = request_body
+            .attachments
+            .iter()
+            .flatten()
+            .chain(request_body.inline_images.iter().flatten());
+
+        let mut attachments = Vec::new();
+        for path in paths {
+            attachments.push(attachment_info(path).await?);
+        }
+
+        let total_size: u64 = attachments.iter().map(|attachment| attachment.size).sum();
+        if total_size > MAX_ATTACHMENTS_SIZE {
+            let mut error = ValidationError::new("attachments_too_large");
+            error.message = Some(
+                format!(
+                    "attachments take {} bytes, the maximum is {}",
+                    total_size, MAX_ATTACHMENTS_SIZE
+                )
+                .into(),
+            );
+            let mut errors = ValidationErrors::new();
+            errors.add("attachments", error);
+            return Err(SdkError::Validation(errors));
         }
+
+        Ok(attachments)
     }
 
     /// See the scheduled time of your Email messages.
@@ -234,28 +435,16 @@ impl EmailClient {
     ) -> Result<SdkResponse<BulksResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_BULKS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Change the date and time for sending scheduled messages.
@@ -287,29 +476,17 @@ impl EmailClient {
     ) -> Result<SdkResponse<RescheduleResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            query_parameters,
             reqwest::Method::PUT,
             PATH_RESCHEDULE,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// See the status of scheduled email messages.
@@ -339,28 +516,16 @@ impl EmailClient {
     ) -> Result<SdkResponse<ScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_SCHEDULED_STATUS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Change status or completely cancel sending of scheduled messages.
@@ -392,29 +557,125 @@ impl EmailClient {
     ) -> Result<SdkResponse<UpdateScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map = HashMap::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            query_parameters,
             reqwest::Method::PUT,
             PATH_UPDATE_SCHEDULED_STATUS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        build_response(response).await
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+    /// Returns the status of the scheduled messages with the given bulk ID, or `None` if the
+    /// response doesn't include it. This is a shortcut for `scheduled_status`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::BulkStatus;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client.bulk_status("some-bulk-id").await?;
+    ///
+    /// if response.body == Some(BulkStatus::Pending) {
+    ///     client.update_bulk_status("some-bulk-id", BulkStatus::Paused).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bulk_status(
+        &self,
+        bulk_id: &str,
+    ) -> Result<SdkResponse<Option<BulkStatus>>, SdkError> {
+        let response = self
+            .scheduled_status(ScheduledStatusQueryParameters::new(bulk_id))
+            .await?;
+
+        let bulks = response.body.bulks.unwrap_or_default();
+        let status = bulks
+            .iter()
+            .find(|bulk| bulk.bulk_id.as_deref() == Some(bulk_id))
+            .or_else(|| bulks.first().filter(|_| bulks.len() == 1))
+            .and_then(|bulk| bulk.status);
+
+        Ok(SdkResponse {
+            body: status,
+            status: response.status,
+            duration: response.duration,
+        })
+    }
+
+    /// Pauses, resumes or cancels the scheduled messages with the given bulk ID. This is a
+    /// shortcut for `update_scheduled_status`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::BulkStatus;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client.update_bulk_status("some-bulk-id", BulkStatus::Canceled).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_bulk_status(
+        &self,
+        bulk_id: &str,
+        status: BulkStatus,
+    ) -> Result<SdkResponse<UpdateScheduledStatusResponseBody>, SdkError> {
+        self.update_scheduled_status(
+            UpdateScheduledStatusQueryParameters::new(bulk_id),
+            UpdateScheduledStatusRequestBody::new(status),
+        )
+        .await
+    }
+
+    /// Changes the sending time of the scheduled messages with the given bulk ID. This is a
+    /// shortcut for `reschedule`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client
+    ///     .reschedule_bulk("some-bulk-id", "2030-10-16T12:00:00.000+0000")
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reschedule_bulk(
+        &self,
+        bulk_id: &str,
+        send_at: &str,
+    ) -> Result<SdkResponse<RescheduleResponseBody>, SdkError> {
+        self.reschedule(
+            RescheduleQueryParameters::new(bulk_id),
+            RescheduleRequestBody::new(send_at),
+        )
+        .await
     }
 
     ///  one-time delivery reports for all sent emails.
@@ -444,37 +705,16 @@ impl EmailClient {
     ) -> Result<SdkResponse<DeliveryReportsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_DELIVERY_REPORTS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     ///  email logs of sent Email messagesId for request. Email logs
@@ -505,52 +745,157 @@ impl EmailClient {
     ) -> Result<SdkResponse<LogsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(from) = query_parameters.from {
-            parameters_map.insert("from".to_string(), from);
-        }
-        if let Some(to) = query_parameters.to {
-            parameters_map.insert("to".to_string(), to);
-        }
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(general_status) = query_parameters.general_status {
-            parameters_map.insert("generalStatus".to_string(), general_status);
-        }
-        if let Some(sent_since) = query_parameters.sent_since {
-            parameters_map.insert("sentSince".to_string(), sent_since);
-        }
-        if let Some(sent_until) = query_parameters.sent_until {
-            parameters_map.insert("sentUntil".to_string(), sent_until);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_LOGS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        build_response(response).await
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+    /// Get the opens of sent emails. Opens are only tracked for domains with open tracking
+    /// enabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::TrackingReportsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_params = TrackingReportsQueryParameters::new();
+    /// query_params.bulk_id = Some("some-bulk-id".to_string());
+    ///
+    /// let response = client.opens(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn opens(
+        &self,
+        query_parameters: TrackingReportsQueryParameters,
+    ) -> Result<SdkResponse<TrackingReportsResponseBody>, SdkError> {
+        self.tracking_reports(query_parameters, PATH_GET_OPENS)
+            .await
+    }
+
+    /// Get the clicks on links of sent emails. Clicks are only tracked for domains with click
+    /// tracking enabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::TrackingReportsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_params = TrackingReportsQueryParameters::new();
+    /// query_params.bulk_id = Some("some-bulk-id".to_string());
+    ///
+    /// let response = client.clicks(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clicks(
+        &self,
+        query_parameters: TrackingReportsQueryParameters,
+    ) -> Result<SdkResponse<TrackingReportsResponseBody>, SdkError> {
+        self.tracking_reports(query_parameters, PATH_GET_CLICKS)
+            .await
+    }
+
+    /// Get the bounces of sent emails, i.e. emails rejected by the recipient's mail server.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::TrackingReportsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_params = TrackingReportsQueryParameters::new();
+    /// query_params.bulk_id = Some("some-bulk-id".to_string());
+    ///
+    /// let response = client.bounces(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bounces(
+        &self,
+        query_parameters: TrackingReportsQueryParameters,
+    ) -> Result<SdkResponse<TrackingReportsResponseBody>, SdkError> {
+        self.tracking_reports(query_parameters, PATH_GET_BOUNCES)
+            .await
+    }
+
+    /// Get the spam complaints of recipients of sent emails.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::email::EmailClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::email::TrackingReportsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_params = TrackingReportsQueryParameters::new();
+    /// query_params.bulk_id = Some("some-bulk-id".to_string());
+    ///
+    /// let response = client.complaints(query_params).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complaints(
+        &self,
+        query_parameters: TrackingReportsQueryParameters,
+    ) -> Result<SdkResponse<TrackingReportsResponseBody>, SdkError> {
+        self.tracking_reports(query_parameters, PATH_GET_COMPLAINTS)
+            .await
+    }
+
+    async fn tracking_reports(
+        &self,
+        query_parameters: TrackingReportsQueryParameters,
+        path: &str,
+    ) -> Result<SdkResponse<TrackingReportsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            query_parameters,
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
     }
 
     /// Run validation to identify poor quality emails to clean up your recipient list.
@@ -582,23 +927,13 @@ impl EmailClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            (),
             reqwest::Method::POST,
             PATH_VALIDATE,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     ///  all domains associated with the account. It also provides details of the
@@ -629,34 +964,16 @@ impl EmailClient {
     ) -> Result<SdkResponse<DomainsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(size) = query_parameters.size {
-            parameters_map.insert("size".to_string(), size.to_string());
-        }
-        if let Some(page) = query_parameters.page {
-            parameters_map.insert("page".to_string(), page.to_string());
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_DOMAINS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// This method allows you to add new domains with a limit to create a maximum of 1000 domains
@@ -689,23 +1006,13 @@ impl EmailClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            (),
             reqwest::Method::POST,
             PATH_ADD_DOMAIN,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     ///  the details of the domain like the DNS records, tracking details, active/blocked
@@ -736,23 +1043,13 @@ impl EmailClient {
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            (),
             reqwest::Method::GET,
             path.as_str(),
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// This method allows you to delete an existing domain.
@@ -767,32 +1064,28 @@ impl EmailClient {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let status = client.delete_domain("example.com").await?;
+    /// let response = client.delete_domain("example.com").await?;
     ///
-    /// assert_eq!(status, StatusCode::NO_CONTENT);
+    /// assert_eq!(response.status, StatusCode::NO_CONTENT);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete_domain(&self, domain_name: &str) -> Result<reqwest::StatusCode, SdkError> {
+    pub async fn delete_domain(
+        &self,
+        domain_name: &str,
+    ) -> Result<SdkResponse<NoContent>, SdkError> {
         let path = PATH_DELETE_DOMAIN.replace("{domainName}", domain_name);
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            (),
             reqwest::Method::DELETE,
             path.as_str(),
         )
         .await?;
 
-        let status = response.status();
-
-        if status.is_success() {
-            Ok(status)
-        } else {
-            let text = response.text().await?;
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Update tracking events for the provided domain. Tracking events can be updated only for
@@ -829,23 +1122,13 @@ impl EmailClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
+            (),
             reqwest::Method::PUT,
             path.as_str(),
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Verify records(TXT, MX, DKIM) associated with the provided domain.
@@ -860,31 +1143,47 @@ impl EmailClient {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = EmailClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let status = client.verify_domain("example.com").await?;
+    /// let response = client.verify_domain("example.com").await?;
     ///
-    /// assert_eq!(status, StatusCode::ACCEPTED);
+    /// assert_eq!(response.status, StatusCode::ACCEPTED);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn verify_domain(&self, domain_name: &str) -> Result<reqwest::StatusCode, SdkError> {
+    pub async fn verify_domain(
+        &self,
+        domain_name: &str,
+    ) -> Result<SdkResponse<NoContent>, SdkError> {
         let path = PATH_VERIFY_DOMAIN.replace("{domainName}", domain_name);
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            (),
             reqwest::Method::POST,
             path.as_str(),
         )
         .await?;
 
-        let status = response.status();
+        build_response(response).await
+    }
 
-        if status.is_success() {
-            Ok(status)
-        } else {
-            let text = response.text().await?;
-            Err(build_api_error(status, &text))
-        }
+    /// Send a request with a JSON body built by hand, or no body, to an Email endpoint the SDK
+    /// doesn't cover yet. Endpoints taking multipart forms, like sending emails, need the typed
+    /// methods. The body is not validated, and the response body is returned as JSON. See
+    /// `SmsClient::send_raw`.
+    pub async fn send_raw(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        request_body: Option<serde_json::Value>,
+    ) -> Result<SdkResponse<serde_json::Value>, SdkError> {
+        send_raw_request(
+            &self.http_client,
+            &self.configuration,
+            method,
+            path,
+            request_body,
+        )
+        .await
     }
 }
@@ -1,21 +1,76 @@
 //! Endpoint functions and base response and error types
+#[cfg(feature = "recording")]
+use crate::api::recording::RecordedRequest;
 use crate::configuration::{ApiKey, Configuration};
+use crate::masking::mask_destination;
+#[cfg(any(
+    feature = "email",
+    feature = "sms",
+    feature = "tfa",
+    feature = "whatsapp"
+))]
+use crate::model::PlatformIds;
+use crate::model::{QueryParams, QueryParamsError};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use reqwest::{RequestBuilder, Response, StatusCode};
-use serde::Deserialize;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Deserializer};
 use serde_derive::Serialize;
+use std::future::Future;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, fmt};
 use thiserror::Error;
-use validator::Validate;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+#[cfg(feature = "account")]
+pub mod account;
+
+pub mod cancellation;
+
+#[cfg(any(feature = "email", feature = "sms", feature = "whatsapp"))]
+pub mod channel;
+
+pub mod circuit_breaker;
+
+pub mod client;
+
+#[cfg(feature = "sms")]
+pub mod deduplication;
+
+#[cfg(feature = "dispatcher")]
+pub mod dispatcher;
 
 #[cfg(feature = "email")]
 pub mod email;
 
+#[cfg(all(feature = "sms", feature = "whatsapp"))]
+pub mod orchestration;
+
+pub mod pool;
+
+#[cfg(feature = "sms")]
+pub mod preview_cache;
+
+pub mod response_validation;
+
+#[cfg(any(feature = "email", feature = "sms", feature = "whatsapp"))]
+pub mod reconciliation;
+
+#[cfg(feature = "recording")]
+pub mod recording;
+
 #[cfg(feature = "sms")]
 pub mod sms;
 
+#[cfg(feature = "tfa")]
+pub mod tfa;
+
 #[cfg(feature = "whatsapp")]
 pub mod whatsapp;
 
+#[cfg(feature = "whatsapp")]
+pub mod whatsapp_scheduler;
+
 /// Holds the possible errors that can happen when calling the Infobip API.
 #[derive(Error, Debug)]
 pub enum SdkError {
@@ -28,27 +83,276 @@ pub enum SdkError {
     #[error("serialization error")]
     Serde(#[from] serde_json::Error),
 
+    #[error("query parameters error")]
+    QueryParams(#[from] QueryParamsError),
+
     #[error("API request error")]
     ApiRequestError(#[from] ApiError),
 
     #[error("IO error")]
     Io(#[from] std::io::Error),
+
+    #[error("queue store error")]
+    Store(#[source] crate::queue::StoreError),
+
+    /// A send with generated IDs failed after the IDs were assigned. The messages may still have
+    /// been accepted, so look them up by their IDs before retrying.
+    #[error("sending with bulk ID {bulk_id} failed")]
+    SendFailed {
+        bulk_id: String,
+        message_ids: Vec<String>,
+        #[source]
+        source: Box<SdkError>,
+    },
+
+    /// The request repeats a message sent to the destinations within the deduplication window,
+    /// see `api::deduplication`. Nothing was sent. The destinations are masked in the message of
+    /// the error, see `masking`.
+    #[error("duplicate message suppressed for {}", mask_destinations(destinations))]
+    DuplicateSuppressed { destinations: Vec<String> },
+
+    /// A cassette in replay mode has no recorded response for the request, see
+    /// `api::recording`.
+    #[error("no recorded response for {method} {path}")]
+    NotRecorded { method: String, path: String },
+
+    /// The call was cancelled by a token or deadline before it completed, see
+    /// `api::cancellation`. A request already sent may still have been accepted.
+    #[error("cancelled")]
+    Cancelled,
+
+    /// The circuit breaker of the configuration is open after repeated failures, see
+    /// `api::circuit_breaker`. Nothing was sent.
+    #[error("circuit open, retry after {retry_after:?}")]
+    CircuitOpen { retry_after: Duration },
+
+    /// The API key isn't allowed to use the required scopes, see
+    /// `api::account::AccountClient::require_scopes`.
+    #[error("API key is missing scopes {}", missing.join(", "))]
+    MissingScopes { missing: Vec<String> },
+
+    /// More logs than fit in one page were sent in the same millisecond, and the API can't page
+    /// through them, see `api::sms::SmsClient::logs_paged`. The logs older than `sent_at` were
+    /// not fetched.
+    #[error("more than {limit} logs were sent at {sent_at}")]
+    LogsPageOverflow { sent_at: String, limit: i32 },
+}
+
+fn mask_destinations(destinations: &[String]) -> String {
+    destinations
+        .iter()
+        .map(|destination| mask_destination(destination))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Classification of an error, for deciding how to handle it without matching on descriptions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Too many requests were sent. Retry later, ideally with backoff.
+    Throttling,
+    /// The credentials are missing, invalid, or not allowed to call the endpoint.
+    Auth,
+    /// The request has invalid fields, found by the SDK or by the API.
+    Validation,
+    /// A network failure, timeout or server error that may not happen again.
+    Temporary,
+    /// The request was rejected and will be rejected again if sent unchanged.
+    Permanent,
+}
+
+impl ErrorKind {
+    /// Returns `true` if sending the same request again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorKind::Throttling | ErrorKind::Temporary)
+    }
+
+    fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => ErrorKind::Throttling,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ErrorKind::Auth,
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => ErrorKind::Validation,
+            StatusCode::REQUEST_TIMEOUT => ErrorKind::Temporary,
+            status if status.is_server_error() => ErrorKind::Temporary,
+            _ => ErrorKind::Permanent,
+        }
+    }
+
+    // Infobip error IDs sent as `messageId` of the service exception.
+    fn from_error_id(id: &str) -> Option<Self> {
+        match id {
+            "TOO_MANY_REQUESTS" | "E429" => Some(ErrorKind::Throttling),
+            "UNAUTHORIZED" | "FORBIDDEN" | "E401" | "E403" => Some(ErrorKind::Auth),
+            "BAD_REQUEST" | "E400" => Some(ErrorKind::Validation),
+            "GENERAL_ERROR" | "INTERNAL_SERVER_ERROR" | "SERVICE_UNAVAILABLE" | "E500" => {
+                Some(ErrorKind::Temporary)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl SdkError {
+    /// Returns the classification of the error.
+    pub fn error_kind(&self) -> ErrorKind {
+        match self {
+            SdkError::Validation(_) => ErrorKind::Validation,
+            SdkError::Reqwest(error) => match error.status() {
+                Some(status) => ErrorKind::from_status(status),
+                None if error.is_timeout() || error.is_connect() || error.is_request() => {
+                    ErrorKind::Temporary
+                }
+                None => ErrorKind::Permanent,
+            },
+            SdkError::ApiRequestError(error) => error.error_kind(),
+            SdkError::Store(_) | SdkError::CircuitOpen { .. } => ErrorKind::Temporary,
+            SdkError::SendFailed { source, .. } => source.error_kind(),
+            SdkError::MissingScopes { .. } => ErrorKind::Auth,
+            SdkError::Serde(_)
+            | SdkError::QueryParams(_)
+            | SdkError::Io(_)
+            | SdkError::DuplicateSuppressed { .. }
+            | SdkError::NotRecorded { .. }
+            | SdkError::LogsPageOverflow { .. }
+            | SdkError::Cancelled => ErrorKind::Permanent,
+        }
+    }
+
+    /// Returns `true` if sending the same request again may succeed. Throttling and temporary
+    /// errors are retryable.
+    pub fn is_retryable(&self) -> bool {
+        self.error_kind().is_retryable()
+    }
+
+    /// Returns how long to wait before sending again, if the API or the circuit breaker said so.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SdkError::ApiRequestError(error) => error.retry_after,
+            SdkError::CircuitOpen { retry_after } => Some(*retry_after),
+            SdkError::SendFailed { source, .. } => source.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// Returns the field errors of a `Validation` error, or an empty list for other errors.
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        match self {
+            SdkError::Validation(errors) => flatten_validation_errors(errors),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A validation error of a single field of a request body.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Path of the field with the names used in the Rust model, e.g.
+    /// `messages[0].destinations[1].to`. Errors of a whole struct, like a missing field that
+    /// depends on another, have the path of the struct; it is empty for the request body itself.
+    pub path: String,
+
+    /// Error code, e.g. `length` or `url`.
+    pub code: String,
+
+    /// Human-readable description of the error, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Flattens nested validation errors into one error per field, sorted by path.
+pub fn flatten_validation_errors(errors: &ValidationErrors) -> Vec<FieldError> {
+    let mut field_errors = Vec::new();
+    collect_field_errors(errors, "", &mut field_errors);
+    field_errors.sort_by(|a, b| a.path.cmp(&b.path));
+
+    field_errors
+}
+
+fn collect_field_errors(
+    errors: &ValidationErrors,
+    prefix: &str,
+    field_errors: &mut Vec<FieldError>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = match (*field, prefix.is_empty()) {
+            ("__all__", _) => prefix.to_string(),
+            (field, true) => field.to_string(),
+            (field, false) => format!("{}.{}", prefix, field),
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                field_errors.extend(errors.iter().map(|error| FieldError {
+                    path: path.clone(),
+                    code: error.code.to_string(),
+                    message: error.message.as_ref().map(|message| message.to_string()),
+                }));
+            }
+            ValidationErrorsKind::Struct(errors) => {
+                collect_field_errors(errors, &path, field_errors);
+            }
+            ValidationErrorsKind::List(errors) => {
+                for (index, errors) in errors {
+                    collect_field_errors(errors, &format!("{}[{}]", path, index), field_errors);
+                }
+            }
+        }
+    }
 }
 
 /// Holds the status code and error details when a 4xx or 5xx response is received.
 #[derive(Error, Clone, Debug)]
 pub struct ApiError {
-    pub details: ApiErrorDetails,
+    /// Details of the error, if the body is an Infobip error. Responses of gateways and proxies,
+    /// like an HTML `503` page or an empty `429`, have none.
+    pub details: Option<Box<ApiErrorDetails>>,
+
+    /// Raw response body, kept when it isn't an Infobip error and isn't empty.
+    pub body: Option<String>,
+
     pub status: StatusCode,
+
+    /// How long to wait before sending again, from the `Retry-After` header that Infobip sends
+    /// with some `429 Too Many Requests` and `503 Service Unavailable` responses.
+    pub retry_after: Option<Duration>,
+}
+
+impl ApiError {
+    /// Returns the classification of the error, from the Infobip error ID if known, otherwise
+    /// from the status code. Responses that aren't Infobip errors, like an HTML page of a
+    /// gateway, are classified from the status code too.
+    pub fn error_kind(&self) -> ErrorKind {
+        let Some(details) = &self.details else {
+            return ErrorKind::from_status(self.status);
+        };
+        let exception = &details.request_error.service_exception;
+        if exception
+            .validation_errors
+            .as_ref()
+            .is_some_and(|errors| !errors.is_empty())
+        {
+            return ErrorKind::Validation;
+        }
+
+        exception
+            .message_id
+            .as_deref()
+            .and_then(ErrorKind::from_error_id)
+            .unwrap_or_else(|| ErrorKind::from_status(self.status))
+    }
+
+    /// Returns `true` if sending the same request again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        self.error_kind().is_retryable()
+    }
 }
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "API request error: status: {} {}",
-            self.status, self.details
-        )
+        match &self.details {
+            Some(details) => write!(f, "API request error: status: {} {}", self.status, details),
+            None => write!(f, "API request error: status: {}", self.status),
+        }
     }
 }
 
@@ -98,16 +402,29 @@ impl fmt::Display for ApiErrorDetails {
 pub struct SdkResponse<T> {
     pub body: T,
     pub status: StatusCode,
+
+    /// Time from sending the request until its response body was read. Calls made of several
+    /// requests report the time of all of them, and answers from a cache report zero.
+    pub duration: Duration,
+}
+
+/// Body of a successful response without content, like the `204 No Content` responses of delete
+/// endpoints. A body sent anyway is ignored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NoContent;
+
+impl<'de> Deserialize<'de> for NoContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        IgnoredAny::deserialize(deserializer)?;
+
+        Ok(NoContent)
+    }
 }
 
 fn api_key_authorization_value(api_key: &ApiKey) -> String {
-    let key = api_key.key.to_owned();
-    let prefix = api_key
-        .prefix
-        .to_owned()
-        .unwrap_or_else(|| "App".to_string());
+    let prefix = api_key.prefix.as_deref().unwrap_or("App");
 
-    format!("{} {}", prefix, key)
+    format!("{} {}", prefix, api_key.key)
 }
 
 // Async version of add_auth, uses async request builder.
@@ -131,17 +448,40 @@ fn user_agent() -> &'static str {
     include!("../../version.txt")
 }
 
+// Returns the user agent with the configured suffix, if any.
+fn configured_user_agent(configuration: &Configuration) -> String {
+    match configuration.user_agent_suffix() {
+        Some(suffix) => format!("{} {}", user_agent(), suffix),
+        None => user_agent().to_string(),
+    }
+}
+
 // Adds user agent to the request builder. Synchronous version.
-fn add_user_agent(builder: RequestBuilder) -> RequestBuilder {
-    builder.header("User-Agent", user_agent())
+fn add_user_agent(builder: RequestBuilder, configuration: &Configuration) -> RequestBuilder {
+    builder.header("User-Agent", configured_user_agent(configuration))
+}
+
+// Adds the configured default headers, replacing any header with the same name.
+fn add_default_headers(builder: RequestBuilder, configuration: &Configuration) -> RequestBuilder {
+    builder.headers(configuration.default_headers().clone())
+}
+
+// Blocking version of add_default_headers, uses blocking request builder.
+#[cfg(feature = "sms")]
+fn add_default_headers_blocking(
+    builder: reqwest::blocking::RequestBuilder,
+    configuration: &Configuration,
+) -> reqwest::blocking::RequestBuilder {
+    builder.headers(configuration.default_headers().clone())
 }
 
 // Adds user agent to the request builder. Synchronous version.
 #[cfg(feature = "sms")]
 fn add_user_agent_blocking(
     builder: reqwest::blocking::RequestBuilder,
+    configuration: &Configuration,
 ) -> reqwest::blocking::RequestBuilder {
-    builder.header("User-Agent", user_agent())
+    builder.header("User-Agent", configured_user_agent(configuration))
 }
 
 // Blocking version of add_auth, uses blocking request builder.
@@ -164,49 +504,240 @@ fn add_auth_blocking(
     builder
 }
 
-fn build_api_error(status: StatusCode, text: &str) -> SdkError {
-    match serde_json::from_str(text) {
-        Ok(details) => SdkError::ApiRequestError(ApiError { details, status }),
-        Err(e) => SdkError::Serde(e),
+// Builds the HTTP client used by a channel client, with the configured timeout and proxy,
+// accepting compressed responses if enabled.
+fn build_http_client(configuration: &Configuration) -> reqwest::Client {
+    let mut builder = reqwest::Client::builder();
+    if let Some(timeout) = configuration.timeout() {
+        builder = builder.timeout(timeout);
     }
+    if let Some(proxy) = configuration.proxy() {
+        builder = builder.proxy(proxy.clone());
+    }
+    #[cfg(feature = "gzip")]
+    let builder = builder.gzip(configuration.compression());
+    #[cfg(feature = "deflate")]
+    let builder = builder.deflate(configuration.compression());
+
+    builder.build().expect("failed to build HTTP client")
+}
+
+// Blocking version of build_http_client.
+#[cfg(feature = "sms")]
+fn build_blocking_http_client(configuration: &Configuration) -> reqwest::blocking::Client {
+    let mut builder = reqwest::blocking::Client::builder();
+    if let Some(timeout) = configuration.timeout() {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(proxy) = configuration.proxy() {
+        builder = builder.proxy(proxy.clone());
+    }
+    #[cfg(feature = "gzip")]
+    let builder = builder.gzip(configuration.compression());
+    #[cfg(feature = "deflate")]
+    let builder = builder.deflate(configuration.compression());
+
+    builder.build().expect("failed to build HTTP client")
+}
+
+// Fills in the CPaaS X IDs of the configuration on messages that don't have their own.
+#[cfg(any(
+    feature = "email",
+    feature = "sms",
+    feature = "tfa",
+    feature = "whatsapp"
+))]
+fn fill_platform_ids<T: PlatformIds>(request_body: &mut T, configuration: &Configuration) {
+    request_body.fill_platform_ids(
+        configuration.application_id().map(String::as_str),
+        configuration.entity_id().map(String::as_str),
+    );
+}
+
+// Parses the body of a successful response. An empty body is parsed like `null`, so it fits
+// `NoContent`, `()` and `Option` bodies instead of failing with an EOF error.
+fn parse_body<T: DeserializeOwned>(text: &str) -> Result<T, serde_json::Error> {
+    if text.trim().is_empty() {
+        serde_json::from_str("null")
+    } else {
+        serde_json::from_str(text)
+    }
+}
+
+// Turns a response into an `SdkResponse` or an API error, for endpoints whose body is parsed
+// as is.
+async fn build_response<T: DeserializeOwned>(
+    response: Response,
+) -> Result<SdkResponse<T>, SdkError> {
+    let status = response.status();
+    let retry_after = retry_after(response.headers());
+    let started = request_started(&response);
+    let text = response.text().await?;
+
+    if status.is_success() {
+        Ok(SdkResponse {
+            body: parse_body(&text)?,
+            status,
+            duration: started.elapsed(),
+        })
+    } else {
+        Err(build_api_error(status, retry_after, &text))
+    }
+}
+
+// Blocking version of build_response.
+#[cfg(feature = "sms")]
+fn build_blocking_response<T: DeserializeOwned>(
+    response: reqwest::blocking::Response,
+) -> Result<SdkResponse<T>, SdkError> {
+    let status = response.status();
+    let retry_after = retry_after(response.headers());
+    let started = blocking_request_started(&response);
+    let text = response.text()?;
+
+    if status.is_success() {
+        Ok(SdkResponse {
+            body: parse_body(&text)?,
+            status,
+            duration: started.elapsed(),
+        })
+    } else {
+        Err(build_api_error(status, retry_after, &text))
+    }
+}
+
+// Builds the error of a failed response. The status and `Retry-After` are kept whatever the
+// body is, so errors of gateways in front of the API can still be classified and retried.
+fn build_api_error(status: StatusCode, retry_after: Option<Duration>, text: &str) -> SdkError {
+    let details = serde_json::from_str(text).ok().map(Box::new);
+    let body = match details {
+        None if !text.trim().is_empty() => Some(text.to_string()),
+        _ => None,
+    };
+
+    SdkError::ApiRequestError(ApiError {
+        details,
+        body,
+        status,
+        retry_after,
+    })
+}
+
+// Reads the `Retry-After` header of a response, given either as seconds or as an HTTP date. A
+// date in the past is a delay of zero.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
 }
 
 async fn send_no_body_request(
     client: &reqwest::Client,
     configuration: &Configuration,
-    query_parameters: HashMap<String, String>,
+    query_parameters: impl QueryParams,
     method: reqwest::Method,
     path: &str,
 ) -> Result<Response, SdkError> {
+    let query_parameters = query_parameters.to_query_map()?;
     let url = format!("{}{}", configuration.base_url(), path);
-    let mut builder = client.request(method, url).query(&query_parameters);
+    let mut builder = client.request(method.clone(), url).query(&query_parameters);
 
     builder = add_auth(builder, configuration);
-    builder = add_user_agent(builder);
-
-    Ok(builder.send().await?)
+    builder = add_user_agent(builder, configuration);
+    builder = add_default_headers(builder, configuration);
+
+    let sent = SentRequest::new(&method, path, || None);
+    send_tracked(configuration, sent, async {
+        #[cfg(feature = "recording")]
+        if let Some(cassette) = configuration.cassette() {
+            let request = RecordedRequest::new(&method, path, &query_parameters, None);
+            return cassette.exchange(request, builder).await;
+        }
+
+        Ok(builder.send().await?)
+    })
+    .await
 }
 
 async fn send_valid_json_request<T: Validate + serde::Serialize>(
     client: &reqwest::Client,
     configuration: &Configuration,
     request_body: T,
-    query_parameters: HashMap<String, String>,
+    query_parameters: impl QueryParams,
     method: reqwest::Method,
     path: &str,
 ) -> Result<Response, SdkError> {
     request_body.validate()?;
 
+    send_json_request(
+        client,
+        configuration,
+        request_body,
+        query_parameters,
+        method,
+        path,
+    )
+    .await
+}
+
+async fn send_json_request<T: serde::Serialize>(
+    client: &reqwest::Client,
+    configuration: &Configuration,
+    request_body: T,
+    query_parameters: impl QueryParams,
+    method: reqwest::Method,
+    path: &str,
+) -> Result<Response, SdkError> {
+    let query_parameters = query_parameters.to_query_map()?;
+
     let url = format!("{}{}", configuration.base_url(), path);
     let mut builder = client
-        .request(method, url)
+        .request(method.clone(), url)
         .json(&request_body)
         .query(&query_parameters);
 
     builder = add_auth(builder, configuration);
-    builder = add_user_agent(builder);
+    builder = add_user_agent(builder, configuration);
+    builder = add_default_headers(builder, configuration);
+
+    let sent = SentRequest::new(&method, path, || json_size(&request_body));
+    send_tracked(configuration, sent, async {
+        #[cfg(feature = "recording")]
+        if let Some(cassette) = configuration.cassette() {
+            let body = serde_json::to_value(&request_body).ok();
+            let request = RecordedRequest::new(&method, path, &query_parameters, body);
+            return cassette.exchange(request, builder).await;
+        }
+
+        Ok(builder.send().await?)
+    })
+    .await
+}
 
-    Ok(builder.send().await?)
+// Sends a JSON body as given, without validating it, or no body.
+async fn send_raw_request(
+    client: &reqwest::Client,
+    configuration: &Configuration,
+    method: reqwest::Method,
+    path: &str,
+    request_body: Option<serde_json::Value>,
+) -> Result<SdkResponse<serde_json::Value>, SdkError> {
+    let response = match request_body {
+        Some(request_body) => {
+            send_json_request(client, configuration, request_body, (), method, path).await?
+        }
+        None => send_no_body_request(client, configuration, (), method, path).await?,
+    };
+
+    build_response(response).await
 }
 
 #[cfg(feature = "email")]
@@ -218,12 +749,23 @@ async fn send_multipart_request(
     path: &str,
 ) -> Result<Response, SdkError> {
     let url = format!("{}{}", configuration.base_url(), path);
-    let mut builder = client.request(method, url);
+    let mut builder = client.request(method.clone(), url);
 
     builder = add_auth(builder, configuration);
-    builder = add_user_agent(builder);
-
-    Ok(builder.multipart(form).send().await?)
+    builder = add_user_agent(builder, configuration);
+    builder = add_default_headers(builder, configuration);
+
+    let sent = SentRequest::new(&method, path, || None);
+    send_tracked(configuration, sent, async {
+        #[cfg(feature = "recording")]
+        if let Some(cassette) = configuration.cassette() {
+            let request = RecordedRequest::new(&method, path, &HashMap::new(), None);
+            return cassette.exchange(request, builder.multipart(form)).await;
+        }
+
+        Ok(builder.multipart(form).send().await?)
+    })
+    .await
 }
 
 #[cfg(feature = "sms")]
@@ -236,13 +778,166 @@ fn send_blocking_valid_json_request<T: Validate + serde::Serialize>(
 ) -> Result<reqwest::blocking::Response, SdkError> {
     request_body.validate()?;
 
+    send_blocking_request(client, configuration, Some(&request_body), method, path)
+}
+
+#[cfg(feature = "sms")]
+fn send_blocking_request<T: serde::Serialize>(
+    client: &reqwest::blocking::Client,
+    configuration: &Configuration,
+    request_body: Option<&T>,
+    method: reqwest::Method,
+    path: &str,
+) -> Result<reqwest::blocking::Response, SdkError> {
     let url = format!("{}{}", configuration.base_url(), path);
-    let mut builder = client.request(method, url);
+    let mut builder = client.request(method.clone(), url);
 
     builder = add_auth_blocking(builder, configuration);
-    builder = add_user_agent_blocking(builder);
+    builder = add_user_agent_blocking(builder, configuration);
+    builder = add_default_headers_blocking(builder, configuration);
+    if let Some(request_body) = request_body {
+        builder = builder.json(request_body);
+    }
+
+    let sent = SentRequest::new(&method, path, || request_body.and_then(json_size));
+    send_tracked_blocking(configuration, sent, || {
+        #[cfg(feature = "recording")]
+        if let Some(cassette) = configuration.cassette() {
+            let body = request_body.and_then(|body| serde_json::to_value(body).ok());
+            let request = RecordedRequest::new(&method, path, &HashMap::new(), body);
+            return cassette.exchange_blocking(request, builder);
+        }
+
+        Ok(builder.send()?)
+    })
+}
+
+// A request as described in slow request warnings. The payload size is only computed for slow
+// requests.
+struct SentRequest<'a, F: FnOnce() -> Option<usize>> {
+    method: &'a reqwest::Method,
+    path: &'a str,
+    payload_size: F,
+}
+
+impl<'a, F: FnOnce() -> Option<usize>> SentRequest<'a, F> {
+    fn new(method: &'a reqwest::Method, path: &'a str, payload_size: F) -> Self {
+        SentRequest {
+            method,
+            path,
+            payload_size,
+        }
+    }
+
+    fn warn_if_slow(self, configuration: &Configuration, elapsed: Duration) {
+        let Some(threshold) = configuration.slow_request_threshold() else {
+            return;
+        };
+        if elapsed < threshold {
+            return;
+        }
+
+        match (self.payload_size)() {
+            Some(size) => log::warn!(
+                "slow Infobip API request: {} {} took {:?}, payload {} bytes",
+                self.method,
+                self.path,
+                elapsed,
+                size
+            ),
+            None => log::warn!(
+                "slow Infobip API request: {} {} took {:?}",
+                self.method,
+                self.path,
+                elapsed
+            ),
+        }
+    }
+}
+
+fn json_size<T: serde::Serialize>(request_body: &T) -> Option<usize> {
+    serde_json::to_vec(request_body).ok().map(|json| json.len())
+}
+
+// When the request of a response was sent, kept in the extensions of the response.
+#[derive(Clone, Copy)]
+struct RequestStart(Instant);
+
+fn request_started(response: &Response) -> Instant {
+    response
+        .extensions()
+        .get::<RequestStart>()
+        .map_or_else(Instant::now, |start| start.0)
+}
+
+#[cfg(feature = "sms")]
+fn blocking_request_started(response: &reqwest::blocking::Response) -> Instant {
+    response
+        .extensions()
+        .get::<RequestStart>()
+        .map_or_else(Instant::now, |start| start.0)
+}
+
+// Sends through the circuit breaker of the configuration, if there is one, and times the request.
+async fn send_tracked<P, F>(
+    configuration: &Configuration,
+    sent: SentRequest<'_, P>,
+    send: F,
+) -> Result<Response, SdkError>
+where
+    P: FnOnce() -> Option<usize>,
+    F: Future<Output = Result<Response, SdkError>>,
+{
+    let permit = configuration
+        .circuit_breaker()
+        .map(|breaker| breaker.acquire())
+        .transpose()?;
+    let started = Instant::now();
+
+    let result = send.await;
+    sent.warn_if_slow(configuration, started.elapsed());
+    if let Some(permit) = permit {
+        match &result {
+            Ok(response) => permit.record_status(response.status()),
+            Err(error) => permit.record_error(error),
+        }
+    }
+
+    result.map(|mut response| {
+        response.extensions_mut().insert(RequestStart(started));
+        response
+    })
+}
+
+#[cfg(feature = "sms")]
+fn send_tracked_blocking<P, F>(
+    configuration: &Configuration,
+    sent: SentRequest<'_, P>,
+    send: F,
+) -> Result<reqwest::blocking::Response, SdkError>
+where
+    P: FnOnce() -> Option<usize>,
+    F: FnOnce() -> Result<reqwest::blocking::Response, SdkError>,
+{
+    let permit = configuration
+        .circuit_breaker()
+        .map(|breaker| breaker.acquire())
+        .transpose()?;
+    let started = Instant::now();
+
+    let result = send();
+    sent.warn_if_slow(configuration, started.elapsed());
+    if let Some(permit) = permit {
+        match &result {
+            Ok(response) => permit.record_status(response.status()),
+            Err(error) => permit.record_error(error),
+        }
+    }
 
-    Ok(builder.json(&request_body).send()?)
+    result.map(|mut response| {
+        response.extensions_mut().insert(RequestStart(started));
+        response
+    })
 }
 
 mod tests;
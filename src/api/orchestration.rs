@@ -0,0 +1,245 @@
+//! Failover of WhatsApp template messages to SMS.
+//!
+//! The WhatsApp API can fall back to SMS by itself, with the `sms_failover` of a template
+//! message, but only when WhatsApp reports the message as undeliverable. The [`FailoverSender`]
+//! also falls back when the WhatsApp request fails, when the message is rejected, and, with a
+//! delivery timeout, when no delivery report arrives in time. Delivery reports are received by
+//! the application's webhook and passed on with [`FailoverSender::report_status`].
+//!
+//! A request falls back as a whole: if any of its messages fails, the whole SMS request is
+//! sent. Send one destination per request to fail over destinations independently.
+//!
+//! # Example
+//! ```no_run
+//! # use std::time::Duration;
+//! # use infobip_sdk::api::orchestration::FailoverSender;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::api::whatsapp::WhatsAppClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+//! # use infobip_sdk::model::whatsapp::{
+//! #     FailoverMessage, SendTemplateRequestBody, TemplateBodyContent, TemplateContent,
+//! #     TemplateData, TemplateLanguage,
+//! # };
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let configuration = Configuration::from_env_api_key()?;
+//! let sender = FailoverSender::new(
+//!     WhatsAppClient::with_configuration(configuration.clone()),
+//!     SmsClient::with_configuration(configuration),
+//! )
+//! .with_delivery_timeout(Duration::from_secs(60));
+//!
+//! let content = TemplateContent::new(
+//!     "order_shipped",
+//!     TemplateData::new(TemplateBodyContent::new(vec!["A-1234".to_string()])),
+//!     TemplateLanguage::EnGb,
+//! );
+//! let whatsapp = SendTemplateRequestBody::new(vec![FailoverMessage::new(
+//!     "44444444444",
+//!     "55555555555",
+//!     content,
+//! )]);
+//!
+//! let mut message = Message::new(vec![Destination::new("55555555555")]);
+//! message.text = Some("Your order A-1234 has shipped.".to_string());
+//! let sms = SendRequestBody::new(vec![message]);
+//!
+//! // Meanwhile, the delivery report webhook calls `sender.report_status(...)`.
+//! let outcome = sender.send(whatsapp, sms).await;
+//! if let Some(reason) = outcome.fallback_reason {
+//!     println!("sent over SMS: {:?}", reason);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use tokio::sync::oneshot;
+
+use crate::api::sms::SmsClient;
+use crate::api::whatsapp::WhatsAppClient;
+use crate::api::{SdkError, SdkResponse};
+use crate::model::sms::{SendRequestBody, SendResponseBody};
+use crate::model::status::StatusGroup;
+use crate::model::whatsapp::{SendTemplateRequestBody, SendTemplateResponseBody};
+
+/// Why a message was sent over SMS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FallbackReason {
+    /// The WhatsApp request failed. Its error is in `FailoverOutcome::whatsapp`.
+    SendFailed,
+
+    /// A WhatsApp message was rejected in the response, with the given status group.
+    Rejected(StatusGroup),
+
+    /// A delivery report with the given unsuccessful status group was reported.
+    NotDelivered(StatusGroup),
+
+    /// Not every WhatsApp message was reported as delivered within the delivery timeout.
+    Timeout,
+}
+
+/// Outcome of a failover send.
+#[derive(Debug)]
+pub struct FailoverOutcome {
+    /// Result of sending the WhatsApp template messages.
+    pub whatsapp: Result<SdkResponse<SendTemplateResponseBody>, SdkError>,
+
+    /// Why the message fell back to SMS, if it did.
+    pub fallback_reason: Option<FallbackReason>,
+
+    /// Result of sending the SMS messages, if the message fell back to SMS.
+    pub sms: Option<Result<SdkResponse<SendResponseBody>, SdkError>>,
+}
+
+type Waiting = Arc<Mutex<HashMap<String, oneshot::Sender<StatusGroup>>>>;
+
+/// Sends WhatsApp template messages, falling back to SMS. See the [module documentation](self).
+pub struct FailoverSender {
+    whatsapp: WhatsAppClient,
+    sms: SmsClient,
+    delivery_timeout: Option<Duration>,
+    waiting: Waiting,
+}
+
+impl FailoverSender {
+    /// Creates a sender that falls back to SMS when the WhatsApp request fails or a message is
+    /// rejected.
+    pub fn new(whatsapp: WhatsAppClient, sms: SmsClient) -> Self {
+        FailoverSender {
+            whatsapp,
+            sms,
+            delivery_timeout: None,
+            waiting: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Also falls back when the WhatsApp messages are not all reported as delivered within
+    /// `timeout` of being sent. Message IDs are generated for messages without one, so reports
+    /// can be matched.
+    pub fn with_delivery_timeout(mut self, timeout: Duration) -> Self {
+        self.delivery_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the delivery timeout, if the sender waits for delivery reports.
+    pub fn delivery_timeout(&self) -> Option<Duration> {
+        self.delivery_timeout
+    }
+
+    /// Passes on the status of a WhatsApp delivery report to the send waiting for it. Returns
+    /// `true` if a send was waiting for the message. Statuses that are not final are ignored.
+    pub fn report_status(&self, message_id: &str, group: StatusGroup) -> bool {
+        if !group.is_final() {
+            return false;
+        }
+
+        match self.waiting.lock().unwrap().remove(message_id) {
+            Some(sender) => sender.send(group).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Sends the WhatsApp template messages, and the SMS messages if they fail.
+    pub async fn send(
+        &self,
+        mut whatsapp: SendTemplateRequestBody,
+        sms: SendRequestBody,
+    ) -> FailoverOutcome {
+        let reports = match self.delivery_timeout {
+            Some(_) => self.wait_for_reports(&mut whatsapp),
+            None => Vec::new(),
+        };
+        let message_ids: Vec<String> = reports.iter().map(|(id, _)| id.clone()).collect();
+
+        let whatsapp = self.whatsapp.send_template(whatsapp).await;
+        let fallback_reason = match &whatsapp {
+            Err(_) => Some(FallbackReason::SendFailed),
+            Ok(response) => {
+                let rejected = response
+                    .body
+                    .partition_by_status()
+                    .rejected
+                    .first()
+                    .map(|(group, _)| *group);
+                match (rejected, self.delivery_timeout) {
+                    (Some(group), _) => Some(FallbackReason::Rejected(group)),
+                    (None, Some(timeout)) => await_delivery(reports, timeout).await,
+                    (None, None) => None,
+                }
+            }
+        };
+
+        {
+            let mut waiting = self.waiting.lock().unwrap();
+            for message_id in message_ids {
+                waiting.remove(&message_id);
+            }
+        }
+
+        let sms = match fallback_reason {
+            Some(_) => Some(self.sms.send(sms).await),
+            None => None,
+        };
+
+        FailoverOutcome {
+            whatsapp,
+            fallback_reason,
+            sms,
+        }
+    }
+
+    // Registers the messages as waiting for a report before sending, so reports arriving right
+    // after the response are not missed.
+    fn wait_for_reports(
+        &self,
+        request_body: &mut SendTemplateRequestBody,
+    ) -> Vec<(String, oneshot::Receiver<StatusGroup>)> {
+        let mut waiting = self.waiting.lock().unwrap();
+
+        request_body
+            .messages
+            .iter_mut()
+            .map(|message| {
+                let message_id = message
+                    .message_id
+                    .get_or_insert_with(|| uuid::Uuid::new_v4().to_string())
+                    .clone();
+                let (sender, receiver) = oneshot::channel();
+                waiting.insert(message_id.clone(), sender);
+
+                (message_id, receiver)
+            })
+            .collect()
+    }
+}
+
+// Waits until every message is reported delivered, or any is reported not delivered.
+async fn await_delivery(
+    reports: Vec<(String, oneshot::Receiver<StatusGroup>)>,
+    timeout: Duration,
+) -> Option<FallbackReason> {
+    let mut reports: FuturesUnordered<_> =
+        reports.into_iter().map(|(_, receiver)| receiver).collect();
+
+    let delivered = async {
+        while let Some(report) = reports.next().await {
+            match report {
+                Ok(StatusGroup::Delivered) => {}
+                Ok(group) => return Some(FallbackReason::NotDelivered(group)),
+                Err(_) => return Some(FallbackReason::Timeout),
+            }
+        }
+        None
+    };
+
+    tokio::time::timeout(timeout, delivered)
+        .await
+        .unwrap_or(Some(FallbackReason::Timeout))
+}
@@ -0,0 +1,151 @@
+//! Routing to the credentials of many accounts, for platforms sending on behalf of their tenants.
+//!
+//! A [`ClientPool`] holds one [`InfobipClient`] per key, e.g. a tenant ID or a sender, each with
+//! its own `Configuration`. All of them send requests with the HTTP client of the pool, so
+//! connections to the API are reused between tenants instead of each tenant opening its own.
+//! As with `InfobipClient::with_http_client`, the timeout, proxy and compression of the tenant
+//! configurations are not applied; set them on the HTTP client of the pool.
+//!
+//! Clients can be added up front with `insert`, or when first needed with
+//! `get_or_try_insert_with`, e.g. loading the credentials of the tenant from a database.
+//! Clones of a pool share the same clients.
+//!
+//! # Example
+//! ```no_run
+//! # use infobip_sdk::api::pool::ClientPool;
+//! # use infobip_sdk::configuration::{ApiKey, Configuration};
+//! # use infobip_sdk::model::sms::PreviewRequestBody;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let pool = ClientPool::new();
+//! pool.insert(
+//!     "tenant-a".to_string(),
+//!     Configuration::with_api_key(
+//!         "https://a1b2c3.api.infobip.com".to_string(),
+//!         ApiKey::new("tenant-a-api-key".to_string()),
+//!     )?,
+//! );
+//!
+//! let client = pool.get("tenant-a").ok_or("unknown tenant")?;
+//! let response = client.sms().preview(PreviewRequestBody::new("Hello!")).await?;
+//!
+//! println!("{:?}", response.body);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use crate::api::client::InfobipClient;
+use crate::configuration::Configuration;
+
+/// Clients for many configurations, looked up by key and sharing one HTTP client. Clones share
+/// the same clients.
+#[derive(Clone, Debug)]
+pub struct ClientPool<K> {
+    http_client: reqwest::Client,
+    clients: Arc<Mutex<HashMap<K, Arc<InfobipClient>>>>,
+}
+
+impl<K: Eq + Hash> ClientPool<K> {
+    /// Builds and returns an empty pool with a new HTTP client.
+    pub fn new() -> Self {
+        Self::with_http_client(reqwest::Client::new())
+    }
+
+    /// Builds and returns an empty pool sending requests with the given HTTP client.
+    pub fn with_http_client(http_client: reqwest::Client) -> Self {
+        ClientPool {
+            http_client,
+            clients: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Adds a client for `key` with the given configuration and returns it. A client already in
+    /// the pool for `key` is replaced, e.g. after the credentials of a tenant were rotated.
+    pub fn insert(&self, key: K, configuration: Configuration) -> Arc<InfobipClient> {
+        let client = Arc::new(InfobipClient::with_http_client(
+            self.http_client.clone(),
+            configuration,
+        ));
+        self.clients.lock().unwrap().insert(key, client.clone());
+
+        client
+    }
+
+    /// Returns the client for `key`, or `None` if there is none.
+    pub fn get<Q>(&self, key: &Q) -> Option<Arc<InfobipClient>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.clients.lock().unwrap().get(key).cloned()
+    }
+
+    /// Returns the client for `key`, adding one with the configuration returned by
+    /// `configuration` if there is none. If building the configuration fails, nothing is added
+    /// and the error is returned.
+    ///
+    /// The pool isn't locked while `configuration` runs. If another client for `key` was added
+    /// in the meantime, that one is returned.
+    pub fn get_or_try_insert_with<E, F>(
+        &self,
+        key: K,
+        configuration: F,
+    ) -> Result<Arc<InfobipClient>, E>
+    where
+        F: FnOnce(&K) -> Result<Configuration, E>,
+    {
+        if let Some(client) = self.clients.lock().unwrap().get(&key) {
+            return Ok(client.clone());
+        }
+
+        let client = Arc::new(InfobipClient::with_http_client(
+            self.http_client.clone(),
+            configuration(&key)?,
+        ));
+
+        Ok(self
+            .clients
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_insert(client)
+            .clone())
+    }
+
+    /// Removes the client for `key` and returns it, e.g. when a tenant is offboarded. Calls
+    /// already holding the client can still finish.
+    pub fn remove<Q>(&self, key: &Q) -> Option<Arc<InfobipClient>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.clients.lock().unwrap().remove(key)
+    }
+
+    /// Returns the number of clients in the pool.
+    pub fn len(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+
+    /// Returns `true` if there are no clients in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the HTTP client shared by the clients of the pool.
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.http_client
+    }
+}
+
+impl<K: Eq + Hash> Default for ClientPool<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,126 @@
+//! Client-side cache of SMS previews. When enabled in the `Configuration`, `SmsClient::preview`
+//! answers repeated previews of the same text and options from memory, so tooling that previews
+//! as the user types doesn't send a request per keystroke.
+//!
+//! Only successful previews are cached. When the cache is full, the least recently used preview
+//! is dropped.
+//!
+//! # Example
+//! ```no_run
+//! # use infobip_sdk::api::preview_cache::PreviewCache;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::PreviewRequestBody;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let cache = PreviewCache::new(500);
+//! let configuration = Configuration::from_env_api_key()?.with_preview_cache(cache.clone());
+//! let sms_client = SmsClient::with_configuration(configuration);
+//!
+//! sms_client.preview(PreviewRequestBody::new("Hello!")).await?;
+//! // Answered from the cache.
+//! sms_client.preview(PreviewRequestBody::new("Hello!")).await?;
+//!
+//! assert_eq!(cache.len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::model::sms::{PreviewRequestBody, PreviewResponseBody};
+
+type Key = (String, Option<String>, Option<String>);
+
+#[derive(Debug, Default)]
+struct Entries {
+    previews: HashMap<Key, (PreviewResponseBody, u64)>,
+    last_use: u64,
+}
+
+/// Least recently used cache of preview responses. Clones share the same storage, so all
+/// clients using a configuration share one cache.
+#[derive(Clone, Debug)]
+pub struct PreviewCache {
+    capacity: usize,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl PreviewCache {
+    /// Creates a cache holding at most `capacity` previews. A capacity of 0 caches nothing.
+    pub fn new(capacity: usize) -> Self {
+        PreviewCache {
+            capacity,
+            entries: Arc::new(Mutex::new(Entries::default())),
+        }
+    }
+
+    /// Returns the maximum number of cached previews.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached previews.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().previews.len()
+    }
+
+    /// Returns `true` if no preview is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops all cached previews.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().previews.clear();
+    }
+
+    // Returns the cached preview of the request, marking it as the most recently used.
+    pub(crate) fn get(&self, request_body: &PreviewRequestBody) -> Option<PreviewResponseBody> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.last_use += 1;
+        let last_use = entries.last_use;
+
+        entries
+            .previews
+            .get_mut(&key(request_body))
+            .map(|(preview, used)| {
+                *used = last_use;
+                preview.clone()
+            })
+    }
+
+    // Caches the preview of the request, dropping the least recently used one if full.
+    pub(crate) fn insert(&self, request_body: &PreviewRequestBody, preview: PreviewResponseBody) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        entries.last_use += 1;
+        let last_use = entries.last_use;
+        let key = key(request_body);
+
+        if entries.previews.len() >= self.capacity && !entries.previews.contains_key(&key) {
+            let oldest = entries
+                .previews
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.previews.remove(&oldest);
+            }
+        }
+        entries.previews.insert(key, (preview, last_use));
+    }
+}
+
+fn key(request_body: &PreviewRequestBody) -> Key {
+    (
+        request_body.text.clone(),
+        request_body.language_code.clone(),
+        request_body.transliteration.clone(),
+    )
+}
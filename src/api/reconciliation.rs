@@ -0,0 +1,206 @@
+//! Waiting for the final status of sent messages.
+//!
+//! A [`Reconciler`] takes the IDs of sent messages and looks up their status over a
+//! [`MessageChannel`] until every message is delivered or failed, or a deadline passes. Messages
+//! without a final status by then are reported as unresolved, with the last status seen.
+//!
+//! The status of every message is looked up in the logs of the channel, which keep the messages
+//! of the last 48 hours. Errors of a lookup are retried on the next round.
+//!
+//! # Example
+//! ```no_run
+//! # use std::time::Duration;
+//! # use infobip_sdk::api::reconciliation::Reconciler;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+//! let message_ids = vec!["some-message-id".to_string(), "other-message-id".to_string()];
+//!
+//! let summary = Reconciler::new(&sms_client)
+//!     .with_poll_interval(Duration::from_secs(30))
+//!     .reconcile(message_ids, Duration::from_secs(600))
+//!     .await;
+//!
+//! println!("{} delivered", summary.delivered.len());
+//! for failed in &summary.failed {
+//!     println!("{} failed: {:?}", failed.message_id, failed.status.description);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use futures_util::stream::{self, StreamExt};
+use tokio::time::Instant;
+
+use crate::api::channel::{MessageChannel, MessageStatus};
+use crate::api::SdkError;
+use crate::model::status::StatusGroup;
+
+/// Default time between two lookups of the messages that aren't resolved yet.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default number of statuses looked up at the same time.
+pub const DEFAULT_CONCURRENCY: usize = 5;
+
+/// A message with a final status other than delivered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailedMessage {
+    pub message_id: String,
+
+    /// Final status of the message, with the reason it wasn't delivered.
+    pub status: MessageStatus,
+}
+
+/// A message without a final status when the deadline passed.
+#[derive(Debug)]
+pub struct UnresolvedMessage {
+    pub message_id: String,
+
+    /// Last status seen, if the message was found.
+    pub status: Option<MessageStatus>,
+
+    /// Error of the last lookup, if it failed.
+    pub error: Option<SdkError>,
+}
+
+/// Outcome of a reconciliation, with every message in one of the lists.
+#[derive(Debug, Default)]
+pub struct ReconciliationSummary {
+    /// IDs of the delivered messages.
+    pub delivered: Vec<String>,
+
+    /// Messages that were not delivered, and won't be.
+    pub failed: Vec<FailedMessage>,
+
+    /// Messages without a final status.
+    pub unresolved: Vec<UnresolvedMessage>,
+}
+
+impl ReconciliationSummary {
+    /// Returns `true` if every message has a final status.
+    pub fn is_resolved(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+/// Looks up the status of sent messages until they are resolved. See the
+/// [module documentation](self).
+pub struct Reconciler<'a> {
+    channel: &'a dyn MessageChannel,
+    poll_interval: Duration,
+    concurrency: usize,
+}
+
+impl<'a> Reconciler<'a> {
+    /// Creates a reconciler that looks up statuses over the given channel.
+    pub fn new(channel: &'a dyn MessageChannel) -> Self {
+        Reconciler {
+            channel,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets the time between two lookups of the messages that aren't resolved yet.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the number of statuses looked up at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Looks up the status of the messages until each is delivered or failed, or `deadline` has
+    /// passed. Messages are looked up at least once, even with a zero deadline, and a deadline
+    /// too far away to represent, e.g. `Duration::MAX`, never passes.
+    pub async fn reconcile<I>(&self, message_ids: I, deadline: Duration) -> ReconciliationSummary
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let deadline = Instant::now().checked_add(deadline);
+        let mut summary = ReconciliationSummary::default();
+        let mut pending: Vec<UnresolvedMessage> = message_ids
+            .into_iter()
+            .map(|message_id| UnresolvedMessage {
+                message_id,
+                status: None,
+                error: None,
+            })
+            .collect();
+
+        loop {
+            pending = self.look_up(pending, &mut summary).await;
+
+            if pending.is_empty() {
+                break;
+            }
+            let delay = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    self.poll_interval.min(deadline - now)
+                }
+                None => self.poll_interval,
+            };
+            tokio::time::sleep(delay).await;
+        }
+
+        summary.unresolved = pending;
+        summary
+    }
+
+    // Looks up the pending messages once, moving resolved ones to the summary and returning the
+    // others.
+    async fn look_up(
+        &self,
+        pending: Vec<UnresolvedMessage>,
+        summary: &mut ReconciliationSummary,
+    ) -> Vec<UnresolvedMessage> {
+        let results: Vec<_> = stream::iter(pending)
+            .map(|message| async move {
+                let result = self.channel.get_status(&message.message_id).await;
+                (message, result)
+            })
+            .buffered(self.concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut unresolved = Vec::new();
+        for (mut message, result) in results {
+            match result {
+                Ok(Some(status)) => match status.group {
+                    Some(StatusGroup::Delivered) => summary.delivered.push(message.message_id),
+                    Some(group) if group.is_final() => summary.failed.push(FailedMessage {
+                        message_id: message.message_id,
+                        status,
+                    }),
+                    _ => {
+                        message.status = Some(status);
+                        message.error = None;
+                        unresolved.push(message);
+                    }
+                },
+                Ok(None) => {
+                    message.error = None;
+                    unresolved.push(message);
+                }
+                Err(error) => {
+                    message.error = Some(error);
+                    unresolved.push(message);
+                }
+            }
+        }
+
+        unresolved
+    }
+}
@@ -0,0 +1,293 @@
+//! Recording of API interactions to cassette files, and replaying them, for running tests
+//! without live credentials. When a `Cassette` is set in the `Configuration`, clients send their
+//! requests through it:
+//!
+//! * In record mode, requests are sent to the API as usual, and every request and response is
+//!   kept in the cassette. Call `Cassette::save` to write them to the cassette file.
+//! * In replay mode, nothing is sent. Each request is answered with the first recorded response
+//!   not yet replayed whose request has the same method, path and query parameters. A request
+//!   without a recorded response fails with `SdkError::NotRecorded`.
+//!
+//! Request headers are not recorded, so cassettes don't contain credentials. Recorded request
+//! bodies are kept for reference, but are not compared when replaying, as they may contain
+//! generated IDs or timestamps.
+//!
+//! # Example
+//! ```no_run
+//! # use infobip_sdk::api::recording::Cassette;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::configuration::{ApiKey, Configuration};
+//! # use infobip_sdk::model::sms::PreviewRequestBody;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Records on the first run, replays on later runs.
+//! let cassette = Cassette::open("tests/cassettes/preview.json")?;
+//! let configuration = Configuration::with_api_key(
+//!     "https://some.api.infobip.com".to_string(),
+//!     ApiKey::new("some-api-key".to_string()),
+//! )?
+//! .with_cassette(cassette.clone());
+//! let sms_client = SmsClient::with_configuration(configuration);
+//!
+//! sms_client.preview(PreviewRequestBody::new("Hello!")).await?;
+//!
+//! cassette.save()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use reqwest::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::api::SdkError;
+
+/// Whether a cassette sends requests and records them, or answers them from recordings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// A request as kept in a cassette.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub query: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+impl RecordedRequest {
+    pub(crate) fn new(
+        method: &reqwest::Method,
+        path: &str,
+        query_parameters: &HashMap<String, String>,
+        body: Option<serde_json::Value>,
+    ) -> Self {
+        RecordedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query_parameters
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            body,
+        }
+    }
+
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.path == other.path && self.query == other.query
+    }
+}
+
+/// A response as kept in a cassette.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    pub body: String,
+}
+
+/// A request and the response the API gave to it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Default)]
+struct Tape {
+    interactions: Vec<Interaction>,
+    replayed: Vec<bool>,
+}
+
+/// Recorded interactions, backed by a JSON file. Clones share the same recordings, so a clone
+/// can be kept for saving after the configuration is handed to a client.
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    tape: Arc<Mutex<Tape>>,
+}
+
+impl Cassette {
+    /// Creates an empty cassette in record mode, saved to `path` by `save`.
+    pub fn record<P: AsRef<Path>>(path: P) -> Self {
+        Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Record,
+            tape: Arc::new(Mutex::new(Tape::default())),
+        }
+    }
+
+    /// Reads a cassette file to replay it.
+    pub fn replay<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path.as_ref())?;
+        let interactions: Vec<Interaction> = serde_json::from_str(&text)?;
+
+        Ok(Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Replay,
+            tape: Arc::new(Mutex::new(Tape {
+                replayed: vec![false; interactions.len()],
+                interactions,
+            })),
+        })
+    }
+
+    /// Replays the cassette file if it exists, or records a new one otherwise.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        if path.as_ref().exists() {
+            Cassette::replay(path)
+        } else {
+            Ok(Cassette::record(path))
+        }
+    }
+
+    /// Writes the recorded interactions to the cassette file, creating its directory if needed.
+    /// Does nothing in replay mode.
+    pub fn save(&self) -> io::Result<()> {
+        if self.mode == CassetteMode::Replay {
+            return Ok(());
+        }
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let text = serde_json::to_string_pretty(&self.interactions())?;
+
+        fs::write(&self.path, text)
+    }
+
+    /// Returns the mode of the cassette.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the recorded interactions.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.tape.lock().unwrap().interactions.clone()
+    }
+
+    /// Returns `true` if every recorded interaction was replayed. Useful at the end of a test to
+    /// check that all expected requests were made.
+    pub fn is_fully_replayed(&self) -> bool {
+        self.tape
+            .lock()
+            .unwrap()
+            .replayed
+            .iter()
+            .all(|replayed| *replayed)
+    }
+
+    pub(crate) async fn exchange(
+        &self,
+        request: RecordedRequest,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, SdkError> {
+        if self.mode == CassetteMode::Replay {
+            return self.play(&request).map(Into::into);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status();
+        let content_type = content_type(response.headers());
+        let body = response.text().await?;
+
+        Ok(self.keep(request, status, content_type, body).into())
+    }
+
+    #[cfg(feature = "sms")]
+    pub(crate) fn exchange_blocking(
+        &self,
+        request: RecordedRequest,
+        builder: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, SdkError> {
+        if self.mode == CassetteMode::Replay {
+            return self.play(&request).map(Into::into);
+        }
+
+        let response = builder.send()?;
+        let status = response.status();
+        let content_type = content_type(response.headers());
+        let body = response.text()?;
+
+        Ok(self.keep(request, status, content_type, body).into())
+    }
+
+    // Answers the request with the first matching interaction not yet replayed.
+    fn play(&self, request: &RecordedRequest) -> Result<http::Response<String>, SdkError> {
+        let mut tape = self.tape.lock().unwrap();
+        let Tape {
+            interactions,
+            replayed,
+        } = &mut *tape;
+        let index = interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, replayed)| !replayed && interaction.request.matches(request))
+            .ok_or_else(|| SdkError::NotRecorded {
+                method: request.method.clone(),
+                path: request.path.clone(),
+            })?;
+        replayed[index] = true;
+
+        Ok(build_response(&interactions[index].response))
+    }
+
+    // Records the interaction, returning the response to hand to the client.
+    fn keep(
+        &self,
+        request: RecordedRequest,
+        status: StatusCode,
+        content_type: Option<String>,
+        body: String,
+    ) -> http::Response<String> {
+        let response = RecordedResponse {
+            status: status.as_u16(),
+            content_type,
+            body,
+        };
+        let built = build_response(&response);
+
+        let mut tape = self.tape.lock().unwrap();
+        tape.interactions.push(Interaction { request, response });
+        tape.replayed.push(false);
+
+        built
+    }
+}
+
+fn content_type(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn build_response(recorded: &RecordedResponse) -> http::Response<String> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    if let Some(content_type) = &recorded.content_type {
+        builder = builder.header(reqwest::header::CONTENT_TYPE, content_type);
+    }
+
+    builder
+        .body(recorded.body.clone())
+        .expect("recorded response is valid")
+}
@@ -0,0 +1,232 @@
+//! Optional checks of response bodies against the requests that produced them. When enabled in
+//! the `Configuration`, clients compare successful responses with what the request implies, e.g.
+//! one sent message per destination, or a bulk ID when sending to multiple destinations. Any
+//! mismatch is recorded as a `ResponseAnomaly` instead of failing the call, so API contract drift
+//! can be detected in production without breaking it.
+//!
+//! Currently checked endpoints are SMS `send` and `send_binary`, and WhatsApp `send_template`.
+//!
+//! # Example
+//! ```no_run
+//! # use infobip_sdk::api::response_validation::AnomalyCollector;
+//! # use infobip_sdk::api::sms::SmsClient;
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let anomalies = AnomalyCollector::new()
+//!     .with_handler(|anomaly| eprintln!("unexpected response: {}", anomaly));
+//! let configuration = Configuration::from_env_api_key()?
+//!     .with_response_validation(anomalies.clone());
+//! let sms_client = SmsClient::with_configuration(configuration);
+//!
+//! let message = Message::new(vec![Destination::new("123456789012")]);
+//! sms_client.send(SendRequestBody::new(vec![message])).await?;
+//!
+//! assert!(anomalies.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Describes a response that doesn't match the expectations derived from its request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseAnomaly {
+    /// Path of the endpoint that returned the response.
+    pub path: String,
+
+    /// Description of the mismatch.
+    pub description: String,
+}
+
+impl fmt::Display for ResponseAnomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.description)
+    }
+}
+
+/// Implemented by request bodies that can tell what a successful response should look like.
+pub trait ResponseExpectations<R> {
+    /// Returns a description of every mismatch between the request and its response.
+    fn check_response(&self, response: &R) -> Vec<String>;
+}
+
+type AnomalyHandler = Arc<dyn Fn(&ResponseAnomaly) + Send + Sync>;
+
+/// Collects response anomalies. Clones share the same storage, so a clone can be kept to inspect
+/// the anomalies found by the clients using the configuration.
+#[derive(Clone, Default)]
+pub struct AnomalyCollector {
+    anomalies: Arc<Mutex<Vec<ResponseAnomaly>>>,
+    handler: Option<AnomalyHandler>,
+}
+
+impl fmt::Debug for AnomalyCollector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnomalyCollector")
+            .field("anomalies", &self.anomalies())
+            .field("handler", &self.handler.is_some())
+            .finish()
+    }
+}
+
+impl AnomalyCollector {
+    /// Creates an empty collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a function called for every anomaly as soon as it is found, e.g. to log it.
+    pub fn with_handler(
+        mut self,
+        handler: impl Fn(&ResponseAnomaly) + Send + Sync + 'static,
+    ) -> Self {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Returns a copy of the anomalies collected so far.
+    pub fn anomalies(&self) -> Vec<ResponseAnomaly> {
+        self.lock().clone()
+    }
+
+    /// Returns the anomalies collected so far and clears the collector.
+    pub fn take(&self) -> Vec<ResponseAnomaly> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Returns `true` if no anomalies were collected.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Checks a response against its request and records any mismatch.
+    pub fn check<Q: ResponseExpectations<R>, R>(&self, path: &str, request: &Q, response: &R) {
+        for description in request.check_response(response) {
+            let anomaly = ResponseAnomaly {
+                path: path.into(),
+                description,
+            };
+            if let Some(handler) = &self.handler {
+                handler(&anomaly);
+            }
+            self.lock().push(anomaly);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<ResponseAnomaly>> {
+        self.anomalies.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(any(feature = "sms", feature = "whatsapp"))]
+fn check_message_count(expected: usize, received: usize, bulk_id: Option<&String>) -> Vec<String> {
+    let mut anomalies = Vec::new();
+    if expected != received {
+        anomalies.push(format!(
+            "expected {} sent messages, received {}",
+            expected, received
+        ));
+    }
+    if expected > 1 && bulk_id.is_none() {
+        anomalies.push(format!(
+            "bulk ID missing when sending to {} destinations",
+            expected
+        ));
+    }
+    anomalies
+}
+
+#[cfg(feature = "sms")]
+mod sms {
+    use super::{check_message_count, ResponseExpectations};
+    use crate::model::sms::{
+        Destination, SendBinaryRequestBody, SendRequestBody, SendResponseBody,
+    };
+
+    fn destination_count(destinations: &Option<Vec<Destination>>) -> usize {
+        destinations.as_ref().map_or(0, Vec::len)
+    }
+
+    fn check_sent_messages(expected: usize, response: &SendResponseBody) -> Vec<String> {
+        let messages = response.messages.as_deref().unwrap_or_default();
+        let mut anomalies =
+            check_message_count(expected, messages.len(), response.bulk_id.as_ref());
+        for (index, message) in messages.iter().enumerate() {
+            if message.message_id.is_none() {
+                anomalies.push(format!("message {} has no message ID", index));
+            }
+            if message.status.is_none() {
+                anomalies.push(format!("message {} has no status", index));
+            }
+        }
+        anomalies
+    }
+
+    impl ResponseExpectations<SendResponseBody> for SendRequestBody {
+        fn check_response(&self, response: &SendResponseBody) -> Vec<String> {
+            let expected = self
+                .messages
+                .iter()
+                .map(|message| destination_count(&message.destinations))
+                .sum();
+            check_sent_messages(expected, response)
+        }
+    }
+
+    impl ResponseExpectations<SendResponseBody> for SendBinaryRequestBody {
+        fn check_response(&self, response: &SendResponseBody) -> Vec<String> {
+            let expected = self
+                .messages
+                .iter()
+                .flatten()
+                .map(|message| destination_count(&message.destinations))
+                .sum();
+            check_sent_messages(expected, response)
+        }
+    }
+}
+
+#[cfg(feature = "whatsapp")]
+mod whatsapp {
+    use super::{check_message_count, ResponseExpectations};
+    use crate::model::whatsapp::{SendTemplateRequestBody, SendTemplateResponseBody};
+
+    impl ResponseExpectations<SendTemplateResponseBody> for SendTemplateRequestBody {
+        fn check_response(&self, response: &SendTemplateResponseBody) -> Vec<String> {
+            let messages = response.messages.as_deref().unwrap_or_default();
+            let mut anomalies = check_message_count(
+                self.messages.len(),
+                messages.len(),
+                response.bulk_id.as_ref(),
+            );
+            for (index, (sent, info)) in self.messages.iter().zip(messages).enumerate() {
+                let same_destination = info.to.as_deref().map(normalize_destination)
+                    == Some(normalize_destination(&sent.to));
+                if !same_destination {
+                    anomalies.push(format!("message {} destination doesn't match", index));
+                }
+                if sent.message_id.is_some() && info.message_id != sent.message_id {
+                    anomalies.push(format!("message {} ID doesn't match", index));
+                }
+            }
+            anomalies
+        }
+    }
+
+    // Removes the formatting the API may drop or add when echoing a destination: whitespace and
+    // a leading `+` or `00`.
+    fn normalize_destination(destination: &str) -> String {
+        let destination: String = destination.chars().filter(|c| !c.is_whitespace()).collect();
+        match destination
+            .strip_prefix('+')
+            .or_else(|| destination.strip_prefix("00"))
+        {
+            Some(rest) => rest.to_string(),
+            None => destination,
+        }
+    }
+}
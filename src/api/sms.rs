@@ -1,63 +1,114 @@
 //! Module with client and endpoint functions for the SMS channel.
 
-use std::collections::HashMap;
+use std::collections::{HashSet, VecDeque};
+use std::thread;
+use std::time::Duration;
 
+use chrono::{DateTime, FixedOffset, Utc};
+use futures_util::stream::{self, Stream, StreamExt};
 use validator::Validate;
 
+use crate::api::tfa::TfaClient;
 use crate::api::{
-    build_api_error, send_blocking_valid_json_request, send_no_body_request,
-    send_valid_json_request, ApiError, SdkError, SdkResponse,
+    build_blocking_http_client, build_blocking_response, build_http_client, build_response,
+    fill_platform_ids, send_blocking_request, send_blocking_valid_json_request,
+    send_no_body_request, send_raw_request, send_valid_json_request, NoContent, SdkError,
+    SdkResponse,
 };
 use crate::model::sms::{
+    AddTrackedDomainRequestBody, AddTrackedDomainResponseBody, ClickReportsQueryParameters,
+    ClickReportsResponseBody, DeliveryReportsQueryParameters, DeliveryReportsResponseBody,
+    Destination, InboundReportsQueryParameters, InboundReportsResponseBody, InboundSmsReport, Log,
+    LogsQueryParameters, LogsResponseBody, Message, MessageIds, RescheduleQueryParameters,
+    RescheduleRequestBody, RescheduleResponseBody, ScheduledQueryParameters, ScheduledResponseBody,
+    ScheduledStatus, ScheduledStatusQueryParameters, ScheduledStatusResponseBody,
+    SendBinaryRequestBody, SendBinaryResponseBody, SendOverQueryParametersQueryParameters,
+    SendOverQueryParametersResponseBody, SendRequestBody, SendResponseBody,
+    TrackedDomainsResponseBody, UpdateScheduledStatusQueryParameters,
+    UpdateScheduledStatusRequestBody, UpdateScheduledStatusResponseBody, DATE_TIME_FORMAT,
+};
+use crate::model::tfa::{
     CreateTfaApplicationRequestBody, CreateTfaApplicationResponseBody,
     CreateTfaMessageTemplateRequestBody, CreateTfaMessageTemplateResponseBody,
-    DeliveryReportsQueryParameters, DeliveryReportsResponseBody, InboundReportsQueryParameters,
-    InboundReportsResponseBody, LogsQueryParameters, LogsResponseBody, RescheduleQueryParameters,
-    RescheduleRequestBody, RescheduleResponseBody, ResendPinOverSmsRequestBody,
+    ResendPinOverEmailRequestBody, ResendPinOverEmailResponseBody, ResendPinOverSmsRequestBody,
     ResendPinOverSmsResponseBody, ResendPinOverVoiceRequestBody, ResendPinOverVoiceResponseBody,
-    ScheduledQueryParameters, ScheduledResponseBody, ScheduledStatusQueryParameters,
-    ScheduledStatusResponseBody, SendBinaryRequestBody, SendBinaryResponseBody,
-    SendOverQueryParametersQueryParameters, SendOverQueryParametersResponseBody,
-    SendPinOverSmsQueryParameters, SendPinOverSmsRequestBody, SendPinOverSmsResponseBody,
-    SendPinOverVoiceRequestBody, SendPinOverVoiceResponseBody, SendRequestBody, SendResponseBody,
-    TfaApplicationResponseBody, TfaApplicationsResponseBody, TfaMessageTemplateResponseBody,
-    TfaMessageTemplatesResponseBody, TfaVerificationStatusQueryParameters,
-    TfaVerificationStatusResponseBody, UpdateScheduledStatusQueryParameters,
-    UpdateScheduledStatusRequestBody, UpdateScheduledStatusResponseBody,
+    SendPinOverEmailRequestBody, SendPinOverEmailResponseBody, SendPinOverSmsQueryParameters,
+    SendPinOverSmsRequestBody, SendPinOverSmsResponseBody, SendPinOverVoiceRequestBody,
+    SendPinOverVoiceResponseBody, TfaApplicationResponseBody, TfaApplicationsResponseBody,
+    TfaMessageTemplateResponseBody, TfaMessageTemplatesResponseBody,
+    TfaVerificationStatusQueryParameters, TfaVerificationStatusResponseBody,
     UpdateTfaApplicationRequestBody, UpdateTfaApplicationResponseBody,
     UpdateTfaMessageTemplateRequestBody, UpdateTfaMessageTemplateResponseBody,
     VerifyPhoneNumberRequestBody, VerifyPhoneNumberResponseBody,
 };
 use crate::{
-    configuration::Configuration,
+    configuration::{Channel, Configuration},
     model::sms::{PreviewRequestBody, PreviewResponseBody},
 };
 
+pub const PATH_ADD_TRACKED_DOMAIN: &str = "/urlshortener/1/domains";
+pub const PATH_CONFIRM_CONVERSION: &str = "/ct/1/log/end/{processKey}";
+pub const PATH_DELETE_TRACKED_DOMAIN: &str = "/urlshortener/1/domains/{domainName}";
+pub const PATH_GET_CLICK_REPORTS: &str = "/urlshortener/1/clicks";
 pub const PATH_GET_DELIVERY_REPORTS: &str = "/sms/1/reports";
 pub const PATH_GET_INBOUND: &str = "/sms/1/inbox/reports";
 pub const PATH_GET_LOGS: &str = "/sms/1/logs";
 pub const PATH_GET_SCHEDULED: &str = "/sms/1/bulks";
 pub const PATH_GET_SCHEDULED_STATUS: &str = "/sms/1/bulks/status";
+pub const PATH_GET_TRACKED_DOMAINS: &str = "/urlshortener/1/domains";
 pub const PATH_PREVIEW: &str = "/sms/1/preview";
 pub const PATH_RESCHEDULE: &str = "/sms/1/bulks";
 pub const PATH_SEND: &str = "/sms/2/text/advanced";
 pub const PATH_SEND_BINARY: &str = "/sms/2/binary/advanced";
 pub const PATH_SEND_OVER_QUERY_PARAMS: &str = "/sms/1/text/query";
 pub const PATH_UPDATE_SCHEDULED_STATUS: &str = "/sms/1/bulks/status";
-pub const PATH_GET_TFA_APPLICATIONS: &str = "/2fa/2/applications";
-pub const PATH_CREATE_TFA_APPLICATION: &str = "/2fa/2/applications";
-pub const PATH_GET_TFA_APPLICATION: &str = "/2fa/2/applications/{appId}";
-pub const PATH_UPDATE_TFA_APPLICATION: &str = "/2fa/2/applications/{appId}";
-pub const PATH_GET_TFA_MESSAGE_TEMPLATES: &str = "/2fa/2/applications/{appId}/messages";
-pub const PATH_CREATE_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages";
-pub const PATH_GET_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages/{msgId}";
-pub const PATH_UPDATE_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages/{msgId}";
-pub const PATH_SEND_PIN_OVER_SMS: &str = "/2fa/2/pin";
-pub const PATH_RESEND_PIN_OVER_SMS: &str = "/2fa/2/pin/{pinId}/resend";
-pub const PATH_SEND_PIN_OVER_VOICE: &str = "/2fa/2/pin/voice";
-pub const PATH_RESEND_PIN_OVER_VOICE: &str = "/2fa/2/pin/{pinId}/resend/voice";
-pub const PATH_VERIFY_PHONE_NUMBER: &str = "/2fa/2/pin/{pinId}/verify";
-pub const PATH_GET_TFA_VERIFICATION_STATUS: &str = "/2fa/2/applications/{appId}/verifications";
+
+/// Default number of concurrent requests made by `preview_many`.
+pub const DEFAULT_PREVIEW_CONCURRENCY: usize = 8;
+
+/// Longest wait between retries of a failing `inbound_stream` poll.
+pub const MAX_INBOUND_STREAM_BACKOFF: Duration = Duration::from_secs(300);
+/// Most logs returned by one call to the logs endpoint, used as page size by `logs_paged`.
+pub const MAX_LOGS_PER_PAGE: i32 = 1000;
+
+/// Number of hours logs are available for after a message is sent.
+pub const LOGS_AVAILABILITY_HOURS: i64 = 48;
+
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_GET_TFA_APPLICATIONS: &str = crate::api::tfa::PATH_GET_TFA_APPLICATIONS;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_CREATE_TFA_APPLICATION: &str = crate::api::tfa::PATH_CREATE_TFA_APPLICATION;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_GET_TFA_APPLICATION: &str = crate::api::tfa::PATH_GET_TFA_APPLICATION;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_UPDATE_TFA_APPLICATION: &str = crate::api::tfa::PATH_UPDATE_TFA_APPLICATION;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_GET_TFA_MESSAGE_TEMPLATES: &str = crate::api::tfa::PATH_GET_TFA_MESSAGE_TEMPLATES;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_CREATE_TFA_MESSAGE_TEMPLATE: &str =
+    crate::api::tfa::PATH_CREATE_TFA_MESSAGE_TEMPLATE;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_GET_TFA_MESSAGE_TEMPLATE: &str = crate::api::tfa::PATH_GET_TFA_MESSAGE_TEMPLATE;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_UPDATE_TFA_MESSAGE_TEMPLATE: &str =
+    crate::api::tfa::PATH_UPDATE_TFA_MESSAGE_TEMPLATE;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_SEND_PIN_OVER_SMS: &str = crate::api::tfa::PATH_SEND_PIN_OVER_SMS;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_RESEND_PIN_OVER_SMS: &str = crate::api::tfa::PATH_RESEND_PIN_OVER_SMS;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_SEND_PIN_OVER_VOICE: &str = crate::api::tfa::PATH_SEND_PIN_OVER_VOICE;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_RESEND_PIN_OVER_VOICE: &str = crate::api::tfa::PATH_RESEND_PIN_OVER_VOICE;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_SEND_PIN_OVER_EMAIL: &str = crate::api::tfa::PATH_SEND_PIN_OVER_EMAIL;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_RESEND_PIN_OVER_EMAIL: &str = crate::api::tfa::PATH_RESEND_PIN_OVER_EMAIL;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_VERIFY_PHONE_NUMBER: &str = crate::api::tfa::PATH_VERIFY_PHONE_NUMBER;
+#[deprecated(since = "0.7.0", note = "moved to `api::tfa`")]
+pub const PATH_GET_TFA_VERIFICATION_STATUS: &str =
+    crate::api::tfa::PATH_GET_TFA_VERIFICATION_STATUS;
 
 /// Main asynchronous client for the Infobip SMS channel.
 #[derive(Clone, Debug)]
@@ -68,10 +119,17 @@ pub struct SmsClient {
 
 impl SmsClient {
     /// Builds and returns a new asynchronous `SmsClient` with specified configuration.
+    /// The client gets its own connection pool, see `with_http_client` to share one.
     pub fn with_configuration(configuration: Configuration) -> Self {
+        Self::with_http_client(build_http_client(&configuration), configuration)
+    }
+
+    /// Builds and returns a new asynchronous `SmsClient` that sends requests with the given HTTP
+    /// client. See `api::client` for sharing an HTTP client between channel clients.
+    pub fn with_http_client(http_client: reqwest::Client, configuration: Configuration) -> Self {
         SmsClient {
-            configuration,
-            http_client: reqwest::Client::new(),
+            configuration: configuration.for_channel(Channel::Sms),
+            http_client,
         }
     }
 
@@ -103,27 +161,115 @@ impl SmsClient {
         &self,
         request_body: PreviewRequestBody,
     ) -> Result<SdkResponse<PreviewResponseBody>, SdkError> {
+        let cache = self.configuration.preview_cache();
+        if let Some(body) = cache.and_then(|cache| cache.get(&request_body)) {
+            return Ok(SdkResponse {
+                body,
+                status: reqwest::StatusCode::OK,
+                duration: Duration::ZERO,
+            });
+        }
+
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
-            request_body,
-            HashMap::new(),
+            &request_body,
+            (),
             reqwest::Method::POST,
             PATH_PREVIEW,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
+        let response: SdkResponse<PreviewResponseBody> = build_response(response).await?;
+        if let Some(cache) = cache {
+            cache.insert(&request_body, response.body.clone());
         }
+
+        Ok(response)
+    }
+
+    /// Preview many texts, making at most `concurrency` requests at the same time. Returns one
+    /// result per text, in the same order as the texts. A `concurrency` of 0 is treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::{SmsClient, DEFAULT_PREVIEW_CONCURRENCY};
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let texts = vec!["Your code is {{code}}", "Your order has shipped!"];
+    /// let results = sms_client
+    ///     .preview_many(texts, DEFAULT_PREVIEW_CONCURRENCY)
+    ///     .await;
+    ///
+    /// for result in results {
+    ///     println!("{:?}", result?.body.previews);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview_many<I>(
+        &self,
+        texts: I,
+        concurrency: usize,
+    ) -> Vec<Result<SdkResponse<PreviewResponseBody>, SdkError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let request_bodies: Vec<PreviewRequestBody> = texts
+            .into_iter()
+            .map(|text| PreviewRequestBody::new(text.as_ref()))
+            .collect();
+
+        self.preview_batch(request_bodies, concurrency).await
+    }
+
+    /// Preview many request bodies, each with its own language and transliteration, making at
+    /// most `concurrency` requests at the same time. Returns one result per request body, in the
+    /// same order. A `concurrency` of 0 is treated as 1. Previews found in the configured
+    /// `PreviewCache` are not requested.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::{SmsClient, DEFAULT_PREVIEW_CONCURRENCY};
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::PreviewRequestBody;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut greek = PreviewRequestBody::new("Καλημέρα");
+    /// greek.transliteration = Some("GREEK".to_string());
+    /// let request_bodies = vec![PreviewRequestBody::new("Good morning"), greek];
+    ///
+    /// let results = sms_client
+    ///     .preview_batch(request_bodies, DEFAULT_PREVIEW_CONCURRENCY)
+    ///     .await;
+    ///
+    /// assert_eq!(results.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview_batch<I>(
+        &self,
+        request_bodies: I,
+        concurrency: usize,
+    ) -> Vec<Result<SdkResponse<PreviewResponseBody>, SdkError>>
+    where
+        I: IntoIterator<Item = PreviewRequestBody>,
+    {
+        stream::iter(request_bodies)
+            .map(|request_body| self.preview(request_body))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     ///  delivery reports for recently sent SMS messages.
@@ -158,37 +304,16 @@ impl SmsClient {
     ) -> Result<SdkResponse<DeliveryReportsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_DELIVERY_REPORTS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send a single, or multiple SMS messages to one or many destinations.
@@ -225,30 +350,88 @@ impl SmsClient {
     /// # }
     /// ```
     pub async fn send(
+        &self,
+        mut request_body: SendRequestBody,
+    ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        let ids = if self.configuration.generated_message_ids() {
+            Some(request_body.generate_ids())
+        } else {
+            None
+        };
+
+        let result = match self.configuration.deduplication() {
+            Some(window) => {
+                let messages = deduplication_keys(&request_body);
+                window
+                    .reserve(&messages)
+                    .map_err(|destinations| SdkError::DuplicateSuppressed { destinations })?;
+
+                let result = self.send_request_body(request_body).await;
+                if let Err(SdkError::Validation(_) | SdkError::ApiRequestError(_)) = result {
+                    window.release(&messages);
+                }
+                result
+            }
+            None => self.send_request_body(request_body).await,
+        };
+
+        match ids {
+            Some(ids) => with_message_ids(result, ids),
+            None => result,
+        }
+    }
+
+    async fn send_request_body(
         &self,
         request_body: SendRequestBody,
     ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
-            request_body,
-            HashMap::new(),
+            &request_body,
+            (),
             reqwest::Method::POST,
             PATH_SEND,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
+        let response = build_response(response).await?;
+        if let Some(collector) = self.configuration.response_validation() {
+            collector.check(PATH_SEND, &request_body, &response.body);
         }
+
+        Ok(response)
+    }
+
+    /// Send a text message to a single destination, from the default sender of the account. See
+    /// `send` for other options and several messages.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = sms_client.send_text_to("491702384590", "Hello").await?;
+    ///
+    /// println!("{:?}", response.body.messages);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_text_to(
+        &self,
+        to: &str,
+        text: &str,
+    ) -> Result<SdkResponse<SendResponseBody>, SdkError> {
+        let mut message = Message::new(vec![Destination::new(to)]);
+        message.text = Some(text.to_string());
+
+        self.send(SendRequestBody::new(vec![message])).await
     }
 
     /// Send single or multiple binary messages to one or more destination addresses.
@@ -279,30 +462,39 @@ impl SmsClient {
     /// # }
     /// ```
     pub async fn send_binary(
+        &self,
+        mut request_body: SendBinaryRequestBody,
+    ) -> Result<SdkResponse<SendBinaryResponseBody>, SdkError> {
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        if self.configuration.generated_message_ids() {
+            let ids = request_body.generate_ids();
+            return with_message_ids(self.send_binary_request_body(request_body).await, ids);
+        }
+
+        self.send_binary_request_body(request_body).await
+    }
+
+    async fn send_binary_request_body(
         &self,
         request_body: SendBinaryRequestBody,
     ) -> Result<SdkResponse<SendBinaryResponseBody>, SdkError> {
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
-            request_body,
-            HashMap::new(),
+            &request_body,
+            (),
             reqwest::Method::POST,
             PATH_SEND_BINARY,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
+        let response = build_response(response).await?;
+        if let Some(collector) = self.configuration.response_validation() {
+            collector.check(PATH_SEND_BINARY, &request_body, &response.body);
         }
+
+        Ok(response)
     }
 
     /// See all scheduled messages and their scheduled date and time. To schedule a message, use
@@ -333,29 +525,16 @@ impl SmsClient {
     ) -> Result<SdkResponse<ScheduledResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_SCHEDULED,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Use this method for displaying logs for example in the user interface. Available are the
@@ -387,58 +566,16 @@ impl SmsClient {
     ) -> Result<SdkResponse<LogsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(from) = query_parameters.from {
-            parameters_map.insert("from".to_string(), from);
-        }
-        if let Some(to) = query_parameters.to {
-            parameters_map.insert("to".to_string(), to);
-        }
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(message_id) = query_parameters.message_id {
-            parameters_map.insert("messageId".to_string(), message_id);
-        }
-        if let Some(general_status) = query_parameters.general_status {
-            parameters_map.insert("generalStatus".to_string(), general_status);
-        }
-        if let Some(sent_since) = query_parameters.sent_since {
-            parameters_map.insert("sentSince".to_string(), sent_since);
-        }
-        if let Some(sent_until) = query_parameters.sent_until {
-            parameters_map.insert("sentUntil".to_string(), sent_until);
-        }
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
-        if let Some(mcc) = query_parameters.mcc {
-            parameters_map.insert("mcc".to_string(), mcc);
-        }
-        if let Some(mnc) = query_parameters.mnc {
-            parameters_map.insert("mnc".to_string(), mnc);
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_LOGS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// If for some reason you are unable to receive incoming SMS to the endpoint of your choice
@@ -471,31 +608,163 @@ impl SmsClient {
     ) -> Result<SdkResponse<InboundReportsResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        if let Some(limit) = query_parameters.limit {
-            parameters_map.insert("limit".to_string(), limit.to_string());
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_INBOUND,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        build_response(response).await
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
+    /// Fetches all logs matching the query, walking back in time one page at a time and yielding
+    /// the logs one by one. Each page ends where the previous one had its oldest log, so logs
+    /// beyond the limit of a single `logs` call are not lost; logs returned twice at a page
+    /// boundary are skipped. `limit` sets the page size, and defaults to [`MAX_LOGS_PER_PAGE`].
+    /// A full page sent within a single millisecond is fetched again with the largest page size;
+    /// if even that is full, the stream ends with `SdkError::LogsPageOverflow` after the logs
+    /// fetched so far, as the API can't page within a millisecond.
+    /// Without `sent_since`, the walk covers the last [`LOGS_AVAILABILITY_HOURS`] hours, which is
+    /// all the API keeps. The stream ends after the last page, or after yielding an error.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use futures_util::StreamExt;
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::LogsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_parameters = LogsQueryParameters::new();
+    /// query_parameters.general_status = Some("DELIVERED".to_string());
+    ///
+    /// let mut logs = Box::pin(sms_client.logs_paged(query_parameters)?);
+    ///
+    /// while let Some(log) = logs.next().await {
+    ///     println!("{:?}", log?.message_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn logs_paged(
+        &self,
+        mut query_parameters: LogsQueryParameters,
+    ) -> Result<impl Stream<Item = Result<Log, SdkError>>, SdkError> {
+        query_parameters.validate()?;
+
+        query_parameters.limit = Some(
+            query_parameters
+                .limit
+                .unwrap_or(MAX_LOGS_PER_PAGE)
+                .clamp(1, MAX_LOGS_PER_PAGE),
+        );
+        if query_parameters.sent_since.is_none() {
+            let since = Utc::now() - chrono::Duration::hours(LOGS_AVAILABILITY_HOURS);
+            query_parameters.sent_since = Some(since.format(DATE_TIME_FORMAT).to_string());
         }
+
+        let state = LogsPagesState {
+            client: self.clone(),
+            query_parameters,
+            seen: HashSet::new(),
+            buffer: VecDeque::new(),
+            overflow: None,
+            done: false,
+        };
+
+        Ok(stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(log) = state.buffer.pop_front() {
+                    return Some((Ok(log), state));
+                }
+
+                if let Some(error) = state.overflow.take() {
+                    return Some((Err(error), state));
+                }
+                if state.done {
+                    return None;
+                }
+                if let Err(error) = state.next_page().await {
+                    state.done = true;
+                    return Some((Err(error), state));
+                }
+            }
+        }))
+    }
+
+    /// Polls the inbox endpoint every `interval` and yields received messages one by one, for
+    /// services that can't expose a notify URL. When more messages are pending, the next poll
+    /// is made right away. The error of a failed poll is yielded too. Retryable errors (see
+    /// `SdkError::is_retryable`) are retried with exponential backoff, up to
+    /// [`MAX_INBOUND_STREAM_BACKOFF`], or after the `Retry-After` delay of the response if longer.
+    /// Any other error, like invalid credentials, ends the stream.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use futures_util::StreamExt;
+    /// # use infobip_sdk::api::sms::SmsClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::sms::InboundReportsQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_parameters = InboundReportsQueryParameters::new();
+    /// let mut inbound = Box::pin(
+    ///     sms_client.inbound_stream(query_parameters, Duration::from_secs(30))?,
+    /// );
+    ///
+    /// while let Some(report) = inbound.next().await {
+    ///     match report {
+    ///         Ok(report) => println!("{:?}: {:?}", report.from, report.text),
+    ///         Err(error) => eprintln!("polling the inbox failed: {}", error),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn inbound_stream(
+        &self,
+        query_parameters: InboundReportsQueryParameters,
+        interval: Duration,
+    ) -> Result<impl Stream<Item = Result<InboundSmsReport, SdkError>>, SdkError> {
+        query_parameters.validate()?;
+
+        let state = InboundStreamState {
+            client: self.clone(),
+            query_parameters,
+            interval,
+            delay: Duration::ZERO,
+            failures: 0,
+            buffer: VecDeque::new(),
+            done: false,
+        };
+
+        Ok(stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(report) = state.buffer.pop_front() {
+                    return Some((Ok(report), state));
+                }
+
+                if state.done {
+                    return None;
+                }
+                if !state.delay.is_zero() {
+                    tokio::time::sleep(state.delay).await;
+                }
+                if let Err(error) = state.poll().await {
+                    return Some((Err(error), state));
+                }
+            }
+        }))
     }
 
     /// All message parameters of the message can be defined in the query string. Use this method
@@ -531,94 +800,16 @@ impl SmsClient {
     ) -> Result<SdkResponse<SendOverQueryParametersResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let mut parameters_map = HashMap::<String, String>::new();
-        parameters_map.insert("username".to_string(), query_parameters.username);
-        parameters_map.insert("password".to_string(), query_parameters.password);
-        parameters_map.insert("to".to_string(), query_parameters.to.join(","));
-
-        if let Some(bulk_id) = query_parameters.bulk_id {
-            parameters_map.insert("bulkId".to_string(), bulk_id);
-        }
-        if let Some(from) = query_parameters.from {
-            parameters_map.insert("from".to_string(), from);
-        }
-        if let Some(text) = query_parameters.text {
-            parameters_map.insert("text".to_string(), text);
-        }
-        if let Some(flash) = query_parameters.flash {
-            parameters_map.insert("flash".to_string(), flash.to_string());
-        }
-        if let Some(transliteration) = query_parameters.transliteration {
-            parameters_map.insert("transliteration".to_string(), transliteration);
-        }
-        if let Some(language_code) = query_parameters.language_code {
-            parameters_map.insert("languageCode".to_string(), language_code);
-        }
-        if let Some(intermediate_report) = query_parameters.intermediate_report {
-            parameters_map.insert(
-                "intermediateReport".to_string(),
-                intermediate_report.to_string(),
-            );
-        }
-        if let Some(notify_url) = query_parameters.notify_url {
-            parameters_map.insert("notifyUrl".to_string(), notify_url);
-        }
-        if let Some(notify_content_type) = query_parameters.notify_content_type {
-            parameters_map.insert("notifyContentType".to_string(), notify_content_type);
-        }
-        if let Some(callback_data) = query_parameters.callback_data {
-            parameters_map.insert("callbackData".to_string(), callback_data);
-        }
-        if let Some(validity_period) = query_parameters.validity_period {
-            parameters_map.insert("validityPeriod".to_string(), validity_period.to_string());
-        }
-        if let Some(send_at) = query_parameters.send_at {
-            parameters_map.insert("sendAt".to_string(), send_at);
-        }
-        if let Some(track) = query_parameters.track {
-            parameters_map.insert("track".to_string(), track);
-        }
-        if let Some(process_key) = query_parameters.process_key {
-            parameters_map.insert("processKey".to_string(), process_key);
-        }
-        if let Some(tracking_type) = query_parameters.tracking_type {
-            parameters_map.insert("trackingType".to_string(), tracking_type);
-        }
-        if let Some(india_dlt_content_template_id) = query_parameters.india_dlt_content_template_id
-        {
-            parameters_map.insert(
-                "indiaDltContentTemplateId".to_string(),
-                india_dlt_content_template_id,
-            );
-        }
-        if let Some(india_dlt_principal_entity_id) = query_parameters.india_dlt_principal_entity_id
-        {
-            parameters_map.insert(
-                "indiaDltPrincipalEntityId".to_string(),
-                india_dlt_principal_entity_id,
-            );
-        }
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_SEND_OVER_QUERY_PARAMS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Change the date and time of already scheduled messages. To schedule a message, use the
@@ -651,30 +842,17 @@ impl SmsClient {
     ) -> Result<SdkResponse<RescheduleResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            query_parameters,
             reqwest::Method::PUT,
             PATH_RESCHEDULE,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     ///  the status of scheduled messages.
@@ -704,29 +882,16 @@ impl SmsClient {
     ) -> Result<SdkResponse<ScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            parameters_map,
+            query_parameters,
             reqwest::Method::GET,
             PATH_GET_SCHEDULED_STATUS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Change status or completely cancel sending of scheduled messages. To schedule a message,
@@ -759,33 +924,21 @@ impl SmsClient {
     ) -> Result<SdkResponse<UpdateScheduledStatusResponseBody>, SdkError> {
         query_parameters.validate()?;
 
-        let parameters_map =
-            HashMap::<String, String>::from([("bulkId".to_string(), query_parameters.bulk_id)]);
-
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters_map,
+            query_parameters,
             reqwest::Method::PUT,
             PATH_UPDATE_SCHEDULED_STATUS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    ///  a list of your 2FA applications.
+    /// Cancel sending of scheduled messages with the given bulk ID. This is a shortcut for
+    /// `update_scheduled_status` with `ScheduledStatus::Canceled`.
     ///
     /// # Example
     /// ```no_run
@@ -795,85 +948,65 @@ impl SmsClient {
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let response = client.tfa_applications().await?;
+    /// let response = sms_client.cancel_scheduled("some-bulk-id").await?;
     ///
     /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
-    pub async fn tfa_applications(
+    /// ```
+    pub async fn cancel_scheduled(
         &self,
-    ) -> Result<SdkResponse<TfaApplicationsResponseBody>, SdkError> {
-        let response = send_no_body_request(
-            &self.http_client,
-            &self.configuration,
-            HashMap::new(),
-            reqwest::Method::GET,
-            PATH_GET_TFA_APPLICATIONS,
+        bulk_id: &str,
+    ) -> Result<SdkResponse<UpdateScheduledStatusResponseBody>, SdkError> {
+        self.update_scheduled_status(
+            UpdateScheduledStatusQueryParameters::new(bulk_id),
+            UpdateScheduledStatusRequestBody::new(ScheduledStatus::Canceled),
         )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        .await
     }
 
-    /// Create and configure a new 2FA application.
+    /// Report a conversion for a message sent with conversion tracking, closing the tracking
+    /// process started by `Tracking::process_key`, e.g. when the recipient entered the PIN they
+    /// got.
     ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::sms::SmsClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::CreateTfaApplicationRequestBody;
     /// # use reqwest::StatusCode;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    /// let request_body = CreateTfaApplicationRequestBody::new("some-name");
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let response = client.create_tfa_application(request_body).await?;
+    /// let response = sms_client.confirm_conversion("some-process-key").await?;
     ///
-    /// assert_eq!(response.status, StatusCode::CREATED);
+    /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
-    pub async fn create_tfa_application(
+    /// ```
+    pub async fn confirm_conversion(
         &self,
-        request_body: CreateTfaApplicationRequestBody,
-    ) -> Result<SdkResponse<CreateTfaApplicationResponseBody>, SdkError> {
-        let response = send_valid_json_request(
+        process_key: &str,
+    ) -> Result<SdkResponse<NoContent>, SdkError> {
+        let path = PATH_CONFIRM_CONVERSION.replace("{processKey}", process_key);
+
+        let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            request_body,
-            HashMap::new(),
+            (),
             reqwest::Method::POST,
-            PATH_CREATE_TFA_APPLICATION,
+            path.as_str(),
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    ///  a single 2FA application to see its configuration details.
+    /// Get the domains used for short URLs in messages, see `UrlOptions::custom_domain`.
+    ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::sms::SmsClient;
@@ -882,94 +1015,70 @@ impl SmsClient {
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let application_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let response = client.tfa_application(application_id).await?;
+    /// let response = sms_client.tracked_domains().await?;
     ///
     /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn tfa_application(
+    pub async fn tracked_domains(
         &self,
-        application_id: &str,
-    ) -> Result<SdkResponse<TfaApplicationResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_APPLICATION.replace("{appId}", application_id);
-
+    ) -> Result<SdkResponse<TrackedDomainsResponseBody>, SdkError> {
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
+            (),
             reqwest::Method::GET,
-            path,
+            PATH_GET_TRACKED_DOMAINS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    /// Change configuration options for your existing 2FA application.
+    /// Add a domain for short URLs. Short URLs use it once its DNS records point to the
+    /// platform and it is verified.
+    ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::sms::SmsClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::UpdateTfaApplicationRequestBody;
+    /// # use infobip_sdk::model::sms::AddTrackedDomainRequestBody;
     /// # use reqwest::StatusCode;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let request_body = AddTrackedDomainRequestBody::new("go.example.com");
     ///
-    /// let application_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let request_body = UpdateTfaApplicationRequestBody::new("some-new-name");
-    /// let response = client.update_tfa_application(application_id, request_body).await?;
+    /// let response = sms_client.add_tracked_domain(request_body).await?;
     ///
     /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update_tfa_application(
+    pub async fn add_tracked_domain(
         &self,
-        application_id: &str,
-        request_body: UpdateTfaApplicationRequestBody,
-    ) -> Result<SdkResponse<UpdateTfaApplicationResponseBody>, SdkError> {
-        let path = &PATH_UPDATE_TFA_APPLICATION.replace("{appId}", application_id);
-
+        request_body: AddTrackedDomainRequestBody,
+    ) -> Result<SdkResponse<AddTrackedDomainResponseBody>, SdkError> {
         let response = send_valid_json_request(
             &self.http_client,
             &self.configuration,
             request_body,
-            HashMap::new(),
-            reqwest::Method::PUT,
-            path,
+            (),
+            reqwest::Method::POST,
+            PATH_ADD_TRACKED_DOMAIN,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    ///  all message templates in a 2FA application.
+    /// Delete a domain for short URLs. Short URLs already sent with it stop working.
+    ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::sms::SmsClient;
@@ -978,512 +1087,481 @@ impl SmsClient {
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let application_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let response = client.tfa_message_templates(application_id).await?;
+    /// let response = sms_client.delete_tracked_domain("go.example.com").await?;
     ///
-    /// assert_eq!(response.status, StatusCode::OK);
+    /// assert_eq!(response.status, StatusCode::NO_CONTENT);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn tfa_message_templates(
+    pub async fn delete_tracked_domain(
         &self,
-        application_id: &str,
-    ) -> Result<SdkResponse<TfaMessageTemplatesResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_MESSAGE_TEMPLATES.replace("{appId}", application_id);
+        domain_name: &str,
+    ) -> Result<SdkResponse<NoContent>, SdkError> {
+        let path = PATH_DELETE_TRACKED_DOMAIN.replace("{domainName}", domain_name);
 
         let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
-            reqwest::Method::GET,
-            path,
+            (),
+            reqwest::Method::DELETE,
+            path.as_str(),
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    /// Create one or more message templates where your PIN will be dynamically included when you send the PIN message.
+    /// Get reports of clicks on short URLs in sent messages, for messages sent with
+    /// `UrlOptions::track_clicks`. Use `ClickReportsResponseBody::unique_clicks` to compute
+    /// click-through rates.
+    ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::sms::SmsClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::CreateTfaMessageTemplateRequestBody;
+    /// # use infobip_sdk::model::sms::ClickReportsQueryParameters;
     /// # use reqwest::StatusCode;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use infobip_sdk::model::sms::PinType::Numeric;
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let application_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let request_body = CreateTfaMessageTemplateRequestBody::new("some-name", Numeric, 4);
-    /// let response = client.create_tfa_message_template(application_id, request_body).await?;
+    /// let mut query_parameters = ClickReportsQueryParameters::new();
+    /// query_parameters.bulk_id = Some("some-bulk-id".to_string());
     ///
-    /// assert_eq!(response.status, StatusCode::OK);
+    /// let response = sms_client.click_reports(query_parameters).await?;
+    ///
+    /// println!("{} messages clicked", response.body.unique_clicks());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_tfa_message_template(
+    pub async fn click_reports(
         &self,
-        application_id: &str,
-        request_body: CreateTfaMessageTemplateRequestBody,
-    ) -> Result<SdkResponse<CreateTfaMessageTemplateResponseBody>, SdkError> {
-        let path = &PATH_CREATE_TFA_MESSAGE_TEMPLATE.replace("{appId}", application_id);
+        query_parameters: ClickReportsQueryParameters,
+    ) -> Result<SdkResponse<ClickReportsResponseBody>, SdkError> {
+        query_parameters.validate()?;
 
-        let response = send_valid_json_request(
+        let response = send_no_body_request(
             &self.http_client,
             &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::POST,
-            path,
+            query_parameters,
+            reqwest::Method::GET,
+            PATH_GET_CLICK_REPORTS,
         )
         .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        build_response(response).await
+    }
+
+    fn tfa_client(&self) -> TfaClient {
+        TfaClient::with_http_client(self.http_client.clone(), self.configuration.clone())
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+    /// Get a list of your 2FA applications.
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::tfa_applications` instead")]
+    pub async fn tfa_applications(
+        &self,
+    ) -> Result<SdkResponse<TfaApplicationsResponseBody>, SdkError> {
+        self.tfa_client().tfa_applications().await
+    }
+
+    /// Create and configure a new 2FA application.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::create_tfa_application` instead"
+    )]
+    pub async fn create_tfa_application(
+        &self,
+        request_body: CreateTfaApplicationRequestBody,
+    ) -> Result<SdkResponse<CreateTfaApplicationResponseBody>, SdkError> {
+        self.tfa_client().create_tfa_application(request_body).await
+    }
+
+    ///  a single 2FA application to see its configuration details.
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::tfa_application` instead")]
+    pub async fn tfa_application(
+        &self,
+        application_id: &str,
+    ) -> Result<SdkResponse<TfaApplicationResponseBody>, SdkError> {
+        self.tfa_client()
+            .tfa_application(&application_id.into())
+            .await
+    }
+
+    /// Change configuration options for your existing 2FA application.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::update_tfa_application` instead"
+    )]
+    pub async fn update_tfa_application(
+        &self,
+        application_id: &str,
+        request_body: UpdateTfaApplicationRequestBody,
+    ) -> Result<SdkResponse<UpdateTfaApplicationResponseBody>, SdkError> {
+        self.tfa_client()
+            .update_tfa_application(&application_id.into(), request_body)
+            .await
+    }
+
+    ///  all message templates in a 2FA application.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::tfa_message_templates` instead"
+    )]
+    pub async fn tfa_message_templates(
+        &self,
+        application_id: &str,
+    ) -> Result<SdkResponse<TfaMessageTemplatesResponseBody>, SdkError> {
+        self.tfa_client()
+            .tfa_message_templates(&application_id.into())
+            .await
+    }
+
+    /// Create one or more message templates where your PIN will be dynamically included when you send the PIN message.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::create_tfa_message_template` instead"
+    )]
+    pub async fn create_tfa_message_template(
+        &self,
+        application_id: &str,
+        request_body: CreateTfaMessageTemplateRequestBody,
+    ) -> Result<SdkResponse<CreateTfaMessageTemplateResponseBody>, SdkError> {
+        self.tfa_client()
+            .create_tfa_message_template(&application_id.into(), request_body)
+            .await
     }
 
     ///  a single 2FA message template from an application to see its configuration details.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let application_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let template_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let response = client.tfa_message_template(application_id, template_id).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::tfa_message_template` instead"
+    )]
     pub async fn tfa_message_template(
         &self,
         application_id: &str,
         template_id: &str,
     ) -> Result<SdkResponse<TfaMessageTemplateResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_MESSAGE_TEMPLATE
-            .replace("{appId}", application_id)
-            .replace("{msgId}", template_id);
-
-        let response = send_no_body_request(
-            &self.http_client,
-            &self.configuration,
-            HashMap::new(),
-            reqwest::Method::GET,
-            path,
-        )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        self.tfa_client()
+            .tfa_message_template(&application_id.into(), &template_id.into())
+            .await
     }
 
     /// Change configuration options for your existing 2FA application message template.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::UpdateTfaMessageTemplateRequestBody;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use infobip_sdk::model::sms::PinType::Numeric;
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let application_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let template_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let request_body = UpdateTfaMessageTemplateRequestBody::new("some-text", Numeric, 4);
-    ///
-    /// let response = client.update_tfa_message_template(application_id, template_id, request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::update_tfa_message_template` instead"
+    )]
     pub async fn update_tfa_message_template(
         &self,
         application_id: &str,
         template_id: &str,
         request_body: UpdateTfaMessageTemplateRequestBody,
     ) -> Result<SdkResponse<UpdateTfaMessageTemplateResponseBody>, SdkError> {
-        let path = &PATH_UPDATE_TFA_MESSAGE_TEMPLATE
-            .replace("{appId}", application_id)
-            .replace("{msgId}", template_id);
-
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::PUT,
-            path,
-        )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        self.tfa_client()
+            .update_tfa_message_template(&application_id.into(), &template_id.into(), request_body)
+            .await
     }
 
     /// Send a PIN code over SMS using a previously created message template.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::SendPinOverSmsQueryParameters;
-    /// # use infobip_sdk::model::sms::SendPinOverSmsRequestBody;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let query_parameters = SendPinOverSmsQueryParameters::default();
-    /// let request_body = SendPinOverSmsRequestBody::new("some-application-id", "some-template-id", "555555555555");
-    ///
-    /// let response = client.send_pin_over_sms(query_parameters, request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::send_pin_over_sms` instead")]
     pub async fn send_pin_over_sms(
         &self,
         query_parameters: SendPinOverSmsQueryParameters,
         request_body: SendPinOverSmsRequestBody,
     ) -> Result<SdkResponse<SendPinOverSmsResponseBody>, SdkError> {
-        query_parameters.validate()?;
-        let mut parameters_map = HashMap::new();
-        if let Some(nc_needed) = query_parameters.nc_needed {
-            parameters_map.insert("ncNeeded".to_string(), nc_needed.to_string());
-        }
-
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            parameters_map,
-            reqwest::Method::POST,
-            PATH_SEND_PIN_OVER_SMS,
-        )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        self.tfa_client()
+            .send_pin_over_sms(query_parameters, request_body)
+            .await
     }
 
     /// Resend the same (previously sent) PIN code over SMS.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::ResendPinOverSmsRequestBody;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let pin_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let request_body = ResendPinOverSmsRequestBody::default();
-    ///
-    /// let response = client.resend_pin_over_sms(pin_id, request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::resend_pin_over_sms` instead")]
     pub async fn resend_pin_over_sms(
         &self,
         pin_id: &str,
         request_body: ResendPinOverSmsRequestBody,
     ) -> Result<SdkResponse<ResendPinOverSmsResponseBody>, SdkError> {
-        let path = &PATH_RESEND_PIN_OVER_SMS.replace("{pinId}", pin_id);
-
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::POST,
-            path,
-        )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        self.tfa_client()
+            .resend_pin_over_sms(&pin_id.into(), request_body)
+            .await
     }
 
     /// Send a PIN code over Voice using previously created message template.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::SendPinOverVoiceRequestBody;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let request_body = SendPinOverVoiceRequestBody::new("some-application-id", "some-template-id", "555555555555");
-    ///
-    /// let response = client.send_pin_over_voice(request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::send_pin_over_voice` instead")]
     pub async fn send_pin_over_voice(
         &self,
         request_body: SendPinOverVoiceRequestBody,
     ) -> Result<SdkResponse<SendPinOverVoiceResponseBody>, SdkError> {
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::POST,
-            PATH_SEND_PIN_OVER_VOICE,
-        )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        self.tfa_client().send_pin_over_voice(request_body).await
     }
 
     /// Resend the same (previously sent) PIN code over Voice.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::ResendPinOverVoiceRequestBody;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let pin_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let request_body = ResendPinOverVoiceRequestBody::default();
-    ///
-    /// let response = client.resend_pin_over_voice(pin_id, request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::resend_pin_over_voice` instead"
+    )]
     pub async fn resend_pin_over_voice(
         &self,
         pin_id: &str,
         request_body: ResendPinOverVoiceRequestBody,
     ) -> Result<SdkResponse<ResendPinOverVoiceResponseBody>, SdkError> {
-        let path = &PATH_RESEND_PIN_OVER_VOICE.replace("{pinId}", pin_id);
-
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::POST,
-            path,
-        )
-        .await?;
+        self.tfa_client()
+            .resend_pin_over_voice(&pin_id.into(), request_body)
+            .await
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
+    /// Send a PIN code over Email using previously created message template with an email
+    /// template ID.
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::send_pin_over_email` instead")]
+    pub async fn send_pin_over_email(
+        &self,
+        request_body: SendPinOverEmailRequestBody,
+    ) -> Result<SdkResponse<SendPinOverEmailResponseBody>, SdkError> {
+        self.tfa_client().send_pin_over_email(request_body).await
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+    /// Resend the same (previously sent) PIN code over Email.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::resend_pin_over_email` instead"
+    )]
+    pub async fn resend_pin_over_email(
+        &self,
+        pin_id: &str,
+        request_body: ResendPinOverEmailRequestBody,
+    ) -> Result<SdkResponse<ResendPinOverEmailResponseBody>, SdkError> {
+        self.tfa_client()
+            .resend_pin_over_email(&pin_id.into(), request_body)
+            .await
     }
 
     /// Verify a phone number to confirm successful 2FA authentication.
-    /// # Example
-    /// ```no_run
-    /// # use infobip_sdk::api::sms::SmsClient;
-    /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::VerifyPhoneNumberRequestBody;
-    /// # use reqwest::StatusCode;
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use infobip_sdk::model::sms::VerifyPhoneNumberResponseBody;
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let pin_id = "02CC3CAAFD733136AA15DFAC720A0C42";
-    /// let request_body = VerifyPhoneNumberRequestBody::new("123456");
-    /// let response = client.verify_phone_number(pin_id, request_body).await?;
-    ///
-    /// assert_eq!(response.status, StatusCode::OK);
-    /// # Ok(())
-    /// # }
-    /// ```
+    #[deprecated(since = "0.7.0", note = "use `TfaClient::verify_phone_number` instead")]
     pub async fn verify_phone_number(
         &self,
         pin_id: &str,
         request_body: VerifyPhoneNumberRequestBody,
     ) -> Result<SdkResponse<VerifyPhoneNumberResponseBody>, SdkError> {
-        let path = &PATH_VERIFY_PHONE_NUMBER.replace("{pinId}", pin_id);
-
-        let response = send_valid_json_request(
-            &self.http_client,
-            &self.configuration,
-            request_body,
-            HashMap::new(),
-            reqwest::Method::POST,
-            path,
-        )
-        .await?;
-
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        self.tfa_client()
+            .verify_phone_number(&pin_id.into(), request_body)
+            .await
     }
 
     /// Check if a phone number is already verified for a specific 2FA application.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `TfaClient::tfa_verification_status` instead"
+    )]
+    pub async fn tfa_verification_status(
+        &self,
+        app_id: &str,
+        query_parameters: TfaVerificationStatusQueryParameters,
+    ) -> Result<SdkResponse<TfaVerificationStatusResponseBody>, SdkError> {
+        self.tfa_client()
+            .tfa_verification_status(&app_id.into(), query_parameters)
+            .await
+    }
+
+    /// Send a request with a JSON body built by hand, or no body, to any endpoint, and get the
+    /// response body as JSON. Useful for API features the SDK doesn't model yet. The body is not
+    /// validated, but authentication, headers, the base URL and error handling are the same as
+    /// for the typed endpoints. Query parameters can be given as part of `path`.
+    ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::sms::SmsClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use infobip_sdk::model::sms::{TfaVerificationStatusQueryParameters,
-    /// #         TfaVerificationStatusResponseBody};
-    /// # use reqwest::StatusCode;
+    /// # use reqwest::Method;
+    /// # use serde_json::json;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
-    ///
-    /// let query_parameters = TfaVerificationStatusQueryParameters::new("555555555555");
-    /// let response = client.tfa_verification_status("some-application-id", query_parameters).await?;
+    /// let sms_client = SmsClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// assert_eq!(response.status, StatusCode::OK);
+    /// let body = json!({
+    ///     "messages": [{
+    ///         "destinations": [{"to": "41793026727"}],
+    ///         "content": {"text": "Hello!"},
+    ///         "someNewOption": true
+    ///     }]
+    /// });
+    /// let response = sms_client
+    ///     .send_raw(Method::POST, "/sms/3/messages", Some(body))
+    ///     .await?;
+    ///
+    /// println!("{}", response.body["bulkId"]);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn tfa_verification_status(
+    pub async fn send_raw(
         &self,
-        app_id: &str,
-        query_parameters: TfaVerificationStatusQueryParameters,
-    ) -> Result<SdkResponse<TfaVerificationStatusResponseBody>, SdkError> {
-        let path = &PATH_GET_TFA_VERIFICATION_STATUS.replace("{appId}", app_id);
-
-        query_parameters.validate()?;
-        let mut parameters_map = HashMap::new();
-        parameters_map.insert("msisdn".to_string(), query_parameters.msisdn);
-        if let Some(verified) = query_parameters.verified {
-            parameters_map.insert("verified".to_string(), verified.to_string());
-        }
-        if let Some(sent) = query_parameters.sent {
-            parameters_map.insert("sent".to_string(), sent.to_string());
-        }
-
-        let response = send_no_body_request(
+        method: reqwest::Method,
+        path: &str,
+        request_body: Option<serde_json::Value>,
+    ) -> Result<SdkResponse<serde_json::Value>, SdkError> {
+        send_raw_request(
             &self.http_client,
             &self.configuration,
-            HashMap::new(),
-            reqwest::Method::GET,
+            method,
             path,
+            request_body,
         )
-        .await?;
+        .await
+    }
+}
 
-        let status = response.status();
-        let text = response.text().await?;
+// Returns the (destination, text) pair of every message destination, for deduplication.
+fn deduplication_keys(request_body: &SendRequestBody) -> Vec<(String, String)> {
+    request_body
+        .messages
+        .iter()
+        .flat_map(|message| {
+            let text = message.text.clone().unwrap_or_default();
+            message
+                .destinations
+                .iter()
+                .flatten()
+                .map(move |destination| (destination.to.clone(), text.clone()))
+        })
+        .collect()
+}
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
+// Attaches the generated IDs to errors that happen after the request could have been sent.
+fn with_message_ids<T>(
+    result: Result<SdkResponse<T>, SdkError>,
+    ids: MessageIds,
+) -> Result<SdkResponse<T>, SdkError> {
+    result.map_err(|error| match error {
+        SdkError::Validation(_) => error,
+        _ => SdkError::SendFailed {
+            bulk_id: ids.bulk_id,
+            message_ids: ids.message_ids,
+            source: Box::new(error),
+        },
+    })
+}
+
+// Paging state of `SmsClient::logs_paged`.
+struct LogsPagesState {
+    client: SmsClient,
+    query_parameters: LogsQueryParameters,
+    // Message IDs of the logs sent at the current `sent_until`, which the next page may repeat.
+    seen: HashSet<String>,
+    buffer: VecDeque<Log>,
+    // Yielded once the buffer is empty, when the walk can't go past a millisecond.
+    overflow: Option<SdkError>,
+    done: bool,
+}
+
+impl LogsPagesState {
+    async fn next_page(&mut self) -> Result<(), SdkError> {
+        let response = self.client.logs(self.query_parameters.clone()).await?;
+        let logs = response.body.results.unwrap_or_default();
+
+        let page_size = self.query_parameters.limit.unwrap_or(MAX_LOGS_PER_PAGE) as usize;
+        let oldest = logs.iter().filter_map(Log::sent_at_time).min();
+        self.done = logs.len() < page_size || oldest.is_none();
+
+        let mut seen = HashSet::new();
+        for log in logs {
+            if let Some(message_id) = &log.message_id {
+                if self.seen.contains(message_id) {
+                    continue;
+                }
+                if log.sent_at_time() == oldest {
+                    seen.insert(message_id.clone());
+                }
+            }
+            self.buffer.push_back(log);
+        }
+
+        if let Some(oldest) = oldest {
+            let previous_until = self
+                .query_parameters
+                .sent_until
+                .as_deref()
+                .and_then(|until| {
+                    DateTime::<FixedOffset>::parse_from_str(until, DATE_TIME_FORMAT).ok()
+                });
+
+            // A full page sent within the same millisecond would be requested forever, and
+            // skipping past it would lose the logs of that millisecond not returned yet. Fetch it
+            // again with the largest page, or stop if that is full too.
+            if previous_until == Some(oldest) && !self.done {
+                if page_size < MAX_LOGS_PER_PAGE as usize {
+                    self.query_parameters.limit = Some(MAX_LOGS_PER_PAGE);
+                    self.seen.extend(seen);
+                } else {
+                    self.done = true;
+                    self.overflow = Some(SdkError::LogsPageOverflow {
+                        sent_at: oldest.format(DATE_TIME_FORMAT).to_string(),
+                        limit: MAX_LOGS_PER_PAGE,
+                    });
+                }
+
+                return Ok(());
+            }
+            self.query_parameters.sent_until = Some(oldest.format(DATE_TIME_FORMAT).to_string());
+        }
+        self.seen = seen;
+
+        Ok(())
+    }
+}
+
+// Polling state of `SmsClient::inbound_stream`.
+struct InboundStreamState {
+    client: SmsClient,
+    query_parameters: InboundReportsQueryParameters,
+    interval: Duration,
+    delay: Duration,
+    failures: u32,
+    buffer: VecDeque<InboundSmsReport>,
+    done: bool,
+}
+
+impl InboundStreamState {
+    // Fetches the next reports into the buffer, and sets the delay before the next poll. Ends
+    // the stream on errors that aren't retryable.
+    async fn poll(&mut self) -> Result<(), SdkError> {
+        match self
+            .client
+            .inbound_reports(self.query_parameters.clone())
+            .await
+        {
+            Ok(response) => {
+                let more_pending = response.body.pending_message_count.unwrap_or(0) > 0;
+                self.buffer
+                    .extend(response.body.results.into_iter().flatten());
+                self.failures = 0;
+                self.delay = if more_pending {
+                    Duration::ZERO
+                } else {
+                    self.interval
+                };
+
+                Ok(())
+            }
+            Err(error) if error.is_retryable() => {
+                self.failures = self.failures.saturating_add(1);
+                let base = self.interval.max(Duration::from_millis(100));
+                let backoff = base
+                    .checked_mul(2u32.saturating_pow(self.failures - 1))
+                    .map_or(MAX_INBOUND_STREAM_BACKOFF, |delay| {
+                        delay.min(MAX_INBOUND_STREAM_BACKOFF)
+                    });
+                self.delay = backoff.max(error.retry_after().unwrap_or_default());
+
+                Err(error)
+            }
+            Err(error) => {
+                self.done = true;
+
+                Err(error)
+            }
         }
     }
 }
@@ -1497,9 +1575,21 @@ pub struct BlockingSmsClient {
 impl BlockingSmsClient {
     /// Builds and returns a new `BlockingSmsClient` with a specified configuration.
     pub fn with_configuration(configuration: Configuration) -> BlockingSmsClient {
-        BlockingSmsClient {
+        BlockingSmsClient::with_http_client(
+            build_blocking_http_client(&configuration),
             configuration,
-            client: reqwest::blocking::Client::new(),
+        )
+    }
+
+    /// Builds and returns a new `BlockingSmsClient` that sends requests with the given blocking
+    /// HTTP client.
+    pub fn with_http_client(
+        client: reqwest::blocking::Client,
+        configuration: Configuration,
+    ) -> BlockingSmsClient {
+        BlockingSmsClient {
+            configuration: configuration.for_channel(Channel::Sms),
+            client,
         }
     }
 
@@ -1517,21 +1607,58 @@ impl BlockingSmsClient {
             PATH_PREVIEW,
         )?;
 
-        let status = response.status();
-        let text = response.text()?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            let api_error = ApiError {
-                details: serde_json::from_str(&text)?,
-                status,
-            };
+        build_blocking_response(response)
+    }
 
-            Err(SdkError::ApiRequestError(api_error))
+    /// Preview many texts, using at most `concurrency` threads at the same time. Returns one
+    /// result per text, in the same order as the texts. This is the blocking version.
+    pub fn preview_many<I>(
+        &self,
+        texts: I,
+        concurrency: usize,
+    ) -> Vec<Result<SdkResponse<PreviewResponseBody>, SdkError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let request_bodies: Vec<PreviewRequestBody> = texts
+            .into_iter()
+            .map(|text| PreviewRequestBody::new(text.as_ref()))
+            .collect();
+
+        let mut results = Vec::with_capacity(request_bodies.len());
+        for chunk in request_bodies.chunks(concurrency.max(1)) {
+            thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .iter()
+                    .map(|request_body| scope.spawn(|| self.preview(request_body.clone())))
+                    .collect();
+
+                for handle in handles {
+                    results.push(handle.join().expect("preview thread panicked"));
+                }
+            });
         }
+
+        results
+    }
+
+    /// Blocking version of `SmsClient::send_raw`: sends a JSON body built by hand, or no body,
+    /// without validating it, and returns the response body as JSON.
+    pub fn send_raw(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        request_body: Option<serde_json::Value>,
+    ) -> Result<SdkResponse<serde_json::Value>, SdkError> {
+        let response = send_blocking_request(
+            &self.client,
+            &self.configuration,
+            request_body.as_ref(),
+            method,
+            path,
+        )?;
+
+        build_blocking_response(response)
     }
 }
@@ -0,0 +1,113 @@
+use httpmock::prelude::*;
+use reqwest::StatusCode;
+
+use crate::api::account::{AccountClient, PATH_GET_CURRENT_API_KEY};
+use crate::api::tests::{mock_json_endpoint, test_configuration};
+use crate::api::{ErrorKind, SdkError};
+
+const API_KEY_RESPONSE: &str = r#"
+{
+  "id": "8F0792F86035A9F4290821F1EE6BC06A",
+  "name": "Production key",
+  "enabled": true,
+  "permissions": ["PUBLIC_API"],
+  "scopeGuids": ["sms:message:send", "whatsapp:message:send"],
+  "allowedIPs": [],
+  "validFrom": "2023-01-01T00:00:00.000+0000",
+  "validTo": "2030-01-01T00:00:00.000+0000"
+}
+"#;
+
+#[tokio::test]
+async fn test_current_api_key() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(PATH_GET_CURRENT_API_KEY)
+            .header("Authorization", "App some-api-key");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(API_KEY_RESPONSE);
+    });
+
+    let client = AccountClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.current_api_key().await.unwrap();
+
+    mock.assert();
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body.name.as_deref(), Some("Production key"));
+    assert_eq!(response.body.allowed_ips, Some(vec![]));
+    assert!(response.body.has_scope("whatsapp:message:send"));
+}
+
+#[tokio::test]
+async fn test_require_scopes_allowed() {
+    let server = mock_json_endpoint(
+        GET,
+        PATH_GET_CURRENT_API_KEY,
+        API_KEY_RESPONSE,
+        StatusCode::OK,
+    )
+    .await;
+
+    let client = AccountClient::with_configuration(test_configuration(&server.base_url()));
+
+    let details = client
+        .require_scopes(&["whatsapp:message:send"])
+        .await
+        .unwrap();
+
+    assert_eq!(details.enabled, Some(true));
+}
+
+#[tokio::test]
+async fn test_require_scopes_missing() {
+    let server = mock_json_endpoint(
+        GET,
+        PATH_GET_CURRENT_API_KEY,
+        API_KEY_RESPONSE,
+        StatusCode::OK,
+    )
+    .await;
+
+    let client = AccountClient::with_configuration(test_configuration(&server.base_url()));
+
+    let error = client
+        .require_scopes(&["email:message:send", "sms:message:send", "2fa:manage"])
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.error_kind(), ErrorKind::Auth);
+    assert_eq!(
+        error.to_string(),
+        "API key is missing scopes email:message:send, 2fa:manage"
+    );
+    match error {
+        SdkError::MissingScopes { missing } => {
+            assert_eq!(missing, vec!["email:message:send", "2fa:manage"])
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_require_scopes_unauthorized() {
+    let server = mock_json_endpoint(
+        GET,
+        PATH_GET_CURRENT_API_KEY,
+        r#"{"requestError": {"serviceException": {"messageId": "UNAUTHORIZED", "text": "Invalid login details"}}}"#,
+        StatusCode::UNAUTHORIZED,
+    )
+    .await;
+
+    let client = AccountClient::with_configuration(test_configuration(&server.base_url()));
+
+    let error = client
+        .require_scopes(&["sms:message:send"])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::ApiRequestError(_)));
+    assert_eq!(error.error_kind(), ErrorKind::Auth);
+}
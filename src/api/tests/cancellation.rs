@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+
+use crate::api::cancellation::*;
+use crate::api::sms::{SmsClient, PATH_GET_INBOUND, PATH_PREVIEW};
+use crate::api::tests::test_configuration;
+use crate::api::whatsapp::WhatsAppClient;
+use crate::api::whatsapp_scheduler::WhatsAppScheduler;
+use crate::api::SdkError;
+use crate::model::sms::{InboundReportsQueryParameters, PreviewRequestBody};
+
+// Starts a server that answers previews after the given delay.
+async fn slow_preview_server(delay: Duration) -> httpmock::MockServer {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"originalText": "Hello!", "previews": []}"#)
+            .delay(delay);
+    });
+
+    server
+}
+
+#[tokio::test]
+async fn cancellable_completes() {
+    let server = slow_preview_server(Duration::ZERO).await;
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let token = CancellationToken::new();
+
+    let response = cancellable(&token, client.preview(PreviewRequestBody::new("Hello!")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.body.original_text.as_deref(), Some("Hello!"));
+}
+
+#[tokio::test]
+async fn cancellable_cancelled_in_flight() {
+    let server = slow_preview_server(Duration::from_secs(10)).await;
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let token = CancellationToken::new();
+
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        cancellable(&token, client.preview(PreviewRequestBody::new("Hello!"))),
+    )
+    .await
+    .unwrap();
+
+    assert!(matches!(result, Err(SdkError::Cancelled)));
+}
+
+#[tokio::test]
+async fn with_deadline_passed() {
+    let server = slow_preview_server(Duration::from_secs(10)).await;
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(50);
+
+    let result = with_deadline(deadline, client.preview(PreviewRequestBody::new("Hello!"))).await;
+
+    assert!(matches!(result, Err(SdkError::Cancelled)));
+    assert!(!SdkError::Cancelled.is_retryable());
+}
+
+#[tokio::test]
+async fn cancellable_stream_ends() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(PATH_GET_INBOUND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": [], "messageCount": 0, "pendingMessageCount": 0}"#);
+    });
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let token = CancellationToken::new();
+
+    let inbound = client
+        .inbound_stream(
+            InboundReportsQueryParameters::new(),
+            Duration::from_millis(10),
+        )
+        .unwrap();
+    let mut inbound = Box::pin(cancellable_stream(&token, inbound));
+    token.cancel();
+
+    let next = tokio::time::timeout(Duration::from_secs(5), inbound.next())
+        .await
+        .unwrap();
+    assert!(next.is_none());
+}
+
+#[tokio::test]
+async fn scheduler_run_until_cancelled() {
+    let scheduler = WhatsAppScheduler::new(WhatsAppClient::with_configuration(test_configuration(
+        "http://localhost:1",
+    )));
+    let token = CancellationToken::new();
+
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        scheduler.run_until_cancelled(Duration::from_millis(10), &token, |_| {}),
+    )
+    .await
+    .unwrap();
+}
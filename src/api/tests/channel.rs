@@ -0,0 +1,138 @@
+use crate::api::channel::{ChannelMessage, MessageChannel};
+use crate::api::email::{self, EmailClient};
+use crate::api::sms::{self, SmsClient};
+use crate::api::tests::test_configuration;
+use crate::api::whatsapp::{self, WhatsAppClient};
+use crate::configuration::Channel;
+use crate::model::status::StatusGroup;
+
+const STATUS: &str = r#"{
+    "groupId": 1,
+    "groupName": "PENDING",
+    "id": 26,
+    "name": "PENDING_ACCEPTED",
+    "description": "Message sent to next instance"
+}"#;
+
+#[tokio::test]
+async fn sms_channel_send_and_get_status() {
+    let server = httpmock::MockServer::start_async().await;
+    let send = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(sms::PATH_SEND)
+            .json_body_partial(
+                r#"{"messages": [{"destinations": [{"to": "41793026727"}], "text": "Hello"}]}"#,
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"messages": [{{"messageId": "some-id", "to": "41793026727", "status": {}}}]}}"#,
+                STATUS
+            ));
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(sms::PATH_GET_LOGS)
+            .query_param("messageId", "some-id");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"results": [{"messageId": "some-id", "status": {"groupId": 3, "groupName": "DELIVERED", "name": "DELIVERED_TO_HANDSET"}}]}"#,
+            );
+    });
+    let channel: Box<dyn MessageChannel> = Box::new(SmsClient::with_configuration(
+        test_configuration(&server.base_url()),
+    ));
+
+    let sent = channel
+        .send(&ChannelMessage::new("41793026727", "Hello"))
+        .await
+        .unwrap();
+    let status = channel.get_status("some-id").await.unwrap().unwrap();
+
+    send.assert_async().await;
+    assert_eq!(channel.channel(), Channel::Sms);
+    assert_eq!(sent.message_id.as_deref(), Some("some-id"));
+    assert_eq!(sent.status.unwrap().group, Some(StatusGroup::Pending));
+    assert_eq!(status.group, Some(StatusGroup::Delivered));
+    assert_eq!(status.name.as_deref(), Some("DELIVERED_TO_HANDSET"));
+}
+
+#[tokio::test]
+async fn sms_channel_status_not_in_logs() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(sms::PATH_GET_LOGS);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+    let channel = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    assert_eq!(
+        MessageChannel::get_status(&channel, "some-id")
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn whatsapp_channel_sends_from_sender() {
+    let server = httpmock::MockServer::start_async().await;
+    let send = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(whatsapp::PATH_SEND_TEXT)
+            .json_body_partial(
+                r#"{"from": "44444444444", "to": "55555555555", "content": {"text": "Hello"}}"#,
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"to": "55555555555", "messageId": "some-id", "status": {}}}"#,
+                STATUS
+            ));
+    });
+    let channel: Box<dyn MessageChannel> = Box::new(
+        WhatsAppClient::with_configuration(test_configuration(&server.base_url()))
+            .sender("44444444444"),
+    );
+
+    let sent = channel
+        .send(&ChannelMessage::new("55555555555", "Hello"))
+        .await
+        .unwrap();
+
+    send.assert_async().await;
+    assert_eq!(channel.channel(), Channel::WhatsApp);
+    assert_eq!(sent.message_id.as_deref(), Some("some-id"));
+}
+
+#[tokio::test]
+async fn email_channel_sends_from_sender() {
+    let server = httpmock::MockServer::start_async().await;
+    let send = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(email::PATH_SEND)
+            .body_contains("orders@company.com")
+            .body_contains("Your order");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"messages": [{{"to": "someone@domain.com", "messageId": "some-id", "status": {}}}]}}"#,
+                STATUS
+            ));
+    });
+    let channel: Box<dyn MessageChannel> = Box::new(
+        EmailClient::with_configuration(test_configuration(&server.base_url()))
+            .sender("orders@company.com"),
+    );
+
+    let message =
+        ChannelMessage::new("someone@domain.com", "It has shipped.").with_subject("Your order");
+    let sent = channel.send(&message).await.unwrap();
+
+    send.assert_async().await;
+    assert_eq!(channel.channel(), Channel::Email);
+    assert_eq!(sent.message_id.as_deref(), Some("some-id"));
+}
@@ -0,0 +1,190 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use httpmock::prelude::*;
+use reqwest::StatusCode;
+
+use crate::api::circuit_breaker::{CircuitBreaker, CircuitEvent, CircuitState};
+use crate::api::sms::{BlockingSmsClient, SmsClient, PATH_PREVIEW};
+use crate::api::tests::{mock_json_endpoint, test_configuration, DUMMY_TEXT};
+use crate::api::{ErrorKind, SdkError};
+use crate::model::sms::PreviewRequestBody;
+
+const SERVER_ERROR: &str = r#"{
+    "requestError": {
+        "serviceException": {
+            "messageId": "GENERAL_ERROR",
+            "text": "Something went wrong."
+        }
+    }
+}"#;
+
+fn sms_client(server: &MockServer, breaker: &CircuitBreaker) -> SmsClient {
+    SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_circuit_breaker(breaker.clone()),
+    )
+}
+
+#[tokio::test]
+async fn opens_after_consecutive_failures() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(SERVER_ERROR);
+    });
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    let breaker = CircuitBreaker::new(3, Duration::from_secs(60))
+        .with_listener(move |event| recorded.lock().unwrap().push(event));
+    let client = sms_client(&server, &breaker);
+
+    for _ in 0..3 {
+        let error = client
+            .preview(PreviewRequestBody::new(DUMMY_TEXT))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SdkError::ApiRequestError(_)));
+    }
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    let error = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+    assert_eq!(error.error_kind(), ErrorKind::Temporary);
+    match error {
+        SdkError::CircuitOpen { retry_after } => assert!(retry_after > Duration::ZERO),
+        other => panic!("unexpected error: {:?}", other),
+    }
+
+    mock.assert_hits(3);
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![CircuitEvent::Opened { failures: 3 }, CircuitEvent::Rejected]
+    );
+}
+
+#[tokio::test]
+async fn client_errors_do_not_count() {
+    let server = mock_json_endpoint(
+        POST,
+        PATH_PREVIEW,
+        r#"{"requestError": {"serviceException": {"messageId": "BAD_REQUEST"}}}"#,
+        StatusCode::BAD_REQUEST,
+    )
+    .await;
+    let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+    let client = sms_client(&server, &breaker);
+
+    for _ in 0..3 {
+        client
+            .preview(PreviewRequestBody::new(DUMMY_TEXT))
+            .await
+            .unwrap_err();
+    }
+
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert_eq!(breaker.consecutive_failures(), 0);
+}
+
+#[tokio::test]
+async fn successful_probe_closes() {
+    let server = MockServer::start_async().await;
+    let mut failing = server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(503)
+            .header("content-type", "application/json")
+            .body(SERVER_ERROR);
+    });
+    let breaker = CircuitBreaker::new(1, Duration::from_millis(50));
+    let client = sms_client(&server, &breaker);
+
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    failing.delete();
+    server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#);
+    });
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[tokio::test]
+async fn failed_probe_reopens() {
+    let server = mock_json_endpoint(
+        POST,
+        PATH_PREVIEW,
+        SERVER_ERROR,
+        StatusCode::INTERNAL_SERVER_ERROR,
+    )
+    .await;
+    let breaker = CircuitBreaker::new(1, Duration::from_millis(50));
+    let client = sms_client(&server, &breaker);
+
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
+
+#[test]
+fn blocking_client_rejects_when_open() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(502)
+            .header("content-type", "application/json")
+            .body(SERVER_ERROR);
+    });
+    let breaker = CircuitBreaker::new(1, Duration::from_secs(60));
+    let client = BlockingSmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_circuit_breaker(breaker.clone()),
+    );
+
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .unwrap_err();
+    let error = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::CircuitOpen { .. }));
+    mock.assert_hits(1);
+}
+
+#[test]
+fn reset_closes() {
+    let breaker = CircuitBreaker::new(1, Duration::from_secs(60));
+    breaker
+        .acquire()
+        .unwrap()
+        .record_status(StatusCode::BAD_GATEWAY);
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    breaker.reset();
+
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert!(breaker.acquire().is_ok());
+}
@@ -0,0 +1,122 @@
+use crate::api::client::InfobipClient;
+use crate::api::email::EmailClient;
+use crate::api::sms::{SmsClient, PATH_PREVIEW};
+use crate::api::tests::{mock_json_endpoint, test_configuration};
+use crate::configuration::Channel;
+use crate::model::sms::PreviewRequestBody;
+
+const DUMMY_BASE_URL: &str = "https://some.url";
+
+#[test]
+fn test_channel_clients_are_built_once() {
+    let client = InfobipClient::new(test_configuration(DUMMY_BASE_URL));
+
+    assert!(std::ptr::eq(client.sms(), client.sms()));
+    assert!(std::ptr::eq(client.whatsapp(), client.whatsapp()));
+    assert!(std::ptr::eq(client.email(), client.email()));
+    assert!(std::ptr::eq(client.tfa(), client.tfa()));
+    assert!(std::ptr::eq(client.account(), client.account()));
+    assert_eq!(client.email().configuration.base_url(), DUMMY_BASE_URL);
+}
+
+#[tokio::test]
+async fn test_channel_client_sends_requests() {
+    let expected_response = r#"
+    {
+      "originalText": "Some text",
+      "previews": []
+    }
+    "#;
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_PREVIEW,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = InfobipClient::new(test_configuration(&server.base_url()));
+
+    let response = client
+        .sms()
+        .preview(PreviewRequestBody::new("Some text"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_channel_clients_with_shared_http_client() {
+    let expected_response = r#"
+    {
+      "originalText": "Some text",
+      "previews": []
+    }
+    "#;
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_PREVIEW,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+    let http_client = reqwest::Client::new();
+
+    let sms_client =
+        SmsClient::with_http_client(http_client.clone(), test_configuration(&server.base_url()));
+    let _email_client =
+        EmailClient::with_http_client(http_client, test_configuration(&server.base_url()));
+
+    let response = sms_client
+        .preview(PreviewRequestBody::new("Some text"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_channel_base_url_override() {
+    let expected_response = r#"
+    {
+      "originalText": "Some text",
+      "previews": []
+    }
+    "#;
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_PREVIEW,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+    let configuration = test_configuration(DUMMY_BASE_URL)
+        .with_channel_base_url(Channel::Sms, &server.base_url())
+        .unwrap();
+
+    let client = InfobipClient::new(configuration);
+
+    let response = client
+        .sms()
+        .preview(PreviewRequestBody::new("Some text"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(client.email().configuration.base_url(), DUMMY_BASE_URL);
+}
+
+#[test]
+fn test_debug_redacts_credentials() {
+    let client = InfobipClient::new(test_configuration(DUMMY_BASE_URL));
+
+    for debug in [
+        format!("{:?}", client),
+        format!("{:?}", client.sms()),
+        format!("{:?}", client.whatsapp()),
+    ] {
+        assert!(!debug.contains("some-api-key"));
+        assert!(debug.contains("***redacted***"));
+    }
+}
@@ -0,0 +1,380 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use futures_util::future::BoxFuture;
+use reqwest::StatusCode;
+
+use crate::api::dispatcher::*;
+use crate::api::sms::{SmsClient, PATH_SEND};
+use crate::api::tests::{mock_json_endpoint, test_configuration};
+use crate::api::{ApiError, SdkError};
+use crate::model::sms::{Destination, Message};
+use crate::queue::{FileQueueStore, MemoryQueueStore, QueueStore, QueuedItem};
+
+// Records the batches it is asked to send, failing with the queued statuses first.
+#[derive(Clone, Default)]
+struct FakeSender {
+    batches: Arc<Mutex<Vec<Vec<String>>>>,
+    failures: Arc<Mutex<Vec<StatusCode>>>,
+    retry_after: Option<Duration>,
+}
+
+impl FakeSender {
+    fn failing_with(statuses: &[StatusCode]) -> Self {
+        let sender = FakeSender::default();
+        *sender.failures.lock().unwrap() = statuses.to_vec();
+        sender
+    }
+
+    fn with_retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = Some(retry_after);
+        self
+    }
+
+    fn batches(&self) -> Vec<Vec<String>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+impl BatchSender<String> for FakeSender {
+    fn send_batch(&self, batch: Vec<String>) -> BoxFuture<'_, Result<(), SdkError>> {
+        Box::pin(async move {
+            self.batches.lock().unwrap().push(batch);
+            let mut failures = self.failures.lock().unwrap();
+            if failures.is_empty() {
+                return Ok(());
+            }
+            let status = failures.remove(0);
+            let details = serde_json::from_value(serde_json::json!({
+                "requestError": {"serviceException": {"messageId": "ERROR", "text": "Error."}}
+            }))
+            .unwrap();
+
+            Err(SdkError::ApiRequestError(ApiError {
+                details: Some(Box::new(details)),
+                body: None,
+                status,
+                retry_after: self.retry_after,
+            }))
+        })
+    }
+}
+
+fn fast_options() -> DispatcherOptions {
+    DispatcherOptions {
+        batch_size: 2,
+        flush_interval: Duration::from_millis(20),
+        initial_backoff: Duration::from_millis(10),
+        ..DispatcherOptions::default()
+    }
+}
+
+type Outcomes<T> = Arc<Mutex<Vec<DispatchOutcome<T>>>>;
+
+fn outcomes_of<T: Send + 'static>() -> (Outcomes<T>, impl FnMut(DispatchOutcome<T>) + Send) {
+    let outcomes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = outcomes.clone();
+
+    (outcomes, move |outcome| {
+        recorded.lock().unwrap().push(outcome)
+    })
+}
+
+#[tokio::test]
+async fn dispatcher_sends_in_batches() {
+    let sender = FakeSender::default();
+    let store = Arc::new(MemoryQueueStore::new());
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let dispatcher =
+        Dispatcher::spawn_with_store(sender.clone(), store.clone(), fast_options(), on_outcome);
+
+    let handle = dispatcher.handle();
+    for text in ["one", "two", "three"] {
+        handle.send(text.to_string()).await.unwrap();
+    }
+    dispatcher.shutdown().await;
+
+    let sent: Vec<String> = sender.batches().concat();
+    assert_eq!(sent, vec!["one", "two", "three"]);
+    assert!(sender.batches().iter().all(|batch| batch.len() <= 2));
+    assert!(store.is_empty().unwrap());
+    assert!(outcomes
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|outcome| matches!(outcome, DispatchOutcome::Sent { .. })));
+}
+
+#[tokio::test]
+async fn dispatcher_retries_retryable_errors() {
+    let sender = FakeSender::failing_with(&[StatusCode::SERVICE_UNAVAILABLE]);
+    let store = Arc::new(MemoryQueueStore::new());
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let dispatcher =
+        Dispatcher::spawn_with_store(sender.clone(), store.clone(), fast_options(), on_outcome);
+
+    dispatcher.handle().send("hello".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    dispatcher.shutdown().await;
+
+    assert_eq!(sender.batches(), vec![vec!["hello"], vec!["hello"]]);
+    assert!(store.is_empty().unwrap());
+    let outcomes = outcomes.lock().unwrap();
+    assert!(matches!(
+        outcomes[0],
+        DispatchOutcome::Retrying { attempts: 1, .. }
+    ));
+    assert!(matches!(outcomes[1], DispatchOutcome::Sent { .. }));
+}
+
+#[tokio::test]
+async fn dispatcher_waits_for_retry_after() {
+    let sender = FakeSender::failing_with(&[StatusCode::TOO_MANY_REQUESTS])
+        .with_retry_after(Duration::from_millis(300));
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let dispatcher = Dispatcher::spawn(sender.clone(), fast_options(), on_outcome);
+
+    dispatcher.handle().send("hello".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(sender.batches().len(), 1);
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    dispatcher.shutdown().await;
+
+    assert_eq!(sender.batches().len(), 2);
+    let outcomes = outcomes.lock().unwrap();
+    match &outcomes[0] {
+        DispatchOutcome::Retrying { delay, .. } => {
+            assert_eq!(*delay, Duration::from_millis(300))
+        }
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn dispatcher_caps_huge_retry_after() {
+    let sender = FakeSender::failing_with(&[StatusCode::TOO_MANY_REQUESTS])
+        .with_retry_after(Duration::from_secs(10_000_000_000_000));
+    let store = Arc::new(MemoryQueueStore::new());
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let dispatcher =
+        Dispatcher::spawn_with_store(sender.clone(), store.clone(), fast_options(), on_outcome);
+
+    dispatcher.handle().send("hello".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    dispatcher.handle().send("again".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    dispatcher.shutdown().await;
+
+    assert_eq!(sender.batches(), vec![vec!["hello"], vec!["again"]]);
+    assert!(matches!(
+        outcomes.lock().unwrap()[0],
+        DispatchOutcome::Retrying { delay, .. } if delay == Duration::from_secs(3600)
+    ));
+    let retried = store
+        .take_due(Utc::now() + chrono::Duration::hours(2))
+        .unwrap();
+    assert_eq!(retried.len(), 1);
+    assert!(retried[0].due_at > Utc::now() + chrono::Duration::minutes(59));
+}
+
+#[tokio::test]
+async fn dispatcher_survives_retry_after_beyond_dates() {
+    let sender =
+        FakeSender::failing_with(&[StatusCode::TOO_MANY_REQUESTS]).with_retry_after(Duration::MAX);
+    let options = DispatcherOptions {
+        max_retry_after: Duration::MAX,
+        ..fast_options()
+    };
+    let store = Arc::new(MemoryQueueStore::new());
+    let (_, on_outcome) = outcomes_of::<String>();
+    let dispatcher =
+        Dispatcher::spawn_with_store(sender.clone(), store.clone(), options, on_outcome);
+
+    dispatcher.handle().send("hello".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    dispatcher.handle().send("again".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    dispatcher.shutdown().await;
+
+    assert_eq!(sender.batches(), vec![vec!["hello"], vec!["again"]]);
+    assert!(store
+        .take_due(Utc::now() + chrono::Duration::days(365))
+        .unwrap()
+        .is_empty());
+    assert_eq!(store.len().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn dispatcher_backoff_longer_than_retry_after() {
+    let sender = FakeSender::failing_with(&[StatusCode::TOO_MANY_REQUESTS])
+        .with_retry_after(Duration::from_millis(1));
+    let options = DispatcherOptions {
+        initial_backoff: Duration::from_secs(2),
+        ..fast_options()
+    };
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let dispatcher = Dispatcher::spawn(sender, options, on_outcome);
+
+    dispatcher.handle().send("hello".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    dispatcher.shutdown().await;
+
+    let outcomes = outcomes.lock().unwrap();
+    assert!(matches!(
+        outcomes[0],
+        DispatchOutcome::Retrying { delay, .. } if delay == Duration::from_secs(2)
+    ));
+}
+
+#[tokio::test]
+async fn dispatcher_drops_permanently_failed_batches() {
+    let sender = FakeSender::failing_with(&[StatusCode::BAD_REQUEST]);
+    let store = Arc::new(MemoryQueueStore::new());
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let dispatcher =
+        Dispatcher::spawn_with_store(sender.clone(), store.clone(), fast_options(), on_outcome);
+
+    let id = dispatcher.handle().send("hello".to_string()).await.unwrap();
+    dispatcher.shutdown().await;
+
+    assert!(store.is_empty().unwrap());
+    let outcomes = outcomes.lock().unwrap();
+    match &outcomes[0] {
+        DispatchOutcome::Failed { items, error } => {
+            assert_eq!(items[0].id, id);
+            assert_eq!(items[0].item.message, "hello");
+            assert!(!error.is_retryable());
+        }
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+}
+
+#[tokio::test]
+async fn dispatcher_gives_up_after_max_attempts() {
+    let sender = FakeSender::failing_with(&[StatusCode::SERVICE_UNAVAILABLE; 3]);
+    let (outcomes, on_outcome) = outcomes_of::<String>();
+    let options = DispatcherOptions {
+        max_attempts: 2,
+        ..fast_options()
+    };
+    let dispatcher = Dispatcher::spawn(sender.clone(), options, on_outcome);
+
+    dispatcher.handle().send("hello".to_string()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    dispatcher.shutdown().await;
+
+    assert_eq!(sender.batches().len(), 2);
+    assert!(matches!(
+        outcomes.lock().unwrap().last(),
+        Some(DispatchOutcome::Failed { .. })
+    ));
+}
+
+#[tokio::test]
+async fn dispatcher_sends_messages_left_by_earlier_run() {
+    let store = Arc::new(MemoryQueueStore::new());
+    store
+        .push(QueuedItem {
+            id: "left-over".to_string(),
+            due_at: Utc::now(),
+            item: Pending {
+                message: "from last run".to_string(),
+                attempts: 1,
+            },
+        })
+        .unwrap();
+    let sender = FakeSender::default();
+    let dispatcher =
+        Dispatcher::spawn_with_store(sender.clone(), store.clone(), fast_options(), |_| {});
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    dispatcher.shutdown().await;
+
+    assert_eq!(sender.batches(), vec![vec!["from last run"]]);
+    assert!(store.is_empty().unwrap());
+}
+
+#[tokio::test]
+async fn dispatcher_handle_fails_after_shutdown() {
+    let dispatcher = Dispatcher::spawn(FakeSender::default(), fast_options(), |_| {});
+    let handle = dispatcher.handle();
+
+    dispatcher.shutdown().await;
+
+    assert!(matches!(
+        handle.send("late".to_string()).await,
+        Err(SdkError::Cancelled)
+    ));
+}
+
+#[tokio::test]
+async fn dispatcher_sends_sms_batch_in_one_request() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"bulkId": "some-bulk-id", "messages": []}"#,
+        StatusCode::OK,
+    )
+    .await;
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let (outcomes, on_outcome) = outcomes_of::<Message>();
+    let dispatcher = Dispatcher::spawn(client, fast_options(), on_outcome);
+
+    for to in ["41793026727", "41793026728"] {
+        let mut message = Message::new(vec![Destination::new(to)]);
+        message.text = Some("Hello!".to_string());
+        dispatcher.handle().send(message).await.unwrap();
+    }
+    dispatcher.shutdown().await;
+
+    let outcomes = outcomes.lock().unwrap();
+    assert_eq!(outcomes.len(), 1);
+    assert!(matches!(&outcomes[0], DispatchOutcome::Sent { ids } if ids.len() == 2));
+}
+
+#[test]
+fn dispatcher_options_default() {
+    let options = DispatcherOptions::new();
+
+    assert_eq!(options.batch_size, 100);
+    assert_eq!(options.max_attempts, 10);
+}
+
+#[test]
+fn file_queue_store_keeps_items_across_opens() {
+    let path = std::env::temp_dir().join(format!(
+        "infobip-sdk-{}-file-queue-store.json",
+        std::process::id()
+    ));
+    std::fs::remove_file(&path).ok();
+    let now = Utc::now();
+
+    let store = FileQueueStore::open(&path).unwrap();
+    for id in ["a", "b", "c"] {
+        store
+            .push(QueuedItem {
+                id: id.to_string(),
+                due_at: now,
+                item: id.to_string(),
+            })
+            .unwrap();
+    }
+    let leased = store
+        .lease_due(now, now + chrono::Duration::minutes(1), 2)
+        .unwrap();
+    store.remove("a").unwrap();
+
+    let reopened: FileQueueStore<String> = FileQueueStore::open(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(leased.len(), 2);
+    assert_eq!(reopened.len().unwrap(), 2);
+    let due: Vec<String> = reopened
+        .take_due(now)
+        .unwrap()
+        .into_iter()
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(due, vec!["c"]);
+}
@@ -1,6 +1,6 @@
 use crate::api::email::*;
 use crate::api::tests::{mock_json_endpoint, test_configuration};
-use crate::api::SdkError;
+use crate::api::{NoContent, SdkError};
 use crate::model::email::*;
 
 const DUMMY_BASE_URL: &str = "https://some.url";
@@ -55,6 +55,61 @@ async fn test_send_valid() {
     assert!(!response.body.messages.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_send_platform_ids() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("name=\"applicationId\"\r\n\r\nmarketing")
+            .body_contains("name=\"entityId\"\r\n\r\ncustomer-1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"messages": []}"#);
+    });
+
+    let client = EmailClient::with_configuration(
+        test_configuration(&server.base_url())
+            .with_application_id("marketing")
+            .with_entity_id("customer-1"),
+    );
+
+    client
+        .send(SendRequestBody::new("some@mail.com"))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_template_recipients() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .body_contains("name=\"templateId\"\r\n\r\n42")
+            .body_contains(
+                "name=\"to\"\r\n\r\n{\"to\":\"jane@company.com\",\"placeholders\":{\"name\":\"Jane\"}}",
+            )
+            .body_contains("name=\"to\"\r\n\r\n{\"to\":\"john@company.com\"}");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"messages": []}"#);
+    });
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+    let mut jane = TemplateRecipient::new("jane@company.com");
+    jane.placeholders
+        .insert("name".to_string(), serde_json::json!("Jane"));
+    let request_body =
+        SendRequestBody::from_template(42, vec![jane, TemplateRecipient::new("john@company.com")]);
+
+    client.send(request_body).await.unwrap();
+
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_send_invalid_request() {
     let client = EmailClient::with_configuration(test_configuration(DUMMY_BASE_URL));
@@ -227,6 +282,130 @@ async fn update_scheduled_status_valid() {
     assert_eq!(response.status, reqwest::StatusCode::OK);
 }
 
+#[tokio::test]
+async fn bulk_status_valid() {
+    let expected_response = r#"
+    {
+      "externalBulkId": "external-bulk-id",
+      "bulks": [
+        {
+          "bulkId": "other-bulk-id",
+          "status": "PENDING"
+        },
+        {
+          "bulkId": "bulk-id",
+          "status": "PAUSED"
+        }
+      ]
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_SCHEDULED_STATUS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.bulk_status("bulk-id").await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body, Some(BulkStatus::Paused));
+}
+
+#[tokio::test]
+async fn bulk_status_not_found() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_SCHEDULED_STATUS,
+        r#"{"bulks": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.bulk_status("bulk-id").await.unwrap();
+
+    assert_eq!(response.body, None);
+}
+
+#[tokio::test]
+async fn update_bulk_status_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::PUT)
+            .path(PATH_UPDATE_SCHEDULED_STATUS)
+            .query_param("bulkId", "bulk-id")
+            .json_body(serde_json::json!({ "status": "CANCELED" }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "bulkId": "bulk-id", "status": "CANCELED" }));
+    });
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .update_bulk_status("bulk-id", BulkStatus::Canceled)
+        .await
+        .unwrap();
+
+    assert_eq!(response.body.bulk_id.unwrap(), "bulk-id");
+    assert_eq!(response.body.status, Some(BulkStatus::Canceled));
+}
+
+#[tokio::test]
+async fn reschedule_bulk_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::PUT)
+            .path(PATH_RESCHEDULE)
+            .query_param("bulkId", "bulk-id")
+            .json_body(serde_json::json!({ "sendAt": "2030-10-16T12:00:00.000+0000" }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "bulkId": "bulk-id", "sendAt": 1918382400000u64 }));
+    });
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .reschedule_bulk("bulk-id", "2030-10-16T12:00:00.000+0000")
+        .await
+        .unwrap();
+
+    assert_eq!(response.body.send_at, Some(1918382400000));
+}
+
+#[tokio::test]
+async fn delivery_reports_entity_filter() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("entityId", "customer-1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": [{"messageId": "MESSAGE-ID-1", "entityId": "customer-1"}]}"#);
+    });
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut query_parameters = DeliveryReportsQueryParameters::new();
+    query_parameters.entity_id = Some("customer-1".to_string());
+
+    let response = client.delivery_reports(query_parameters).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(
+        response.body.results.unwrap()[0].entity_id.as_deref(),
+        Some("customer-1")
+    );
+}
+
 #[tokio::test]
 async fn delivery_reports_valid() {
     let expected_response = r#"
@@ -329,6 +508,90 @@ async fn logs_valid() {
     assert_eq!(response.status, reqwest::StatusCode::OK);
 }
 
+#[tokio::test]
+async fn opens_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_OPENS)
+            .query_param("bulkId", "bulk-id")
+            .query_param("size", "50");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "paging": {"page": 0, "size": 50, "totalPages": 1, "totalResults": 1},
+                "results": [{
+                    "bulkId": "bulk-id",
+                    "messageId": "message-id",
+                    "to": "john.smith@example.com",
+                    "eventAt": "2022-10-03T15:20:00.000+0000",
+                    "deviceType": "Phone",
+                    "os": "Android"
+                }]
+            }));
+    });
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut query_parameters = TrackingReportsQueryParameters::new();
+    query_parameters.bulk_id = Some("bulk-id".to_string());
+    query_parameters.size = Some(50);
+
+    let response = client.opens(query_parameters).await.unwrap();
+
+    assert_eq!(response.body.paging.unwrap().total_results, Some(1));
+    let results = response.body.results.unwrap();
+    assert_eq!(results[0].device_type.as_deref(), Some("Phone"));
+}
+
+#[tokio::test]
+async fn bounces_valid() {
+    let expected_response = r#"
+    {
+      "results": [
+        {
+          "messageId": "message-id",
+          "to": "john.smith@example.com",
+          "eventAt": "2022-10-03T15:20:00.000+0000",
+          "bounceType": "HARD",
+          "reason": "Mailbox does not exist"
+        }
+      ]
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_BOUNCES,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .bounces(TrackingReportsQueryParameters::new())
+        .await
+        .unwrap();
+
+    let results = response.body.results.unwrap();
+    assert_eq!(results[0].bounce_type.as_deref(), Some("HARD"));
+    assert!(response.body.paging.is_none());
+}
+
+#[tokio::test]
+async fn clicks_invalid_page_size() {
+    let client = EmailClient::with_configuration(test_configuration("http://localhost"));
+
+    let mut query_parameters = TrackingReportsQueryParameters::new();
+    query_parameters.size = Some(1001);
+
+    let error = client.clicks(query_parameters).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
 #[tokio::test]
 async fn validate_address_valid() {
     let expected_response = r#"
@@ -512,9 +775,9 @@ async fn delete_domain_valid() {
 
     let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
 
-    let status = client.delete_domain(domain_name).await.unwrap();
+    let response = client.delete_domain(domain_name).await.unwrap();
 
-    assert_eq!(status, reqwest::StatusCode::NO_CONTENT);
+    assert_eq!(response.status, reqwest::StatusCode::NO_CONTENT);
 }
 
 #[tokio::test]
@@ -580,7 +843,110 @@ async fn verify_domain_valid() {
 
     let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
 
-    let status = client.verify_domain(domain_name).await.unwrap();
+    let response = client.verify_domain(domain_name).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn verify_domain_ignores_body() {
+    let domain_name = "newDomain.com";
+    let path = PATH_VERIFY_DOMAIN.replace("{domainName}", domain_name);
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        path.as_str(),
+        r#"{"domainName": "newDomain.com"}"#,
+        reqwest::StatusCode::ACCEPTED,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.verify_domain(domain_name).await.unwrap();
+
+    assert_eq!(response.body, NoContent);
+}
+
+fn temp_file(name: &str, contents: &[u8]) -> String {
+    let path = std::env::temp_dir().join(format!("infobip-sdk-{}-{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn attachment_info_sniffs_content_type() {
+    let png = temp_file("image.bin", b"\x89PNG\r\n\x1a\nrest-of-image");
+    let csv = temp_file("report.csv", b"a,b\n1,2\n");
+    let unknown = temp_file("data.unknown-extension", b"some bytes");
+
+    let png_info = attachment_info(&png).await.unwrap();
+    assert_eq!(png_info.content_type, "image/png");
+    assert!(png_info.file_name.ends_with("image.bin"));
+    assert_eq!(png_info.size, 21);
+
+    assert_eq!(
+        attachment_info(&csv).await.unwrap().content_type,
+        "text/csv"
+    );
+    assert_eq!(
+        attachment_info(&unknown).await.unwrap().content_type,
+        "application/octet-stream"
+    );
+}
+
+#[tokio::test]
+async fn check_attachments_too_large() {
+    let client = EmailClient::with_configuration(test_configuration(DUMMY_BASE_URL));
+
+    let path = temp_file("large.pdf", b"%PDF-1.7");
+    let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+    file.set_len(MAX_ATTACHMENTS_SIZE + 1).unwrap();
+
+    let mut request_body = SendRequestBody::new("some@mail.com");
+    request_body.add_attachment(&path);
+
+    let error = client.send(request_body).await.unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    if let SdkError::Validation(validation_error) = error {
+        assert!(validation_error.field_errors().contains_key("attachments"));
+    } else {
+        panic!("not validation error");
+    }
+}
+
+#[tokio::test]
+async fn check_attachments_missing_file() {
+    let client = EmailClient::with_configuration(test_configuration(DUMMY_BASE_URL));
+
+    let mut request_body = SendRequestBody::new("some@mail.com");
+    request_body.add_attachment("path/that/does/not/exist.pdf");
+
+    assert!(matches!(
+        client.check_attachments(&request_body).await,
+        Err(SdkError::Io(_))
+    ));
+}
+
+#[tokio::test]
+async fn send_with_attachment() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"bulkId": "some-bulk-id", "messages": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut request_body = SendRequestBody::new("some@mail.com");
+    request_body.add_attachment(&temp_file("attachment.pdf", b"%PDF-1.7"));
 
-    assert_eq!(status, reqwest::StatusCode::ACCEPTED);
+    let attachments = client.check_attachments(&request_body).await.unwrap();
+    let response = client.send(request_body).await.unwrap();
+
+    assert_eq!(attachments[0].content_type, "application/pdf");
+    assert_eq!(response.status, reqwest::StatusCode::OK);
 }
@@ -0,0 +1,295 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+use reqwest::StatusCode;
+
+use crate::api::{retry_after, ApiError, ApiErrorDetails, ErrorKind, SdkError};
+
+fn api_error(status: StatusCode, message_id: &str) -> ApiError {
+    let details: ApiErrorDetails = serde_json::from_value(serde_json::json!({
+        "requestError": {"serviceException": {"messageId": message_id, "text": "Error."}}
+    }))
+    .unwrap();
+
+    ApiError {
+        details: Some(Box::new(details)),
+        body: None,
+        status,
+        retry_after: None,
+    }
+}
+
+#[test]
+fn error_kind_from_status() {
+    let cases = [
+        (StatusCode::TOO_MANY_REQUESTS, ErrorKind::Throttling),
+        (StatusCode::UNAUTHORIZED, ErrorKind::Auth),
+        (StatusCode::FORBIDDEN, ErrorKind::Auth),
+        (StatusCode::BAD_REQUEST, ErrorKind::Validation),
+        (StatusCode::SERVICE_UNAVAILABLE, ErrorKind::Temporary),
+        (StatusCode::REQUEST_TIMEOUT, ErrorKind::Temporary),
+        (StatusCode::NOT_FOUND, ErrorKind::Permanent),
+    ];
+
+    for (status, kind) in cases {
+        assert_eq!(
+            api_error(status, "SOMETHING").error_kind(),
+            kind,
+            "{}",
+            status
+        );
+    }
+}
+
+#[test]
+fn error_kind_prefers_error_id() {
+    let error = api_error(StatusCode::BAD_REQUEST, "TOO_MANY_REQUESTS");
+
+    assert_eq!(error.error_kind(), ErrorKind::Throttling);
+    assert!(error.is_retryable());
+}
+
+#[test]
+fn error_kind_validation_errors() {
+    let details: ApiErrorDetails = crate::fixtures::get("errors/validation_errors")
+        .unwrap()
+        .deserialize()
+        .unwrap();
+    let error = ApiError {
+        details: Some(Box::new(details)),
+        body: None,
+        status: StatusCode::BAD_REQUEST,
+        retry_after: None,
+    };
+
+    assert_eq!(error.error_kind(), ErrorKind::Validation);
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn sdk_error_kind() {
+    let throttled = SdkError::ApiRequestError(api_error(StatusCode::TOO_MANY_REQUESTS, "E429"));
+    assert!(throttled.is_retryable());
+
+    let send_failed = SdkError::SendFailed {
+        bulk_id: "bulk-id".to_string(),
+        message_ids: vec![],
+        source: Box::new(SdkError::ApiRequestError(api_error(
+            StatusCode::UNAUTHORIZED,
+            "UNAUTHORIZED",
+        ))),
+    };
+    assert_eq!(send_failed.error_kind(), ErrorKind::Auth);
+
+    let validation = SdkError::Validation(validator::ValidationErrors::new());
+    assert_eq!(validation.error_kind(), ErrorKind::Validation);
+
+    let duplicate = SdkError::DuplicateSuppressed {
+        destinations: vec!["41793026727".to_string()],
+    };
+    assert!(!duplicate.is_retryable());
+}
+
+fn headers_with_retry_after(value: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+    headers
+}
+
+#[test]
+fn retry_after_seconds() {
+    assert_eq!(
+        retry_after(&headers_with_retry_after("120")),
+        Some(Duration::from_secs(120))
+    );
+    assert_eq!(retry_after(&HeaderMap::new()), None);
+    assert_eq!(retry_after(&headers_with_retry_after("soon")), None);
+}
+
+#[test]
+fn retry_after_http_date() {
+    let in_a_minute = (chrono::Utc::now() + chrono::Duration::seconds(60))
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string();
+    let delay = retry_after(&headers_with_retry_after(&in_a_minute)).unwrap();
+    assert!(delay > Duration::from_secs(55) && delay <= Duration::from_secs(60));
+
+    assert_eq!(
+        retry_after(&headers_with_retry_after("Wed, 21 Oct 2015 07:28:00 GMT")),
+        Some(Duration::ZERO)
+    );
+}
+
+#[test]
+fn sdk_error_retry_after() {
+    let mut throttled = api_error(StatusCode::TOO_MANY_REQUESTS, "E429");
+    throttled.retry_after = Some(Duration::from_secs(5));
+    let send_failed = SdkError::SendFailed {
+        bulk_id: "bulk-id".to_string(),
+        message_ids: vec![],
+        source: Box::new(SdkError::ApiRequestError(throttled)),
+    };
+    assert_eq!(send_failed.retry_after(), Some(Duration::from_secs(5)));
+
+    let circuit_open = SdkError::CircuitOpen {
+        retry_after: Duration::from_secs(1),
+    };
+    assert_eq!(circuit_open.retry_after(), Some(Duration::from_secs(1)));
+    assert_eq!(SdkError::Cancelled.retry_after(), None);
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn api_error_has_retry_after_of_response() {
+    use crate::api::sms::{SmsClient, PATH_PREVIEW};
+    use crate::api::tests::test_configuration;
+    use crate::model::sms::PreviewRequestBody;
+
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(429)
+            .header("content-type", "application/json")
+            .header("Retry-After", "30")
+            .body(
+                r#"{"requestError": {"serviceException": {"messageId": "TOO_MANY_REQUESTS", "text": "Too many requests"}}}"#,
+            );
+    });
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let error = client
+        .preview(PreviewRequestBody::new("Hello"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.error_kind(), ErrorKind::Throttling);
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+}
+
+#[cfg(feature = "sms")]
+async fn preview_error(status: u16, content_type: &str, body: &str) -> SdkError {
+    use crate::api::sms::{SmsClient, PATH_PREVIEW};
+    use crate::api::tests::test_configuration;
+    use crate::model::sms::PreviewRequestBody;
+
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(status)
+            .header("content-type", content_type)
+            .header("Retry-After", "30")
+            .body(body);
+    });
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    client
+        .preview(PreviewRequestBody::new("Hello"))
+        .await
+        .unwrap_err()
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn api_error_of_html_response() {
+    let body = "<html><body><h1>503 Service Unavailable</h1></body></html>";
+    let error = preview_error(503, "text/html", body).await;
+
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    match error {
+        SdkError::ApiRequestError(api_error) => {
+            assert_eq!(api_error.status, StatusCode::SERVICE_UNAVAILABLE);
+            assert!(api_error.details.is_none());
+            assert_eq!(api_error.body.as_deref(), Some(body));
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn api_error_of_empty_response() {
+    let error = preview_error(429, "application/json", "").await;
+
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    match error {
+        SdkError::ApiRequestError(api_error) => {
+            assert_eq!(api_error.status, StatusCode::TOO_MANY_REQUESTS);
+            assert!(api_error.details.is_none());
+            assert!(api_error.body.is_none());
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[cfg(feature = "sms")]
+#[tokio::test]
+async fn error_kind_of_responses_without_details() {
+    let unavailable = preview_error(503, "text/html", "<html>Service Unavailable</html>").await;
+    assert_eq!(unavailable.error_kind(), ErrorKind::Temporary);
+    assert!(unavailable.is_retryable());
+
+    let throttled = preview_error(429, "text/plain", "").await;
+    assert_eq!(throttled.error_kind(), ErrorKind::Throttling);
+    assert!(throttled.is_retryable());
+
+    let not_found = preview_error(404, "text/html", "<html>Not Found</html>").await;
+    assert_eq!(not_found.error_kind(), ErrorKind::Permanent);
+}
+
+#[test]
+fn duplicate_suppressed_masks_destinations() {
+    let error = SdkError::DuplicateSuppressed {
+        destinations: vec!["41793026727".to_string(), "41793026834".to_string()],
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "duplicate message suppressed for 4179***727, 4179***834"
+    );
+}
+
+#[tokio::test]
+async fn sdk_error_kind_connection_refused() {
+    let error = reqwest::get("http://127.0.0.1:1").await.unwrap_err();
+
+    assert_eq!(SdkError::Reqwest(error).error_kind(), ErrorKind::Temporary);
+}
+
+#[cfg(feature = "sms")]
+#[test]
+fn flatten_nested_validation_errors() {
+    use validator::Validate;
+
+    use crate::api::flatten_validation_errors;
+    use crate::model::sms::{Destination, Message, SendRequestBody};
+
+    let mut message = Message::new(vec![Destination::new("41793026727"), Destination::new("")]);
+    message.notify_url = Some("not a url".to_string());
+    let request_body = SendRequestBody::new(vec![Message::new(vec![]), message]);
+
+    let errors = flatten_validation_errors(&request_body.validate().unwrap_err());
+
+    let paths: Vec<(&str, &str)> = errors
+        .iter()
+        .map(|error| (error.path.as_str(), error.code.as_str()))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            ("messages[0].destinations", "length"),
+            ("messages[1].destinations[1].to", "length"),
+            ("messages[1].notify_url", "url"),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_value(&errors[2]).unwrap(),
+        serde_json::json!({"path": "messages[1].notify_url", "code": "url"})
+    );
+}
+
+#[test]
+fn field_errors_of_other_errors() {
+    let error = SdkError::ApiRequestError(api_error(StatusCode::BAD_REQUEST, "BAD_REQUEST"));
+
+    assert!(error.field_errors().is_empty());
+}
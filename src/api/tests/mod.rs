@@ -4,12 +4,57 @@ use httpmock::prelude::*;
 
 use crate::configuration::{ApiKey, Configuration};
 
+#[cfg(all(test, feature = "account"))]
+mod account;
+
+#[cfg(test)]
+mod cancellation;
+
+#[cfg(test)]
+mod channel;
+
+#[cfg(test)]
+mod circuit_breaker;
+
+#[cfg(test)]
+mod client;
+
+#[cfg(all(test, feature = "dispatcher"))]
+mod dispatcher;
+
+#[cfg(test)]
+mod errors;
+
+#[cfg(test)]
+mod orchestration;
+
+#[cfg(test)]
+mod pool;
+
+#[cfg(test)]
+mod raw;
+
+#[cfg(test)]
+mod reconciliation;
+
+#[cfg(all(test, feature = "recording"))]
+mod recording;
+
+#[cfg(test)]
+mod responses;
+
 #[cfg(test)]
 mod sms;
 
+#[cfg(test)]
+mod tfa;
+
 #[cfg(test)]
 mod whatsapp;
 
+#[cfg(test)]
+mod whatsapp_scheduler;
+
 #[cfg(test)]
 mod email;
 
@@ -61,4 +106,5 @@ fn test_configuration(server_url: &str) -> Configuration {
             prefix: None,
         },
     )
+    .unwrap()
 }
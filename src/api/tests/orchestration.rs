@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use crate::api::orchestration::*;
+use crate::api::sms::{SmsClient, PATH_SEND};
+use crate::api::tests::test_configuration;
+use crate::api::whatsapp::{WhatsAppClient, PATH_SEND_TEMPLATE};
+use crate::model::sms::{Destination, Message, SendRequestBody};
+use crate::model::status::StatusGroup;
+use crate::model::whatsapp::{
+    FailoverMessage, SendTemplateRequestBody, TemplateBodyContent, TemplateContent, TemplateData,
+    TemplateLanguage,
+};
+
+const MESSAGE_ID: &str = "a28dd97c-1ffb-4fcf-99f1-0b557ed381da";
+
+fn template_response(group_name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "messages": [{
+            "to": "441134960001",
+            "messageId": MESSAGE_ID,
+            "status": {"groupName": group_name, "name": "SOME_STATUS"}
+        }]
+    })
+}
+
+// Starts a server answering template sends with the given status and accepting SMS sends.
+async fn failover_server(
+    template_status: u16,
+    template_response: serde_json::Value,
+) -> httpmock::MockServer {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND_TEMPLATE);
+        then.status(template_status)
+            .header("content-type", "application/json")
+            .json_body(template_response);
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_SEND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({"messages": [{"to": "441134960001"}]}));
+    });
+
+    server
+}
+
+fn failover_sender(server: &httpmock::MockServer) -> FailoverSender {
+    FailoverSender::new(
+        WhatsAppClient::with_configuration(test_configuration(&server.base_url())),
+        SmsClient::with_configuration(test_configuration(&server.base_url())),
+    )
+}
+
+fn request_bodies() -> (SendTemplateRequestBody, SendRequestBody) {
+    let data = TemplateData::new(TemplateBodyContent::new(vec![]));
+    let content = TemplateContent::new("template_name", data, TemplateLanguage::EnGb);
+    let mut message = FailoverMessage::new("441134960000", "441134960001", content);
+    message.message_id = Some(MESSAGE_ID.to_string());
+
+    let mut sms = Message::new(vec![Destination::new("441134960001")]);
+    sms.text = Some("Some text".to_string());
+
+    (
+        SendTemplateRequestBody::new(vec![message]),
+        SendRequestBody::new(vec![sms]),
+    )
+}
+
+#[tokio::test]
+async fn failover_not_needed() {
+    let server = failover_server(200, template_response("PENDING")).await;
+    let (whatsapp, sms) = request_bodies();
+
+    let outcome = failover_sender(&server).send(whatsapp, sms).await;
+
+    assert!(outcome.whatsapp.is_ok());
+    assert_eq!(outcome.fallback_reason, None);
+    assert!(outcome.sms.is_none());
+}
+
+#[tokio::test]
+async fn failover_on_send_error() {
+    let server = failover_server(
+        500,
+        serde_json::json!({"requestError": {"serviceException": {"messageId": "GENERAL_ERROR"}}}),
+    )
+    .await;
+    let (whatsapp, sms) = request_bodies();
+
+    let outcome = failover_sender(&server).send(whatsapp, sms).await;
+
+    assert!(outcome.whatsapp.is_err());
+    assert_eq!(outcome.fallback_reason, Some(FallbackReason::SendFailed));
+    assert!(outcome.sms.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn failover_on_rejected() {
+    let server = failover_server(200, template_response("REJECTED")).await;
+    let (whatsapp, sms) = request_bodies();
+
+    let outcome = failover_sender(&server).send(whatsapp, sms).await;
+
+    assert_eq!(
+        outcome.fallback_reason,
+        Some(FallbackReason::Rejected(StatusGroup::Rejected))
+    );
+    assert!(outcome.sms.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn failover_delivery_reported() {
+    let server = failover_server(200, template_response("PENDING")).await;
+    let sender = failover_sender(&server).with_delivery_timeout(Duration::from_secs(10));
+    let (whatsapp, sms) = request_bodies();
+
+    let (outcome, reported) = tokio::join!(sender.send(whatsapp, sms), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!sender.report_status(MESSAGE_ID, StatusGroup::Pending));
+        sender.report_status(MESSAGE_ID, StatusGroup::Delivered)
+    });
+
+    assert!(reported);
+    assert_eq!(outcome.fallback_reason, None);
+    assert!(outcome.sms.is_none());
+}
+
+#[tokio::test]
+async fn failover_on_undeliverable_report() {
+    let server = failover_server(200, template_response("PENDING")).await;
+    let sender = failover_sender(&server).with_delivery_timeout(Duration::from_secs(10));
+    let (whatsapp, sms) = request_bodies();
+
+    let (outcome, _) = tokio::join!(sender.send(whatsapp, sms), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        sender.report_status(MESSAGE_ID, StatusGroup::Undeliverable)
+    });
+
+    assert_eq!(
+        outcome.fallback_reason,
+        Some(FallbackReason::NotDelivered(StatusGroup::Undeliverable))
+    );
+    assert!(outcome.sms.is_some());
+}
+
+#[tokio::test]
+async fn failover_on_delivery_timeout() {
+    let server = failover_server(200, template_response("PENDING")).await;
+    let sender = failover_sender(&server).with_delivery_timeout(Duration::from_millis(100));
+    let (whatsapp, sms) = request_bodies();
+
+    let outcome = sender.send(whatsapp, sms).await;
+
+    assert_eq!(outcome.fallback_reason, Some(FallbackReason::Timeout));
+    assert!(outcome.sms.is_some());
+    assert!(!sender.report_status(MESSAGE_ID, StatusGroup::Delivered));
+}
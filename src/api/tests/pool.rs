@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use httpmock::prelude::*;
+
+use crate::api::pool::ClientPool;
+use crate::api::sms::PATH_PREVIEW;
+use crate::configuration::{ApiKey, Configuration, ConfigurationError};
+use crate::model::sms::PreviewRequestBody;
+
+const DUMMY_BASE_URL: &str = "https://some.url";
+
+fn tenant_configuration(base_url: &str, key: &str) -> Configuration {
+    Configuration::with_api_key(base_url.to_string(), ApiKey::new(key.to_string())).unwrap()
+}
+
+#[tokio::test]
+async fn routes_to_tenant_credentials() {
+    let server = MockServer::start_async().await;
+    let mocks: Vec<_> = ["tenant-a-key", "tenant-b-key"]
+        .iter()
+        .map(|key| {
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path(PATH_PREVIEW)
+                    .header("Authorization", format!("App {}", key));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(r#"{{"originalText": "{}", "previews": []}}"#, key));
+            })
+        })
+        .collect();
+
+    let pool = ClientPool::new();
+    pool.insert(
+        "tenant-a",
+        tenant_configuration(&server.base_url(), "tenant-a-key"),
+    );
+    pool.insert(
+        "tenant-b",
+        tenant_configuration(&server.base_url(), "tenant-b-key"),
+    );
+
+    for (tenant, key) in [("tenant-b", "tenant-b-key"), ("tenant-a", "tenant-a-key")] {
+        let response = pool
+            .get(tenant)
+            .unwrap()
+            .sms()
+            .preview(PreviewRequestBody::new("Hello!"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.body.original_text.as_deref(), Some(key));
+    }
+
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[test]
+fn get_unknown_tenant() {
+    let pool: ClientPool<String> = ClientPool::default();
+
+    assert!(pool.get("tenant-a").is_none());
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn insert_replaces_client() {
+    let pool = ClientPool::new();
+    pool.insert(1, tenant_configuration(DUMMY_BASE_URL, "old-key"));
+    pool.insert(1, tenant_configuration(DUMMY_BASE_URL, "new-key"));
+
+    let client = pool.get(&1).unwrap();
+
+    assert_eq!(pool.len(), 1);
+    assert_eq!(
+        client.configuration().api_key().unwrap().reveal(),
+        "new-key"
+    );
+}
+
+#[test]
+fn get_or_try_insert_with_builds_once() {
+    let pool = ClientPool::new();
+    let mut builds = 0;
+
+    for _ in 0..2 {
+        pool.get_or_try_insert_with("tenant-a".to_string(), |tenant| {
+            builds += 1;
+            assert_eq!(tenant, "tenant-a");
+            Ok::<_, ConfigurationError>(tenant_configuration(DUMMY_BASE_URL, "tenant-a-key"))
+        })
+        .unwrap();
+    }
+
+    assert_eq!(builds, 1);
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn get_or_try_insert_with_error_adds_nothing() {
+    let pool = ClientPool::new();
+
+    let result = pool.get_or_try_insert_with("tenant-a".to_string(), |_| {
+        Configuration::with_api_key(
+            "http://some.url".to_string(),
+            ApiKey::new("some-api-key".to_string()),
+        )
+    });
+
+    assert!(matches!(result, Err(ConfigurationError::InvalidUrl { .. })));
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn clones_share_clients() {
+    let pool = ClientPool::new();
+    let clone = pool.clone();
+
+    let client = pool.insert("tenant-a", tenant_configuration(DUMMY_BASE_URL, "some-key"));
+
+    assert!(Arc::ptr_eq(&clone.get("tenant-a").unwrap(), &client));
+    assert!(clone.remove("tenant-a").is_some());
+    assert!(pool.is_empty());
+}
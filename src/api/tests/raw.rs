@@ -0,0 +1,130 @@
+use httpmock::prelude::*;
+use reqwest::Method;
+use serde_json::json;
+
+use crate::api::email::EmailClient;
+use crate::api::sms::{BlockingSmsClient, SmsClient};
+use crate::api::tests::test_configuration;
+use crate::api::tfa::TfaClient;
+use crate::api::whatsapp::WhatsAppClient;
+use crate::api::SdkError;
+
+#[tokio::test]
+async fn send_raw_sends_body_as_is() {
+    let server = MockServer::start_async().await;
+    // Not a valid typed request: messages of SMS requests need destinations.
+    let request_body =
+        json!({"messages": [{"text": "Hello!", "someNewOption": {"enabled": true}}]});
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/sms/3/messages")
+            .header("Authorization", "App some-api-key")
+            .json_body(request_body.clone());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "someNewField": 42}"#);
+    });
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .send_raw(Method::POST, "/sms/3/messages", Some(request_body.clone()))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body["someNewField"], 42);
+}
+
+#[tokio::test]
+async fn send_raw_without_body() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/whatsapp/1/senders/441134960000/new-feature")
+            .query_param("limit", "5");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"results": []}"#);
+    });
+    let client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .send_raw(
+            Method::GET,
+            "/whatsapp/1/senders/441134960000/new-feature?limit=5",
+            None,
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.body, json!({"results": []}));
+}
+
+#[tokio::test]
+async fn send_raw_empty_response() {
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(DELETE).path("/2fa/2/applications/some-app-id");
+        then.status(204);
+    });
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .send_raw(Method::DELETE, "/2fa/2/applications/some-app-id", None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::NO_CONTENT);
+    assert!(response.body.is_null());
+}
+
+#[tokio::test]
+async fn send_raw_api_error() {
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(POST).path("/email/1/new-feature");
+        then.status(400)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "BAD_REQUEST", "text": "Bad request"}}}"#);
+    });
+    let client = EmailClient::with_configuration(test_configuration(&server.base_url()));
+
+    let error = client
+        .send_raw(Method::POST, "/email/1/new-feature", Some(json!({})))
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::ApiRequestError(error) => {
+            assert_eq!(error.status, reqwest::StatusCode::BAD_REQUEST)
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn blocking_send_raw() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/sms/3/messages")
+            .json_body(json!({"messages": []}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id"}"#);
+    });
+    let client = BlockingSmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .send_raw(
+            Method::POST,
+            "/sms/3/messages",
+            Some(json!({"messages": []})),
+        )
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(response.body["bulkId"], "some-bulk-id");
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+
+use crate::api::channel::{ChannelMessage, MessageChannel, MessageStatus, SentMessage};
+use crate::api::reconciliation::Reconciler;
+use crate::api::SdkError;
+use crate::configuration::Channel;
+use crate::model::status::StatusGroup;
+
+// Answers status lookups with the queued results of each message ID, repeating the last one.
+#[derive(Default)]
+struct FakeChannel {
+    statuses: Mutex<HashMap<String, Vec<Option<StatusGroup>>>>,
+    lookups: Mutex<usize>,
+}
+
+impl FakeChannel {
+    fn with(mut self, message_id: &str, groups: &[Option<StatusGroup>]) -> Self {
+        self.statuses
+            .get_mut()
+            .unwrap()
+            .insert(message_id.to_string(), groups.to_vec());
+        self
+    }
+
+    fn lookups(&self) -> usize {
+        *self.lookups.lock().unwrap()
+    }
+}
+
+impl MessageChannel for FakeChannel {
+    fn channel(&self) -> Channel {
+        Channel::Sms
+    }
+
+    fn send<'a>(
+        &'a self,
+        _message: &'a ChannelMessage,
+    ) -> BoxFuture<'a, Result<SentMessage, SdkError>> {
+        Box::pin(async { Ok(SentMessage::default()) })
+    }
+
+    fn get_status<'a>(
+        &'a self,
+        message_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<MessageStatus>, SdkError>> {
+        Box::pin(async move {
+            *self.lookups.lock().unwrap() += 1;
+            let mut statuses = self.statuses.lock().unwrap();
+            let Some(groups) = statuses.get_mut(message_id) else {
+                return Err(SdkError::Cancelled);
+            };
+            let group = if groups.len() > 1 {
+                groups.remove(0)
+            } else {
+                groups[0]
+            };
+
+            Ok(group.map(|group| MessageStatus {
+                group: Some(group),
+                name: Some(group.name().to_string()),
+                description: None,
+            }))
+        })
+    }
+}
+
+fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[tokio::test]
+async fn reconcile_until_resolved() {
+    let channel = FakeChannel::default()
+        .with(
+            "delivered",
+            &[
+                None,
+                Some(StatusGroup::Pending),
+                Some(StatusGroup::Delivered),
+            ],
+        )
+        .with("rejected", &[Some(StatusGroup::Rejected)]);
+
+    let summary = Reconciler::new(&channel)
+        .with_poll_interval(Duration::from_millis(10))
+        .reconcile(ids(&["delivered", "rejected"]), Duration::from_secs(5))
+        .await;
+
+    assert!(summary.is_resolved());
+    assert_eq!(summary.delivered, vec!["delivered"]);
+    assert_eq!(summary.failed.len(), 1);
+    assert_eq!(summary.failed[0].message_id, "rejected");
+    assert_eq!(summary.failed[0].status.group, Some(StatusGroup::Rejected));
+    assert_eq!(channel.lookups(), 4);
+}
+
+#[tokio::test]
+async fn reconcile_deadline_leaves_unresolved() {
+    let channel = FakeChannel::default().with("pending", &[Some(StatusGroup::Pending)]);
+
+    let summary = Reconciler::new(&channel)
+        .with_poll_interval(Duration::from_millis(20))
+        .reconcile(ids(&["pending", "missing"]), Duration::from_millis(50))
+        .await;
+
+    assert!(!summary.is_resolved());
+    assert!(summary.delivered.is_empty());
+    assert_eq!(summary.unresolved.len(), 2);
+    let pending = &summary.unresolved[0];
+    assert_eq!(pending.message_id, "pending");
+    assert_eq!(
+        pending.status.as_ref().unwrap().group,
+        Some(StatusGroup::Pending)
+    );
+    assert!(matches!(
+        summary.unresolved[1].error,
+        Some(SdkError::Cancelled)
+    ));
+}
+
+#[tokio::test]
+async fn reconcile_zero_deadline_looks_up_once() {
+    let channel = FakeChannel::default().with("delivered", &[Some(StatusGroup::Delivered)]);
+
+    let summary = Reconciler::new(&channel)
+        .reconcile(ids(&["delivered"]), Duration::ZERO)
+        .await;
+
+    assert_eq!(summary.delivered, vec!["delivered"]);
+    assert_eq!(channel.lookups(), 1);
+}
+
+#[tokio::test]
+async fn reconcile_unbounded_deadline() {
+    let channel = FakeChannel::default().with(
+        "delivered",
+        &[Some(StatusGroup::Pending), Some(StatusGroup::Delivered)],
+    );
+
+    let summary = Reconciler::new(&channel)
+        .with_poll_interval(Duration::from_millis(10))
+        .reconcile(ids(&["delivered"]), Duration::MAX)
+        .await;
+
+    assert!(summary.is_resolved());
+    assert_eq!(summary.delivered, vec!["delivered"]);
+    assert_eq!(channel.lookups(), 2);
+}
@@ -0,0 +1,125 @@
+use httpmock::prelude::*;
+use reqwest::StatusCode;
+
+use crate::api::recording::*;
+use crate::api::sms::{BlockingSmsClient, SmsClient, PATH_PREVIEW};
+use crate::api::tests::test_configuration;
+use crate::api::SdkError;
+use crate::model::sms::PreviewRequestBody;
+
+const PREVIEW_RESPONSE: &str = r#"{"originalText": "Hello!", "previews": []}"#;
+
+fn cassette_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("infobip-sdk-{}-{}.json", std::process::id(), name))
+}
+
+fn recorded_preview() -> Interaction {
+    Interaction {
+        request: RecordedRequest {
+            method: "POST".to_string(),
+            path: PATH_PREVIEW.to_string(),
+            query: Default::default(),
+            body: None,
+        },
+        response: RecordedResponse {
+            status: 200,
+            content_type: Some("application/json".to_string()),
+            body: PREVIEW_RESPONSE.to_string(),
+        },
+    }
+}
+
+#[tokio::test]
+async fn record_then_replay() {
+    let server = MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(PREVIEW_RESPONSE);
+    });
+    let path = cassette_path("record_then_replay");
+
+    let cassette = Cassette::record(&path);
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_cassette(cassette.clone()),
+    );
+    let response = client
+        .preview(PreviewRequestBody::new("Hello!"))
+        .await
+        .unwrap();
+    assert_eq!(response.status, StatusCode::OK);
+    cassette.save().unwrap();
+
+    let interactions = cassette.interactions();
+    assert_eq!(interactions.len(), 1);
+    assert_eq!(interactions[0].request.path, PATH_PREVIEW);
+    assert_eq!(
+        interactions[0].request.body,
+        Some(serde_json::json!({"text": "Hello!"}))
+    );
+
+    let cassette = Cassette::open(&path).unwrap();
+    assert_eq!(cassette.mode(), CassetteMode::Replay);
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_cassette(cassette.clone()),
+    );
+    let replayed = client
+        .preview(PreviewRequestBody::new("Hello!"))
+        .await
+        .unwrap();
+
+    assert_eq!(replayed.body, response.body);
+    assert!(cassette.is_fully_replayed());
+    mock.assert_hits(1);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn replay_not_recorded() {
+    let path = cassette_path("replay_not_recorded");
+    std::fs::write(&path, "[]").unwrap();
+    let cassette = Cassette::replay(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let client = SmsClient::with_configuration(
+        test_configuration("http://localhost:1").with_cassette(cassette),
+    );
+    let error = client
+        .preview(PreviewRequestBody::new("Hello!"))
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::NotRecorded { method, path } => {
+            assert_eq!(method, "POST");
+            assert_eq!(path, PATH_PREVIEW);
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[test]
+fn replay_blocking() {
+    let path = cassette_path("replay_blocking");
+    std::fs::write(
+        &path,
+        serde_json::to_string(&vec![recorded_preview()]).unwrap(),
+    )
+    .unwrap();
+    let cassette = Cassette::replay(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let client = BlockingSmsClient::with_configuration(
+        test_configuration("http://localhost:1").with_cassette(cassette.clone()),
+    );
+    let response = client.preview(PreviewRequestBody::new("Hello!")).unwrap();
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body.original_text.as_deref(), Some("Hello!"));
+    assert!(cassette.is_fully_replayed());
+    assert!(matches!(
+        client.preview(PreviewRequestBody::new("Hello!")),
+        Err(SdkError::NotRecorded { .. })
+    ));
+}
@@ -0,0 +1,76 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use httpmock::prelude::*;
+
+use crate::api::sms::{SmsClient, PATH_PREVIEW};
+use crate::api::tests::{test_configuration, DUMMY_TEXT};
+use crate::api::{parse_body, NoContent};
+use crate::model::sms::PreviewRequestBody;
+
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct WarningCollector;
+
+impl log::Log for WarningCollector {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            WARNINGS.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn parse_empty_body() {
+    assert_eq!(parse_body::<NoContent>("").unwrap(), NoContent);
+    assert_eq!(parse_body::<NoContent>(" \n").unwrap(), NoContent);
+    assert_eq!(parse_body::<()>("").unwrap(), ());
+    assert_eq!(parse_body::<Option<String>>("").unwrap(), None);
+}
+
+#[test]
+fn no_content_ignores_body() {
+    assert_eq!(
+        parse_body::<NoContent>(r#"{"status": "ok"}"#).unwrap(),
+        NoContent
+    );
+}
+
+#[test]
+fn parse_empty_body_into_struct_fails() {
+    assert!(parse_body::<serde_json::Map<String, serde_json::Value>>("").is_err());
+}
+
+#[tokio::test]
+async fn slow_request_warning() {
+    log::set_logger(&WarningCollector).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(POST).path(PATH_PREVIEW);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#)
+            .delay(Duration::from_millis(50));
+    });
+    let configuration =
+        test_configuration(&server.base_url()).with_slow_request_warning(Duration::from_millis(20));
+    let client = SmsClient::with_configuration(configuration);
+
+    let response = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap();
+
+    assert!(response.duration >= Duration::from_millis(50));
+    let warnings = WARNINGS.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("slow Infobip API request: POST /sms/1/preview took"));
+    assert!(warnings[0].contains("payload"));
+}
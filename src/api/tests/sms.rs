@@ -1,7 +1,12 @@
+use futures_util::StreamExt;
+
 use crate::api::{
+    deduplication::DeduplicationWindow,
+    preview_cache::PreviewCache,
+    response_validation::AnomalyCollector,
     sms::*,
     tests::{mock_blocking_json_endpoint, mock_json_endpoint, test_configuration, DUMMY_TEXT},
-    SdkError,
+    ErrorKind, SdkError,
 };
 use crate::model::sms::{ScheduledStatus::Paused, *};
 
@@ -92,6 +97,296 @@ fn test_blocking_preview_valid() {
     assert!(!response.body.previews.unwrap().is_empty());
 }
 
+fn default_headers_configuration(server_url: &str) -> crate::configuration::Configuration {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("X-Request-Origin", "billing-service".parse().unwrap());
+    headers.insert("User-Agent", "custom-agent".parse().unwrap());
+
+    test_configuration(server_url).with_default_headers(headers)
+}
+
+#[tokio::test]
+async fn test_preview_default_headers() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_PREVIEW)
+            .header("X-Request-Origin", "billing-service")
+            .header("User-Agent", "custom-agent")
+            .header("Authorization", "App some-api-key");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#);
+    });
+
+    let client = SmsClient::with_configuration(default_headers_configuration(&server.base_url()));
+
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_blocking_preview_default_headers() {
+    let server = httpmock::MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_PREVIEW)
+            .header("X-Request-Origin", "billing-service")
+            .header("User-Agent", "custom-agent");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#);
+    });
+
+    let client =
+        BlockingSmsClient::with_configuration(default_headers_configuration(&server.base_url()));
+
+    client.preview(PreviewRequestBody::new(DUMMY_TEXT)).unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_preview_user_agent_suffix() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_PREVIEW)
+            .matches(|req| {
+                req.headers.iter().flatten().any(|(name, value)| {
+                    name.eq_ignore_ascii_case("user-agent")
+                        && value.starts_with("@infobip/rust-sdk/")
+                        && value.ends_with(" billing-service/1.2.3")
+                })
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#);
+    });
+
+    let configuration =
+        test_configuration(&server.base_url()).with_user_agent_suffix("billing-service/1.2.3");
+    let client = SmsClient::with_configuration(configuration);
+
+    client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_blocking_preview_app_info() {
+    let server = httpmock::MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_PREVIEW)
+            .matches(|req| {
+                req.headers.iter().flatten().any(|(name, value)| {
+                    name.eq_ignore_ascii_case("user-agent")
+                        && value.starts_with("@infobip/rust-sdk/")
+                        && value.ends_with(" billing-service/1.2.3")
+                })
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#);
+    });
+
+    let configuration =
+        test_configuration(&server.base_url()).with_app_info("billing-service/1.2.3");
+    let client = BlockingSmsClient::with_configuration(configuration);
+
+    client.preview(PreviewRequestBody::new(DUMMY_TEXT)).unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_preview_timeout() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(200)
+            .delay(std::time::Duration::from_millis(500))
+            .header("content-type", "application/json")
+            .body(r#"{"previews": []}"#);
+    });
+
+    let configuration =
+        test_configuration(&server.base_url()).with_timeout(std::time::Duration::from_millis(50));
+    let client = SmsClient::with_configuration(configuration);
+
+    let error = client
+        .preview(PreviewRequestBody::new(DUMMY_TEXT))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Reqwest(ref e) if e.is_timeout()));
+}
+
+fn mock_preview_texts(server: &httpmock::MockServer, texts: &[&str]) {
+    for text in texts {
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path(PATH_PREVIEW)
+                .json_body(serde_json::json!({ "text": text }));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({ "originalText": text, "previews": [] }));
+        });
+    }
+}
+
+#[tokio::test]
+async fn test_preview_many_valid() {
+    let texts = ["first text", "second text", "third text"];
+    let server = httpmock::MockServer::start_async().await;
+    mock_preview_texts(&server, &texts);
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let results = client.preview_many(texts, 2).await;
+    let original_texts: Vec<String> = results
+        .into_iter()
+        .map(|result| result.unwrap().body.original_text.unwrap())
+        .collect();
+
+    assert_eq!(original_texts, texts);
+}
+
+#[tokio::test]
+async fn test_preview_many_partial_failure() {
+    let server = httpmock::MockServer::start_async().await;
+    mock_preview_texts(&server, &["known text"]);
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let results = client.preview_many(["known text", "unknown text"], 0).await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+}
+
+#[test]
+fn test_blocking_preview_many_valid() {
+    let texts = ["first text", "second text", "third text"];
+    let server = httpmock::MockServer::start();
+    mock_preview_texts(&server, &texts);
+
+    let client = BlockingSmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let results = client.preview_many(texts, 2);
+    let original_texts: Vec<String> = results
+        .into_iter()
+        .map(|result| result.unwrap().body.original_text.unwrap())
+        .collect();
+
+    assert_eq!(original_texts, texts);
+}
+
+#[tokio::test]
+async fn test_preview_batch_options() {
+    let server = httpmock::MockServer::start_async().await;
+    mock_preview_texts(&server, &["plain text"]);
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_PREVIEW)
+            .json_body(serde_json::json!({ "text": "greek text", "transliteration": "GREEK" }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "originalText": "transliterated", "previews": [] }));
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut greek = PreviewRequestBody::new("greek text");
+    greek.transliteration = Some("GREEK".to_string());
+    let results = client
+        .preview_batch(vec![greek, PreviewRequestBody::new("plain text")], 2)
+        .await;
+
+    let original_texts: Vec<String> = results
+        .into_iter()
+        .map(|result| result.unwrap().body.original_text.unwrap())
+        .collect();
+    assert_eq!(original_texts, ["transliterated", "plain text"]);
+}
+
+#[tokio::test]
+async fn test_preview_cached() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "originalText": DUMMY_TEXT, "previews": [] }));
+    });
+
+    let cache = PreviewCache::new(10);
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_preview_cache(cache.clone()),
+    );
+
+    let results = client
+        .preview_many([DUMMY_TEXT, DUMMY_TEXT, DUMMY_TEXT], 1)
+        .await;
+    let mut transliterated = PreviewRequestBody::new(DUMMY_TEXT);
+    transliterated.transliteration = Some("GREEK".to_string());
+    client.preview(transliterated).await.unwrap();
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(mock.hits_async().await, 2);
+    assert_eq!(cache.len(), 2);
+}
+
+#[tokio::test]
+async fn test_preview_errors_not_cached() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(PATH_PREVIEW);
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR"}}}"#);
+    });
+
+    let cache = PreviewCache::new(10);
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_preview_cache(cache.clone()),
+    );
+
+    let results = client.preview_many([DUMMY_TEXT, DUMMY_TEXT], 1).await;
+
+    assert!(results.iter().all(Result::is_err));
+    assert_eq!(mock.hits_async().await, 2);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_preview_cache_evicts_least_recently_used() {
+    let cache = PreviewCache::new(2);
+    let first = PreviewRequestBody::new("first");
+    let second = PreviewRequestBody::new("second");
+    let third = PreviewRequestBody::new("third");
+
+    cache.insert(&first, PreviewResponseBody::default());
+    cache.insert(&second, PreviewResponseBody::default());
+    assert!(cache.get(&first).is_some());
+    cache.insert(&third, PreviewResponseBody::default());
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&first).is_some());
+    assert!(cache.get(&second).is_none());
+    assert!(cache.get(&third).is_some());
+}
+
 #[tokio::test]
 async fn test_preview_server_error() {
     let expected_response = r#"
@@ -123,6 +418,7 @@ async fn test_preview_server_error() {
         assert_eq!(api_error.status, expected_status);
         assert!(!api_error
             .details
+            .unwrap()
             .request_error
             .service_exception
             .text
@@ -285,6 +581,300 @@ async fn test_send_valid() {
     assert!(!response.body.messages.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_send_text_to() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .json_body_partial(
+                r#"{"messages": [{"destinations": [{"to": "491702384590"}], "text": "Hello"}]}"#,
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": [{"to": "491702384590"}]}"#);
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.send_text_to("491702384590", "Hello").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.messages.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_send_generated_ids_sent() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .json_body_partial(r#"{"bulkId": "existing-bulk-id"}"#)
+            .matches(|request| {
+                let body: serde_json::Value =
+                    serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+                let destinations = &body["messages"][0]["destinations"];
+                destinations[0]["messageId"].as_str().unwrap().len() == 36
+                    && destinations[1]["messageId"] == "existing-message-id"
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "existing-bulk-id", "messages": []}"#);
+    });
+
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_generated_message_ids(),
+    );
+
+    let mut destination = Destination::new("41793026834");
+    destination.message_id = Some("existing-message-id".to_string());
+    let message = Message::new(vec![Destination::new("41793026727"), destination]);
+    let mut request_body = SendRequestBody::new(vec![message]);
+    request_body.bulk_id = Some("existing-bulk-id".to_string());
+
+    client.send(request_body).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_platform_ids() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND)
+            .json_body_partial(
+                r#"{"messages": [
+                    {"applicationId": "marketing", "entityId": "customer-1"},
+                    {"applicationId": "marketing", "entityId": "customer-2"}
+                ]}"#,
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"messages": []}"#);
+    });
+
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url())
+            .with_application_id("marketing")
+            .with_entity_id("customer-1"),
+    );
+
+    let mut own_entity = Message::new(vec![Destination::new("41793026834")]);
+    own_entity.entity_id = Some("customer-2".to_string());
+    let request_body = SendRequestBody::new(vec![
+        Message::new(vec![Destination::new("41793026727")]),
+        own_entity,
+    ]);
+
+    client.send(request_body).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+fn deduplication_request_body(text: &str) -> SendRequestBody {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some(text.to_string());
+
+    SendRequestBody::new(vec![message])
+}
+
+#[tokio::test]
+async fn test_send_duplicate_suppressed() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"messages": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let window = DeduplicationWindow::new(std::time::Duration::from_secs(60));
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_deduplication(window.clone()),
+    );
+
+    client
+        .send(deduplication_request_body("Your order has shipped."))
+        .await
+        .unwrap();
+    let error = client
+        .send(deduplication_request_body("Your order has shipped."))
+        .await
+        .unwrap_err();
+    client
+        .send(deduplication_request_body("Your order was delivered."))
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(error, SdkError::DuplicateSuppressed { destinations } if destinations == vec!["41793026727"])
+    );
+    assert_eq!(window.len(), 2);
+}
+
+#[tokio::test]
+async fn test_send_duplicate_in_request() {
+    let window = DeduplicationWindow::new(std::time::Duration::from_secs(60));
+    let client = SmsClient::with_configuration(
+        test_configuration(DUMMY_BASE_URL).with_deduplication(window.clone()),
+    );
+
+    let mut request_body = deduplication_request_body("Hi");
+    request_body.messages.push(request_body.messages[0].clone());
+
+    let error = client.send(request_body).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::DuplicateSuppressed { .. }));
+    assert!(window.is_empty());
+}
+
+#[tokio::test]
+async fn test_send_rejected_not_deduplicated() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"requestError": {"serviceException": {"messageId": "BAD_REQUEST", "text": "Bad request"}}}"#,
+        reqwest::StatusCode::BAD_REQUEST,
+    )
+    .await;
+
+    let window = DeduplicationWindow::new(std::time::Duration::from_secs(60));
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_deduplication(window.clone()),
+    );
+
+    for _ in 0..2 {
+        let error = client
+            .send(deduplication_request_body("Hi"))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SdkError::ApiRequestError(_)));
+    }
+    assert!(window.is_empty());
+}
+
+#[tokio::test]
+async fn test_send_duplicate_after_ttl() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"messages": []}"#,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url())
+            .with_deduplication(DeduplicationWindow::new(std::time::Duration::ZERO)),
+    );
+
+    for _ in 0..2 {
+        client.send(deduplication_request_body("Hi")).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_send_generated_ids_on_failure() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR", "text": "Something went wrong"}}}"#,
+        reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+    )
+    .await;
+
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_generated_message_ids(),
+    );
+
+    let message = Message::new(vec![Destination::new("41793026727")]);
+    let error = client
+        .send(SendRequestBody::new(vec![message]))
+        .await
+        .unwrap_err();
+
+    match error {
+        SdkError::SendFailed {
+            bulk_id,
+            message_ids,
+            source,
+        } => {
+            assert_eq!(bulk_id.len(), 36);
+            assert_eq!(message_ids.len(), 1);
+            assert!(matches!(*source, SdkError::ApiRequestError(_)));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_send_generated_ids_validation_error() {
+    let client = SmsClient::with_configuration(
+        test_configuration(DUMMY_BASE_URL).with_generated_message_ids(),
+    );
+
+    let error = client.send(SendRequestBody::new(vec![])).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn test_send_response_validation() {
+    let expected_response = r#"
+    {
+      "messages": [
+        {
+          "messageId": "41793026727",
+          "status": {
+            "description": "Message sent to next instance",
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 26,
+            "name": "MESSAGE_ACCEPTED"
+          },
+          "to": "123456789101"
+        }
+      ]
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let anomalies = AnomalyCollector::new();
+    let configuration =
+        test_configuration(&server.base_url()).with_response_validation(anomalies.clone());
+    let client = SmsClient::with_configuration(configuration);
+
+    let message = Message::new(vec![Destination::new("123456789101")]);
+    client
+        .send(SendRequestBody::new(vec![message]))
+        .await
+        .unwrap();
+    assert!(anomalies.is_empty());
+
+    let message = Message::new(vec![
+        Destination::new("123456789101"),
+        Destination::new("123456789102"),
+    ]);
+    client
+        .send(SendRequestBody::new(vec![message]))
+        .await
+        .unwrap();
+
+    let found = anomalies.take();
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().all(|anomaly| anomaly.path == PATH_SEND));
+    assert!(anomalies.is_empty());
+}
+
 #[tokio::test]
 async fn test_send_binary_valid() {
     let expected_response = r#"
@@ -382,6 +972,38 @@ async fn test_send_over_query_parameters_valid() {
     assert!(!response.body.messages.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_send_over_query_parameters_joins_destinations() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_SEND_OVER_QUERY_PARAMS)
+            .query_param("username", "username")
+            .query_param("to", "41793026727,41793026834")
+            .query_param("flash", "true")
+            .query_param("validityPeriod", "60");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"bulkId": "some-bulk-id", "messages": []}"#);
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let mut query_parameters = SendOverQueryParametersQueryParameters::new(
+        "username",
+        "password",
+        vec!["41793026727".to_string(), "41793026834".to_string()],
+    );
+    query_parameters.flash = Some(true);
+    query_parameters.validity_period = Some(60);
+
+    client
+        .send_over_query_parameters(query_parameters)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_scheduled_valid() {
     let expected_response = r#"
@@ -545,6 +1167,35 @@ async fn test_update_scheduled_status_valid() {
     assert_eq!(response.body.status.unwrap(), Paused);
 }
 
+#[tokio::test]
+async fn test_cancel_scheduled_valid() {
+    let expected_response = r#"
+    {
+      "bulkId": "BULK-ID-123-xyz",
+      "status": "CANCELED"
+    }
+    "#;
+
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::PUT)
+            .path(PATH_UPDATE_SCHEDULED_STATUS)
+            .query_param("bulkId", "BULK-ID-123-xyz")
+            .json_body(serde_json::json!({ "status": "CANCELED" }));
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(expected_response);
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.cancel_scheduled("BULK-ID-123-xyz").await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.status.unwrap(), ScheduledStatus::Canceled);
+}
+
 #[tokio::test]
 async fn test_update_scheduled_status_empty_bulk_id() {
     let client = SmsClient::with_configuration(test_configuration("https://some.url"));
@@ -612,30 +1263,153 @@ async fn test_inbound_reports_big_limit() {
     assert!(client.inbound_reports(query_parameters).await.is_err());
 }
 
+fn inbound_report_json(message_id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "results": [{ "messageId": message_id, "from": "385916242493", "text": DUMMY_TEXT }],
+        "messageCount": 1,
+        "pendingMessageCount": 0
+    })
+}
+
 #[tokio::test]
-async fn test_logs_valid() {
-    let expected_response = r#"
-    {
-      "results": [
-        {
-          "bulkId": "BULK-ID-123-xyz",
-          "messageId": "MESSAGE-ID-123-xyz",
-          "to": "41793026727",
-          "sentAt": "2019-11-09T16:00:00.000+0000",
-          "doneAt": "2019-11-09T16:00:00.000+0000",
-          "smsCount": 1,
-          "mccMnc": "22801",
-          "price": {
-            "pricePerMessage": 0.01,
-            "currency": "EUR"
-          },
-          "status": {
-            "groupId": 3,
-            "groupName": "DELIVERED",
-            "id": 5,
-            "name": "DELIVERED_TO_HANDSET",
-            "description": "Message delivered to handset"
-          },
+async fn test_inbound_stream_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(PATH_GET_INBOUND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(inbound_report_json("817790313235066447"));
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let reports: Vec<Result<InboundSmsReport, SdkError>> = client
+        .inbound_stream(
+            InboundReportsQueryParameters::new(),
+            std::time::Duration::from_millis(10),
+        )
+        .unwrap()
+        .take(2)
+        .collect()
+        .await;
+
+    assert_eq!(reports.len(), 2);
+    assert_eq!(
+        reports[0].as_ref().unwrap().text.as_deref(),
+        Some(DUMMY_TEXT)
+    );
+    assert_eq!(mock.hits_async().await, 2);
+}
+
+#[tokio::test]
+async fn test_inbound_stream_recovers_from_errors() {
+    let server = httpmock::MockServer::start_async().await;
+    let failing_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(PATH_GET_INBOUND);
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR", "text": "Something went wrong"}}}"#);
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let mut inbound = Box::pin(
+        client
+            .inbound_stream(
+                InboundReportsQueryParameters::new(),
+                std::time::Duration::from_millis(10),
+            )
+            .unwrap(),
+    );
+
+    let error = inbound.next().await.unwrap().unwrap_err();
+    assert!(error.is_retryable());
+
+    failing_mock.delete_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(PATH_GET_INBOUND);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(inbound_report_json("after-error"));
+    });
+
+    let report = tokio::time::timeout(std::time::Duration::from_secs(5), inbound.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(report.message_id.as_deref(), Some("after-error"));
+}
+
+#[tokio::test]
+async fn test_inbound_stream_ends_on_auth_error() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(PATH_GET_INBOUND);
+        then.status(401)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "UNAUTHORIZED", "text": "Invalid login details"}}}"#);
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let reports: Vec<_> = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client
+            .inbound_stream(
+                InboundReportsQueryParameters::new(),
+                std::time::Duration::from_millis(10),
+            )
+            .unwrap()
+            .collect::<Vec<_>>(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(reports.len(), 1);
+    assert!(matches!(
+        &reports[0],
+        Err(error) if error.error_kind() == ErrorKind::Auth
+    ));
+    mock.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn test_inbound_stream_big_limit() {
+    let client = SmsClient::with_configuration(test_configuration("https://some.url"));
+
+    let mut query_parameters = InboundReportsQueryParameters::new();
+    query_parameters.limit = Some(1001);
+
+    assert!(client
+        .inbound_stream(query_parameters, std::time::Duration::from_secs(1))
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_logs_valid() {
+    let expected_response = r#"
+    {
+      "results": [
+        {
+          "bulkId": "BULK-ID-123-xyz",
+          "messageId": "MESSAGE-ID-123-xyz",
+          "to": "41793026727",
+          "sentAt": "2019-11-09T16:00:00.000+0000",
+          "doneAt": "2019-11-09T16:00:00.000+0000",
+          "smsCount": 1,
+          "mccMnc": "22801",
+          "price": {
+            "pricePerMessage": 0.01,
+            "currency": "EUR"
+          },
+          "status": {
+            "groupId": 3,
+            "groupName": "DELIVERED",
+            "id": 5,
+            "name": "DELIVERED_TO_HANDSET",
+            "description": "Message delivered to handset"
+          },
           "error": {
             "groupId": 0,
             "groupName": "Ok",
@@ -695,6 +1469,39 @@ async fn test_logs_valid() {
     assert_eq!(response.body.results.unwrap().len(), 2usize);
 }
 
+#[tokio::test]
+async fn test_logs_platform_filters() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("entityId", "customer-1")
+            .query_param("applicationId", "app-1")
+            .query_param("campaignReferenceId", "campaign-1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"results": [{"messageId": "MESSAGE-ID-1", "entityId": "customer-1",
+                "applicationId": "app-1", "campaignReferenceId": "campaign-1"}]}"#,
+            );
+    });
+
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.entity_id = Some("customer-1".to_string());
+    query_parameters.application_id = Some("app-1".to_string());
+    query_parameters.campaign_reference_id = Some("campaign-1".to_string());
+
+    let response = client.logs(query_parameters).await.unwrap();
+
+    mock.assert_async().await;
+    let log = &response.body.results.unwrap()[0];
+    assert_eq!(log.entity_id.as_deref(), Some("customer-1"));
+    assert_eq!(log.application_id.as_deref(), Some("app-1"));
+    assert_eq!(log.campaign_reference_id.as_deref(), Some("campaign-1"));
+}
+
 #[tokio::test]
 async fn test_logs_big_limit() {
     let client = SmsClient::with_configuration(test_configuration("https://some.url"));
@@ -705,268 +1512,323 @@ async fn test_logs_big_limit() {
     assert!(client.logs(query_parameters).await.is_err());
 }
 
-#[tokio::test]
-async fn test_tfa_applications_valid() {
-    let expected_response = r#"
-    [
-        {
-        "applicationId": "0933F3BC087D2A617AC6DCB2EF5B8A61",
-        "name": "Test application BASIC 1",
-        "configuration": {
-        "pinAttempts": 10,
-        "allowMultiplePinVerifications": true,
-        "pinTimeToLive": "2h",
-        "verifyPinLimit": "1/3s",
-        "sendPinPerApplicationLimit": "10000/1d",
-        "sendPinPerPhoneNumberLimit": "3/1d"
-        },
-        "enabled": true
-        }
-    ]
-    "#;
+fn logs_page(logs: &[(&str, &str)]) -> String {
+    let results: Vec<serde_json::Value> = logs
+        .iter()
+        .map(
+            |(message_id, sent_at)| serde_json::json!({"messageId": message_id, "sentAt": sent_at}),
+        )
+        .collect();
 
-    let server = mock_json_endpoint(
-        httpmock::Method::GET,
-        PATH_GET_TFA_APPLICATIONS,
-        expected_response,
-        reqwest::StatusCode::OK,
-    )
-    .await;
+    serde_json::json!({ "results": results }).to_string()
+}
+
+#[tokio::test]
+async fn test_logs_paged() {
+    let server = httpmock::MockServer::start_async().await;
+    let first_page = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("limit", "2")
+            .query_param_exists("sentSince")
+            .matches(|request| {
+                !request
+                    .query_params
+                    .iter()
+                    .flatten()
+                    .any(|(name, _)| name == "sentUntil")
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&[
+                ("MESSAGE-ID-3", "2019-11-09T18:00:00.000+0000"),
+                ("MESSAGE-ID-2", "2019-11-09T17:00:00.000+0000"),
+            ]));
+    });
+    let second_page = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("sentUntil", "2019-11-09T17:00:00.000+0000");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&[
+                ("MESSAGE-ID-2", "2019-11-09T17:00:00.000+0000"),
+                ("MESSAGE-ID-1", "2019-11-09T16:00:00.000+0000"),
+            ]));
+    });
+    let last_page = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("sentUntil", "2019-11-09T16:00:00.000+0000");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&[(
+                "MESSAGE-ID-1",
+                "2019-11-09T16:00:00.000+0000",
+            )]));
+    });
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.limit = Some(2);
 
-    let response = client.tfa_applications().await.unwrap();
+    let logs: Vec<Log> = client
+        .logs_paged(query_parameters)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
 
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.len(), 1usize);
+    let message_ids: Vec<_> = logs.iter().map(|log| log.message_id.as_deref()).collect();
+    assert_eq!(
+        message_ids,
+        vec![
+            Some("MESSAGE-ID-3"),
+            Some("MESSAGE-ID-2"),
+            Some("MESSAGE-ID-1")
+        ]
+    );
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    last_page.assert_async().await;
 }
 
 #[tokio::test]
-async fn test_create_tfa_application_valid() {
-    let expected_response = r#"
-    {
-      "applicationId": "1234567",
-      "name": "Application name",
-      "configuration": {
-        "pinAttempts": 5,
-        "allowMultiplePinVerifications": true,
-        "pinTimeToLive": "10m",
-        "verifyPinLimit": "2/4s",
-        "sendPinPerApplicationLimit": "5000/12h",
-        "sendPinPerPhoneNumberLimit": "2/1d"
-      },
-      "enabled": true
-    }
-    "#;
-
-    let server = mock_json_endpoint(
-        httpmock::Method::POST,
-        PATH_CREATE_TFA_APPLICATION,
-        expected_response,
-        reqwest::StatusCode::OK,
-    )
-    .await;
+async fn test_logs_paged_same_millisecond() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .matches(|request| {
+                !request
+                    .query_params
+                    .iter()
+                    .flatten()
+                    .any(|(name, _)| name == "sentUntil")
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&[
+                ("MESSAGE-ID-3", "2019-11-09T18:00:00.000+0000"),
+                ("MESSAGE-ID-2", "2019-11-09T17:00:00.000+0000"),
+            ]));
+    });
+    let same_millisecond_page = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("sentUntil", "2019-11-09T17:00:00.000+0000")
+            .query_param("limit", "2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&[
+                ("MESSAGE-ID-2", "2019-11-09T17:00:00.000+0000"),
+                ("MESSAGE-ID-1", "2019-11-09T17:00:00.000+0000"),
+            ]));
+    });
+    let largest_page = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("sentUntil", "2019-11-09T17:00:00.000+0000")
+            .query_param("limit", "1000");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&[
+                ("MESSAGE-ID-2", "2019-11-09T17:00:00.000+0000"),
+                ("MESSAGE-ID-1", "2019-11-09T17:00:00.000+0000"),
+                ("MESSAGE-ID-0", "2019-11-09T17:00:00.000+0000"),
+            ]));
+    });
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.limit = Some(2);
 
-    let request_body = CreateTfaApplicationRequestBody::new("Application name");
-
-    let response = client.create_tfa_application(request_body).await.unwrap();
+    let logs: Vec<Log> = client
+        .logs_paged(query_parameters)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
 
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.application_id.unwrap(), "1234567");
+    let message_ids: Vec<_> = logs.iter().map(|log| log.message_id.as_deref()).collect();
+    assert_eq!(
+        message_ids,
+        vec![
+            Some("MESSAGE-ID-3"),
+            Some("MESSAGE-ID-2"),
+            Some("MESSAGE-ID-1"),
+            Some("MESSAGE-ID-0")
+        ]
+    );
+    same_millisecond_page.assert_async().await;
+    largest_page.assert_async().await;
 }
 
 #[tokio::test]
-async fn test_create_tfa_application_no_name() {
-    let client = SmsClient::with_configuration(test_configuration("https://some.url"));
-
-    let request_body = CreateTfaApplicationRequestBody::new("");
+async fn test_logs_paged_same_millisecond_overflow() {
+    let message_ids: Vec<String> = (0..MAX_LOGS_PER_PAGE)
+        .map(|i| format!("MESSAGE-ID-{}", i))
+        .collect();
+    let page: Vec<(&str, &str)> = message_ids
+        .iter()
+        .map(|message_id| (message_id.as_str(), "2019-11-09T17:00:00.000+0000"))
+        .collect();
+
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("sentUntil", "2019-11-09T17:00:00.000+0000");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(logs_page(&page));
+    });
 
-    assert!(client.create_tfa_application(request_body).await.is_err());
+    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.sent_until = Some("2019-11-09T17:00:00.000+0000".to_string());
+
+    let logs: Vec<_> = client.logs_paged(query_parameters).unwrap().collect().await;
+
+    assert_eq!(logs.len(), MAX_LOGS_PER_PAGE as usize + 1);
+    assert!(logs[..MAX_LOGS_PER_PAGE as usize].iter().all(Result::is_ok));
+    assert!(matches!(
+        logs.last(),
+        Some(Err(SdkError::LogsPageOverflow { sent_at, limit: MAX_LOGS_PER_PAGE }))
+            if sent_at == "2019-11-09T17:00:00.000+0000"
+    ));
+    mock.assert_hits_async(1).await;
 }
 
 #[tokio::test]
-async fn test_tfa_application_valid() {
-    let expected_response = r#"
-    {
-      "applicationId": "1234567",
-      "name": "Application name",
-      "configuration": {
-        "pinAttempts": 5,
-        "allowMultiplePinVerifications": true,
-        "pinTimeToLive": "10m",
-        "verifyPinLimit": "2/4s",
-        "sendPinPerApplicationLimit": "5000/12h",
-        "sendPinPerPhoneNumberLimit": "2/1d"
-      },
-      "enabled": true
-    }
-    "#;
-
+async fn test_logs_paged_error() {
     let server = mock_json_endpoint(
         httpmock::Method::GET,
-        &PATH_GET_TFA_APPLICATION.replace("{appId}", "1234567"),
-        expected_response,
-        reqwest::StatusCode::OK,
+        PATH_GET_LOGS,
+        r#"{"requestError": {"serviceException": {"messageId": "UNAUTHORIZED", "text": "Invalid login details"}}}"#,
+        reqwest::StatusCode::UNAUTHORIZED,
     )
     .await;
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let response = client.tfa_application("1234567").await.unwrap();
+    let logs: Vec<_> = client
+        .logs_paged(LogsQueryParameters::new())
+        .unwrap()
+        .collect()
+        .await;
 
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.application_id.unwrap(), "1234567");
+    assert_eq!(logs.len(), 1);
+    assert!(matches!(logs[0], Err(SdkError::ApiRequestError(_))));
 }
 
-#[tokio::test]
-async fn test_update_tfa_application_valid() {
-    let expected_response = r#"
+const COMPRESSED_LOGS: &str = r#"
+{
+  "results": [
     {
-      "applicationId": "1234567",
-      "name": "Application name 2",
-      "configuration": {
-        "pinAttempts": 5,
-        "allowMultiplePinVerifications": true,
-        "pinTimeToLive": "10m",
-        "verifyPinLimit": "2/4s",
-        "sendPinPerApplicationLimit": "5000/12h",
-        "sendPinPerPhoneNumberLimit": "2/1d"
-      },
-      "enabled": true
+      "bulkId": "BULK-ID-123-xyz",
+      "messageId": "MESSAGE-ID-123-xyz",
+      "to": "41793026727",
+      "smsCount": 1
     }
-    "#;
-
-    let server = mock_json_endpoint(
-        httpmock::Method::PUT,
-        &PATH_UPDATE_TFA_APPLICATION.replace("{appId}", "1234567"),
-        expected_response,
-        reqwest::StatusCode::OK,
-    )
-    .await;
-
-    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
-
-    let request_body = UpdateTfaApplicationRequestBody::new("Application name 2");
-
-    let response = client
-        .update_tfa_application("1234567", request_body)
-        .await
-        .unwrap();
-
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.application_id.unwrap(), "1234567");
+  ]
+}
+"#;
+
+async fn mock_compressed_logs(encoding: &str, body: Vec<u8>) -> httpmock::MockServer {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .header_exists("Accept-Encoding");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("content-encoding", encoding)
+            .body(body);
+    });
+
+    server
 }
 
+#[cfg(feature = "gzip")]
 #[tokio::test]
-async fn test_tfa_message_templates_valid() {
-    let expected_response = r#"
-    [
-      {
-        "messageId": "9C815F8AF3328",
-        "applicationId": "HJ675435E3A6EA43432G5F37A635KJ8B",
-        "pinPlaceholder": "{{pin}}",
-        "messageText": "Your PIN is {{pin}}.",
-        "pinLength": 4,
-        "pinType": "NUMERIC",
-        "language": "en",
-        "repeatDTMF": "1#",
-        "speechRate": 1
-      }
-    ]
-    "#;
+async fn test_logs_gzip_response() {
+    use std::io::Write;
 
-    let server = mock_json_endpoint(
-        httpmock::Method::GET,
-        &PATH_GET_TFA_MESSAGE_TEMPLATES.replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B"),
-        expected_response,
-        reqwest::StatusCode::OK,
-    )
-    .await;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(COMPRESSED_LOGS.as_bytes()).unwrap();
+    let server = mock_compressed_logs("gzip", encoder.finish().unwrap()).await;
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let response = client
-        .tfa_message_templates("HJ675435E3A6EA43432G5F37A635KJ8B")
-        .await
-        .unwrap();
+    let response = client.logs(LogsQueryParameters::new()).await.unwrap();
 
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.len(), 1usize);
+    assert_eq!(
+        response.body.results.unwrap()[0].message_id.as_deref(),
+        Some("MESSAGE-ID-123-xyz")
+    );
 }
 
+#[cfg(feature = "deflate")]
 #[tokio::test]
-async fn test_create_tfa_message_template_valid() {
-    let expected_response = r#"
-    {
-      "pinPlaceholder": "{{pin}}",
-      "messageText": "Your pin is {{pin}}",
-      "pinLength": 4,
-      "pinType": "ALPHANUMERIC",
-      "language": "en",
-      "senderId": "Infobip 2FA",
-      "repeatDTMF": "1#",
-      "speechRate": 1
-    }
-    "#;
+async fn test_logs_deflate_response() {
+    use std::io::Write;
 
-    let server = mock_json_endpoint(
-        httpmock::Method::POST,
-        &PATH_CREATE_TFA_MESSAGE_TEMPLATE.replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B"),
-        expected_response,
-        reqwest::StatusCode::OK,
-    )
-    .await;
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(COMPRESSED_LOGS.as_bytes()).unwrap();
+    let server = mock_compressed_logs("deflate", encoder.finish().unwrap()).await;
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let request_body =
-        CreateTfaMessageTemplateRequestBody::new("Your pin is {{pin}}", PinType::Alphanumeric, 4);
-    let application_id = "HJ675435E3A6EA43432G5F37A635KJ8B";
-
-    let response = client
-        .create_tfa_message_template(application_id, request_body)
-        .await
-        .unwrap();
+    let response = client.logs(LogsQueryParameters::new()).await.unwrap();
 
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.pin_length, 4);
+    assert_eq!(response.body.results.unwrap().len(), 1);
 }
 
 #[tokio::test]
-async fn test_create_tfa_message_template_empty_message_text() {
-    let client = SmsClient::with_configuration(test_configuration("https://some.url"));
+async fn test_logs_compression_disabled() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .matches(|request| {
+                !request
+                    .headers
+                    .iter()
+                    .flatten()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("accept-encoding"))
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(COMPRESSED_LOGS);
+    });
+
+    let client = SmsClient::with_configuration(
+        test_configuration(&server.base_url()).with_compression(false),
+    );
 
-    let request_body = CreateTfaMessageTemplateRequestBody::new("", PinType::Alphanumeric, 4);
+    let response = client.logs(LogsQueryParameters::new()).await.unwrap();
 
-    assert!(client
-        .create_tfa_message_template("some-app-id", request_body)
-        .await
-        .is_err());
+    mock.assert_async().await;
+    assert_eq!(response.body.results.unwrap().len(), 1);
 }
 
 #[tokio::test]
-async fn test_tfa_message_template_valid() {
+#[allow(deprecated)]
+async fn test_deprecated_tfa_applications_valid() {
     let expected_response = r#"
-    {
-      "pinPlaceholder": "{{pin}}",
-      "messageText": "Your pin is {{pin}}",
-      "pinLength": 4,
-      "pinType": "ALPHANUMERIC",
-      "language": "en",
-      "senderId": "Infobip 2FA",
-      "repeatDTMF": "1#",
-      "speechRate": 1
-    }
+    [
+        {
+        "applicationId": "0933F3BC087D2A617AC6DCB2EF5B8A61",
+        "name": "Test application BASIC 1",
+        "enabled": true
+        }
+    ]
     "#;
 
-    let endpoint_path = &PATH_GET_TFA_MESSAGE_TEMPLATE
-        .replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B")
-        .replace("{msgId}", "16A8B5FE2BCD6CA716A2D780CB3F3390");
     let server = mock_json_endpoint(
         httpmock::Method::GET,
-        endpoint_path,
+        crate::api::tfa::PATH_GET_TFA_APPLICATIONS,
         expected_response,
         reqwest::StatusCode::OK,
     )
@@ -974,39 +1836,30 @@ async fn test_tfa_message_template_valid() {
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let response = client
-        .tfa_message_template(
-            "HJ675435E3A6EA43432G5F37A635KJ8B",
-            "16A8B5FE2BCD6CA716A2D780CB3F3390",
-        )
-        .await
-        .unwrap();
+    let response = client.tfa_applications().await.unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.pin_length, 4);
+    assert_eq!(response.body.len(), 1);
 }
 
 #[tokio::test]
-async fn test_update_tfa_message_template_valid() {
+async fn tracked_domains_valid() {
     let expected_response = r#"
     {
-      "pinPlaceholder": "{{pin}}",
-      "messageText": "Your pin is {{pin}}",
-      "pinLength": 6,
-      "pinType": "ALPHANUMERIC",
-      "language": "en",
-      "senderId": "Infobip 2FA",
-      "repeatDTMF": "1#",
-      "speechRate": 1
+      "results": [
+        {
+          "domainName": "go.example.com",
+          "verified": true,
+          "default": true,
+          "createdAt": "2021-08-25T16:00:00.000+0000"
+        }
+      ]
     }
     "#;
 
-    let endpoint_path = &PATH_UPDATE_TFA_MESSAGE_TEMPLATE
-        .replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B")
-        .replace("{msgId}", "16A8B5FE2BCD6CA716A2D780CB3F3390");
     let server = mock_json_endpoint(
-        httpmock::Method::PUT,
-        endpoint_path,
+        httpmock::Method::GET,
+        PATH_GET_TRACKED_DOMAINS,
         expected_response,
         reqwest::StatusCode::OK,
     )
@@ -1014,36 +1867,27 @@ async fn test_update_tfa_message_template_valid() {
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let request_body =
-        UpdateTfaMessageTemplateRequestBody::new("Your pin is {{pin}}", PinType::Alphanumeric, 6);
-
-    let response = client
-        .update_tfa_message_template(
-            "HJ675435E3A6EA43432G5F37A635KJ8B",
-            "16A8B5FE2BCD6CA716A2D780CB3F3390",
-            request_body,
-        )
-        .await
-        .unwrap();
+    let response = client.tracked_domains().await.unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.pin_length, 6);
+    let domains = response.body.results.unwrap();
+    assert_eq!(domains[0].domain_name.as_deref(), Some("go.example.com"));
+    assert_eq!(domains[0].verified, Some(true));
 }
 
 #[tokio::test]
-async fn test_send_pin_over_sms_valid() {
+async fn add_tracked_domain_valid() {
     let expected_response = r#"
     {
-      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
-      "to": "41793026727",
-      "ncStatus": "NC_DESTINATION_REACHABLE",
-      "smsStatus": "MESSAGE_SENT"
+      "domainName": "go.example.com",
+      "verified": false,
+      "default": false
     }
     "#;
 
     let server = mock_json_endpoint(
         httpmock::Method::POST,
-        PATH_SEND_PIN_OVER_SMS,
+        PATH_ADD_TRACKED_DOMAIN,
         expected_response,
         reqwest::StatusCode::OK,
     )
@@ -1051,90 +1895,78 @@ async fn test_send_pin_over_sms_valid() {
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let query_parameters = SendPinOverSmsQueryParameters::default();
-    let request_body = SendPinOverSmsRequestBody::new(
-        "HJ675435E3A6EA43432G5F37A635KJ8B",
-        "16A8B5FE2BCD6CA716A2D780CB3F3390",
-        "5555555555",
-    );
-
     let response = client
-        .send_pin_over_sms(query_parameters, request_body)
+        .add_tracked_domain(AddTrackedDomainRequestBody::new("go.example.com"))
         .await
         .unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(
-        response.body.pin_id.unwrap(),
-        "9C817C6F8AF3D48F9FE553282AFA2B67"
-    );
+    assert_eq!(response.body.verified, Some(false));
 }
 
 #[tokio::test]
-async fn test_send_pin_over_sms_empty_app_id() {
+async fn add_tracked_domain_empty_name() {
     let client = SmsClient::with_configuration(test_configuration("https://some.url"));
 
-    let query_parameters = SendPinOverSmsQueryParameters::default();
-    let request_body =
-        SendPinOverSmsRequestBody::new("", "16A8B5FE2BCD6CA716A2D780CB3F3390", "5555555555");
-
     assert!(client
-        .send_pin_over_sms(query_parameters, request_body)
+        .add_tracked_domain(AddTrackedDomainRequestBody::new(""))
         .await
         .is_err());
 }
 
 #[tokio::test]
-async fn test_resend_pin_over_sms_valid() {
-    let expected_response = r#"
-    {
-      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
-      "to": "41793026727",
-      "ncStatus": "NC_DESTINATION_REACHABLE",
-      "smsStatus": "MESSAGE_SENT"
-    }
-    "#;
-
-    let endpoint_path =
-        &PATH_RESEND_PIN_OVER_SMS.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+async fn delete_tracked_domain_valid() {
+    let domain_name = "go.example.com";
+    let path = PATH_DELETE_TRACKED_DOMAIN.replace("{domainName}", domain_name);
 
     let server = mock_json_endpoint(
-        httpmock::Method::POST,
-        endpoint_path,
-        expected_response,
-        reqwest::StatusCode::OK,
+        httpmock::Method::DELETE,
+        path.as_str(),
+        "",
+        reqwest::StatusCode::NO_CONTENT,
     )
     .await;
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let request_body = ResendPinOverSmsRequestBody::new();
-
-    let response = client
-        .resend_pin_over_sms("9C817C6F8AF3D48F9FE553282AFA2B67", request_body)
-        .await
-        .unwrap();
+    let response = client.delete_tracked_domain(domain_name).await.unwrap();
 
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(
-        response.body.pin_id.unwrap(),
-        "9C817C6F8AF3D48F9FE553282AFA2B67"
-    );
+    assert_eq!(response.status, reqwest::StatusCode::NO_CONTENT);
 }
 
 #[tokio::test]
-async fn test_send_pin_over_voice_valid() {
+async fn click_reports_valid() {
     let expected_response = r#"
     {
-      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
-      "to": "41793026727",
-      "callStatus": "PENDING_ACCEPTED"
+      "results": [
+        {
+          "bulkId": "some-bulk-id",
+          "messageId": "message-1",
+          "to": "41793026727",
+          "url": "https://www.example.com/offer",
+          "shortUrl": "https://go.example.com/abc",
+          "clickedAt": "2021-08-25T16:00:00.000+0000",
+          "deviceType": "MOBILE"
+        },
+        {
+          "bulkId": "some-bulk-id",
+          "messageId": "message-1",
+          "to": "41793026727",
+          "clickedAt": "2021-08-25T16:05:00.000+0000"
+        },
+        {
+          "bulkId": "some-bulk-id",
+          "messageId": "message-2",
+          "to": "41793026834",
+          "clickedAt": "2021-08-25T16:10:00.000+0000"
+        }
+      ]
     }
     "#;
 
     let server = mock_json_endpoint(
-        httpmock::Method::POST,
-        PATH_SEND_PIN_OVER_VOICE,
+        httpmock::Method::GET,
+        PATH_GET_CLICK_REPORTS,
         expected_response,
         reqwest::StatusCode::OK,
     )
@@ -1142,165 +1974,66 @@ async fn test_send_pin_over_voice_valid() {
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let request_body = SendPinOverVoiceRequestBody::new(
-        "HJ675435E3A6EA43432G5F37A635KJ8B",
-        "16A8B5FE2BCD6CA716A2D780CB3F3390",
-        "5555555555",
-    );
+    let mut query_parameters = ClickReportsQueryParameters::new();
+    query_parameters.bulk_id = Some("some-bulk-id".to_string());
 
-    let response = client.send_pin_over_voice(request_body).await.unwrap();
+    let response = client.click_reports(query_parameters).await.unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.unique_clicks(), 2);
+    let reports = response.body.results.unwrap();
     assert_eq!(
-        response.body.pin_id.unwrap(),
-        "9C817C6F8AF3D48F9FE553282AFA2B67"
+        reports[0].clicked_at_time().unwrap().to_rfc3339(),
+        "2021-08-25T16:00:00+00:00"
     );
 }
 
 #[tokio::test]
-async fn test_send_pin_over_voice_empty_app_id() {
+async fn click_reports_big_limit() {
     let client = SmsClient::with_configuration(test_configuration("https://some.url"));
 
-    let request_body =
-        SendPinOverVoiceRequestBody::new("", "16A8B5FE2BCD6CA716A2D780CB3F3390", "5555555555");
+    let mut query_parameters = ClickReportsQueryParameters::new();
+    query_parameters.limit = Some(1001);
 
-    assert!(client.send_pin_over_voice(request_body).await.is_err());
+    assert!(client.click_reports(query_parameters).await.is_err());
 }
 
 #[tokio::test]
-async fn test_resend_pin_over_voice_valid() {
-    let expected_response = r#"
-    {
-      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
-      "to": "41793026727",
-      "callStatus": "PENDING_ACCEPTED"
-    }
-    "#;
-
-    let endpoint_path =
-        &PATH_RESEND_PIN_OVER_VOICE.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+async fn confirm_conversion_valid() {
+    let process_key = "some-process-key";
+    let path = PATH_CONFIRM_CONVERSION.replace("{processKey}", process_key);
 
     let server = mock_json_endpoint(
         httpmock::Method::POST,
-        endpoint_path,
-        expected_response,
+        path.as_str(),
+        "",
         reqwest::StatusCode::OK,
     )
     .await;
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let request_body = ResendPinOverVoiceRequestBody::new();
-
-    let response = client
-        .resend_pin_over_voice("9C817C6F8AF3D48F9FE553282AFA2B67", request_body)
-        .await
-        .unwrap();
+    let response = client.confirm_conversion(process_key).await.unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(
-        response.body.pin_id.unwrap(),
-        "9C817C6F8AF3D48F9FE553282AFA2B67"
-    );
 }
 
 #[tokio::test]
-async fn test_verify_phone_number_valid() {
-    let expected_response = r#"
-    {
-      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
-      "msisdn": "41793026727",
-      "verified": true,
-      "attemptsRemaining": 0
-    }
-    "#;
-
-    let endpoint_path =
-        &PATH_VERIFY_PHONE_NUMBER.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+async fn confirm_conversion_unknown_process_key() {
+    let process_key = "unknown-process-key";
+    let path = PATH_CONFIRM_CONVERSION.replace("{processKey}", process_key);
 
     let server = mock_json_endpoint(
         httpmock::Method::POST,
-        endpoint_path,
-        expected_response,
-        reqwest::StatusCode::OK,
-    )
-    .await;
-
-    let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
-
-    let request_body = VerifyPhoneNumberRequestBody::new("123456");
-
-    let response = client
-        .verify_phone_number("9C817C6F8AF3D48F9FE553282AFA2B67", request_body)
-        .await
-        .unwrap();
-
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(
-        response.body.pin_id.unwrap(),
-        "9C817C6F8AF3D48F9FE553282AFA2B67"
-    );
-}
-
-#[tokio::test]
-async fn test_verify_phone_number_no_pin() {
-    let client = SmsClient::with_configuration(test_configuration("https://some.url"));
-
-    let request_body = VerifyPhoneNumberRequestBody::new("");
-
-    assert!(client
-        .verify_phone_number("some-pin-id", request_body)
-        .await
-        .is_err());
-}
-
-#[tokio::test]
-async fn test_tfa_verification_status_valid() {
-    let expected_response = r#"
-    {
-      "verifications": [
-        {
-          "msisdn": "41793026727",
-          "verified": true,
-          "verifiedAt": 1418364366,
-          "sentAt": 1418364246
-        }
-      ]
-    }
-    "#;
-
-    let endpoint_path =
-        &PATH_GET_TFA_VERIFICATION_STATUS.replace("{appId}", "16A8B5FE2BCD6CA716A2D780CB3F3390");
-
-    let server = mock_json_endpoint(
-        httpmock::Method::GET,
-        endpoint_path,
-        expected_response,
-        reqwest::StatusCode::OK,
+        path.as_str(),
+        r#"{"requestError":{"serviceException":{"messageId":"NOT_FOUND","text":"Process key not found"}}}"#,
+        reqwest::StatusCode::NOT_FOUND,
     )
     .await;
 
     let client = SmsClient::with_configuration(test_configuration(&server.base_url()));
 
-    let query_parameters = TfaVerificationStatusQueryParameters::new("41793026727");
-
-    let response = client
-        .tfa_verification_status("16A8B5FE2BCD6CA716A2D780CB3F3390", query_parameters)
-        .await
-        .unwrap();
-
-    assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert_eq!(response.body.verifications.unwrap().len(), 1usize);
-}
-
-#[tokio::test]
-async fn test_tfa_verification_status_empty_msisdn() {
-    let client = SmsClient::with_configuration(test_configuration("https://some.url"));
-
-    let query_parameters = TfaVerificationStatusQueryParameters::new("");
+    let error = client.confirm_conversion(process_key).await.unwrap_err();
 
-    assert!(client
-        .tfa_verification_status("some-app-id", query_parameters)
-        .await
-        .is_err());
+    assert!(matches!(error, SdkError::ApiRequestError(_)));
 }
@@ -0,0 +1,889 @@
+use crate::api::{
+    tests::{mock_json_endpoint, test_configuration},
+    tfa::*,
+};
+use crate::model::tfa::*;
+
+const DUMMY_BASE_URL: &str = "https://some.url";
+
+#[tokio::test]
+async fn test_tfa_applications_valid() {
+    let expected_response = r#"
+    [
+        {
+        "applicationId": "0933F3BC087D2A617AC6DCB2EF5B8A61",
+        "name": "Test application BASIC 1",
+        "configuration": {
+        "pinAttempts": 10,
+        "allowMultiplePinVerifications": true,
+        "pinTimeToLive": "2h",
+        "verifyPinLimit": "1/3s",
+        "sendPinPerApplicationLimit": "10000/1d",
+        "sendPinPerPhoneNumberLimit": "3/1d"
+        },
+        "enabled": true
+        }
+    ]
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_TFA_APPLICATIONS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client.tfa_applications().await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.len(), 1usize);
+}
+
+const APPLICATIONS_RESPONSE: &str = r#"
+    [
+        { "applicationId": "A1", "name": "Login 2FA", "enabled": true },
+        { "applicationId": "A2", "name": "Password reset", "enabled": true },
+        { "applicationId": "A3", "name": "Old login", "enabled": false }
+    ]
+"#;
+
+#[tokio::test]
+async fn test_tfa_applications_filtered() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_TFA_APPLICATIONS,
+        APPLICATIONS_RESPONSE,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut filter = TfaApplicationsFilter::new();
+    filter.enabled = Some(true);
+    filter.name_contains = Some("LOGIN".to_string());
+
+    let response = client.tfa_applications_filtered(&filter).await.unwrap();
+
+    assert_eq!(response.body.len(), 1usize);
+    assert_eq!(response.body[0].application_id.as_deref(), Some("A1"));
+}
+
+#[tokio::test]
+async fn test_find_application_by_name() {
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_TFA_APPLICATIONS,
+        APPLICATIONS_RESPONSE,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let found = client
+        .find_application_by_name("Password reset")
+        .await
+        .unwrap();
+    assert_eq!(found.unwrap().application_id.as_deref(), Some("A2"));
+
+    let missing = client.find_application_by_name("Password").await.unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn test_create_tfa_application_valid() {
+    let expected_response = r#"
+    {
+      "applicationId": "1234567",
+      "name": "Application name",
+      "configuration": {
+        "pinAttempts": 5,
+        "allowMultiplePinVerifications": true,
+        "pinTimeToLive": "10m",
+        "verifyPinLimit": "2/4s",
+        "sendPinPerApplicationLimit": "5000/12h",
+        "sendPinPerPhoneNumberLimit": "2/1d"
+      },
+      "enabled": true
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_CREATE_TFA_APPLICATION,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = CreateTfaApplicationRequestBody::new("Application name");
+
+    let response = client.create_tfa_application(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.application_id.unwrap(), "1234567");
+}
+
+#[tokio::test]
+async fn test_create_tfa_application_no_name() {
+    let client = TfaClient::with_configuration(test_configuration("https://some.url"));
+
+    let request_body = CreateTfaApplicationRequestBody::new("");
+
+    assert!(client.create_tfa_application(request_body).await.is_err());
+}
+
+#[tokio::test]
+async fn test_tfa_application_valid() {
+    let expected_response = r#"
+    {
+      "applicationId": "1234567",
+      "name": "Application name",
+      "configuration": {
+        "pinAttempts": 5,
+        "allowMultiplePinVerifications": true,
+        "pinTimeToLive": "10m",
+        "verifyPinLimit": "2/4s",
+        "sendPinPerApplicationLimit": "5000/12h",
+        "sendPinPerPhoneNumberLimit": "2/1d"
+      },
+      "enabled": true
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        &PATH_GET_TFA_APPLICATION.replace("{appId}", "1234567"),
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .tfa_application(&AppId::from("1234567"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.application_id.unwrap(), "1234567");
+}
+
+#[tokio::test]
+async fn test_update_tfa_application_valid() {
+    let expected_response = r#"
+    {
+      "applicationId": "1234567",
+      "name": "Application name 2",
+      "configuration": {
+        "pinAttempts": 5,
+        "allowMultiplePinVerifications": true,
+        "pinTimeToLive": "10m",
+        "verifyPinLimit": "2/4s",
+        "sendPinPerApplicationLimit": "5000/12h",
+        "sendPinPerPhoneNumberLimit": "2/1d"
+      },
+      "enabled": true
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        &PATH_UPDATE_TFA_APPLICATION.replace("{appId}", "1234567"),
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = UpdateTfaApplicationRequestBody::new("Application name 2");
+
+    let response = client
+        .update_tfa_application(&AppId::from("1234567"), request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.application_id.unwrap(), "1234567");
+}
+
+#[tokio::test]
+async fn test_tfa_message_templates_valid() {
+    let expected_response = r#"
+    [
+      {
+        "messageId": "9C815F8AF3328",
+        "applicationId": "HJ675435E3A6EA43432G5F37A635KJ8B",
+        "pinPlaceholder": "{{pin}}",
+        "messageText": "Your PIN is {{pin}}.",
+        "pinLength": 4,
+        "pinType": "NUMERIC",
+        "language": "en",
+        "repeatDTMF": "1#",
+        "speechRate": 1
+      }
+    ]
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        &PATH_GET_TFA_MESSAGE_TEMPLATES.replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B"),
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .tfa_message_templates(&AppId::from("HJ675435E3A6EA43432G5F37A635KJ8B"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.len(), 1usize);
+}
+
+#[tokio::test]
+async fn test_create_tfa_message_template_valid() {
+    let expected_response = r#"
+    {
+      "pinPlaceholder": "{{pin}}",
+      "messageText": "Your pin is {{pin}}",
+      "pinLength": 4,
+      "pinType": "ALPHANUMERIC",
+      "language": "en",
+      "senderId": "Infobip 2FA",
+      "repeatDTMF": "1#",
+      "speechRate": 1
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        &PATH_CREATE_TFA_MESSAGE_TEMPLATE.replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B"),
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body =
+        CreateTfaMessageTemplateRequestBody::new("Your pin is {{pin}}", PinType::Alphanumeric, 4);
+    let application_id = "HJ675435E3A6EA43432G5F37A635KJ8B";
+
+    let response = client
+        .create_tfa_message_template(&AppId::from(application_id), request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.pin_length, 4);
+}
+
+#[tokio::test]
+async fn test_create_tfa_message_template_empty_message_text() {
+    let client = TfaClient::with_configuration(test_configuration("https://some.url"));
+
+    let request_body = CreateTfaMessageTemplateRequestBody::new("", PinType::Alphanumeric, 4);
+
+    assert!(client
+        .create_tfa_message_template(&AppId::from("some-app-id"), request_body)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_tfa_message_template_valid() {
+    let expected_response = r#"
+    {
+      "pinPlaceholder": "{{pin}}",
+      "messageText": "Your pin is {{pin}}",
+      "pinLength": 4,
+      "pinType": "ALPHANUMERIC",
+      "language": "en",
+      "senderId": "Infobip 2FA",
+      "repeatDTMF": "1#",
+      "speechRate": 1
+    }
+    "#;
+
+    let endpoint_path = &PATH_GET_TFA_MESSAGE_TEMPLATE
+        .replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B")
+        .replace("{msgId}", "16A8B5FE2BCD6CA716A2D780CB3F3390");
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = client
+        .tfa_message_template(
+            &AppId::from("HJ675435E3A6EA43432G5F37A635KJ8B"),
+            &TemplateId::from("16A8B5FE2BCD6CA716A2D780CB3F3390"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.pin_length, 4);
+}
+
+#[tokio::test]
+async fn test_update_tfa_message_template_valid() {
+    let expected_response = r#"
+    {
+      "pinPlaceholder": "{{pin}}",
+      "messageText": "Your pin is {{pin}}",
+      "pinLength": 6,
+      "pinType": "ALPHANUMERIC",
+      "language": "en",
+      "senderId": "Infobip 2FA",
+      "repeatDTMF": "1#",
+      "speechRate": 1
+    }
+    "#;
+
+    let endpoint_path = &PATH_UPDATE_TFA_MESSAGE_TEMPLATE
+        .replace("{appId}", "HJ675435E3A6EA43432G5F37A635KJ8B")
+        .replace("{msgId}", "16A8B5FE2BCD6CA716A2D780CB3F3390");
+    let server = mock_json_endpoint(
+        httpmock::Method::PUT,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body =
+        UpdateTfaMessageTemplateRequestBody::new("Your pin is {{pin}}", PinType::Alphanumeric, 6);
+
+    let response = client
+        .update_tfa_message_template(
+            &AppId::from("HJ675435E3A6EA43432G5F37A635KJ8B"),
+            &TemplateId::from("16A8B5FE2BCD6CA716A2D780CB3F3390"),
+            request_body,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.pin_length, 6);
+}
+
+#[tokio::test]
+async fn test_send_pin_over_sms_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "41793026727",
+      "ncStatus": "NC_DESTINATION_REACHABLE",
+      "smsStatus": "MESSAGE_SENT"
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_PIN_OVER_SMS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let query_parameters = SendPinOverSmsQueryParameters::default();
+    let request_body = SendPinOverSmsRequestBody::new(
+        "HJ675435E3A6EA43432G5F37A635KJ8B",
+        "16A8B5FE2BCD6CA716A2D780CB3F3390",
+        "5555555555",
+    );
+
+    let response = client
+        .send_pin_over_sms(query_parameters, request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.pin_id.unwrap(),
+        "9C817C6F8AF3D48F9FE553282AFA2B67"
+    );
+}
+
+#[tokio::test]
+async fn test_send_pin_over_sms_platform_ids() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_PIN_OVER_SMS)
+            .json_body(serde_json::json!({
+                "applicationId": "HJ675435E3A6EA43432G5F37A635KJ8B",
+                "entityId": "customer-1",
+                "messageId": "16A8B5FE2BCD6CA716A2D780CB3F3390",
+                "to": "5555555555"
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"pinId": "9C817C6F8AF3D48F9FE553282AFA2B67"}"#);
+    });
+
+    let client = TfaClient::with_configuration(
+        test_configuration(&server.base_url())
+            .with_application_id("marketing")
+            .with_entity_id("customer-1"),
+    );
+
+    let request_body = SendPinOverSmsRequestBody::new(
+        "HJ675435E3A6EA43432G5F37A635KJ8B",
+        "16A8B5FE2BCD6CA716A2D780CB3F3390",
+        "5555555555",
+    );
+
+    client
+        .send_pin_over_sms(SendPinOverSmsQueryParameters::default(), request_body)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_pin_over_sms_bulk_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "41793026727",
+      "ncStatus": "NC_DESTINATION_REACHABLE",
+      "smsStatus": "MESSAGE_SENT"
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_PIN_OVER_SMS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_bodies = ["41793026727", "41793026834", "41793026999"].map(|to| {
+        SendPinOverSmsRequestBody::new(
+            "HJ675435E3A6EA43432G5F37A635KJ8B",
+            "16A8B5FE2BCD6CA716A2D780CB3F3390",
+            to,
+        )
+    });
+
+    let results = client
+        .send_pin_over_sms_bulk(SendPinOverSmsQueryParameters::default(), request_bodies, 2)
+        .await;
+
+    assert_eq!(results.len(), 3);
+    for result in results {
+        assert_eq!(result.unwrap().status, reqwest::StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn test_send_pin_over_sms_bulk_keeps_failures_per_recipient() {
+    let client = TfaClient::with_configuration(test_configuration(DUMMY_BASE_URL));
+
+    let request_bodies = vec![
+        SendPinOverSmsRequestBody::new("", "16A8B5FE2BCD6CA716A2D780CB3F3390", "41793026727"),
+        SendPinOverSmsRequestBody::new("", "16A8B5FE2BCD6CA716A2D780CB3F3390", "41793026834"),
+    ];
+
+    let results = client
+        .send_pin_over_sms_bulk(SendPinOverSmsQueryParameters::default(), request_bodies, 0)
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(Result::is_err));
+}
+
+#[tokio::test]
+async fn test_send_pin_over_sms_empty_app_id() {
+    let client = TfaClient::with_configuration(test_configuration("https://some.url"));
+
+    let query_parameters = SendPinOverSmsQueryParameters::default();
+    let request_body =
+        SendPinOverSmsRequestBody::new("", "16A8B5FE2BCD6CA716A2D780CB3F3390", "5555555555");
+
+    assert!(client
+        .send_pin_over_sms(query_parameters, request_body)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_resend_pin_over_sms_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "41793026727",
+      "ncStatus": "NC_DESTINATION_REACHABLE",
+      "smsStatus": "MESSAGE_SENT"
+    }
+    "#;
+
+    let endpoint_path =
+        &PATH_RESEND_PIN_OVER_SMS.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = ResendPinOverSmsRequestBody::new();
+
+    let response = client
+        .resend_pin_over_sms(
+            &PinId::from("9C817C6F8AF3D48F9FE553282AFA2B67"),
+            request_body,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.pin_id.unwrap(),
+        "9C817C6F8AF3D48F9FE553282AFA2B67"
+    );
+}
+
+#[tokio::test]
+async fn test_send_pin_over_voice_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "41793026727",
+      "callStatus": "PENDING_ACCEPTED"
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_PIN_OVER_VOICE,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = SendPinOverVoiceRequestBody::new(
+        "HJ675435E3A6EA43432G5F37A635KJ8B",
+        "16A8B5FE2BCD6CA716A2D780CB3F3390",
+        "5555555555",
+    );
+
+    let response = client.send_pin_over_voice(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.pin_id.unwrap(),
+        "9C817C6F8AF3D48F9FE553282AFA2B67"
+    );
+}
+
+#[tokio::test]
+async fn test_send_pin_over_voice_empty_app_id() {
+    let client = TfaClient::with_configuration(test_configuration("https://some.url"));
+
+    let request_body =
+        SendPinOverVoiceRequestBody::new("", "16A8B5FE2BCD6CA716A2D780CB3F3390", "5555555555");
+
+    assert!(client.send_pin_over_voice(request_body).await.is_err());
+}
+
+#[tokio::test]
+async fn test_resend_pin_over_voice_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "41793026727",
+      "callStatus": "PENDING_ACCEPTED"
+    }
+    "#;
+
+    let endpoint_path =
+        &PATH_RESEND_PIN_OVER_VOICE.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = ResendPinOverVoiceRequestBody::new();
+
+    let response = client
+        .resend_pin_over_voice(
+            &PinId::from("9C817C6F8AF3D48F9FE553282AFA2B67"),
+            request_body,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.pin_id.unwrap(),
+        "9C817C6F8AF3D48F9FE553282AFA2B67"
+    );
+}
+
+#[tokio::test]
+async fn test_send_pin_over_email_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "john.smith@example.com",
+      "emailStatus": {
+        "name": "PENDING_ACCEPTED",
+        "description": "Message accepted, pending for delivery."
+      }
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_PIN_OVER_EMAIL,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = SendPinOverEmailRequestBody::new(
+        "HJ675435E3A6EA43432G5F37A635KJ8B",
+        "16A8B5FE2BCD6CA716A2D780CB3F3390",
+        "company@example.com",
+        "john.smith@example.com",
+    );
+
+    let response = client.send_pin_over_email(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.pin_id.unwrap(),
+        "9C817C6F8AF3D48F9FE553282AFA2B67"
+    );
+    assert_eq!(
+        response.body.email_status.unwrap().name.unwrap(),
+        "PENDING_ACCEPTED"
+    );
+}
+
+#[tokio::test]
+async fn test_send_pin_over_email_invalid_from() {
+    let client = TfaClient::with_configuration(test_configuration(DUMMY_BASE_URL));
+
+    let request_body = SendPinOverEmailRequestBody::new(
+        "HJ675435E3A6EA43432G5F37A635KJ8B",
+        "16A8B5FE2BCD6CA716A2D780CB3F3390",
+        "company",
+        "john.smith@example.com",
+    );
+
+    assert!(client.send_pin_over_email(request_body).await.is_err());
+}
+
+#[tokio::test]
+async fn test_resend_pin_over_email_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "to": "john.smith@example.com",
+      "emailStatus": {
+        "name": "PENDING_ACCEPTED",
+        "description": "Message accepted, pending for delivery."
+      }
+    }
+    "#;
+
+    let endpoint_path =
+        &PATH_RESEND_PIN_OVER_EMAIL.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = ResendPinOverEmailRequestBody::new();
+
+    let response = client
+        .resend_pin_over_email(
+            &PinId::from("9C817C6F8AF3D48F9FE553282AFA2B67"),
+            request_body,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.to.unwrap(), "john.smith@example.com");
+}
+
+#[tokio::test]
+async fn test_verify_phone_number_valid() {
+    let expected_response = r#"
+    {
+      "pinId": "9C817C6F8AF3D48F9FE553282AFA2B67",
+      "msisdn": "41793026727",
+      "verified": true,
+      "attemptsRemaining": 0
+    }
+    "#;
+
+    let endpoint_path =
+        &PATH_VERIFY_PHONE_NUMBER.replace("{pinId}", "9C817C6F8AF3D48F9FE553282AFA2B67");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = VerifyPhoneNumberRequestBody::new("123456");
+
+    let response = client
+        .verify_phone_number(
+            &PinId::from("9C817C6F8AF3D48F9FE553282AFA2B67"),
+            request_body,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.pin_id.unwrap(),
+        "9C817C6F8AF3D48F9FE553282AFA2B67"
+    );
+}
+
+#[tokio::test]
+async fn test_verify_phone_number_no_pin() {
+    let client = TfaClient::with_configuration(test_configuration("https://some.url"));
+
+    let request_body = VerifyPhoneNumberRequestBody::new("");
+
+    assert!(client
+        .verify_phone_number(&PinId::from("some-pin-id"), request_body)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_tfa_verification_status_valid() {
+    let expected_response = r#"
+    {
+      "verifications": [
+        {
+          "msisdn": "41793026727",
+          "verified": true,
+          "verifiedAt": 1418364366,
+          "sentAt": 1418364246
+        }
+      ]
+    }
+    "#;
+
+    let endpoint_path =
+        &PATH_GET_TFA_VERIFICATION_STATUS.replace("{appId}", "16A8B5FE2BCD6CA716A2D780CB3F3390");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        endpoint_path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+
+    let query_parameters = TfaVerificationStatusQueryParameters::new("41793026727");
+
+    let response = client
+        .tfa_verification_status(
+            &AppId::from("16A8B5FE2BCD6CA716A2D780CB3F3390"),
+            query_parameters,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.verifications.unwrap().len(), 1usize);
+}
+
+#[tokio::test]
+async fn test_tfa_verification_status_sends_query_parameters() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_TFA_VERIFICATION_STATUS.replace("{appId}", "some-app-id"))
+            .query_param("msisdn", "41793026727")
+            .query_param("verified", "true");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"verifications": []}"#);
+    });
+
+    let client = TfaClient::with_configuration(test_configuration(&server.base_url()));
+    let mut query_parameters = TfaVerificationStatusQueryParameters::new("41793026727");
+    query_parameters.verified = Some(true);
+
+    client
+        .tfa_verification_status(&AppId::from("some-app-id"), query_parameters)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_tfa_verification_status_empty_msisdn() {
+    let client = TfaClient::with_configuration(test_configuration("https://some.url"));
+
+    let query_parameters = TfaVerificationStatusQueryParameters::new("");
+
+    assert!(client
+        .tfa_verification_status(&AppId::from("some-app-id"), query_parameters)
+        .await
+        .is_err());
+}
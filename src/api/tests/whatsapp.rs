@@ -1,6 +1,7 @@
 use crate::api::tests::{mock_json_endpoint, test_configuration};
 use crate::api::whatsapp::*;
-use crate::api::SdkError::ApiRequestError;
+use crate::api::SdkError::{self, ApiRequestError};
+use crate::model::status::StatusGroup;
 use crate::model::whatsapp::*;
 
 fn dummy_send_text_request_body() -> SendTextRequestBody {
@@ -56,6 +57,31 @@ async fn send_text_valid() {
     assert!(!response.body.message_id.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn send_simple_text_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEXT)
+            .json_body_partial(
+                r#"{"from": "44444444444", "to": "55555555555", "content": {"text": "Hello"}}"#,
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"to": "55555555555", "messageCount": 1, "messageId": "some-id"}"#);
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client
+        .send_simple_text("44444444444", "55555555555", "Hello")
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.body.message_id.as_deref(), Some("some-id"));
+}
+
 #[tokio::test]
 async fn send_text_api_error() {
     let request_body =
@@ -96,6 +122,7 @@ async fn send_text_api_error() {
             assert_eq!(
                 api_error
                     .details
+                    .unwrap()
                     .request_error
                     .service_exception
                     .message_id
@@ -144,6 +171,7 @@ async fn send_text_api_error_401() {
             assert_eq!(
                 api_error
                     .details
+                    .unwrap()
                     .request_error
                     .service_exception
                     .message_id
@@ -192,6 +220,7 @@ async fn send_text_api_error_429() {
             assert_eq!(
                 api_error
                     .details
+                    .unwrap()
                     .request_error
                     .service_exception
                     .message_id
@@ -686,6 +715,226 @@ async fn send_contact_valid() {
     assert!(!response.body.message_id.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn send_interactive_flow_valid() {
+    let request_body: SendInteractiveFlowRequestBody = serde_json::from_str(
+        r#"
+            {
+              "from": "441134960000",
+              "to": "441134960001",
+              "messageId": "a28dd97c-1ffb-4fcf-99f1-0b557ed381da",
+              "content": {
+                "body": {
+                  "text": "Book your appointment"
+                },
+                "action": {
+                  "mode": "PUBLISHED",
+                  "flowMessageVersion": 3,
+                  "flowToken": "some-token",
+                  "flowId": "1234567890",
+                  "callToActionButton": "Book now",
+                  "flowAction": "NAVIGATE",
+                  "flowActionPayload": {
+                    "screen": "BOOKING",
+                    "data": {
+                      "slots": 3
+                    }
+                  }
+                },
+                "header": {
+                  "type": "TEXT",
+                  "text": "Appointments"
+                },
+                "footer": {
+                  "text": "Footer text"
+                }
+              },
+              "callbackData": "Callback data",
+              "notifyUrl": "https://www.example.com/whatsapp"
+            }
+        "#,
+    )
+    .unwrap();
+
+    let expected_response = r#"
+        {
+          "to": "441134960001",
+          "messageCount": 1,
+          "messageId": "a28dd97c-1ffb-4fcf-99f1-0b557ed381da",
+          "status": {
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 7,
+            "name": "PENDING_ENROUTE",
+            "description": "Message sent to next instance"
+          }
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_INTERACTIVE_FLOW,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client.send_interactive_flow(request_body).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(!response.body.message_id.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn send_interactive_order_details_valid() {
+    let request_body: SendInteractiveOrderDetailsRequestBody = serde_json::from_str(
+        r#"
+            {
+              "from": "441134960000",
+              "to": "441134960001",
+              "messageId": "a28dd97c-1ffb-4fcf-99f1-0b557ed381da",
+              "content": {
+                "body": {
+                  "text": "Your order"
+                },
+                "action": {
+                  "displayText": "Review and Pay",
+                  "type": "PHYSICAL_GOODS",
+                  "payment": {
+                    "paymentConfiguration": "payment-configuration",
+                    "referenceId": "order-1234"
+                  },
+                  "currency": "INR",
+                  "totalAmount": {
+                    "value": 105000,
+                    "offset": 100
+                  },
+                  "order": {
+                    "catalogId": "1234567890",
+                    "items": [
+                      {
+                        "retailerId": "shirt-1",
+                        "name": "T-shirt",
+                        "amount": {
+                          "value": 50000,
+                          "offset": 100
+                        },
+                        "quantity": 2
+                      }
+                    ],
+                    "subtotal": {
+                      "value": 100000,
+                      "offset": 100
+                    },
+                    "shipping": {
+                      "value": 5000,
+                      "offset": 100,
+                      "description": "Express delivery"
+                    }
+                  }
+                },
+                "footer": {
+                  "text": "Footer text"
+                }
+              },
+              "callbackData": "Callback data"
+            }
+        "#,
+    )
+    .unwrap();
+
+    let expected_response = r#"
+        {
+          "to": "441134960001",
+          "messageCount": 1,
+          "messageId": "a28dd97c-1ffb-4fcf-99f1-0b557ed381da",
+          "status": {
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 7,
+            "name": "PENDING_ENROUTE",
+            "description": "Message sent to next instance"
+          }
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_INTERACTIVE_ORDER_DETAILS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client
+        .send_interactive_order_details(request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(!response.body.message_id.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn send_interactive_order_status_valid() {
+    let request_body: SendInteractiveOrderStatusRequestBody = serde_json::from_str(
+        r#"
+            {
+              "from": "441134960000",
+              "to": "441134960001",
+              "content": {
+                "body": {
+                  "text": "Your order is on its way"
+                },
+                "action": {
+                  "referenceId": "order-1234",
+                  "status": "SHIPPED",
+                  "description": "Shipped with express delivery"
+                }
+              }
+            }
+        "#,
+    )
+    .unwrap();
+
+    let expected_response = r#"
+        {
+          "to": "441134960001",
+          "messageCount": 1,
+          "messageId": "a28dd97c-1ffb-4fcf-99f1-0b557ed381da",
+          "status": {
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 7,
+            "name": "PENDING_ENROUTE",
+            "description": "Message sent to next instance"
+          }
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_INTERACTIVE_ORDER_STATUS,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client
+        .send_interactive_order_status(request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(!response.body.message_id.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn send_template_valid() {
     let request_body: SendTemplateRequestBody = serde_json::from_str(
@@ -754,101 +1003,513 @@ async fn send_template_valid() {
     assert!(!response.body.messages.unwrap().is_empty());
 }
 
-#[tokio::test]
-async fn templates_valid() {
-    let expected_response = r#"
-        {
-          "templates": [
-            {
-              "id": "111",
-              "businessAccountId": 222,
-              "name": "media_template_with_buttons",
-              "language": "en",
-              "status": "APPROVED",
-              "category": "ACCOUNT_UPDATE",
-              "structure": {
-                "header": {
-                  "format": "IMAGE"
-                },
-                "body": {
-                  "text": "example {{1}} body"
-                },
-                "footer": {
-                  "text": "exampleFooter"
-                },
-                "buttons": [
-                  {
-                    "text": "Dial 911",
-                    "phoneNumber": "911",
-                    "type": "PHONE_NUMBER"
-                  },
-                  {
-                    "text": "Visit our website",
-                    "url": "https://www.infobip.com",
-                    "type": "URL"
-                  }
-                ],
-                "type": "MEDIA"
-              }
-            }
-          ]
-        }
-    "#;
+fn dummy_template_message(to: &str) -> FailoverMessage {
+    let data = TemplateData::new(TemplateBodyContent::new(vec![]));
+    let content = TemplateContent::new("template_name", data, TemplateLanguage::EnGb);
 
-    let sender = "441134960000";
-    let path = PATH_GET_TEMPLATES.replace("{sender}", &sender);
+    FailoverMessage::new("441134960000", to, content)
+}
+
+const CHECK_CONTACTS_RESPONSE: &str = r#"
+    {
+      "contacts": [
+        {"input": "441134960001", "status": "VALID", "waId": "441134960001"},
+        {"input": "441134960002", "status": "INVALID"}
+      ]
+    }
+"#;
 
+#[tokio::test]
+async fn check_contacts_valid() {
+    let path = PATH_CHECK_CONTACTS.replace("{sender}", "441134960000");
     let server = mock_json_endpoint(
-        httpmock::Method::GET,
+        httpmock::Method::POST,
         &path,
-        expected_response,
+        CHECK_CONTACTS_RESPONSE,
         reqwest::StatusCode::OK,
     )
     .await;
 
     let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
 
-    let response = wa_client.templates(sender).await.unwrap();
+    let request_body =
+        CheckContactsRequestBody::new(vec!["441134960001".to_string(), "441134960002".to_string()]);
+
+    let response = wa_client
+        .check_contacts("441134960000", request_body)
+        .await
+        .unwrap();
 
     assert_eq!(response.status, reqwest::StatusCode::OK);
-    assert!(!response.body.templates.unwrap().is_empty());
+    assert_eq!(response.body.enabled(), vec!["441134960001"]);
+    assert_eq!(response.body.not_enabled(), vec!["441134960002"]);
 }
 
 #[tokio::test]
-async fn create_template_valid() {
-    let request_body: CreateTemplateRequestBody = serde_json::from_str(
-        r#"
-            {
-              "name": "media_template_with_quick_reply_buttons",
-              "language": "en",
-              "category": "OTP",
-              "structure": {
-                "body": {
-                  "text": "body {{1}} content",
-                  "examples": [
-                    "example"
-                  ]
-                },
-                "buttons": [
-                  {
-                    "text": "quick reply 1",
-                    "type": "QUICK_REPLY"
-                  },
-                  {
-                    "text": "quick reply 2",
-                    "type": "QUICK_REPLY"
-                  },
-                  {
-                    "text": "quick reply 3",
-                    "type": "QUICK_REPLY"
-                  }
-                ],
-                "type": "MEDIA"
-              }
-            }
-        "#,
-    )
-    .unwrap();
+async fn check_contacts_batch_valid() {
+    let path = PATH_CHECK_CONTACTS.replace("{sender}", "441134960000");
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path(path.as_str());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CHECK_CONTACTS_RESPONSE);
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let contacts = (1..=3).map(|i| format!("44113496000{}", i));
+
+    let response = wa_client
+        .check_contacts_batch("441134960000", contacts, 2)
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(2).await;
+    assert_eq!(response.body.contacts.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn check_contacts_empty() {
+    let wa_client = WhatsAppClient::with_configuration(test_configuration("https://some.url"));
+
+    let error = wa_client
+        .check_contacts("441134960000", CheckContactsRequestBody::new(vec![]))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+fn sent_template_response(destinations: &[&str]) -> String {
+    let messages: Vec<String> = destinations
+        .iter()
+        .map(|to| {
+            format!(
+                r#"{{
+                  "to": "{to}",
+                  "messageCount": 1,
+                  "messageId": "message-{to}",
+                  "status": {{
+                    "groupId": 1,
+                    "groupName": "PENDING",
+                    "id": 7,
+                    "name": "PENDING_ENROUTE",
+                    "description": "Message sent to next instance"
+                  }}
+                }}"#
+            )
+        })
+        .collect();
+
+    format!(
+        r#"{{"messages": [{}], "bulkId": "bulk-{}"}}"#,
+        messages.join(","),
+        destinations[0]
+    )
+}
+
+#[tokio::test]
+async fn send_template_batch_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let first_chunk = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEMPLATE)
+            .body_contains("441134960001");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(sent_template_response(&["441134960001", "441134960002"]));
+    });
+    let second_chunk = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEMPLATE)
+            .body_contains("441134960003");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(sent_template_response(&["441134960003"]));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let destinations = ["441134960001", "441134960002", "441134960003"];
+    let request_body = SendTemplateRequestBody::new(
+        destinations
+            .iter()
+            .map(|to| dummy_template_message(to))
+            .collect(),
+    );
+
+    let response = wa_client
+        .send_template_batch(request_body.clone(), 2)
+        .await
+        .unwrap();
+
+    first_chunk.assert_async().await;
+    second_chunk.assert_async().await;
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.messages.len(), destinations.len());
+    assert_eq!(
+        response.body.bulk_ids,
+        vec!["bulk-441134960001", "bulk-441134960003"]
+    );
+    for (index, to) in destinations.iter().enumerate() {
+        assert_eq!(
+            response.body.message_at(index).unwrap().to.as_deref(),
+            Some(*to)
+        );
+    }
+    assert!(response.body.correlate(&request_body).is_ok());
+    assert!(response
+        .body
+        .message_by_id("message-441134960003")
+        .is_some());
+}
+
+#[tokio::test]
+async fn send_template_batch_missing_results() {
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_TEMPLATE,
+        &sent_template_response(&["441134960001"]),
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = SendTemplateRequestBody::new(vec![
+        dummy_template_message("441134960001"),
+        dummy_template_message("441134960002"),
+        dummy_template_message("441134960003"),
+    ]);
+
+    let response = wa_client
+        .send_template_batch(request_body.clone(), 2)
+        .await
+        .unwrap();
+
+    assert_eq!(response.body.messages.len(), 2);
+    assert_eq!(
+        response.body.correlate(&request_body),
+        Err(CorrelationError::CountMismatch {
+            sent: 3,
+            received: 2
+        })
+    );
+}
+
+#[tokio::test]
+async fn send_template_batch_partial_failure() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEMPLATE)
+            .body_contains("441134960001");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(sent_template_response(&["441134960001", "441134960002"]));
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEMPLATE)
+            .body_contains("441134960003");
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(r#"{"requestError": {"serviceException": {"messageId": "GENERAL_ERROR", "text": "Something went wrong."}}}"#);
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let request_body = SendTemplateRequestBody::new(vec![
+        dummy_template_message("441134960001"),
+        dummy_template_message("441134960002"),
+        dummy_template_message("441134960003"),
+    ]);
+
+    let error = wa_client
+        .send_template_batch(request_body, 2)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.sent, 2);
+    assert_eq!(error.accepted.messages.len(), 2);
+    assert_eq!(error.accepted.bulk_ids, vec!["bulk-441134960001"]);
+    assert!(matches!(error.source, ApiRequestError(_)));
+}
+
+#[tokio::test]
+async fn send_template_batch_invalid_message() {
+    let wa_client = WhatsAppClient::with_configuration(test_configuration("https://some.url"));
+
+    let request_body = SendTemplateRequestBody::new(vec![
+        dummy_template_message("441134960001"),
+        dummy_template_message(""),
+    ]);
+
+    let error = wa_client
+        .send_template_batch(request_body, 1)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.sent, 0);
+    assert!(error.accepted.messages.is_empty());
+    assert!(matches!(error.source, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn templates_valid() {
+    let expected_response = r#"
+        {
+          "templates": [
+            {
+              "id": "111",
+              "businessAccountId": 222,
+              "name": "media_template_with_buttons",
+              "language": "en",
+              "status": "APPROVED",
+              "category": "ACCOUNT_UPDATE",
+              "structure": {
+                "header": {
+                  "format": "IMAGE"
+                },
+                "body": {
+                  "text": "example {{1}} body"
+                },
+                "footer": {
+                  "text": "exampleFooter"
+                },
+                "buttons": [
+                  {
+                    "text": "Dial 911",
+                    "phoneNumber": "911",
+                    "type": "PHONE_NUMBER"
+                  },
+                  {
+                    "text": "Visit our website",
+                    "url": "https://www.infobip.com",
+                    "type": "URL"
+                  }
+                ],
+                "type": "MEDIA"
+              }
+            }
+          ]
+        }
+    "#;
+
+    let sender = "441134960000";
+    let path = PATH_GET_TEMPLATES.replace("{sender}", sender);
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        &path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client.templates(sender).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(!response.body.templates.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn template_valid() {
+    let expected_response = r#"
+        {
+          "id": "111",
+          "businessAccountId": 222,
+          "name": "media_template_with_buttons",
+          "language": "en",
+          "status": "APPROVED",
+          "category": "MARKETING",
+          "structure": {
+            "body": {
+              "text": "example {{1}} body"
+            },
+            "type": "TEXT"
+          },
+          "qualityScore": {
+            "score": "YELLOW",
+            "date": "2023-07-01T12:00:00.000+0000"
+          }
+        }
+    "#;
+
+    let sender = "441134960000";
+    let path = PATH_GET_TEMPLATE
+        .replace("{sender}", sender)
+        .replace("{id}", "111");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        &path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client.template(sender, "111").await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(
+        response.body.quality_score.unwrap().score,
+        Some(TemplateQualityRating::Yellow)
+    );
+    assert!(response.body.rejection_reason.is_none());
+}
+
+#[tokio::test]
+async fn template_by_name_valid() {
+    let expected_response = r#"
+        {
+          "templates": [
+            {
+              "id": "111",
+              "name": "welcome",
+              "language": "en",
+              "status": "APPROVED"
+            },
+            {
+              "id": "112",
+              "name": "welcome",
+              "language": "es",
+              "status": "REJECTED",
+              "rejectionReason": "INVALID_FORMAT"
+            }
+          ]
+        }
+    "#;
+
+    let sender = "441134960000";
+    let path = PATH_GET_TEMPLATES.replace("{sender}", sender);
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        &path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client
+        .template_by_name(sender, "welcome", TemplateLanguage::Es)
+        .await
+        .unwrap();
+    let template = response.body.unwrap();
+
+    assert_eq!(template.id.as_deref(), Some("112"));
+    assert_eq!(template.rejection_reason.as_deref(), Some("INVALID_FORMAT"));
+
+    let response = wa_client
+        .template_by_name(sender, "welcome", TemplateLanguage::De)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert!(response.body.is_none());
+}
+
+#[tokio::test]
+async fn update_template_valid() {
+    let request_body: UpdateTemplateRequestBody = serde_json::from_str(
+        r#"
+            {
+              "category": "UTILITY",
+              "structure": {
+                "body": {
+                  "text": "example {{1}} body"
+                },
+                "type": "TEXT"
+              }
+            }
+        "#,
+    )
+    .unwrap();
+
+    let expected_response = r#"
+        {
+          "id": "111",
+          "businessAccountId": 222,
+          "name": "exampleName",
+          "language": "en",
+          "status": "PENDING",
+          "category": "UTILITY",
+          "structure": {
+            "body": {
+              "text": "example {{1}} body"
+            },
+            "type": "TEXT"
+          }
+        }
+    "#;
+
+    let sender = "441134960000";
+    let path = PATH_UPDATE_TEMPLATE
+        .replace("{sender}", sender)
+        .replace("{id}", "111");
+
+    let server = mock_json_endpoint(
+        httpmock::Method::PATCH,
+        &path,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client
+        .update_template(sender, "111", request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.status, Some(TemplateStatus::Pending));
+}
+
+#[tokio::test]
+async fn create_template_valid() {
+    let request_body: CreateTemplateRequestBody = serde_json::from_str(
+        r#"
+            {
+              "name": "media_template_with_quick_reply_buttons",
+              "language": "en",
+              "category": "OTP",
+              "structure": {
+                "body": {
+                  "text": "body {{1}} content",
+                  "examples": [
+                    "example"
+                  ]
+                },
+                "buttons": [
+                  {
+                    "text": "quick reply 1",
+                    "type": "QUICK_REPLY"
+                  },
+                  {
+                    "text": "quick reply 2",
+                    "type": "QUICK_REPLY"
+                  },
+                  {
+                    "text": "quick reply 3",
+                    "type": "QUICK_REPLY"
+                  }
+                ],
+                "type": "MEDIA"
+              }
+            }
+        "#,
+    )
+    .unwrap();
 
     let expected_response = r#"
         {
@@ -886,7 +1547,7 @@ async fn create_template_valid() {
     "#;
 
     let sender = "441134960000";
-    let path = PATH_CREATE_TEMPLATE.replace("{sender}", &sender);
+    let path = PATH_CREATE_TEMPLATE.replace("{sender}", sender);
 
     let server = mock_json_endpoint(
         httpmock::Method::POST,
@@ -925,10 +1586,500 @@ async fn delete_template_valid() {
 
     let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
 
-    let status = wa_client
+    let response = wa_client
         .delete_template(sender, template_name)
         .await
         .unwrap();
 
-    assert_eq!(status, reqwest::StatusCode::NO_CONTENT);
+    assert_eq!(response.status, reqwest::StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn send_typing_indicator_valid() {
+    let sender = "441134960000";
+    let message_id = "ABEGVUGWh3gEAgo-sBzm3mz4hQrq";
+    let path = PATH_SEND_TYPING_INDICATOR
+        .replace("{sender}", sender)
+        .replace("{messageId}", message_id);
+
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(path)
+            .json_body(serde_json::json!({"type": "TEXT"}));
+        then.status(200);
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client
+        .send_typing_indicator(sender, message_id, SendTypingIndicatorRequestBody::new())
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn send_typing_indicator_unknown_message() {
+    let sender = "441134960000";
+    let message_id = "unknown";
+    let path = PATH_SEND_TYPING_INDICATOR
+        .replace("{sender}", sender)
+        .replace("{messageId}", message_id);
+    let response = r#"
+    {
+      "requestError": {
+        "serviceException": {
+          "messageId": "NOT_FOUND",
+          "text": "Message not found."
+        }
+      }
+    }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        &path,
+        response,
+        reqwest::StatusCode::NOT_FOUND,
+    )
+    .await;
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let error = wa_client
+        .send_typing_indicator(sender, message_id, SendTypingIndicatorRequestBody::new())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, ApiRequestError(api_error) if api_error.status == reqwest::StatusCode::NOT_FOUND)
+    );
+}
+
+#[tokio::test]
+async fn send_text_platform_ids() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEXT)
+            .json_body_partial(r#"{"applicationId": "support", "entityId": "customer-1"}"#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"to": "55555555555", "messageCount": 1}"#);
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(
+        test_configuration(&server.base_url())
+            .with_application_id("support")
+            .with_entity_id("customer-1"),
+    );
+
+    wa_client
+        .send_text(dummy_send_text_request_body())
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn delivery_reports_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_DELIVERY_REPORTS)
+            .query_param("messageId", "some-message-id")
+            .query_param("limit", "10");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "results": [{
+                    "messageId": "some-message-id",
+                    "to": "441134960001",
+                    "sentAt": "2024-03-01T10:00:00.000+0000",
+                    "doneAt": "2024-03-01T10:00:01.000+0000",
+                    "messageCount": 1,
+                    "price": {"pricePerMessage": 0.01, "currency": "EUR"},
+                    "status": {"groupId": 3, "groupName": "DELIVERED", "id": 5, "name": "DELIVERED_TO_HANDSET"},
+                    "error": {"groupId": 0, "groupName": "OK", "id": 0, "name": "NO_ERROR", "permanent": false},
+                    "callbackData": "{\"orderId\":42}"
+                }]
+            }));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut query_parameters = DeliveryReportsQueryParameters::new();
+    query_parameters.message_id = Some("some-message-id".to_string());
+    query_parameters.limit = Some(10);
+
+    let response = wa_client.delivery_reports(query_parameters).await.unwrap();
+
+    let report = &response.body.results.unwrap()[0];
+    assert_eq!(report.status_group(), Some(StatusGroup::Delivered));
+    assert_eq!(
+        report.parse_callback_data::<serde_json::Value>().unwrap(),
+        Some(serde_json::json!({"orderId": 42}))
+    );
+}
+
+#[tokio::test]
+async fn logs_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("generalStatus", "UNDELIVERABLE")
+            .query_param("sentSince", "2024-03-01T00:00:00.000+0000");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "results": [{
+                    "bulkId": "some-bulk-id",
+                    "messageId": "some-message-id",
+                    "to": "441134960001",
+                    "from": "441134960000",
+                    "text": "Hello",
+                    "status": {"groupId": 2, "groupName": "UNDELIVERABLE"}
+                }]
+            }));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.general_status = Some("UNDELIVERABLE".to_string());
+    query_parameters.sent_since = Some("2024-03-01T00:00:00.000+0000".to_string());
+
+    let response = wa_client.logs(query_parameters).await.unwrap();
+
+    let log = &response.body.results.unwrap()[0];
+    assert_eq!(log.text.as_deref(), Some("Hello"));
+    assert_eq!(log.status_group(), Some(StatusGroup::Undeliverable));
+}
+
+#[tokio::test]
+async fn logs_invalid_limit() {
+    let wa_client = WhatsAppClient::with_configuration(test_configuration("http://localhost"));
+
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.limit = Some(1001);
+
+    let error = wa_client.logs(query_parameters).await.unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[tokio::test]
+async fn message_status_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_LOGS)
+            .query_param("messageId", "some-message-id")
+            .query_param("limit", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "results": [{
+                    "messageId": "some-message-id",
+                    "status": {"groupId": 3, "groupName": "DELIVERED", "id": 5, "name": "DELIVERED_TO_HANDSET"}
+                }]
+            }));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client.message_status("some-message-id").await.unwrap();
+
+    let status = response.body.unwrap();
+    assert_eq!(status.group(), Some(StatusGroup::Delivered));
+    assert_eq!(status.name.as_deref(), Some("DELIVERED_TO_HANDSET"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn message_status_unknown() {
+    let server = httpmock::MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path(PATH_GET_LOGS);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({"results": []}));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let response = wa_client.message_status("unknown-id").await.unwrap();
+
+    assert_eq!(response.body, None);
+}
+
+#[tokio::test]
+async fn sender_send_text() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path(PATH_SEND_TEXT)
+            .json_body(serde_json::json!({
+                "from": "44444444444",
+                "to": "55555555555",
+                "content": {"text": "Hello!"},
+                "notifyUrl": "https://example.com/reports"
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({"to": "55555555555", "messageCount": 1}));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+    let sender = wa_client
+        .sender("44444444444")
+        .with_notify_url("https://example.com/reports");
+
+    let response = sender
+        .send_text("55555555555", TextContent::new("Hello!"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.body.to.as_deref(), Some("55555555555"));
+    assert_eq!(sender.from(), "44444444444");
+    mock.assert_async().await;
+}
+
+#[test]
+fn sender_request_body() {
+    let wa_client = WhatsAppClient::with_configuration(test_configuration("http://localhost"));
+    let sender = wa_client
+        .sender("44444444444")
+        .with_callback_data("campaign-7");
+
+    let request_body = sender
+        .request_body(
+            "55555555555",
+            ImageContent::new("https://example.com/image.png"),
+        )
+        .with_message_id("some-message-id");
+
+    assert_eq!(request_body.from, "44444444444");
+    assert_eq!(request_body.to, "55555555555");
+    assert_eq!(request_body.callback_data.as_deref(), Some("campaign-7"));
+    assert_eq!(request_body.message_id.as_deref(), Some("some-message-id"));
+    assert_eq!(request_body.notify_url, None);
+}
+
+#[tokio::test]
+async fn template_library_valid() {
+    let expected_response = r#"
+        {
+          "templates": [
+            {
+              "name": "delivery_update_1",
+              "language": "en_US",
+              "category": "UTILITY",
+              "topic": "ORDER_MANAGEMENT",
+              "industries": ["E_COMMERCE"],
+              "structure": {
+                "body": {
+                  "text": "Your order {{1}} is on its way.",
+                  "examples": ["12345"]
+                }
+              }
+            }
+          ],
+          "totalCount": 1
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::GET,
+        PATH_GET_TEMPLATE_LIBRARY,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut query_parameters = TemplateLibraryQueryParameters::new();
+    query_parameters.search = Some("delivery".to_string());
+    query_parameters.language = Some(TemplateLanguage::EnUs);
+
+    let response = client.template_library(query_parameters).await.unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.total_count, Some(1));
+    let template = &response.body.templates.unwrap()[0];
+    assert_eq!(template.category, Some(TemplateCategory::Utility));
+}
+
+#[tokio::test]
+async fn template_library_big_size() {
+    let client = WhatsAppClient::with_configuration(test_configuration("https://some.url"));
+
+    let mut query_parameters = TemplateLibraryQueryParameters::new();
+    query_parameters.size = Some(101);
+
+    assert!(client.template_library(query_parameters).await.is_err());
+}
+
+#[tokio::test]
+async fn create_template_from_library_valid() {
+    let sender = "1234567891011";
+    let expected_response = r#"
+        {
+          "id": "111",
+          "businessAccountId": 222,
+          "name": "delivery_update",
+          "language": "en_US",
+          "status": "APPROVED",
+          "category": "UTILITY"
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_CREATE_TEMPLATE.replace("{sender}", sender).as_str(),
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+
+    let mut request_body = CreateLibraryTemplateRequestBody::new(
+        "delivery_update",
+        TemplateLanguage::EnUs,
+        TemplateCategory::Utility,
+        "delivery_update_1",
+    );
+    request_body.library_template_button_inputs = Some(vec![LibraryTemplateButtonInput::url(
+        "https://www.example.com/track",
+    )]);
+
+    let response = client
+        .create_template_from_library(sender, request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.body.status, Some(TemplateStatus::Approved));
+}
+
+#[tokio::test]
+async fn create_template_from_library_bad_button_url() {
+    let client = WhatsAppClient::with_configuration(test_configuration("https://some.url"));
+
+    let mut request_body = CreateLibraryTemplateRequestBody::new(
+        "delivery_update",
+        TemplateLanguage::EnUs,
+        TemplateCategory::Utility,
+        "delivery_update_1",
+    );
+    request_body.library_template_button_inputs =
+        Some(vec![LibraryTemplateButtonInput::url("not a url")]);
+
+    assert!(client
+        .create_template_from_library("1234567891011", request_body)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn sender_quality_valid() {
+    let server = httpmock::MockServer::start_async().await;
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::GET)
+            .path(PATH_GET_SENDER_QUALITY)
+            .query_param("senders", "441134960000,441134960001");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "results": [
+                    {
+                        "sender": "441134960000",
+                        "qualityRating": "HIGH",
+                        "status": "CONNECTED",
+                        "currentLimit": "TIER_10K",
+                        "lastUpdated": "2024-03-01T10:00:00.000+0000"
+                    },
+                    {
+                        "sender": "441134960001",
+                        "qualityRating": "LOW",
+                        "status": "FLAGGED",
+                        "currentLimit": "TIER_1K"
+                    }
+                ]
+            }));
+    });
+
+    let wa_client = WhatsAppClient::with_configuration(test_configuration(&server.base_url()));
+    let query_parameters = SenderQualityQueryParameters::new(vec![
+        "441134960000".to_string(),
+        "441134960001".to_string(),
+    ]);
+
+    let response = wa_client.sender_quality(query_parameters).await.unwrap();
+
+    mock.assert_async().await;
+    let quality = response.body.sender("441134960000").unwrap();
+    assert_eq!(quality.quality_rating, Some(SenderQualityRating::High));
+    assert_eq!(
+        quality
+            .current_limit
+            .and_then(|limit| limit.recipients_per_day()),
+        Some(10_000)
+    );
+    let at_risk = response.body.at_risk();
+    assert_eq!(at_risk.len(), 1);
+    assert_eq!(at_risk[0].sender.as_deref(), Some("441134960001"));
+}
+
+#[tokio::test]
+async fn sender_quality_no_senders() {
+    let wa_client = WhatsAppClient::with_configuration(test_configuration("https://some.url"));
+
+    let error = wa_client
+        .sender_quality(SenderQualityQueryParameters::new(vec![]))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SdkError::Validation(_)));
+}
+
+#[test]
+fn send_template_response_validation_normalizes_destinations() {
+    use crate::api::response_validation::ResponseExpectations;
+
+    let request_body: SendTemplateRequestBody = serde_json::from_value(serde_json::json!({
+        "messages": [
+            {
+                "from": "441134960000",
+                "to": "+44 113 496 0001",
+                "content": {"templateName": "template_name", "templateData": {"body": {"placeholders": []}}, "language": "en"}
+            },
+            {
+                "from": "441134960000",
+                "to": "00441134960002",
+                "content": {"templateName": "template_name", "templateData": {"body": {"placeholders": []}}, "language": "en"}
+            }
+        ]
+    }))
+    .unwrap();
+    let mut response_body: SendTemplateResponseBody = serde_json::from_value(serde_json::json!({
+        "bulkId": "some-bulk-id",
+        "messages": [{"to": "441134960001"}, {"to": "441134960002"}]
+    }))
+    .unwrap();
+
+    assert!(request_body.check_response(&response_body).is_empty());
+
+    response_body.messages.as_mut().unwrap()[1].to = Some("441134960003".to_string());
+    assert_eq!(
+        request_body.check_response(&response_body),
+        vec!["message 1 destination doesn't match"]
+    );
 }
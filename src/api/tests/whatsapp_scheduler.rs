@@ -0,0 +1,128 @@
+use chrono::{Duration, Utc};
+
+use crate::api::tests::{mock_json_endpoint, test_configuration};
+use crate::api::whatsapp::{WhatsAppClient, PATH_SEND_TEXT};
+use crate::api::whatsapp_scheduler::*;
+use crate::api::SdkError;
+use crate::model::whatsapp::{SendTextRequestBody, TextContent};
+use crate::queue::{MemoryQueueStore, QueueStore, QueuedItem};
+
+fn dummy_text_message(text: &str) -> WhatsAppMessage {
+    WhatsAppMessage::Text(SendTextRequestBody::new(
+        "44444444444",
+        "55555555555",
+        TextContent::new(text),
+    ))
+}
+
+#[tokio::test]
+async fn dispatch_due_sends_only_due_messages() {
+    let expected_response = r#"
+        {
+          "to": "55555555555",
+          "messageCount": 1,
+          "messageId": "a28dd97c-1ffb-4fcf-99f1-0b557ed381da",
+          "status": {
+            "groupId": 1,
+            "groupName": "PENDING",
+            "id": 7,
+            "name": "PENDING_ENROUTE",
+            "description": "Message sent to next instance"
+          }
+        }
+    "#;
+
+    let server = mock_json_endpoint(
+        httpmock::Method::POST,
+        PATH_SEND_TEXT,
+        expected_response,
+        reqwest::StatusCode::OK,
+    )
+    .await;
+
+    let scheduler = WhatsAppScheduler::new(WhatsAppClient::with_configuration(test_configuration(
+        &server.base_url(),
+    )));
+
+    let now = Utc::now();
+    let due_id = scheduler
+        .schedule(dummy_text_message("due"), now - Duration::minutes(1))
+        .unwrap();
+    scheduler
+        .schedule(dummy_text_message("later"), now + Duration::hours(1))
+        .unwrap();
+
+    let dispatched = scheduler.dispatch_due(now).await.unwrap();
+
+    assert_eq!(dispatched.len(), 1);
+    assert_eq!(dispatched[0].id, due_id);
+    match &dispatched[0].result.as_ref().unwrap().body {
+        WhatsAppSendResponse::Content(body) => assert!(body.message_id.is_some()),
+        other => panic!("unexpected response: {other:?}"),
+    }
+    assert_eq!(scheduler.pending().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn cancel_removes_scheduled_message() {
+    let scheduler = WhatsAppScheduler::new(WhatsAppClient::with_configuration(test_configuration(
+        "https://some.url",
+    )));
+
+    let id = scheduler
+        .schedule(dummy_text_message("hello"), Utc::now() + Duration::hours(1))
+        .unwrap();
+
+    assert!(scheduler.cancel(&id).unwrap());
+    assert!(!scheduler.cancel(&id).unwrap());
+    assert_eq!(scheduler.pending().unwrap(), 0);
+}
+
+#[test]
+fn schedule_invalid_message() {
+    let scheduler = WhatsAppScheduler::new(WhatsAppClient::with_configuration(test_configuration(
+        "https://some.url",
+    )));
+
+    let result = scheduler.schedule(dummy_text_message(""), Utc::now());
+
+    assert!(matches!(result, Err(SdkError::Validation(_))));
+    assert_eq!(scheduler.pending().unwrap(), 0);
+}
+
+#[test]
+fn memory_queue_store_take_due_in_order() {
+    let store = MemoryQueueStore::new();
+    let now = Utc::now();
+
+    for (id, offset) in [("b", 2), ("a", 1), ("c", -1)] {
+        store
+            .push(QueuedItem {
+                id: id.to_string(),
+                due_at: now - Duration::minutes(offset),
+                item: (),
+            })
+            .unwrap();
+    }
+
+    let due: Vec<String> = store
+        .take_due(now)
+        .unwrap()
+        .into_iter()
+        .map(|item| item.id)
+        .collect();
+
+    assert_eq!(due, vec!["b", "a"]);
+    assert_eq!(store.len().unwrap(), 1);
+}
+
+#[test]
+fn whatsapp_message_serde_round_trip() {
+    let message = dummy_text_message("hello");
+
+    let serialized = serde_json::to_string(&message).unwrap();
+    let deserialized: WhatsAppMessage = serde_json::from_str(&serialized).unwrap();
+
+    assert!(serialized.starts_with(r#"{"type":"TEXT","body":"#));
+    assert_eq!(deserialized, message);
+}
@@ -0,0 +1,852 @@
+//! Module with client and endpoint functions for 2FA (two-factor authentication) over SMS, Voice,
+//! and Email.
+
+use futures_util::stream::{self, StreamExt};
+use validator::Validate;
+
+use crate::api::{
+    build_http_client, build_response, fill_platform_ids, send_no_body_request, send_raw_request,
+    send_valid_json_request, SdkError, SdkResponse,
+};
+use crate::configuration::{Channel, Configuration};
+use crate::model::tfa::{
+    AppId, CreateTfaApplicationRequestBody, CreateTfaApplicationResponseBody,
+    CreateTfaMessageTemplateRequestBody, CreateTfaMessageTemplateResponseBody, PinId,
+    ResendPinOverEmailRequestBody, ResendPinOverEmailResponseBody, ResendPinOverSmsRequestBody,
+    ResendPinOverSmsResponseBody, ResendPinOverVoiceRequestBody, ResendPinOverVoiceResponseBody,
+    SendPinOverEmailRequestBody, SendPinOverEmailResponseBody, SendPinOverSmsQueryParameters,
+    SendPinOverSmsRequestBody, SendPinOverSmsResponseBody, SendPinOverVoiceRequestBody,
+    SendPinOverVoiceResponseBody, TemplateId, TfaApplication, TfaApplicationResponseBody,
+    TfaApplicationsFilter, TfaApplicationsResponseBody, TfaMessageTemplateResponseBody,
+    TfaMessageTemplatesResponseBody, TfaVerificationStatusQueryParameters,
+    TfaVerificationStatusResponseBody, UpdateTfaApplicationRequestBody,
+    UpdateTfaApplicationResponseBody, UpdateTfaMessageTemplateRequestBody,
+    UpdateTfaMessageTemplateResponseBody, VerifyPhoneNumberRequestBody,
+    VerifyPhoneNumberResponseBody,
+};
+
+pub const PATH_GET_TFA_APPLICATIONS: &str = "/2fa/2/applications";
+pub const PATH_CREATE_TFA_APPLICATION: &str = "/2fa/2/applications";
+pub const PATH_GET_TFA_APPLICATION: &str = "/2fa/2/applications/{appId}";
+pub const PATH_UPDATE_TFA_APPLICATION: &str = "/2fa/2/applications/{appId}";
+pub const PATH_GET_TFA_MESSAGE_TEMPLATES: &str = "/2fa/2/applications/{appId}/messages";
+pub const PATH_CREATE_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages";
+pub const PATH_GET_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages/{msgId}";
+pub const PATH_UPDATE_TFA_MESSAGE_TEMPLATE: &str = "/2fa/2/applications/{appId}/messages/{msgId}";
+pub const PATH_SEND_PIN_OVER_SMS: &str = "/2fa/2/pin";
+pub const PATH_RESEND_PIN_OVER_SMS: &str = "/2fa/2/pin/{pinId}/resend";
+pub const PATH_SEND_PIN_OVER_VOICE: &str = "/2fa/2/pin/voice";
+pub const PATH_RESEND_PIN_OVER_VOICE: &str = "/2fa/2/pin/{pinId}/resend/voice";
+pub const PATH_SEND_PIN_OVER_EMAIL: &str = "/2fa/2/pin/email";
+pub const PATH_RESEND_PIN_OVER_EMAIL: &str = "/2fa/2/pin/{pinId}/resend/email";
+pub const PATH_VERIFY_PHONE_NUMBER: &str = "/2fa/2/pin/{pinId}/verify";
+pub const PATH_GET_TFA_VERIFICATION_STATUS: &str = "/2fa/2/applications/{appId}/verifications";
+
+/// Default number of concurrent requests made by `send_pin_over_sms_bulk`.
+pub const DEFAULT_PIN_CONCURRENCY: usize = 8;
+
+/// Main asynchronous client for the Infobip 2FA endpoints.
+#[derive(Clone, Debug)]
+pub struct TfaClient {
+    pub configuration: Configuration,
+    pub http_client: reqwest::Client,
+}
+
+impl TfaClient {
+    /// Builds and returns a new asynchronous `TfaClient` with specified configuration.
+    /// The client gets its own connection pool, see `with_http_client` to share one.
+    pub fn with_configuration(configuration: Configuration) -> Self {
+        Self::with_http_client(build_http_client(&configuration), configuration)
+    }
+
+    /// Builds and returns a new asynchronous `TfaClient` that sends requests with the given HTTP
+    /// client. See `api::client` for sharing an HTTP client between channel clients.
+    pub fn with_http_client(http_client: reqwest::Client, configuration: Configuration) -> Self {
+        TfaClient {
+            configuration: configuration.for_channel(Channel::Tfa),
+            http_client,
+        }
+    }
+
+    /// Get a list of your 2FA applications.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = client.tfa_applications().await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    pub async fn tfa_applications(
+        &self,
+    ) -> Result<SdkResponse<TfaApplicationsResponseBody>, SdkError> {
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            reqwest::Method::GET,
+            PATH_GET_TFA_APPLICATIONS,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get the 2FA applications that pass the filter.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::TfaApplicationsFilter;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut filter = TfaApplicationsFilter::new();
+    /// filter.enabled = Some(true);
+    /// filter.name_contains = Some("login".to_string());
+    ///
+    /// let response = client.tfa_applications_filtered(&filter).await?;
+    ///
+    /// for application in response.body {
+    ///     println!("{}", application.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tfa_applications_filtered(
+        &self,
+        filter: &TfaApplicationsFilter,
+    ) -> Result<SdkResponse<TfaApplicationsResponseBody>, SdkError> {
+        let mut response = self.tfa_applications().await?;
+        response
+            .body
+            .retain(|application| filter.matches(application));
+
+        Ok(response)
+    }
+
+    /// Get the 2FA application with the given name, or `None` if there is none.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// if let Some(application) = client.find_application_by_name("2FA for login").await? {
+    ///     println!("{:?}", application.application_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_application_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<TfaApplication>, SdkError> {
+        let response = self.tfa_applications().await?;
+
+        Ok(response
+            .body
+            .into_iter()
+            .find(|application| application.name == name))
+    }
+
+    /// Create and configure a new 2FA application.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::CreateTfaApplicationRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    /// let request_body = CreateTfaApplicationRequestBody::new("some-name");
+    ///
+    /// let response = client.create_tfa_application(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::CREATED);
+    /// # Ok(())
+    /// # }
+    pub async fn create_tfa_application(
+        &self,
+        request_body: CreateTfaApplicationRequestBody,
+    ) -> Result<SdkResponse<CreateTfaApplicationResponseBody>, SdkError> {
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            PATH_CREATE_TFA_APPLICATION,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get a single 2FA application to see its configuration details.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::AppId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let application_id = AppId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let response = client.tfa_application(&application_id).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tfa_application(
+        &self,
+        application_id: &AppId,
+    ) -> Result<SdkResponse<TfaApplicationResponseBody>, SdkError> {
+        let path = &PATH_GET_TFA_APPLICATION.replace("{appId}", application_id.as_str());
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Change configuration options for your existing 2FA application.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::AppId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::UpdateTfaApplicationRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let application_id = AppId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = UpdateTfaApplicationRequestBody::new("some-new-name");
+    /// let response = client.update_tfa_application(&application_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_tfa_application(
+        &self,
+        application_id: &AppId,
+        request_body: UpdateTfaApplicationRequestBody,
+    ) -> Result<SdkResponse<UpdateTfaApplicationResponseBody>, SdkError> {
+        let path = &PATH_UPDATE_TFA_APPLICATION.replace("{appId}", application_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::PUT,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get all message templates in a 2FA application.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::AppId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let application_id = AppId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let response = client.tfa_message_templates(&application_id).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tfa_message_templates(
+        &self,
+        application_id: &AppId,
+    ) -> Result<SdkResponse<TfaMessageTemplatesResponseBody>, SdkError> {
+        let path = &PATH_GET_TFA_MESSAGE_TEMPLATES.replace("{appId}", application_id.as_str());
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Create one or more message templates where your PIN will be dynamically included when you send the PIN message.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::AppId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::CreateTfaMessageTemplateRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use infobip_sdk::model::tfa::PinType::Numeric;
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let application_id = AppId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = CreateTfaMessageTemplateRequestBody::new("some-name", Numeric, 4);
+    /// let response = client.create_tfa_message_template(&application_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tfa_message_template(
+        &self,
+        application_id: &AppId,
+        request_body: CreateTfaMessageTemplateRequestBody,
+    ) -> Result<SdkResponse<CreateTfaMessageTemplateResponseBody>, SdkError> {
+        let path = &PATH_CREATE_TFA_MESSAGE_TEMPLATE.replace("{appId}", application_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get a single 2FA message template from an application to see its configuration details.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::{AppId, TemplateId};
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let application_id = AppId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let template_id = TemplateId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let response = client.tfa_message_template(&application_id, &template_id).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tfa_message_template(
+        &self,
+        application_id: &AppId,
+        template_id: &TemplateId,
+    ) -> Result<SdkResponse<TfaMessageTemplateResponseBody>, SdkError> {
+        let path = &PATH_GET_TFA_MESSAGE_TEMPLATE
+            .replace("{appId}", application_id.as_str())
+            .replace("{msgId}", template_id.as_str());
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Change configuration options for your existing 2FA application message template.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::{AppId, TemplateId};
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::UpdateTfaMessageTemplateRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use infobip_sdk::model::tfa::PinType::Numeric;
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let application_id = AppId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let template_id = TemplateId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = UpdateTfaMessageTemplateRequestBody::new("some-text", Numeric, 4);
+    ///
+    /// let response = client.update_tfa_message_template(&application_id, &template_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_tfa_message_template(
+        &self,
+        application_id: &AppId,
+        template_id: &TemplateId,
+        request_body: UpdateTfaMessageTemplateRequestBody,
+    ) -> Result<SdkResponse<UpdateTfaMessageTemplateResponseBody>, SdkError> {
+        let path = &PATH_UPDATE_TFA_MESSAGE_TEMPLATE
+            .replace("{appId}", application_id.as_str())
+            .replace("{msgId}", template_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::PUT,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Send a PIN code over SMS using a previously created message template.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::SendPinOverSmsQueryParameters;
+    /// # use infobip_sdk::model::tfa::SendPinOverSmsRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_parameters = SendPinOverSmsQueryParameters::default();
+    /// let request_body = SendPinOverSmsRequestBody::new("some-application-id", "some-template-id", "555555555555");
+    ///
+    /// let response = client.send_pin_over_sms(query_parameters, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_pin_over_sms(
+        &self,
+        query_parameters: SendPinOverSmsQueryParameters,
+        mut request_body: SendPinOverSmsRequestBody,
+    ) -> Result<SdkResponse<SendPinOverSmsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            query_parameters,
+            reqwest::Method::POST,
+            PATH_SEND_PIN_OVER_SMS,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Send PIN codes over SMS to many recipients, making at most `concurrency` requests at the
+    /// same time. Returns one result per request body, in the same order, so a failed send
+    /// doesn't affect the others. A `concurrency` of 0 is treated as 1.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::{TfaClient, DEFAULT_PIN_CONCURRENCY};
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::{SendPinOverSmsQueryParameters, SendPinOverSmsRequestBody};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let request_bodies = ["41793026727", "41793026834"].map(|to| {
+    ///     SendPinOverSmsRequestBody::new("some-application-id", "some-template-id", to)
+    /// });
+    ///
+    /// let results = client
+    ///     .send_pin_over_sms_bulk(
+    ///         SendPinOverSmsQueryParameters::default(),
+    ///         request_bodies,
+    ///         DEFAULT_PIN_CONCURRENCY,
+    ///     )
+    ///     .await;
+    ///
+    /// for result in results {
+    ///     println!("{:?}", result?.body.pin_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_pin_over_sms_bulk<I>(
+        &self,
+        query_parameters: SendPinOverSmsQueryParameters,
+        request_bodies: I,
+        concurrency: usize,
+    ) -> Vec<Result<SdkResponse<SendPinOverSmsResponseBody>, SdkError>>
+    where
+        I: IntoIterator<Item = SendPinOverSmsRequestBody>,
+    {
+        stream::iter(request_bodies)
+            .map(|request_body| self.send_pin_over_sms(query_parameters.clone(), request_body))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Resend the same (previously sent) PIN code over SMS.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::PinId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::ResendPinOverSmsRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let pin_id = PinId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = ResendPinOverSmsRequestBody::default();
+    ///
+    /// let response = client.resend_pin_over_sms(&pin_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resend_pin_over_sms(
+        &self,
+        pin_id: &PinId,
+        request_body: ResendPinOverSmsRequestBody,
+    ) -> Result<SdkResponse<ResendPinOverSmsResponseBody>, SdkError> {
+        let path = &PATH_RESEND_PIN_OVER_SMS.replace("{pinId}", pin_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Send a PIN code over Voice using previously created message template.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::SendPinOverVoiceRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let request_body = SendPinOverVoiceRequestBody::new("some-application-id", "some-template-id", "555555555555");
+    ///
+    /// let response = client.send_pin_over_voice(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_pin_over_voice(
+        &self,
+        mut request_body: SendPinOverVoiceRequestBody,
+    ) -> Result<SdkResponse<SendPinOverVoiceResponseBody>, SdkError> {
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            PATH_SEND_PIN_OVER_VOICE,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Resend the same (previously sent) PIN code over Voice.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::PinId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::ResendPinOverVoiceRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let pin_id = PinId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = ResendPinOverVoiceRequestBody::default();
+    ///
+    /// let response = client.resend_pin_over_voice(&pin_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resend_pin_over_voice(
+        &self,
+        pin_id: &PinId,
+        request_body: ResendPinOverVoiceRequestBody,
+    ) -> Result<SdkResponse<ResendPinOverVoiceResponseBody>, SdkError> {
+        let path = &PATH_RESEND_PIN_OVER_VOICE.replace("{pinId}", pin_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Send a PIN code over Email using previously created message template with an email
+    /// template ID.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::SendPinOverEmailRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let request_body = SendPinOverEmailRequestBody::new(
+    ///     "some-application-id",
+    ///     "some-template-id",
+    ///     "company@example.com",
+    ///     "john.smith@example.com",
+    /// );
+    ///
+    /// let response = client.send_pin_over_email(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_pin_over_email(
+        &self,
+        mut request_body: SendPinOverEmailRequestBody,
+    ) -> Result<SdkResponse<SendPinOverEmailResponseBody>, SdkError> {
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            PATH_SEND_PIN_OVER_EMAIL,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Resend the same (previously sent) PIN code over Email.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::PinId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::ResendPinOverEmailRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let pin_id = PinId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = ResendPinOverEmailRequestBody::default();
+    ///
+    /// let response = client.resend_pin_over_email(&pin_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resend_pin_over_email(
+        &self,
+        pin_id: &PinId,
+        request_body: ResendPinOverEmailRequestBody,
+    ) -> Result<SdkResponse<ResendPinOverEmailResponseBody>, SdkError> {
+        let path = &PATH_RESEND_PIN_OVER_EMAIL.replace("{pinId}", pin_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Verify a phone number to confirm successful 2FA authentication.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::PinId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::VerifyPhoneNumberRequestBody;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use infobip_sdk::model::tfa::VerifyPhoneNumberResponseBody;
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let pin_id = PinId::from("02CC3CAAFD733136AA15DFAC720A0C42");
+    /// let request_body = VerifyPhoneNumberRequestBody::new("123456");
+    /// let response = client.verify_phone_number(&pin_id, request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_phone_number(
+        &self,
+        pin_id: &PinId,
+        request_body: VerifyPhoneNumberRequestBody,
+    ) -> Result<SdkResponse<VerifyPhoneNumberResponseBody>, SdkError> {
+        let path = &PATH_VERIFY_PHONE_NUMBER.replace("{pinId}", pin_id.as_str());
+
+        let response = send_valid_json_request(
+            &self.http_client,
+            &self.configuration,
+            request_body,
+            (),
+            reqwest::Method::POST,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Check if a phone number is already verified for a specific 2FA application.
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::tfa::TfaClient;
+    /// # use infobip_sdk::model::tfa::AppId;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::tfa::{TfaVerificationStatusQueryParameters,
+    /// #         TfaVerificationStatusResponseBody};
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = TfaClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_parameters = TfaVerificationStatusQueryParameters::new("555555555555");
+    /// let response = client.tfa_verification_status(&AppId::from("some-application-id"), query_parameters).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tfa_verification_status(
+        &self,
+        app_id: &AppId,
+        query_parameters: TfaVerificationStatusQueryParameters,
+    ) -> Result<SdkResponse<TfaVerificationStatusResponseBody>, SdkError> {
+        let path = &PATH_GET_TFA_VERIFICATION_STATUS.replace("{appId}", app_id.as_str());
+
+        query_parameters.validate()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            query_parameters,
+            reqwest::Method::GET,
+            path,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Send a request with a JSON body built by hand, or no body, e.g. to use a 2FA option the
+    /// SDK doesn't model yet. The body is sent as is, without validation, and the response body
+    /// is returned as JSON. See `SmsClient::send_raw`.
+    pub async fn send_raw(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        request_body: Option<serde_json::Value>,
+    ) -> Result<SdkResponse<serde_json::Value>, SdkError> {
+        send_raw_request(
+            &self.http_client,
+            &self.configuration,
+            method,
+            path,
+            request_body,
+        )
+        .await
+    }
+}
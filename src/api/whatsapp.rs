@@ -1,45 +1,220 @@
 //! Module with client and endpoint functions for the WhatsApp channel.
 
-use std::collections::HashMap;
+use std::time::Instant;
 
 use reqwest::{Method, Response};
 use serde::Serialize;
+use thiserror::Error;
 use validator::Validate;
 
 use crate::api::{
-    build_api_error, send_no_body_request, send_valid_json_request, SdkError, SdkResponse,
+    build_http_client, build_response, fill_platform_ids, send_no_body_request, send_raw_request,
+    send_valid_json_request, NoContent, SdkError, SdkResponse,
 };
-use crate::configuration::Configuration;
+use crate::configuration::{Channel, Configuration};
 use crate::model::whatsapp::{
-    CreateTemplateRequestBody, CreateTemplateResponseBody, SendAudioRequestBody,
-    SendAudioResponseBody, SendContactRequestBody, SendContactResponseBody,
-    SendDocumentRequestBody, SendDocumentResponseBody, SendImageRequestBody, SendImageResponseBody,
+    AudioContent, CheckContactsRequestBody, CheckContactsResponseBody, ContactContent,
+    CreateLibraryTemplateRequestBody, CreateLibraryTemplateResponseBody, CreateTemplateRequestBody,
+    CreateTemplateResponseBody, DeliveryReportsQueryParameters, DeliveryReportsResponseBody,
+    DocumentContent, ImageContent, InteractiveButtonsContent, InteractiveFlowContent,
+    InteractiveListContent, InteractiveMultiproductContent, InteractiveOrderDetailsContent,
+    InteractiveOrderStatusContent, InteractiveProductContent, LocationContent, LogsQueryParameters,
+    LogsResponseBody, SendAudioRequestBody, SendAudioResponseBody, SendContactRequestBody,
+    SendContactResponseBody, SendContentRequestBody, SendDocumentRequestBody,
+    SendDocumentResponseBody, SendImageRequestBody, SendImageResponseBody,
     SendInteractiveButtonsRequestBody, SendInteractiveButtonsResponseBody,
+    SendInteractiveFlowRequestBody, SendInteractiveFlowResponseBody,
     SendInteractiveListRequestBody, SendInteractiveListResponseBody,
     SendInteractiveMultiproductRequestBody, SendInteractiveMultiproductResponseBody,
+    SendInteractiveOrderDetailsRequestBody, SendInteractiveOrderDetailsResponseBody,
+    SendInteractiveOrderStatusRequestBody, SendInteractiveOrderStatusResponseBody,
     SendInteractiveProductRequestBody, SendInteractiveProductResponseBody, SendLocationRequestBody,
     SendLocationResponseBody, SendStickerRequestBody, SendStickerResponseBody,
-    SendTemplateRequestBody, SendTemplateResponseBody, SendTextRequestBody, SendTextResponseBody,
-    SendVideoRequestBody, SendVideoResponseBody, TemplatesResponseBody,
+    SendTemplateBatchResponseBody, SendTemplateRequestBody, SendTemplateResponseBody,
+    SendTextRequestBody, SendTextResponseBody, SendTypingIndicatorRequestBody,
+    SendVideoRequestBody, SendVideoResponseBody, SenderQualityQueryParameters,
+    SenderQualityResponseBody, Status, StickerContent, TemplateLanguage,
+    TemplateLibraryQueryParameters, TemplateLibraryResponseBody, TemplateResponseBody,
+    TemplatesResponseBody, TextContent, UpdateTemplateRequestBody, UpdateTemplateResponseBody,
+    VideoContent, MAX_CONTACTS_PER_CHECK,
 };
+use crate::model::{PlatformIds, QueryParams};
 
+pub const PATH_CHECK_CONTACTS: &str = "/whatsapp/1/senders/{sender}/contacts/check";
 pub const PATH_CREATE_TEMPLATE: &str = "/whatsapp/2/senders/{sender}/templates";
 pub const PATH_DELETE_TEMPLATE: &str = "/whatsapp/2/senders/{sender}/templates/{templateName}";
+pub const PATH_GET_DELIVERY_REPORTS: &str = "/whatsapp/1/reports";
+pub const PATH_GET_LOGS: &str = "/whatsapp/1/logs";
+pub const PATH_GET_SENDER_QUALITY: &str = "/whatsapp/1/senders/quality";
+pub const PATH_GET_TEMPLATE_LIBRARY: &str = "/whatsapp/2/template-library";
+pub const PATH_GET_TEMPLATE: &str = "/whatsapp/2/senders/{sender}/templates/{id}";
 pub const PATH_GET_TEMPLATES: &str = "/whatsapp/2/senders/{sender}/templates";
 pub const PATH_SEND_AUDIO: &str = "/whatsapp/1/message/audio";
 pub const PATH_SEND_CONTACT: &str = "/whatsapp/1/message/contact";
 pub const PATH_SEND_DOCUMENT: &str = "/whatsapp/1/message/document";
 pub const PATH_SEND_IMAGE: &str = "/whatsapp/1/message/image";
 pub const PATH_SEND_INTERACTIVE_BUTTONS: &str = "/whatsapp/1/message/interactive/buttons";
+pub const PATH_SEND_INTERACTIVE_FLOW: &str = "/whatsapp/1/message/interactive/flow";
 pub const PATH_SEND_INTERACTIVE_LIST: &str = "/whatsapp/1/message/interactive/list";
 pub const PATH_SEND_INTERACTIVE_MULTIPRODUCT: &str =
     "/whatsapp/1/message/interactive/multi-product";
+pub const PATH_SEND_INTERACTIVE_ORDER_DETAILS: &str =
+    "/whatsapp/1/message/interactive/order-details";
+pub const PATH_SEND_INTERACTIVE_ORDER_STATUS: &str = "/whatsapp/1/message/interactive/order-status";
 pub const PATH_SEND_INTERACTIVE_PRODUCT: &str = "/whatsapp/1/message/interactive/product";
 pub const PATH_SEND_LOCATION: &str = "/whatsapp/1/message/location";
 pub const PATH_SEND_STICKER: &str = "/whatsapp/1/message/sticker";
 pub const PATH_SEND_TEMPLATE: &str = "/whatsapp/1/message/template";
 pub const PATH_SEND_TEXT: &str = "/whatsapp/1/message/text";
+pub const PATH_SEND_TYPING_INDICATOR: &str =
+    "/whatsapp/1/senders/{sender}/message/{messageId}/typing";
 pub const PATH_SEND_VIDEO: &str = "/whatsapp/1/message/video";
+pub const PATH_UPDATE_TEMPLATE: &str = "/whatsapp/2/senders/{sender}/templates/{id}";
+
+/// Default number of messages sent per request by `WhatsAppClient::send_template_batch`.
+pub const DEFAULT_TEMPLATE_BATCH_SIZE: usize = 100;
+
+/// Error of `WhatsAppClient::send_template_batch`. Holds the results of the chunks accepted
+/// before the failure, so the messages that went out are known and only the rest are retried.
+#[derive(Error, Debug)]
+#[error("template batch failed after {sent} messages were sent")]
+pub struct TemplateBatchError {
+    /// Number of request messages accepted before the failure. They are always the first ones of
+    /// the request; the messages from this index on were not sent.
+    pub sent: usize,
+
+    /// Aggregated results of the accepted chunks.
+    pub accepted: SendTemplateBatchResponseBody,
+
+    /// Error of the failed chunk, or of the validation of the whole batch.
+    #[source]
+    pub source: SdkError,
+}
+
+// Generates the send methods of `WhatsAppSender`, each building the request body of a
+// `WhatsAppClient` send method.
+macro_rules! sender_send_methods {
+    ($($(#[$doc:meta])* $name:ident($content:ty) -> $response:ty;)+) => {
+        $(
+            $(#[$doc])*
+            pub async fn $name(
+                &self,
+                to: &str,
+                content: $content,
+            ) -> Result<SdkResponse<$response>, SdkError> {
+                self.client.$name(self.request_body(to, content)).await
+            }
+        )+
+    };
+}
+
+/// Sends WhatsApp messages from one sender number, so it doesn't have to be repeated in every
+/// request body. Created with `WhatsAppClient::sender`. Use the `WhatsAppClient` send methods
+/// directly for options not set here.
+///
+/// # Example
+/// ```no_run
+/// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+/// # use infobip_sdk::configuration::Configuration;
+/// # use infobip_sdk::model::whatsapp::{ImageContent, TextContent};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+/// let sender = wa_client
+///     .sender("44444444444")
+///     .with_notify_url("https://example.com/whatsapp/reports");
+///
+/// sender.send_text("55555555555", TextContent::new("Hello!")).await?;
+/// sender
+///     .send_image("55555555555", ImageContent::new("https://example.com/image.png"))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WhatsAppSender {
+    client: WhatsAppClient,
+    from: String,
+    notify_url: Option<String>,
+    callback_data: Option<String>,
+}
+
+impl WhatsAppSender {
+    /// Sets the URL delivery reports of all messages are sent to.
+    pub fn with_notify_url(mut self, notify_url: &str) -> Self {
+        self.notify_url = Some(notify_url.to_string());
+        self
+    }
+
+    /// Sets the data included in delivery reports of all messages.
+    pub fn with_callback_data(mut self, callback_data: &str) -> Self {
+        self.callback_data = Some(callback_data.to_string());
+        self
+    }
+
+    /// Returns the sender number.
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the client the sender sends with.
+    pub fn client(&self) -> &WhatsAppClient {
+        &self.client
+    }
+
+    /// Builds the request body of a message to `to`, with the sender settings.
+    pub fn request_body<T: Serialize + Validate>(
+        &self,
+        to: &str,
+        content: T,
+    ) -> SendContentRequestBody<T> {
+        let mut request_body = SendContentRequestBody::with_content(&self.from, to, content);
+        request_body.notify_url = self.notify_url.clone();
+        request_body.callback_data = self.callback_data.clone();
+
+        request_body
+    }
+
+    sender_send_methods! {
+        /// Sends a text message, see `WhatsAppClient::send_text`.
+        send_text(TextContent) -> SendTextResponseBody;
+        /// Sends a document message, see `WhatsAppClient::send_document`.
+        send_document(DocumentContent) -> SendDocumentResponseBody;
+        /// Sends an image message, see `WhatsAppClient::send_image`.
+        send_image(ImageContent) -> SendImageResponseBody;
+        /// Sends an audio message, see `WhatsAppClient::send_audio`.
+        send_audio(AudioContent) -> SendAudioResponseBody;
+        /// Sends a video message, see `WhatsAppClient::send_video`.
+        send_video(VideoContent) -> SendVideoResponseBody;
+        /// Sends a sticker message, see `WhatsAppClient::send_sticker`.
+        send_sticker(StickerContent) -> SendStickerResponseBody;
+        /// Sends a location message, see `WhatsAppClient::send_location`.
+        send_location(LocationContent) -> SendLocationResponseBody;
+        /// Sends a contact message, see `WhatsAppClient::send_contact`.
+        send_contact(ContactContent) -> SendContactResponseBody;
+        /// Sends an interactive buttons message, see `WhatsAppClient::send_interactive_buttons`.
+        send_interactive_buttons(InteractiveButtonsContent) -> SendInteractiveButtonsResponseBody;
+        /// Sends an interactive list message, see `WhatsAppClient::send_interactive_list`.
+        send_interactive_list(InteractiveListContent) -> SendInteractiveListResponseBody;
+        /// Sends an interactive product message, see `WhatsAppClient::send_interactive_product`.
+        send_interactive_product(InteractiveProductContent) -> SendInteractiveProductResponseBody;
+        /// Sends an interactive multi-product message, see
+        /// `WhatsAppClient::send_interactive_multiproduct`.
+        send_interactive_multiproduct(InteractiveMultiproductContent)
+            -> SendInteractiveMultiproductResponseBody;
+        /// Sends an interactive flow message, see `WhatsAppClient::send_interactive_flow`.
+        send_interactive_flow(InteractiveFlowContent) -> SendInteractiveFlowResponseBody;
+        /// Sends an interactive order details message, see
+        /// `WhatsAppClient::send_interactive_order_details`.
+        send_interactive_order_details(InteractiveOrderDetailsContent)
+            -> SendInteractiveOrderDetailsResponseBody;
+        /// Sends an interactive order status message, see
+        /// `WhatsAppClient::send_interactive_order_status`.
+        send_interactive_order_status(InteractiveOrderStatusContent)
+            -> SendInteractiveOrderStatusResponseBody;
+    }
+}
 
 /// Main asynchronous client for the Infobip WhatsApp channel.
 #[derive(Clone, Debug)]
@@ -50,17 +225,34 @@ pub struct WhatsAppClient {
 
 impl WhatsAppClient {
     /// Builds and returns a new asynchronous `WhatsAppClient` with a specified configuration.
+    /// The client gets its own connection pool, see `with_http_client` to share one.
     pub fn with_configuration(configuration: Configuration) -> Self {
+        Self::with_http_client(build_http_client(&configuration), configuration)
+    }
+
+    /// Builds and returns a new asynchronous `WhatsAppClient` that sends requests with the given HTTP
+    /// client. See `api::client` for sharing an HTTP client between channel clients.
+    pub fn with_http_client(http_client: reqwest::Client, configuration: Configuration) -> Self {
         WhatsAppClient {
-            configuration,
-            http_client: reqwest::Client::new(),
+            configuration: configuration.for_channel(Channel::WhatsApp),
+            http_client,
+        }
+    }
+
+    /// Returns a handle that sends messages from the given sender number.
+    pub fn sender(&self, from: &str) -> WhatsAppSender {
+        WhatsAppSender {
+            client: self.clone(),
+            from: from.to_string(),
+            notify_url: None,
+            callback_data: None,
         }
     }
 
     async fn send_request<T: Validate + Serialize>(
         &self,
         request_body: T,
-        parameters: HashMap<String, String>,
+        query_parameters: impl QueryParams,
         method: Method,
         path: &str,
     ) -> Result<Response, SdkError> {
@@ -68,13 +260,25 @@ impl WhatsAppClient {
             &self.http_client,
             &self.configuration,
             request_body,
-            parameters,
+            query_parameters,
             method,
             path,
         )
         .await
     }
 
+    // Sends a message, with the CPaaS X IDs of the configuration filled in.
+    async fn send_message_request<T: Validate + Serialize + PlatformIds>(
+        &self,
+        mut request_body: T,
+        path: &str,
+    ) -> Result<Response, SdkError> {
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        self.send_request(request_body, (), Method::POST, path)
+            .await
+    }
+
     /// Send a text message to a single recipient. Text messages can only be successfully delivered
     /// if the recipient has contacted the business within the last 24 hours, otherwise template
     /// message should be used.
@@ -107,20 +311,39 @@ impl WhatsAppClient {
         request_body: SendTextRequestBody,
     ) -> Result<SdkResponse<SendTextResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_TEXT)
+            .send_message_request(request_body, PATH_SEND_TEXT)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
+        build_response(response).await
+    }
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+    /// Send a text message with just the text, see `send_text`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client
+    ///     .send_simple_text("44444444444", "55555555555", "Hello, Rustacean!")
+    ///     .await?;
+    ///
+    /// println!("{:?}", response.body.message_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_simple_text(
+        &self,
+        from: &str,
+        to: &str,
+        text: &str,
+    ) -> Result<SdkResponse<SendTextResponseBody>, SdkError> {
+        self.send_text(SendTextRequestBody::new(from, to, TextContent::new(text)))
+            .await
     }
 
     /// Send a document to a single recipient. Document messages can only be successfully delivered
@@ -155,25 +378,10 @@ impl WhatsAppClient {
         request_body: SendDocumentRequestBody,
     ) -> Result<SdkResponse<SendDocumentResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_DOCUMENT,
-            )
+            .send_message_request(request_body, PATH_SEND_DOCUMENT)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send an image to a single recipient. Image messages can only be successfully delivered if
@@ -208,20 +416,10 @@ impl WhatsAppClient {
         request_body: SendImageRequestBody,
     ) -> Result<SdkResponse<SendImageResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_IMAGE)
+            .send_message_request(request_body, PATH_SEND_IMAGE)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send an audio to a single recipient. Audio messages can only be successfully delivered if
@@ -256,20 +454,10 @@ impl WhatsAppClient {
         request_body: SendAudioRequestBody,
     ) -> Result<SdkResponse<SendAudioResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_AUDIO)
+            .send_message_request(request_body, PATH_SEND_AUDIO)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send a video to a single recipient. Video messages can only be successfully delivered if
@@ -304,20 +492,10 @@ impl WhatsAppClient {
         request_body: SendVideoRequestBody,
     ) -> Result<SdkResponse<SendVideoResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, PATH_SEND_VIDEO)
+            .send_message_request(request_body, PATH_SEND_VIDEO)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send a sticker to a single recipient. Sticker messages can only be successfully delivered
@@ -352,25 +530,10 @@ impl WhatsAppClient {
         request_body: SendStickerRequestBody,
     ) -> Result<SdkResponse<SendStickerResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_STICKER,
-            )
+            .send_message_request(request_body, PATH_SEND_STICKER)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send a location to a single recipient. Location messages can only be successfully
@@ -405,25 +568,10 @@ impl WhatsAppClient {
         request_body: SendLocationRequestBody,
     ) -> Result<SdkResponse<SendLocationResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_LOCATION,
-            )
+            .send_message_request(request_body, PATH_SEND_LOCATION)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send a contact to a single recipient. Contact messages can only be successfully delivered
@@ -459,25 +607,10 @@ impl WhatsAppClient {
         request_body: SendContactRequestBody,
     ) -> Result<SdkResponse<SendContactResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_CONTACT,
-            )
+            .send_message_request(request_body, PATH_SEND_CONTACT)
             .await?;
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send an interactive buttons message to a single recipient. Interactive buttons messages
@@ -521,24 +654,9 @@ impl WhatsAppClient {
         request_body: SendInteractiveButtonsRequestBody,
     ) -> Result<SdkResponse<SendInteractiveButtonsResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_INTERACTIVE_BUTTONS,
-            )
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_BUTTONS)
             .await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send an interactive list message to a single recipient. Interactive list messages can only
@@ -584,24 +702,9 @@ impl WhatsAppClient {
         request_body: SendInteractiveListRequestBody,
     ) -> Result<SdkResponse<SendInteractiveListResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_INTERACTIVE_LIST,
-            )
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_LIST)
             .await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send an interactive product message to a single recipient. Interactive product messages
@@ -639,24 +742,9 @@ impl WhatsAppClient {
         request_body: SendInteractiveProductRequestBody,
     ) -> Result<SdkResponse<SendInteractiveProductResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_INTERACTIVE_PRODUCT,
-            )
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_PRODUCT)
             .await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
     /// Send an interactive multi-product message to a single recipient. Interactive multi-product
@@ -702,40 +790,26 @@ impl WhatsAppClient {
         request_body: SendInteractiveMultiproductRequestBody,
     ) -> Result<SdkResponse<SendInteractiveMultiproductResponseBody>, SdkError> {
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_INTERACTIVE_MULTIPRODUCT,
-            )
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_MULTIPRODUCT)
             .await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    /// Create a WhatsApp template. Created template will be submitted for WhatsApp's review and
-    /// approval. Once approved, template can be sent to end-users. Refer to template guidelines
-    /// for additional info.
+    /// Send an interactive flow message to a single recipient. The flow is a form that opens
+    /// inside the chat. Interactive flow messages can only be successfully delivered if the
+    /// recipient has contacted the business within the last 24 hours, otherwise template message
+    /// should be used.
     ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
     /// # use infobip_sdk::configuration::Configuration;
     /// # use infobip_sdk::model::whatsapp::{
-    /// #     CreateTemplateRequestBody,
-    /// #     TemplateStructure,
-    /// #     TemplateLanguage,
-    /// #     TemplateCategory,
-    /// #     TemplateBody,
+    /// #     InteractiveBody,
+    /// #     InteractiveFlowAction,
+    /// #     InteractiveFlowActionPayload,
+    /// #     InteractiveFlowContent,
+    /// #     SendInteractiveFlowRequestBody,
     /// # };
     /// # use reqwest::StatusCode;
     /// #
@@ -743,166 +817,264 @@ impl WhatsAppClient {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let structure = TemplateStructure::new(TemplateBody::new("Hello World"));
-    /// let request_body = CreateTemplateRequestBody::new(
-    ///     "template_name",
-    ///     TemplateLanguage::EnUs,
-    ///     TemplateCategory::Marketing,
-    ///     structure,
+    /// let mut action = InteractiveFlowAction::new("1234567890", "Book now");
+    /// action.flow_action_payload = Some(InteractiveFlowActionPayload::new("BOOKING"));
+    ///
+    /// let request_body = SendInteractiveFlowRequestBody::new(
+    ///     "44444444444",
+    ///     "55555555555",
+    ///     InteractiveFlowContent::new(InteractiveBody::new("Book your appointment"), action),
     /// );
     ///
-    /// let response = wa_client.create_template(
-    ///     "1234567891011",
-    ///     request_body)
-    /// .await.unwrap();
+    /// let response = wa_client.send_interactive_flow(request_body).await?;
     ///
     /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_template(
+    pub async fn send_interactive_flow(
         &self,
-        sender: &str,
-        request_body: CreateTemplateRequestBody,
-    ) -> Result<SdkResponse<CreateTemplateResponseBody>, SdkError> {
-        let path = PATH_CREATE_TEMPLATE.replace("{sender}", sender);
+        request_body: SendInteractiveFlowRequestBody,
+    ) -> Result<SdkResponse<SendInteractiveFlowResponseBody>, SdkError> {
+        let response = self
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_FLOW)
+            .await?;
+        build_response(response).await
+    }
+
+    /// Send an order details message to a single recipient, asking to review and pay for an
+    /// order. Order details messages can only be successfully delivered if the recipient has
+    /// contacted the business within the last 24 hours, otherwise template message should be
+    /// used.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     InteractiveBody,
+    /// #     InteractiveOrderDetailsAction,
+    /// #     InteractiveOrderDetailsContent,
+    /// #     OrderAmount,
+    /// #     OrderDetails,
+    /// #     OrderItem,
+    /// #     OrderPayment,
+    /// #     SendInteractiveOrderDetailsRequestBody,
+    /// # };
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let item = OrderItem::new("T-shirt", OrderAmount::new(50000, 100), 2);
+    /// let order = OrderDetails::new(vec![item], OrderAmount::new(100000, 100));
+    /// let action = InteractiveOrderDetailsAction::new(
+    ///     OrderPayment::new("payment-configuration", "order-1234"),
+    ///     "INR",
+    ///     OrderAmount::new(100000, 100),
+    ///     order,
+    /// );
+    ///
+    /// let request_body = SendInteractiveOrderDetailsRequestBody::new(
+    ///     "44444444444",
+    ///     "55555555555",
+    ///     InteractiveOrderDetailsContent::new(InteractiveBody::new("Your order"), action),
+    /// );
+    ///
+    /// let response = wa_client.send_interactive_order_details(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_interactive_order_details(
+        &self,
+        request_body: SendInteractiveOrderDetailsRequestBody,
+    ) -> Result<SdkResponse<SendInteractiveOrderDetailsResponseBody>, SdkError> {
+        let response = self
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_ORDER_DETAILS)
+            .await?;
+        build_response(response).await
+    }
 
+    /// Send an order status message to a single recipient, updating the status of an order
+    /// previously sent with an order details message.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     InteractiveBody,
+    /// #     InteractiveOrderStatusAction,
+    /// #     InteractiveOrderStatusContent,
+    /// #     OrderStatus,
+    /// #     SendInteractiveOrderStatusRequestBody,
+    /// # };
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let action = InteractiveOrderStatusAction::new("order-1234", OrderStatus::Shipped);
+    /// let request_body = SendInteractiveOrderStatusRequestBody::new(
+    ///     "44444444444",
+    ///     "55555555555",
+    ///     InteractiveOrderStatusContent::new(InteractiveBody::new("Your order is on its way"), action),
+    /// );
+    ///
+    /// let response = wa_client.send_interactive_order_status(request_body).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_interactive_order_status(
+        &self,
+        request_body: SendInteractiveOrderStatusRequestBody,
+    ) -> Result<SdkResponse<SendInteractiveOrderStatusResponseBody>, SdkError> {
         let response = self
-            .send_request(request_body, HashMap::new(), Method::POST, path.as_str())
+            .send_message_request(request_body, PATH_SEND_INTERACTIVE_ORDER_STATUS)
             .await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    ///  all the templates and their statuses for a given sender.
+    /// Show a typing indicator to the user who sent the inbound message `message_id`, so they know
+    /// a reply is being prepared. The message is also marked as read.
     ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
     /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::SendTypingIndicatorRequestBody;
     /// # use reqwest::StatusCode;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let response = wa_client.templates("12345789101112").await.unwrap();
+    /// let response = wa_client
+    ///     .send_typing_indicator(
+    ///         "44444444444",
+    ///         "ABEGVUGWh3gEAgo-sBzm3mz4hQrq",
+    ///         SendTypingIndicatorRequestBody::new(),
+    ///     )
+    ///     .await?;
     ///
     /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn templates(
+    pub async fn send_typing_indicator(
         &self,
         sender: &str,
-    ) -> Result<SdkResponse<TemplatesResponseBody>, SdkError> {
-        let path = PATH_GET_TEMPLATES.replace("{sender}", sender);
-
-        let response = send_no_body_request(
-            &self.http_client,
-            &self.configuration,
-            HashMap::new(),
-            Method::GET,
-            path.as_str(),
-        )
-        .await?;
+        message_id: &str,
+        request_body: SendTypingIndicatorRequestBody,
+    ) -> Result<SdkResponse<NoContent>, SdkError> {
+        let path = PATH_SEND_TYPING_INDICATOR
+            .replace("{sender}", sender)
+            .replace("{messageId}", message_id);
 
-        let status = response.status();
-        let text = response.text().await?;
+        let response = self
+            .send_request(request_body, (), Method::POST, path.as_str())
+            .await?;
 
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+        build_response(response).await
     }
 
-    /// Delete a WhatsApp template.
-    ///
-    /// If registered in multiple languages, deleting the message template will also delete all its
-    /// languages.
-    /// The template will be deleted for all senders registered under the same WhatsApp Business
-    /// Account (WABA).
-    /// The system will attempt to deliver sent messages for 30 days, regardless of the template
-    /// deletion.
-    /// Once deleted, the name of the approved template cannot be reused for 30 days.
+    /// Check which phone numbers can receive WhatsApp messages from the sender, before sending
+    /// to them. Up to `MAX_CONTACTS_PER_CHECK` numbers can be checked in one request, see
+    /// `check_contacts_batch` for more.
     ///
     /// # Example
     /// ```no_run
     /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
     /// # use infobip_sdk::configuration::Configuration;
-    /// # use reqwest::StatusCode;
+    /// # use infobip_sdk::model::whatsapp::CheckContactsRequestBody;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let status = wa_client.delete_template(
-    ///     "1234567891011",
-    ///     "template_name"
-    /// )
-    /// .await.unwrap();
+    /// let request_body = CheckContactsRequestBody::new(vec![
+    ///     "441134960001".to_string(),
+    ///     "441134960002".to_string(),
+    /// ]);
     ///
-    /// assert_eq!(status, StatusCode::OK);
+    /// let response = wa_client.check_contacts("441134960000", request_body).await?;
+    ///
+    /// for number in response.body.not_enabled() {
+    ///     println!("send SMS to {}", number);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete_template(
+    pub async fn check_contacts(
         &self,
         sender: &str,
-        template_name: &str,
-    ) -> Result<reqwest::StatusCode, SdkError> {
-        let path = PATH_DELETE_TEMPLATE
-            .replace("{sender}", sender)
-            .replace("{templateName}", template_name);
+        request_body: CheckContactsRequestBody,
+    ) -> Result<SdkResponse<CheckContactsResponseBody>, SdkError> {
+        let path = PATH_CHECK_CONTACTS.replace("{sender}", sender);
 
-        let response = send_no_body_request(
-            &self.http_client,
-            &self.configuration,
-            HashMap::new(),
-            Method::DELETE,
-            path.as_str(),
-        )
-        .await?;
+        let response = self
+            .send_request(request_body, (), Method::POST, path.as_str())
+            .await?;
+
+        build_response(response).await
+    }
+
+    /// Check any number of phone numbers, split into requests of at most `chunk_size` numbers,
+    /// capped at `MAX_CONTACTS_PER_CHECK`. The results of all requests are returned together, in
+    /// the order of the numbers. Stops at the first failed request.
+    pub async fn check_contacts_batch<I>(
+        &self,
+        sender: &str,
+        contacts: I,
+        chunk_size: usize,
+    ) -> Result<SdkResponse<CheckContactsResponseBody>, SdkError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let started = Instant::now();
+        let contacts: Vec<String> = contacts.into_iter().collect();
+        let chunk_size = chunk_size.clamp(1, MAX_CONTACTS_PER_CHECK);
+        let mut checks = Vec::with_capacity(contacts.len());
+        let mut status = reqwest::StatusCode::OK;
 
-        let status = response.status();
+        for chunk in contacts.chunks(chunk_size) {
+            let response = self
+                .check_contacts(sender, CheckContactsRequestBody::new(chunk.to_vec()))
+                .await?;
+            status = response.status;
 
-        if status.is_success() {
-            Ok(status)
-        } else {
-            let text = response.text().await?;
-            Err(build_api_error(status, &text))
+            checks.extend(response.body.contacts.unwrap_or_default());
         }
+
+        Ok(SdkResponse {
+            body: CheckContactsResponseBody {
+                contacts: Some(checks),
+            },
+            status,
+            duration: started.elapsed(),
+        })
     }
 
-    /// Send a single or multiple template messages to one or more recipients. Template messages
-    /// can be sent and delivered at anytime. Each template needs to be registered and pre-approved
-    /// by WhatsApp.
+    /// Create a WhatsApp template. Created template will be submitted for WhatsApp's review and
+    /// approval. Once approved, template can be sent to end-users. Refer to template guidelines
+    /// for additional info.
     ///
     /// # Example
-    ///
     /// ```no_run
     /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
     /// # use infobip_sdk::configuration::Configuration;
     /// # use infobip_sdk::model::whatsapp::{
-    /// #     FailoverMessage,
-    /// #     TemplateBodyContent,
-    /// #     TemplateContent,
-    /// #     TemplateData,
+    /// #     CreateTemplateRequestBody,
+    /// #     TemplateStructure,
     /// #     TemplateLanguage,
-    /// #     SendTemplateRequestBody
+    /// #     TemplateCategory,
+    /// #     TemplateBody,
     /// # };
     /// # use reqwest::StatusCode;
     /// #
@@ -910,45 +1082,703 @@ impl WhatsAppClient {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
     ///
-    /// let body = TemplateBodyContent::new(vec!["placeholder1".to_string()]);
-    /// let data = TemplateData::new(body);
-    /// let content = TemplateContent::new("template_name", data, TemplateLanguage::EnUs);
-    /// let message = FailoverMessage::new(
-    ///     "1234567891011",
-    ///     "1234567891012",
-    ///     content,
+    /// let structure = TemplateStructure::new(TemplateBody::new("Hello World"));
+    /// let request_body = CreateTemplateRequestBody::new(
+    ///     "template_name",
+    ///     TemplateLanguage::EnUs,
+    ///     TemplateCategory::Marketing,
+    ///     structure,
     /// );
-    /// let request_body = SendTemplateRequestBody::new(vec![message]);
     ///
-    /// let response = wa_client.send_template(request_body).await.unwrap();
+    /// let response = wa_client.create_template(
+    ///     "1234567891011",
+    ///     request_body)
+    /// .await.unwrap();
     ///
     /// assert_eq!(response.status, StatusCode::OK);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn send_template(
+    pub async fn create_template(
         &self,
-        request_body: SendTemplateRequestBody,
-    ) -> Result<SdkResponse<SendTemplateResponseBody>, SdkError> {
+        sender: &str,
+        request_body: CreateTemplateRequestBody,
+    ) -> Result<SdkResponse<CreateTemplateResponseBody>, SdkError> {
+        let path = PATH_CREATE_TEMPLATE.replace("{sender}", sender);
+
         let response = self
-            .send_request(
-                request_body,
-                HashMap::new(),
-                Method::POST,
-                PATH_SEND_TEMPLATE,
-            )
+            .send_request(request_body, (), Method::POST, path.as_str())
             .await?;
+        build_response(response).await
+    }
 
-        let status = response.status();
-        let text = response.text().await?;
-
-        if status.is_success() {
-            Ok(SdkResponse {
-                body: serde_json::from_str(&text)?,
-                status,
-            })
-        } else {
-            Err(build_api_error(status, &text))
-        }
+    /// Browse the library of pre-approved template examples, optionally searching and
+    /// filtering them. Register one for a sender with `create_template_from_library`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{TemplateLanguage, TemplateLibraryQueryParameters};
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_parameters = TemplateLibraryQueryParameters::new();
+    /// query_parameters.search = Some("delivery".to_string());
+    /// query_parameters.language = Some(TemplateLanguage::EnUs);
+    ///
+    /// let response = wa_client.template_library(query_parameters).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn template_library(
+        &self,
+        query_parameters: TemplateLibraryQueryParameters,
+    ) -> Result<SdkResponse<TemplateLibraryResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            query_parameters,
+            Method::GET,
+            PATH_GET_TEMPLATE_LIBRARY,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Register a template from the template library for a sender. Its content was already
+    /// approved, so the template is usually approved right away.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     CreateLibraryTemplateRequestBody, LibraryTemplateButtonInput, TemplateCategory,
+    /// #     TemplateLanguage,
+    /// # };
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = CreateLibraryTemplateRequestBody::new(
+    ///     "delivery_update",
+    ///     TemplateLanguage::EnUs,
+    ///     TemplateCategory::Utility,
+    ///     "delivery_update_1",
+    /// );
+    /// request_body.library_template_button_inputs =
+    ///     Some(vec![LibraryTemplateButtonInput::url("https://www.example.com/track")]);
+    ///
+    /// let response = wa_client
+    ///     .create_template_from_library("1234567891011", request_body)
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_template_from_library(
+        &self,
+        sender: &str,
+        request_body: CreateLibraryTemplateRequestBody,
+    ) -> Result<SdkResponse<CreateLibraryTemplateResponseBody>, SdkError> {
+        let path = PATH_CREATE_TEMPLATE.replace("{sender}", sender);
+
+        let response = self
+            .send_request(request_body, (), Method::POST, path.as_str())
+            .await?;
+        build_response(response).await
+    }
+
+    ///  all the templates and their statuses for a given sender.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client.templates("12345789101112").await.unwrap();
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn templates(
+        &self,
+        sender: &str,
+    ) -> Result<SdkResponse<TemplatesResponseBody>, SdkError> {
+        let path = PATH_GET_TEMPLATES.replace("{sender}", sender);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get a single template and its status, quality score and rejection reason, by template ID.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client.template("12345789101112", "111").await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn template(
+        &self,
+        sender: &str,
+        template_id: &str,
+    ) -> Result<SdkResponse<TemplateResponseBody>, SdkError> {
+        let path = PATH_GET_TEMPLATE
+            .replace("{sender}", sender)
+            .replace("{id}", template_id);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            Method::GET,
+            path.as_str(),
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get a single template by its name and language. The body is `None` if the sender has no
+    /// such template.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::TemplateLanguage;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client
+    ///     .template_by_name("12345789101112", "template_name", TemplateLanguage::EnUs)
+    ///     .await?;
+    ///
+    /// if let Some(template) = response.body {
+    ///     println!("{:?}", template.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn template_by_name(
+        &self,
+        sender: &str,
+        name: &str,
+        language: TemplateLanguage,
+    ) -> Result<SdkResponse<Option<TemplateResponseBody>>, SdkError> {
+        let response = self.templates(sender).await?;
+
+        Ok(SdkResponse {
+            body: response.body.find(name, language).cloned(),
+            status: response.status,
+            duration: response.duration,
+        })
+    }
+
+    /// Edit a WhatsApp template. Only the category and structure can be changed; approved
+    /// templates go through review again after editing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     TemplateBody,
+    /// #     TemplateStructure,
+    /// #     UpdateTemplateRequestBody,
+    /// # };
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut request_body = UpdateTemplateRequestBody::new();
+    /// request_body.structure = Some(TemplateStructure::new(TemplateBody::new("Hello again")));
+    ///
+    /// let response = wa_client
+    ///     .update_template("12345789101112", "111", request_body)
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_template(
+        &self,
+        sender: &str,
+        template_id: &str,
+        request_body: UpdateTemplateRequestBody,
+    ) -> Result<SdkResponse<UpdateTemplateResponseBody>, SdkError> {
+        let path = PATH_UPDATE_TEMPLATE
+            .replace("{sender}", sender)
+            .replace("{id}", template_id);
+
+        let response = self
+            .send_request(request_body, (), Method::PATCH, path.as_str())
+            .await?;
+        build_response(response).await
+    }
+
+    /// Delete a WhatsApp template.
+    ///
+    /// If registered in multiple languages, deleting the message template will also delete all its
+    /// languages.
+    /// The template will be deleted for all senders registered under the same WhatsApp Business
+    /// Account (WABA).
+    /// The system will attempt to deliver sent messages for 30 days, regardless of the template
+    /// deletion.
+    /// Once deleted, the name of the approved template cannot be reused for 30 days.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client.delete_template(
+    ///     "1234567891011",
+    ///     "template_name"
+    /// )
+    /// .await.unwrap();
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_template(
+        &self,
+        sender: &str,
+        template_name: &str,
+    ) -> Result<SdkResponse<NoContent>, SdkError> {
+        let path = PATH_DELETE_TEMPLATE
+            .replace("{sender}", sender)
+            .replace("{templateName}", template_name);
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            (),
+            Method::DELETE,
+            path.as_str(),
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Send a single or multiple template messages to one or more recipients. Template messages
+    /// can be sent and delivered at anytime. Each template needs to be registered and pre-approved
+    /// by WhatsApp.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     FailoverMessage,
+    /// #     TemplateBodyContent,
+    /// #     TemplateContent,
+    /// #     TemplateData,
+    /// #     TemplateLanguage,
+    /// #     SendTemplateRequestBody
+    /// # };
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let body = TemplateBodyContent::new(vec!["placeholder1".to_string()]);
+    /// let data = TemplateData::new(body);
+    /// let content = TemplateContent::new("template_name", data, TemplateLanguage::EnUs);
+    /// let message = FailoverMessage::new(
+    ///     "1234567891011",
+    ///     "1234567891012",
+    ///     content,
+    /// );
+    /// let request_body = SendTemplateRequestBody::new(vec![message]);
+    ///
+    /// let response = wa_client.send_template(request_body).await.unwrap();
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_template(
+        &self,
+        mut request_body: SendTemplateRequestBody,
+    ) -> Result<SdkResponse<SendTemplateResponseBody>, SdkError> {
+        fill_platform_ids(&mut request_body, &self.configuration);
+
+        let response = self
+            .send_request(&request_body, (), Method::POST, PATH_SEND_TEMPLATE)
+            .await?;
+
+        let response = build_response(response).await?;
+        if let Some(collector) = self.configuration.response_validation() {
+            collector.check(PATH_SEND_TEMPLATE, &request_body, &response.body);
+        }
+
+        Ok(response)
+    }
+
+    /// Send a large number of template messages, splitting them into requests of at most
+    /// `chunk_size` messages each. The whole batch is validated before anything is sent. The
+    /// returned messages keep the order of the request messages, so they can be correlated by
+    /// index or message ID. If a bulk ID is set and more than one request is needed, each chunk
+    /// gets the bulk ID suffixed with its index, e.g. `my-bulk-0`, `my-bulk-1`.
+    ///
+    /// Sending stops at the first failed request. The returned [`TemplateBatchError`] holds the
+    /// results of the chunks accepted before it, and the number of messages they carried.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::{WhatsAppClient, DEFAULT_TEMPLATE_BATCH_SIZE};
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::{
+    /// #     FailoverMessage,
+    /// #     TemplateBodyContent,
+    /// #     TemplateContent,
+    /// #     TemplateData,
+    /// #     TemplateLanguage,
+    /// #     SendTemplateRequestBody
+    /// # };
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let messages = (0..1000)
+    ///     .map(|i| {
+    ///         let body = TemplateBodyContent::new(vec![format!("customer {}", i)]);
+    ///         let content =
+    ///             TemplateContent::new("template_name", TemplateData::new(body), TemplateLanguage::EnUs);
+    ///         FailoverMessage::new("1234567891011", "1234567891012", content)
+    ///     })
+    ///     .collect();
+    /// let request_body = SendTemplateRequestBody::new(messages);
+    ///
+    /// let response = wa_client
+    ///     .send_template_batch(request_body, DEFAULT_TEMPLATE_BATCH_SIZE)
+    ///     .await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// assert_eq!(response.body.messages.len(), 1000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_template_batch(
+        &self,
+        request_body: SendTemplateRequestBody,
+        chunk_size: usize,
+    ) -> Result<SdkResponse<SendTemplateBatchResponseBody>, TemplateBatchError> {
+        let mut batch = SendTemplateBatchResponseBody::default();
+        let mut sent = 0;
+
+        if let Err(error) = request_body.validate() {
+            return Err(TemplateBatchError {
+                sent,
+                accepted: batch,
+                source: error.into(),
+            });
+        }
+
+        let started = Instant::now();
+        let chunk_size = chunk_size.max(1);
+        let chunked = request_body.messages.len() > chunk_size;
+        let mut status = reqwest::StatusCode::OK;
+
+        for (index, messages) in request_body.messages.chunks(chunk_size).enumerate() {
+            let bulk_id = match &request_body.bulk_id {
+                Some(bulk_id) if chunked => Some(format!("{}-{}", bulk_id, index)),
+                bulk_id => bulk_id.clone(),
+            };
+            let chunk = SendTemplateRequestBody {
+                messages: messages.to_vec(),
+                bulk_id,
+            };
+
+            let response = match self.send_template(chunk).await {
+                Ok(response) => response,
+                Err(source) => {
+                    return Err(TemplateBatchError {
+                        sent,
+                        accepted: batch,
+                        source,
+                    })
+                }
+            };
+            status = response.status;
+            sent += messages.len();
+
+            if let Some(bulk_id) = response.body.bulk_id {
+                batch.bulk_ids.push(bulk_id);
+            }
+            batch
+                .messages
+                .extend(response.body.messages.unwrap_or_default());
+        }
+
+        Ok(SdkResponse {
+            body: batch,
+            status,
+            duration: started.elapsed(),
+        })
+    }
+
+    /// Get delivery reports of sent WhatsApp messages, for integrations that can't receive them
+    /// on a webhook. Each report is returned only once, and reports are kept for the last 48
+    /// hours.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::DeliveryReportsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_parameters = DeliveryReportsQueryParameters::new();
+    /// query_parameters.limit = Some(100);
+    ///
+    /// let response = wa_client.delivery_reports(query_parameters).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delivery_reports(
+        &self,
+        query_parameters: DeliveryReportsQueryParameters,
+    ) -> Result<SdkResponse<DeliveryReportsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            query_parameters,
+            Method::GET,
+            PATH_GET_DELIVERY_REPORTS,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get logs of sent WhatsApp messages. Unlike delivery reports, logs can be fetched more than
+    /// once and filtered. Logs are available for the last 48 hours.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::LogsQueryParameters;
+    /// # use reqwest::StatusCode;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let mut query_parameters = LogsQueryParameters::new();
+    /// query_parameters.general_status = Some("UNDELIVERABLE".to_string());
+    ///
+    /// let response = wa_client.logs(query_parameters).await?;
+    ///
+    /// assert_eq!(response.status, StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn logs(
+        &self,
+        query_parameters: LogsQueryParameters,
+    ) -> Result<SdkResponse<LogsResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            query_parameters,
+            Method::GET,
+            PATH_GET_LOGS,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get the quality rating, status and messaging limit tier of senders, e.g. to plan how many
+    /// new conversations can be started, or to alert before a sender gets restricted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use infobip_sdk::model::whatsapp::SenderQualityQueryParameters;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let query_parameters = SenderQualityQueryParameters::new(vec!["441134960000".to_string()]);
+    ///
+    /// let response = wa_client.sender_quality(query_parameters).await?;
+    ///
+    /// for quality in response.body.at_risk() {
+    ///     println!("{:?} is {:?}", quality.sender, quality.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sender_quality(
+        &self,
+        query_parameters: SenderQualityQueryParameters,
+    ) -> Result<SdkResponse<SenderQualityResponseBody>, SdkError> {
+        query_parameters.validate()?;
+
+        let response = send_no_body_request(
+            &self.http_client,
+            &self.configuration,
+            query_parameters,
+            Method::GET,
+            PATH_GET_SENDER_QUALITY,
+        )
+        .await?;
+
+        build_response(response).await
+    }
+
+    /// Get the current status of a sent WhatsApp message, for reconciling messages without
+    /// waiting for delivery reports. The status is read from the message log, so it is `None`
+    /// for unknown messages and messages sent more than 48 hours ago.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client.message_status("some-message-id").await?;
+    ///
+    /// if let Some(status) = response.body {
+    ///     println!("{:?}: {:?}", status.group(), status.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn message_status(
+        &self,
+        message_id: &str,
+    ) -> Result<SdkResponse<Option<Status>>, SdkError> {
+        let mut query_parameters = LogsQueryParameters::new();
+        query_parameters.message_id = Some(message_id.to_string());
+        query_parameters.limit = Some(1);
+
+        let response = self.logs(query_parameters).await?;
+        let status = response
+            .body
+            .results
+            .into_iter()
+            .flatten()
+            .find(|log| log.message_id.as_deref() == Some(message_id))
+            .and_then(|log| log.status);
+
+        Ok(SdkResponse {
+            body: status,
+            status: response.status,
+            duration: response.duration,
+        })
+    }
+
+    /// Send a request with a JSON body built by hand, or no body, e.g. a message type added to
+    /// the WhatsApp API after this version of the SDK. The body is not validated, and the
+    /// response body is returned as JSON. See `SmsClient::send_raw`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::api::whatsapp::WhatsAppClient;
+    /// # use infobip_sdk::configuration::Configuration;
+    /// # use reqwest::Method;
+    /// # use serde_json::json;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+    ///
+    /// let response = wa_client
+    ///     .send_raw(Method::GET, "/whatsapp/2/senders/441134960000/templates", None)
+    ///     .await?;
+    ///
+    /// println!("{}", response.body);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_raw(
+        &self,
+        method: Method,
+        path: &str,
+        request_body: Option<serde_json::Value>,
+    ) -> Result<SdkResponse<serde_json::Value>, SdkError> {
+        send_raw_request(
+            &self.http_client,
+            &self.configuration,
+            method,
+            path,
+            request_body,
+        )
+        .await
     }
 }
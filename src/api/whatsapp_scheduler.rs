@@ -0,0 +1,256 @@
+//! Client-side scheduling of WhatsApp messages.
+//!
+//! The WhatsApp API sends messages right away and has no `sendAt` field. The [`WhatsAppScheduler`]
+//! keeps messages in a [`QueueStore`] until they are due, and sends them with a
+//! [`WhatsAppClient`]. Messages are removed from the store before being sent, so each message is
+//! sent at most once; failed sends are reported and not retried.
+//!
+//! # Example
+//! ```no_run
+//! # use std::time::Duration;
+//! # use infobip_sdk::api::whatsapp::WhatsAppClient;
+//! # use infobip_sdk::api::whatsapp_scheduler::{WhatsAppMessage, WhatsAppScheduler};
+//! # use infobip_sdk::configuration::Configuration;
+//! # use infobip_sdk::model::whatsapp::{SendTextRequestBody, TextContent};
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let wa_client = WhatsAppClient::with_configuration(Configuration::from_env_api_key()?);
+//! let scheduler = WhatsAppScheduler::new(wa_client);
+//!
+//! let message = SendTextRequestBody::new("44444444444", "55555555555", TextContent::new("Hi!"));
+//! let send_at = chrono::Utc::now() + chrono::Duration::hours(1);
+//! scheduler.schedule(WhatsAppMessage::Text(message), send_at)?;
+//!
+//! scheduler
+//!     .run(Duration::from_secs(10), |dispatched| {
+//!         println!("{}: {:?}", dispatched.id, dispatched.result.is_ok());
+//!     })
+//!     .await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::api::cancellation::CancellationToken;
+use crate::api::whatsapp::WhatsAppClient;
+use crate::api::{SdkError, SdkResponse};
+use crate::model::whatsapp::{
+    SendAudioRequestBody, SendContactRequestBody, SendContentResponseBody, SendDocumentRequestBody,
+    SendImageRequestBody, SendInteractiveButtonsRequestBody, SendInteractiveListRequestBody,
+    SendLocationRequestBody, SendStickerRequestBody, SendTemplateRequestBody,
+    SendTemplateResponseBody, SendTextRequestBody, SendVideoRequestBody,
+};
+use crate::queue::{MemoryQueueStore, QueueStore, QueuedItem, StoreError};
+
+/// A WhatsApp message that can be scheduled.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "body", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WhatsAppMessage {
+    Text(SendTextRequestBody),
+    Document(SendDocumentRequestBody),
+    Image(SendImageRequestBody),
+    Audio(SendAudioRequestBody),
+    Video(SendVideoRequestBody),
+    Sticker(SendStickerRequestBody),
+    Location(SendLocationRequestBody),
+    Contact(SendContactRequestBody),
+    InteractiveButtons(SendInteractiveButtonsRequestBody),
+    InteractiveList(SendInteractiveListRequestBody),
+    Template(SendTemplateRequestBody),
+}
+
+impl WhatsAppMessage {
+    fn validate(&self) -> Result<(), SdkError> {
+        match self {
+            WhatsAppMessage::Text(body) => body.validate(),
+            WhatsAppMessage::Document(body) => body.validate(),
+            WhatsAppMessage::Image(body) => body.validate(),
+            WhatsAppMessage::Audio(body) => body.validate(),
+            WhatsAppMessage::Video(body) => body.validate(),
+            WhatsAppMessage::Sticker(body) => body.validate(),
+            WhatsAppMessage::Location(body) => body.validate(),
+            WhatsAppMessage::Contact(body) => body.validate(),
+            WhatsAppMessage::InteractiveButtons(body) => body.validate(),
+            WhatsAppMessage::InteractiveList(body) => body.validate(),
+            WhatsAppMessage::Template(body) => body.validate(),
+        }
+        .map_err(SdkError::from)
+    }
+}
+
+/// Response of a sent scheduled message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WhatsAppSendResponse {
+    Content(SendContentResponseBody),
+    Template(SendTemplateResponseBody),
+}
+
+/// Outcome of sending a scheduled message.
+#[derive(Debug)]
+pub struct Dispatched {
+    /// ID returned by [`WhatsAppScheduler::schedule`].
+    pub id: String,
+
+    /// Time the message was scheduled for.
+    pub due_at: DateTime<Utc>,
+
+    /// Result of sending the message.
+    pub result: Result<SdkResponse<WhatsAppSendResponse>, SdkError>,
+}
+
+/// Sends WhatsApp messages at a given time. See the [module documentation](self).
+pub struct WhatsAppScheduler {
+    client: WhatsAppClient,
+    store: Arc<dyn QueueStore<WhatsAppMessage>>,
+    counter: AtomicU64,
+}
+
+impl WhatsAppScheduler {
+    /// Creates a scheduler that keeps scheduled messages in memory.
+    pub fn new(client: WhatsAppClient) -> Self {
+        Self::with_store(client, Arc::new(MemoryQueueStore::new()))
+    }
+
+    /// Creates a scheduler that keeps scheduled messages in the given store.
+    pub fn with_store(client: WhatsAppClient, store: Arc<dyn QueueStore<WhatsAppMessage>>) -> Self {
+        Self {
+            client,
+            store,
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Validates and schedules a message to be sent at `send_at`, returning its ID. Messages due
+    /// in the past are sent on the next dispatch.
+    pub fn schedule(
+        &self,
+        message: WhatsAppMessage,
+        send_at: DateTime<Utc>,
+    ) -> Result<String, SdkError> {
+        message.validate()?;
+
+        let id = format!(
+            "{}-{}",
+            Utc::now().timestamp_millis(),
+            self.counter.fetch_add(1, Ordering::Relaxed)
+        );
+        self.store
+            .push(QueuedItem {
+                id: id.clone(),
+                due_at: send_at,
+                item: message,
+            })
+            .map_err(SdkError::Store)?;
+
+        Ok(id)
+    }
+
+    /// Cancels a scheduled message. Returns `false` if it was already sent or is unknown.
+    pub fn cancel(&self, id: &str) -> Result<bool, StoreError> {
+        Ok(self.store.remove(id)?.is_some())
+    }
+
+    /// Returns the number of messages waiting to be sent.
+    pub fn pending(&self) -> Result<usize, StoreError> {
+        self.store.len()
+    }
+
+    /// Sends all messages due at or before `now`, in order of their due time.
+    pub async fn dispatch_due(&self, now: DateTime<Utc>) -> Result<Vec<Dispatched>, StoreError> {
+        let mut dispatched = Vec::new();
+
+        for queued in self.store.take_due(now)? {
+            let result = self.send(queued.item).await;
+            dispatched.push(Dispatched {
+                id: queued.id,
+                due_at: queued.due_at,
+                result,
+            });
+        }
+
+        Ok(dispatched)
+    }
+
+    /// Checks for due messages every `poll_interval` and sends them, calling `on_dispatch` with
+    /// the outcome of each one. Runs until the future is dropped, so it is usually spawned as a
+    /// task. Store errors are retried on the next check.
+    pub async fn run<F>(&self, poll_interval: Duration, on_dispatch: F)
+    where
+        F: FnMut(Dispatched),
+    {
+        self.run_until_cancelled(poll_interval, &CancellationToken::new(), on_dispatch)
+            .await
+    }
+
+    /// Like `run`, but returns when the token is cancelled. Messages taken from the store are
+    /// always sent and reported before returning, unlike when dropping the `run` future, which
+    /// may lose them.
+    pub async fn run_until_cancelled<F>(
+        &self,
+        poll_interval: Duration,
+        token: &CancellationToken,
+        mut on_dispatch: F,
+    ) where
+        F: FnMut(Dispatched),
+    {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = token.cancelled() => return,
+                _ = interval.tick() => {}
+            }
+
+            if let Ok(dispatched) = self.dispatch_due(Utc::now()).await {
+                dispatched.into_iter().for_each(&mut on_dispatch);
+            }
+        }
+    }
+
+    async fn send(
+        &self,
+        message: WhatsAppMessage,
+    ) -> Result<SdkResponse<WhatsAppSendResponse>, SdkError> {
+        let client = &self.client;
+
+        let response = match message {
+            WhatsAppMessage::Text(body) => client.send_text(body).await?,
+            WhatsAppMessage::Document(body) => client.send_document(body).await?,
+            WhatsAppMessage::Image(body) => client.send_image(body).await?,
+            WhatsAppMessage::Audio(body) => client.send_audio(body).await?,
+            WhatsAppMessage::Video(body) => client.send_video(body).await?,
+            WhatsAppMessage::Sticker(body) => client.send_sticker(body).await?,
+            WhatsAppMessage::Location(body) => client.send_location(body).await?,
+            WhatsAppMessage::Contact(body) => client.send_contact(body).await?,
+            WhatsAppMessage::InteractiveButtons(body) => {
+                client.send_interactive_buttons(body).await?
+            }
+            WhatsAppMessage::InteractiveList(body) => client.send_interactive_list(body).await?,
+            WhatsAppMessage::Template(body) => {
+                let response = client.send_template(body).await?;
+                return Ok(SdkResponse {
+                    body: WhatsAppSendResponse::Template(response.body),
+                    status: response.status,
+                    duration: response.duration,
+                });
+            }
+        };
+
+        Ok(SdkResponse {
+            body: WhatsAppSendResponse::Content(response.body),
+            status: response.status,
+            duration: response.duration,
+        })
+    }
+}
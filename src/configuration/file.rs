@@ -0,0 +1,199 @@
+//! Reading a `Configuration` from a TOML or YAML file, see `Configuration::from_file`.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use reqwest::header::HeaderValue;
+use reqwest::Proxy;
+use serde_derive::Deserialize;
+
+use crate::configuration::{
+    parse_base_url, ApiKey, Channel, Configuration, ConfigurationError, FileError,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigurationFile {
+    base_url: String,
+    api_key: Option<String>,
+    api_key_env: Option<String>,
+    api_key_prefix: Option<String>,
+    timeout_ms: Option<u64>,
+    proxy_url: Option<String>,
+    user_agent_suffix: Option<String>,
+    #[serde(default)]
+    channels: ChannelsFile,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChannelsFile {
+    sms: Option<ChannelFile>,
+    tfa: Option<ChannelFile>,
+    whatsapp: Option<ChannelFile>,
+    email: Option<ChannelFile>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChannelFile {
+    base_url: String,
+}
+
+impl Configuration {
+    /// Reads a `Configuration` from a TOML file, or a YAML file with the same keys, chosen by
+    /// the `.toml`, `.yaml` or `.yml` extension of the path. Needs the `config-file` feature.
+    ///
+    /// | Key | Setting |
+    /// |---|---|
+    /// | `base_url` | Base URL, required. Checked like in `with_api_key`. |
+    /// | `api_key` | API key. |
+    /// | `api_key_env` | Environment variable to read the API key from, instead of `api_key`. |
+    /// | `api_key_prefix` | Prefix of the API key, `App` by default. |
+    /// | `timeout_ms` | Request timeout in milliseconds, see `with_timeout`. |
+    /// | `proxy_url` | Proxy for all requests, see `with_proxy`. |
+    /// | `user_agent_suffix` | Appended to the user agent, see `with_user_agent_suffix`. |
+    /// | `channels.<channel>.base_url` | Base URL of `sms`, `tfa`, `whatsapp` or `email` requests, see `with_channel_base_url`. |
+    ///
+    /// Unknown keys are rejected, so typos don't go unnoticed. Fails with
+    /// `ConfigurationError::File`, listing every invalid setting of the file.
+    ///
+    /// # Example
+    /// ```toml
+    /// base_url = "https://k3v9xx.api.infobip.com"
+    /// api_key_env = "INFOBIP_API_KEY"
+    /// timeout_ms = 10000
+    ///
+    /// [channels.whatsapp]
+    /// base_url = "https://us.api.infobip.com"
+    /// ```
+    ///
+    /// ```no_run
+    /// # use infobip_sdk::configuration::Configuration;
+    /// match Configuration::from_file("infobip.toml") {
+    ///     Ok(configuration) => println!("using {}", configuration.base_url()),
+    ///     Err(error) => eprintln!("check the configuration file: {}", error),
+    /// }
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Configuration, ConfigurationError> {
+        let path = path.as_ref();
+        let error = |problems: Vec<String>| FileError {
+            path: path.display().to_string(),
+            problems,
+        };
+
+        let text = fs::read_to_string(path).map_err(|e| error(vec![e.to_string()]))?;
+        let file: ConfigurationFile = match path.extension().and_then(OsStr::to_str) {
+            Some("toml") => toml::from_str(&text).map_err(|e| error(vec![e.to_string()]))?,
+            Some("yaml") | Some("yml") => {
+                serde_yaml::from_str(&text).map_err(|e| error(vec![e.to_string()]))?
+            }
+            _ => {
+                return Err(error(vec![
+                    "unknown format, use a .toml, .yaml or .yml file".to_string()
+                ])
+                .into())
+            }
+        };
+
+        file.build(|name| env::var(name).ok())
+            .map_err(|problems| error(problems).into())
+    }
+}
+
+impl ConfigurationFile {
+    // Builds the configuration, collecting every problem of the settings.
+    fn build<F: Fn(&str) -> Option<String>>(self, var: F) -> Result<Configuration, Vec<String>> {
+        let mut problems = Vec::new();
+
+        let base_url = match parse_base_url(&self.base_url) {
+            Ok(base_url) => Some(base_url),
+            Err(e) => {
+                problems.push(format!("base_url: {}", e));
+                None
+            }
+        };
+
+        let key = match (self.api_key, self.api_key_env) {
+            (Some(key), None) => Some(key),
+            (None, Some(name)) => {
+                let key = var(&name);
+                if key.is_none() {
+                    problems.push(format!(
+                        "api_key_env: environment variable {} is not set",
+                        name
+                    ));
+                }
+                key
+            }
+            (Some(_), Some(_)) => {
+                problems.push("set either api_key or api_key_env, not both".to_string());
+                None
+            }
+            (None, None) => {
+                problems.push("missing api_key or api_key_env".to_string());
+                None
+            }
+        };
+
+        let timeout = match self.timeout_ms {
+            Some(0) => {
+                problems.push("timeout_ms: must be greater than zero".to_string());
+                None
+            }
+            millis => millis.map(Duration::from_millis),
+        };
+        let proxy = self
+            .proxy_url
+            .and_then(|url| match Proxy::all(url.as_str()) {
+                Ok(proxy) => Some(proxy),
+                Err(e) => {
+                    problems.push(format!("proxy_url: {}", e));
+                    None
+                }
+            });
+        if let Some(suffix) = &self.user_agent_suffix {
+            if HeaderValue::from_str(suffix).is_err() {
+                problems.push("user_agent_suffix: must be visible ASCII characters".to_string());
+            }
+        }
+
+        let mut channel_base_urls = Vec::new();
+        for (channel, name, channel_file) in [
+            (Channel::Sms, "sms", self.channels.sms),
+            (Channel::Tfa, "tfa", self.channels.tfa),
+            (Channel::WhatsApp, "whatsapp", self.channels.whatsapp),
+            (Channel::Email, "email", self.channels.email),
+        ] {
+            if let Some(channel_file) = channel_file {
+                match parse_base_url(&channel_file.base_url) {
+                    Ok(base_url) => channel_base_urls.push((channel, base_url)),
+                    Err(e) => problems.push(format!("channels.{}.base_url: {}", name, e)),
+                }
+            }
+        }
+
+        let (Some(base_url), Some(key)) = (base_url, key) else {
+            return Err(problems);
+        };
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        let api_key = ApiKey {
+            key,
+            prefix: Some(self.api_key_prefix.unwrap_or_else(|| "App".to_string())),
+        };
+        let mut configuration =
+            Configuration::with_api_key(base_url, api_key).map_err(|e| vec![e.to_string()])?;
+        configuration.timeout = timeout;
+        configuration.proxy = proxy;
+        configuration.user_agent_suffix = self.user_agent_suffix;
+        configuration.channel_base_urls.extend(channel_base_urls);
+
+        Ok(configuration)
+    }
+}
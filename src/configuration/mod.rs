@@ -1,35 +1,360 @@
 //! Configuration of the Infobip client
-use std::env::{self, VarError};
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use regex::Regex;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Proxy, Url};
+use thiserror::Error;
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::api::circuit_breaker::CircuitBreaker;
+#[cfg(feature = "sms")]
+use crate::api::deduplication::DeduplicationWindow;
+#[cfg(feature = "sms")]
+use crate::api::preview_cache::PreviewCache;
+#[cfg(feature = "recording")]
+use crate::api::recording::Cassette;
+use crate::api::response_validation::AnomalyCollector;
+
+// Shown instead of secrets in `Debug` output.
+pub(crate) const REDACTED: &str = "***redacted***";
 
 /// Holds the necessary configuration URL and authentication details of an Infobip client.
-#[derive(Debug, Clone)]
+///
+/// The `Debug` output redacts the credentials and shows only the names of the default headers,
+/// so configurations and the clients holding them can be logged.
+#[derive(Clone)]
 pub struct Configuration {
     base_url: String,
+    channel_base_urls: HashMap<Channel, String>,
     basic_auth: Option<BasicAuth>,
     bearer_access_token: Option<String>,
     api_key: Option<ApiKey>,
+    response_validation: Option<AnomalyCollector>,
+    generate_message_ids: bool,
+    default_headers: HeaderMap,
+    compression: bool,
+    application_id: Option<String>,
+    entity_id: Option<String>,
+    timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    user_agent_suffix: Option<String>,
+    circuit_breaker: Option<CircuitBreaker>,
+    slow_request_threshold: Option<Duration>,
+    #[cfg(feature = "sms")]
+    deduplication: Option<DeduplicationWindow>,
+    #[cfg(feature = "sms")]
+    preview_cache: Option<PreviewCache>,
+    #[cfg(feature = "recording")]
+    cassette: Option<Cassette>,
 }
 
 impl Configuration {
     /// Reads API key details and IB_BASE_URL environment variable to build and return a
-    /// `Configuration` instance.
-    pub fn from_env_api_key() -> Result<Configuration, VarError> {
-        Ok(Configuration {
-            base_url: env::var("IB_BASE_URL")?,
-            api_key: Some(ApiKey::from_env()?),
-            basic_auth: None,
-            bearer_access_token: None,
-        })
+    /// `Configuration` instance. The base URL is checked like in `with_api_key`.
+    pub fn from_env_api_key() -> Result<Configuration, ConfigurationError> {
+        let base_url = env::var("IB_BASE_URL")
+            .map_err(|_| ConfigurationError::MissingVar("IB_BASE_URL".to_string()))?;
+
+        Configuration::with_api_key(base_url, ApiKey::from_env()?)
+    }
+
+    /// Reads the base URL and API key like `from_env_api_key`, and the optional settings below,
+    /// from environment variables. All variables are checked before returning, so the error
+    /// lists every missing or malformed one.
+    ///
+    /// | Variable | Setting |
+    /// |---|---|
+    /// | `IB_BASE_URL` | Base URL, required. |
+    /// | `IB_API_KEY` | API key, required. |
+    /// | `IB_API_KEY_PREFIX` | Prefix of the API key, `App` by default. |
+    /// | `IB_TIMEOUT_MS` | Request timeout in milliseconds, see `with_timeout`. |
+    /// | `IB_PROXY_URL` | Proxy for all requests, see `with_proxy`. |
+    /// | `IB_USER_AGENT_SUFFIX` | Appended to the user agent, see `with_user_agent_suffix`. |
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use infobip_sdk::configuration::Configuration;
+    /// match Configuration::from_env() {
+    ///     Ok(configuration) => println!("using {}", configuration.base_url()),
+    ///     Err(error) => eprintln!("check the environment: {}", error),
+    /// }
+    /// ```
+    pub fn from_env() -> Result<Configuration, ConfigurationError> {
+        Configuration::from_vars(|name| env::var(name).ok())
     }
 
-    // Builds and returns a `Configuration` instance set with an API key.
-    pub fn with_api_key(base_url: String, api_key: ApiKey) -> Configuration {
-        Configuration {
+    // Builds a configuration from variables looked up by name, collecting every problem.
+    fn from_vars<F: Fn(&str) -> Option<String>>(
+        var: F,
+    ) -> Result<Configuration, ConfigurationError> {
+        let mut error = EnvError::default();
+        let mut required = |name: &str| {
+            let value = var(name);
+            if value.is_none() {
+                error.missing.push(name.to_string());
+            }
+            value
+        };
+        let base_url = required("IB_BASE_URL").and_then(|value| match parse_base_url(&value) {
+            Ok(base_url) => Some(base_url),
+            Err(_) => {
+                error.malformed.push("IB_BASE_URL".to_string());
+                None
+            }
+        });
+        let key = required("IB_API_KEY");
+
+        let timeout = var("IB_TIMEOUT_MS").and_then(|value| match value.parse::<u64>() {
+            Ok(millis) if millis > 0 => Some(Duration::from_millis(millis)),
+            _ => {
+                error.malformed.push("IB_TIMEOUT_MS".to_string());
+                None
+            }
+        });
+        let proxy = var("IB_PROXY_URL").and_then(|value| match Proxy::all(value.as_str()) {
+            Ok(proxy) => Some(proxy),
+            Err(_) => {
+                error.malformed.push("IB_PROXY_URL".to_string());
+                None
+            }
+        });
+        let user_agent_suffix = var("IB_USER_AGENT_SUFFIX").and_then(|value| {
+            if HeaderValue::from_str(&value).is_ok() {
+                Some(value)
+            } else {
+                error.malformed.push("IB_USER_AGENT_SUFFIX".to_string());
+                None
+            }
+        });
+
+        match (base_url, key) {
+            (Some(base_url), Some(key)) if error.is_empty() => {
+                let api_key = ApiKey {
+                    key,
+                    prefix: Some(var("IB_API_KEY_PREFIX").unwrap_or_else(|| "App".to_string())),
+                };
+                let mut configuration = Configuration::with_api_key(base_url, api_key)?;
+                configuration.timeout = timeout;
+                configuration.proxy = proxy;
+                configuration.user_agent_suffix = user_agent_suffix;
+
+                Ok(configuration)
+            }
+            _ => Err(error.into()),
+        }
+    }
+
+    /// Builds and returns a `Configuration` instance set with an API key.
+    ///
+    /// The base URL must be an `https` URL without credentials, query or fragment; a URL
+    /// without a scheme, like the one shown in the web interface, gets `https://`. Plain `http`
+    /// is only accepted for `localhost` and loopback addresses, for local testing. The URL of the
+    /// web interface is rejected, and so are personal subdomains with invalid characters. See
+    /// `BaseUrl` for building the URL from a region or subdomain. Fails with
+    /// `ConfigurationError::Conflict` if the key already starts with its prefix.
+    pub fn with_api_key(
+        base_url: String,
+        api_key: ApiKey,
+    ) -> Result<Configuration, ConfigurationError> {
+        let base_url = parse_base_url(&base_url)?;
+        if let Some(prefix) = &api_key.prefix {
+            if api_key.key.starts_with(&format!("{} ", prefix)) {
+                return Err(ConfigurationError::Conflict(format!(
+                    "the API key already starts with its prefix {}",
+                    prefix
+                )));
+            }
+        }
+
+        Ok(Configuration {
             base_url,
+            channel_base_urls: HashMap::new(),
             api_key: Some(api_key),
             basic_auth: None,
             bearer_access_token: None,
+            response_validation: None,
+            generate_message_ids: false,
+            default_headers: HeaderMap::new(),
+            compression: true,
+            application_id: None,
+            entity_id: None,
+            timeout: None,
+            proxy: None,
+            user_agent_suffix: None,
+            circuit_breaker: None,
+            slow_request_threshold: None,
+            #[cfg(feature = "sms")]
+            deduplication: None,
+            #[cfg(feature = "sms")]
+            preview_cache: None,
+            #[cfg(feature = "recording")]
+            cassette: None,
+        })
+    }
+
+    /// Enables checking responses against their requests, recording any mismatch in the given
+    /// collector. See the `api::response_validation` module.
+    pub fn with_response_validation(mut self, collector: AnomalyCollector) -> Self {
+        self.response_validation = Some(collector);
+        self
+    }
+
+    /// Makes clients fail fast with `SdkError::CircuitOpen` while the API keeps failing. See the
+    /// `api::circuit_breaker` module.
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Logs a warning with the endpoint and payload size of every request the API takes at least
+    /// `threshold` to answer. Warnings are emitted with the `log` crate, so they show up in the
+    /// logger, or `tracing` subscriber with its `log` compatibility, of the application. The
+    /// duration of every call is also returned in `SdkResponse::duration`.
+    pub fn with_slow_request_warning(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = Some(threshold);
+        self
+    }
+
+    /// Makes the SMS client suppress messages repeated within the window. See the
+    /// `api::deduplication` module.
+    #[cfg(feature = "sms")]
+    pub fn with_deduplication(mut self, window: DeduplicationWindow) -> Self {
+        self.deduplication = Some(window);
+        self
+    }
+
+    /// Makes the SMS client answer repeated previews from the cache. See the
+    /// `api::preview_cache` module.
+    #[cfg(feature = "sms")]
+    pub fn with_preview_cache(mut self, cache: PreviewCache) -> Self {
+        self.preview_cache = Some(cache);
+        self
+    }
+
+    /// Makes clients record their requests to the cassette, or answer them from it. See the
+    /// `api::recording` module.
+    #[cfg(feature = "recording")]
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Makes clients generate a UUID for every missing bulk ID and message ID before sending, so
+    /// a send that failed mid-flight can be looked up and deduplicated before retrying. The IDs
+    /// of a failed send are returned in `SdkError::SendFailed`.
+    pub fn with_generated_message_ids(mut self) -> Self {
+        self.generate_message_ids = true;
+        self
+    }
+
+    /// Adds headers sent with every request, e.g. for tracing or routing. They replace the
+    /// SDK's own headers with the same name.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::configuration::{ApiKey, Configuration};
+    /// # use reqwest::header::{HeaderMap, HeaderValue};
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("X-Request-Origin", HeaderValue::from_static("billing-service"));
+    ///
+    /// let configuration = Configuration::with_api_key(
+    ///     "https://some.api.infobip.com".to_string(),
+    ///     ApiKey::new("some-api-key".to_string()),
+    /// )
+    /// .unwrap()
+    /// .with_default_headers(headers);
+    ///
+    /// assert_eq!(configuration.default_headers().len(), 1);
+    /// ```
+    pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
+        for (name, value) in headers.iter() {
+            self.default_headers.insert(name.clone(), value.clone());
+        }
+        self
+    }
+
+    /// Enables or disables compressed responses. When enabled, which is the default, clients
+    /// send an `Accept-Encoding` header and decompress the response body. Large log and delivery
+    /// report responses are much smaller compressed. Needs the `gzip` or `deflate` feature;
+    /// without them responses are never compressed.
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Sets a timeout for requests, from connecting until the response body is read. Requests
+    /// that time out fail with `SdkError::Reqwest`. There is no timeout by default.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends all requests through a proxy.
+    pub fn with_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Appends text to the `User-Agent` header of requests, replacing any text set before. See
+    /// `with_app_info` for identifying the application.
+    pub fn with_user_agent_suffix(mut self, suffix: &str) -> Self {
+        self.user_agent_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Identifies the application in the `User-Agent` header of requests, so Infobip support can
+    /// attribute traffic to it. Use a product token like `my-service/1.2.3`. Each call adds a
+    /// token after the ones set before, e.g. for a library built on the SDK and the service using
+    /// the library. Control characters are removed, as they are not allowed in headers.
+    pub fn with_app_info(mut self, app_info: &str) -> Self {
+        let app_info: String = app_info
+            .trim()
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect();
+        if app_info.is_empty() {
+            return self;
         }
+        self.user_agent_suffix = Some(match self.user_agent_suffix.take() {
+            Some(suffix) => format!("{} {}", suffix, app_info),
+            None => app_info,
+        });
+        self
+    }
+
+    /// Sends the requests of one channel to another base URL, e.g. a regional cluster, while the
+    /// other channels keep the base URL of the Configuration. The URL is checked like in
+    /// `with_api_key`. Channel clients pick the URL up when they are built, so set overrides
+    /// before building clients from the Configuration.
+    pub fn with_channel_base_url(
+        mut self,
+        channel: Channel,
+        base_url: &str,
+    ) -> Result<Self, ConfigurationError> {
+        self.channel_base_urls
+            .insert(channel, parse_base_url(base_url)?);
+        Ok(self)
+    }
+
+    /// Sets the CPaaS X application ID of messages sent by clients with this configuration, for
+    /// platforms that serve several applications. Messages with their own application ID keep it.
+    pub fn with_application_id(mut self, application_id: &str) -> Self {
+        self.application_id = Some(application_id.to_string());
+        self
+    }
+
+    /// Sets the CPaaS X entity ID of messages sent by clients with this configuration, e.g. the
+    /// customer a multi-tenant platform sends on behalf of. Messages with their own entity ID
+    /// keep it.
+    pub fn with_entity_id(mut self, entity_id: &str) -> Self {
+        self.entity_id = Some(entity_id.to_string());
+        self
     }
 
     /// Returns the base URL of the Configuration.
@@ -37,6 +362,22 @@ impl Configuration {
         &self.base_url
     }
 
+    /// Returns the base URL requests of the channel are sent to: its override if there is one,
+    /// or the base URL of the Configuration.
+    pub fn channel_base_url(&self, channel: Channel) -> &String {
+        self.channel_base_urls
+            .get(&channel)
+            .unwrap_or(&self.base_url)
+    }
+
+    // Returns the Configuration a client of the channel uses, with the base URL of the channel.
+    pub(crate) fn for_channel(mut self, channel: Channel) -> Configuration {
+        if let Some(base_url) = self.channel_base_urls.get(&channel) {
+            self.base_url = base_url.clone();
+        }
+        self
+    }
+
     /// Returns the API key of the Configuration.
     pub fn api_key(&self) -> Option<&ApiKey> {
         self.api_key.as_ref()
@@ -51,22 +392,411 @@ impl Configuration {
     pub fn bearer_access_token(&self) -> Option<&String> {
         self.bearer_access_token.as_ref()
     }
+
+    /// Returns the headers added to every request.
+    pub fn default_headers(&self) -> &HeaderMap {
+        &self.default_headers
+    }
+
+    /// Returns the CPaaS X application ID set on sent messages.
+    pub fn application_id(&self) -> Option<&String> {
+        self.application_id.as_ref()
+    }
+
+    /// Returns the CPaaS X entity ID set on sent messages.
+    pub fn entity_id(&self) -> Option<&String> {
+        self.entity_id.as_ref()
+    }
+
+    /// Returns the request timeout, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the proxy requests are sent through, if any.
+    pub fn proxy(&self) -> Option<&Proxy> {
+        self.proxy.as_ref()
+    }
+
+    /// Returns the text appended to the `User-Agent` header, if any.
+    pub fn user_agent_suffix(&self) -> Option<&String> {
+        self.user_agent_suffix.as_ref()
+    }
+
+    /// Returns `true` if clients accept compressed responses.
+    pub fn compression(&self) -> bool {
+        self.compression
+    }
+
+    /// Returns `true` if clients generate missing bulk IDs and message IDs before sending.
+    pub fn generated_message_ids(&self) -> bool {
+        self.generate_message_ids
+    }
+
+    /// Returns the circuit breaker of the Configuration, if there is one.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Returns the duration above which requests are logged as slow, if set.
+    pub fn slow_request_threshold(&self) -> Option<Duration> {
+        self.slow_request_threshold
+    }
+
+    /// Returns the deduplication window of the Configuration, if deduplication is enabled.
+    #[cfg(feature = "sms")]
+    pub fn deduplication(&self) -> Option<&DeduplicationWindow> {
+        self.deduplication.as_ref()
+    }
+
+    /// Returns the preview cache of the Configuration, if previews are cached.
+    #[cfg(feature = "sms")]
+    pub fn preview_cache(&self) -> Option<&PreviewCache> {
+        self.preview_cache.as_ref()
+    }
+
+    /// Returns the cassette requests are recorded to or replayed from, if any.
+    #[cfg(feature = "recording")]
+    pub fn cassette(&self) -> Option<&Cassette> {
+        self.cassette.as_ref()
+    }
+
+    /// Returns the response anomaly collector of the Configuration, if response validation is
+    /// enabled.
+    pub fn response_validation(&self) -> Option<&AnomalyCollector> {
+        self.response_validation.as_ref()
+    }
 }
 
-/// Holds the details for authentication based on username and password.
-#[derive(Debug, Clone)]
+impl fmt::Debug for Configuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Configuration");
+        debug
+            .field("base_url", &self.base_url)
+            .field("channel_base_urls", &self.channel_base_urls)
+            .field("basic_auth", &self.basic_auth)
+            .field(
+                "bearer_access_token",
+                &self.bearer_access_token.as_ref().map(|_| REDACTED),
+            )
+            .field("api_key", &self.api_key)
+            .field("response_validation", &self.response_validation)
+            .field("generate_message_ids", &self.generate_message_ids)
+            .field(
+                "default_headers",
+                &self.default_headers.keys().collect::<Vec<_>>(),
+            )
+            .field("compression", &self.compression)
+            .field("application_id", &self.application_id)
+            .field("entity_id", &self.entity_id)
+            .field("timeout", &self.timeout)
+            .field("proxy", &self.proxy)
+            .field("user_agent_suffix", &self.user_agent_suffix)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("slow_request_threshold", &self.slow_request_threshold);
+        #[cfg(feature = "sms")]
+        debug
+            .field("deduplication", &self.deduplication)
+            .field("preview_cache", &self.preview_cache);
+        #[cfg(feature = "recording")]
+        debug.field("cassette", &self.cassette);
+
+        debug.finish()
+    }
+}
+
+// The API key and basic authentication wipe their own secrets.
+#[cfg(feature = "zeroize")]
+impl Drop for Configuration {
+    fn drop(&mut self) {
+        self.bearer_access_token.zeroize();
+    }
+}
+
+/// Holds the possible errors of building a `Configuration`.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigurationError {
+    #[error("environment variable {0} is not set")]
+    MissingVar(String),
+
+    #[error("invalid base URL {url}: {reason}")]
+    InvalidUrl { url: String, reason: String },
+
+    #[error("conflicting configuration: {0}")]
+    Conflict(String),
+
+    #[error(transparent)]
+    Env(#[from] EnvError),
+
+    #[error(transparent)]
+    File(#[from] FileError),
+}
+
+// Checks the base URL, returning it with `https://` added if it has no scheme, and without a
+// trailing slash, as request paths are appended to it.
+fn parse_base_url(base_url: &str) -> Result<String, ConfigurationError> {
+    let invalid = |reason: &str| ConfigurationError::InvalidUrl {
+        url: base_url.to_string(),
+        reason: reason.to_string(),
+    };
+
+    let trimmed = base_url.trim();
+    let with_scheme = if trimmed.contains("://") {
+        trimmed.to_string()
+    } else {
+        format!("https://{}", trimmed)
+    };
+    let url = Url::parse(&with_scheme).map_err(|error| invalid(&error.to_string()))?;
+
+    if let Some(host) = url.host_str() {
+        if host == PORTAL_HOST {
+            return Err(invalid(
+                "this is the web interface URL, use the API base URL shown on its home page",
+            ));
+        }
+        if let Some(subdomain) = host.strip_suffix(&format!(".{}", API_HOST)) {
+            if !SUBDOMAIN.is_match(subdomain) {
+                return Err(invalid("the personal subdomain must be letters and digits"));
+            }
+        }
+    }
+
+    let loopback = matches!(
+        url.host_str(),
+        Some("localhost") | Some("127.0.0.1") | Some("[::1]")
+    );
+    match url.scheme() {
+        "https" => {}
+        "http" if loopback => {}
+        _ => return Err(invalid("the scheme must be https")),
+    }
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(invalid("credentials belong in the API key, not the URL"));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(invalid("the URL must not have a query or fragment"));
+    }
+
+    Ok(with_scheme.trim_end_matches('/').to_string())
+}
+
+const API_HOST: &str = "api.infobip.com";
+const PORTAL_HOST: &str = "portal.infobip.com";
+
+lazy_static::lazy_static! {
+    static ref SUBDOMAIN: Regex = Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap();
+}
+
+/// Channels of the API, for settings that differ between channel clients.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Sms,
+    Tfa,
+    WhatsApp,
+    Email,
+}
+
+/// Regions with a shared base URL, for accounts without a personal base URL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Region {
+    Eu,
+    Us,
+    Apac,
+}
+
+impl Region {
+    fn subdomain(&self) -> &'static str {
+        match self {
+            Region::Eu => "eu",
+            Region::Us => "us",
+            Region::Apac => "apac",
+        }
+    }
+}
+
+/// A checked base URL of the Infobip API. Use it instead of a free-form string to build a
+/// `Configuration` from a region or a personal subdomain.
+///
+/// # Example
+/// ```
+/// # use infobip_sdk::configuration::{ApiKey, BaseUrl, Configuration, Region};
+/// let base_url = BaseUrl::personal("k3v9xx").unwrap();
+/// assert_eq!(base_url.as_str(), "https://k3v9xx.api.infobip.com");
+///
+/// let configuration = Configuration::with_api_key(
+///     BaseUrl::from_region(Region::Eu).into(),
+///     ApiKey::new("some-api-key".to_string()),
+/// )
+/// .unwrap();
+/// assert_eq!(configuration.base_url(), "https://eu.api.infobip.com");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BaseUrl(String);
+
+impl BaseUrl {
+    /// Returns the shared base URL of the region.
+    pub fn from_region(region: Region) -> BaseUrl {
+        BaseUrl(format!("https://{}.{}", region.subdomain(), API_HOST))
+    }
+
+    /// Returns the personal base URL with the given subdomain, the part before `.api.infobip.com`
+    /// of the URL shown in the web interface.
+    pub fn personal(subdomain: &str) -> Result<BaseUrl, ConfigurationError> {
+        BaseUrl::parse(&format!("{}.{}", subdomain.trim(), API_HOST))
+    }
+
+    /// Checks a base URL like `Configuration::with_api_key` does.
+    pub fn parse(base_url: &str) -> Result<BaseUrl, ConfigurationError> {
+        parse_base_url(base_url).map(BaseUrl)
+    }
+
+    /// Returns the URL as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for BaseUrl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for BaseUrl {
+    type Err = ConfigurationError;
+
+    fn from_str(base_url: &str) -> Result<Self, Self::Err> {
+        BaseUrl::parse(base_url)
+    }
+}
+
+impl From<BaseUrl> for String {
+    fn from(base_url: BaseUrl) -> String {
+        base_url.0
+    }
+}
+
+/// Environment variables that are missing or malformed, returned by `Configuration::from_env`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnvError {
+    /// Names of the required variables that are not set.
+    pub missing: Vec<String>,
+
+    /// Names of the variables whose value is not valid.
+    pub malformed: Vec<String>,
+}
+
+impl EnvError {
+    fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.malformed.is_empty()
+    }
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut problems = Vec::new();
+        if !self.missing.is_empty() {
+            problems.push(format!("missing {}", self.missing.join(", ")));
+        }
+        if !self.malformed.is_empty() {
+            problems.push(format!("malformed {}", self.malformed.join(", ")));
+        }
+
+        write!(f, "invalid environment: {}", problems.join("; "))
+    }
+}
+
+impl std::error::Error for EnvError {}
+
+/// A configuration file that can't be read or has invalid settings, returned by
+/// `Configuration::from_file`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileError {
+    /// Path of the file.
+    pub path: String,
+
+    /// Descriptions of the problems, each starting with the key of the setting if there is one.
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid configuration file {}: {}",
+            self.path,
+            self.problems.join("; ")
+        )
+    }
+}
+
+impl std::error::Error for FileError {}
+
+/// Holds the details for authentication based on username and password. The password is
+/// redacted in `Debug` output.
+#[derive(Clone)]
 pub struct BasicAuth {
     pub username: String,
     pub password: Option<String>,
 }
 
-/// Holds the details for API key authentication.
-#[derive(Debug, Clone)]
+impl BasicAuth {
+    /// Returns the password, for the rare cases where it has to be shown.
+    pub fn reveal(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BasicAuth {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for BasicAuth {}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+/// Holds the details for API key authentication. The key is redacted in `Debug` output.
+///
+/// With the `zeroize` feature, the key is overwritten with zeros when the `ApiKey` is dropped,
+/// and so are the password of `BasicAuth` and the bearer token of `Configuration`. Every clone
+/// wipes its own copy. Copies made to send requests, like the `Authorization` header values
+/// built by the HTTP client, are not covered.
+#[derive(Clone)]
 pub struct ApiKey {
     pub prefix: Option<String>,
     pub key: String,
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for ApiKey {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for ApiKey {}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ApiKey")
+            .field("prefix", &self.prefix)
+            .field("key", &REDACTED)
+            .finish()
+    }
+}
+
 impl ApiKey {
     /// Creates a new `ApiKey`.
     pub fn new(key: String) -> ApiKey {
@@ -77,10 +807,23 @@ impl ApiKey {
     }
 
     /// Reads `IB_API_KEY`, and optionally `IB_API_KEY_PREFIX`, variables from environment.
-    pub fn from_env() -> Result<ApiKey, VarError> {
+    pub fn from_env() -> Result<ApiKey, ConfigurationError> {
         Ok(ApiKey {
-            key: env::var("IB_API_KEY")?,
+            key: env::var("IB_API_KEY")
+                .map_err(|_| ConfigurationError::MissingVar("IB_API_KEY".to_string()))?,
             prefix: Some(env::var("IB_API_KEY_PREFIX").unwrap_or_else(|_| "App".to_string())),
         })
     }
+
+    /// Returns the key, for the rare cases where it has to be shown, e.g. to pass it to another
+    /// tool. Prefer logging the `ApiKey` itself, which redacts the key.
+    pub fn reveal(&self) -> &str {
+        &self.key
+    }
 }
+
+#[cfg(feature = "config-file")]
+mod file;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,131 @@
+//! Corpus of anonymized response payloads recorded from the Infobip API.
+//!
+//! The payloads cover shapes that are easy to get wrong: delivery reports with fields the models
+//! don't know about, `null` values where a field is usually present, error bodies and template
+//! listings. Every fixture is deserialized into its model by the SDK's tests, so a change that
+//! breaks one of them is caught before release. The corpus is public so applications can run
+//! their own code against the same payloads.
+//!
+//! # Example
+//! ```
+//! # use infobip_sdk::fixtures;
+//! # use infobip_sdk::model::sms::DeliveryReportsResponseBody;
+//! let fixture = fixtures::get("sms/delivery_reports_unusual_fields").unwrap();
+//! let body: DeliveryReportsResponseBody = fixture.deserialize().unwrap();
+//!
+//! assert_eq!(body.results.unwrap().len(), 2);
+//! ```
+//!
+//! # Contributing fixtures
+//! Payloads that broke deserialization in production are welcome. To add one:
+//!
+//! 1. Anonymize the payload: replace IDs with placeholders like `MESSAGE-ID-1`, phone numbers
+//!    with test numbers like `41793026727`, email addresses with `example.com` addresses, and
+//!    remove any message text that is not generic.
+//! 2. Save it as `src/fixtures/<channel>/<snake_case_name>.json`, where the channel is `sms`,
+//!    `whatsapp`, `email`, `tfa` or `errors`. The file holds the response body exactly as
+//!    received, pretty-printed with two spaces.
+//! 3. Register it in [`FIXTURES`] with the model it deserializes into, and a description of
+//!    what makes it interesting.
+//!
+//! The tests fail if a file under `src/fixtures` is not registered, or if a registered model is
+//! not known to the tests.
+
+use serde::de::DeserializeOwned;
+
+/// A recorded response payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fixture {
+    /// Path of the fixture file, relative to `src/fixtures` and without the `.json` extension.
+    pub name: &'static str,
+
+    /// Path of the model the payload deserializes into, relative to the crate root, e.g.
+    /// `model::sms::DeliveryReportsResponseBody`.
+    pub model: &'static str,
+
+    /// What makes the payload worth keeping.
+    pub description: &'static str,
+
+    /// The JSON payload.
+    pub payload: &'static str,
+}
+
+impl Fixture {
+    /// Deserializes the payload into a model.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(self.payload)
+    }
+}
+
+macro_rules! fixture {
+    ($name:literal, $model:literal, $description:literal) => {
+        Fixture {
+            name: $name,
+            model: $model,
+            description: $description,
+            payload: include_str!(concat!($name, ".json")),
+        }
+    };
+}
+
+/// All recorded fixtures.
+pub const FIXTURES: &[Fixture] = &[
+    fixture!(
+        "email/delivery_reports",
+        "model::email::DeliveryReportsResponseBody",
+        "Report with an integer price and an undocumented channel field."
+    ),
+    fixture!(
+        "errors/null_validation_errors",
+        "api::ApiErrorDetails",
+        "Error body with an explicit null instead of a validation error map."
+    ),
+    fixture!(
+        "errors/unauthorized",
+        "api::ApiErrorDetails",
+        "Error body without validation errors."
+    ),
+    fixture!(
+        "errors/validation_errors",
+        "api::ApiErrorDetails",
+        "Error body with several validation errors for one field."
+    ),
+    fixture!(
+        "sms/delivery_reports_empty",
+        "model::sms::DeliveryReportsResponseBody",
+        "No reports available."
+    ),
+    fixture!(
+        "sms/delivery_reports_unusual_fields",
+        "model::sms::DeliveryReportsResponseBody",
+        "Reports with undocumented fields, nulls, a zero price and a permanent error."
+    ),
+    fixture!(
+        "sms/logs_null_fields",
+        "model::sms::LogsResponseBody",
+        "Log of a pending message, with most fields null."
+    ),
+    fixture!(
+        "tfa/verification_status",
+        "model::tfa::TfaVerificationStatusResponseBody",
+        "Verified and unverified numbers, with an undocumented sentAt field."
+    ),
+    fixture!(
+        "whatsapp/send_template_bulk",
+        "model::whatsapp::SendTemplateResponseBody",
+        "Template sent to several destinations, with a bulk ID."
+    ),
+    fixture!(
+        "whatsapp/templates_mixed",
+        "model::whatsapp::TemplatesResponseBody",
+        "Templates in different statuses, with quality score and rejection reason."
+    ),
+];
+
+/// Returns the fixture with the given name, if any.
+pub fn get(name: &str) -> Option<&'static Fixture> {
+    FIXTURES.iter().find(|fixture| fixture.name == name)
+}
+
+#[cfg(test)]
+mod tests;
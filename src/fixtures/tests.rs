@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::fixtures::*;
+
+// Deserializes the payload, then checks that serializing and deserializing the model again
+// gives the same value, so no known field is lost on the way.
+fn check_round_trip<T>(fixture: &Fixture)
+where
+    T: DeserializeOwned + Serialize + PartialEq + std::fmt::Debug,
+{
+    let model: T = fixture
+        .deserialize()
+        .unwrap_or_else(|error| panic!("fixture {} failed: {}", fixture.name, error));
+
+    let serialized = serde_json::to_string(&model).unwrap();
+    let deserialized: T = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, model, "fixture {} changed", fixture.name);
+}
+
+// Returns false for models of channels that are not enabled.
+fn check_fixture(fixture: &Fixture) -> bool {
+    match fixture.model {
+        "api::ApiErrorDetails" => check_round_trip::<crate::api::ApiErrorDetails>(fixture),
+        #[cfg(feature = "email")]
+        "model::email::DeliveryReportsResponseBody" => {
+            check_round_trip::<crate::model::email::DeliveryReportsResponseBody>(fixture)
+        }
+        #[cfg(feature = "sms")]
+        "model::sms::DeliveryReportsResponseBody" => {
+            check_round_trip::<crate::model::sms::DeliveryReportsResponseBody>(fixture)
+        }
+        #[cfg(feature = "sms")]
+        "model::sms::LogsResponseBody" => {
+            check_round_trip::<crate::model::sms::LogsResponseBody>(fixture)
+        }
+        #[cfg(feature = "tfa")]
+        "model::tfa::TfaVerificationStatusResponseBody" => {
+            check_round_trip::<crate::model::tfa::TfaVerificationStatusResponseBody>(fixture)
+        }
+        #[cfg(feature = "whatsapp")]
+        "model::whatsapp::SendTemplateResponseBody" => {
+            check_round_trip::<crate::model::whatsapp::SendTemplateResponseBody>(fixture)
+        }
+        #[cfg(feature = "whatsapp")]
+        "model::whatsapp::TemplatesResponseBody" => {
+            check_round_trip::<crate::model::whatsapp::TemplatesResponseBody>(fixture)
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+fn fixture_files(dir: &Path, prefix: &str, names: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap().to_str().unwrap().to_string();
+
+        if path.is_dir() {
+            fixture_files(&path, &format!("{}{}/", prefix, file_name), names);
+        } else if let Some(name) = file_name.strip_suffix(".json") {
+            names.insert(format!("{}{}", prefix, name));
+        }
+    }
+}
+
+#[test]
+fn fixtures_deserialize_into_their_models() {
+    let checked = FIXTURES.iter().filter(|f| check_fixture(f)).count();
+
+    #[cfg(all(
+        feature = "email",
+        feature = "sms",
+        feature = "tfa",
+        feature = "whatsapp"
+    ))]
+    assert_eq!(checked, FIXTURES.len(), "some fixture models are unknown");
+    assert!(checked > 0);
+}
+
+#[test]
+fn fixture_files_are_registered() {
+    let mut files = BTreeSet::new();
+    fixture_files(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src/fixtures"),
+        "",
+        &mut files,
+    );
+
+    let registered: BTreeSet<String> = FIXTURES.iter().map(|f| f.name.to_string()).collect();
+
+    assert_eq!(files, registered);
+}
+
+#[test]
+fn fixtures_are_sorted_and_unique() {
+    let names: Vec<&str> = FIXTURES.iter().map(|f| f.name).collect();
+    let mut sorted = names.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    assert_eq!(names, sorted);
+}
+
+#[test]
+fn get_fixture() {
+    assert_eq!(
+        get("errors/unauthorized").unwrap().model,
+        "api::ApiErrorDetails"
+    );
+    assert!(get("errors/missing").is_none());
+}
+
+#[cfg(feature = "sms")]
+#[test]
+fn unusual_delivery_report_fields() {
+    let body: crate::model::sms::DeliveryReportsResponseBody =
+        get("sms/delivery_reports_unusual_fields")
+            .unwrap()
+            .deserialize()
+            .unwrap();
+    let results = body.results.unwrap();
+
+    assert_eq!(
+        results[0].price.as_ref().unwrap().price_per_message,
+        Some(0.0)
+    );
+    assert!(results[1].bulk_id.is_none());
+    assert_eq!(results[1].error.as_ref().unwrap().permanent, Some(true));
+}
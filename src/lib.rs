@@ -21,7 +21,10 @@
 //! To use the library, you'll need to set up an [Infobip account](https://www.infobip.com/signup).
 //! Then you can use your API Key and custom base URL to call the endpoints. You can use the
 //! `Configuration::from_env_api_key()` method to load the configuration from the environment. To
-//! do that, set the `IB_API_KEY` and `IB_BASE_URL` variables.
+//! do that, set the `IB_API_KEY` and `IB_BASE_URL` variables. `Configuration::from_env()` also
+//! reads optional settings like `IB_TIMEOUT_MS` and `IB_PROXY_URL`, and reports every missing or
+//! malformed variable at once. Use the `BaseUrl` type to build the base URL from your personal
+//! subdomain or a region, instead of a free-form string.
 //!
 //! ## Usage
 //!
@@ -104,6 +107,20 @@
 //! .unwrap();
 //! ```
 //!
+//! Request bodies and messages can also be built from a `serde_json::Value`, which validates
+//! them too. This is handy when they come from configuration files:
+//!
+//! ```rust
+//! # use infobip_sdk::model::sms::Message;
+//! let message = Message::try_from(serde_json::json!({
+//!     "destinations": [{"to": "123456789012"}],
+//!     "text": "Your message text"
+//! }))
+//! .unwrap();
+//!
+//! assert!(Message::try_from(serde_json::json!({"destinations": []})).is_err());
+//! ```
+//!
 //! or this:
 //!
 //! ```rust
@@ -137,7 +154,22 @@
 //! default-features = false
 //! features = ["rustls-tls", "email", "sms", "whatsapp"]
 //! ```
+//!
+//! ### Minimum Supported Rust Version
+//! The SDK builds on Rust 1.70 and newer, with all features enabled. The MSRV is checked in CI
+//! and is only raised in minor releases, never in patch releases. Dependencies are resolved
+//! with Cargo's MSRV-aware resolver for that check, so older toolchains may need to do the same:
+//!
+//! ```bash
+//! CARGO_RESOLVER_INCOMPATIBLE_RUST_VERSIONS=fallback cargo +stable update
+//! ```
 
 pub mod api;
 pub mod configuration;
+pub mod fixtures;
+pub mod masking;
 pub mod model;
+pub mod queue;
+
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
@@ -0,0 +1,68 @@
+//! Masking of phone numbers and email addresses, for logs that must not contain personal data.
+//!
+//! The SDK masks the destinations it puts in its own error messages, and the same helpers can be
+//! used for the logs of an application, so phone numbers and email addresses are masked the same
+//! way everywhere. Masked values keep enough to tell destinations apart in most cases, like the
+//! country and operator prefix of a number, but not enough to contact anyone. The number of
+//! masked characters isn't revealed.
+//!
+//! # Example
+//! ```
+//! # use infobip_sdk::masking::{mask_destination, mask_email, mask_phone_number};
+//! assert_eq!(mask_phone_number("+491702384590"), "+49170***590");
+//! assert_eq!(mask_email("jane.doe@example.com"), "j***@example.com");
+//! assert_eq!(mask_destination("491702384590"), "49170***590");
+//! ```
+
+/// Replaces the masked part of a value.
+pub const MASK: &str = "***";
+
+/// Masks a phone number, keeping up to the first five and the last three digits, and masking at
+/// least a third of them. A leading `+` is kept, and other characters than digits are dropped.
+pub fn mask_phone_number(number: &str) -> String {
+    let digits: Vec<char> = number.chars().filter(char::is_ascii_digit).collect();
+    let visible = digits.len() * 2 / 3;
+    let suffix = (visible / 2).min(3);
+    let prefix = (visible - suffix).min(5);
+
+    let mut masked = String::new();
+    if number.trim_start().starts_with('+') {
+        masked.push('+');
+    }
+    masked.extend(&digits[..prefix]);
+    masked.push_str(MASK);
+    masked.extend(&digits[digits.len() - suffix..]);
+
+    masked
+}
+
+/// Masks the local part of an email address, keeping its first character and the domain. A
+/// value without `@` is masked the same way as a whole.
+pub fn mask_email(address: &str) -> String {
+    let (local, domain) = match address.rsplit_once('@') {
+        Some((local, domain)) => (local, Some(domain)),
+        None => (address, None),
+    };
+
+    let mut masked: String = local.chars().take(1).collect();
+    masked.push_str(MASK);
+    if let Some(domain) = domain {
+        masked.push('@');
+        masked.push_str(domain);
+    }
+
+    masked
+}
+
+/// Masks the destination of a message of any channel: email addresses with `mask_email`, and
+/// anything else with `mask_phone_number`.
+pub fn mask_destination(destination: &str) -> String {
+    if destination.contains('@') {
+        mask_email(destination)
+    } else {
+        mask_phone_number(destination)
+    }
+}
+
+#[cfg(test)]
+mod tests;
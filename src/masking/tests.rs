@@ -0,0 +1,43 @@
+use crate::masking::*;
+
+#[test]
+fn mask_phone_numbers() {
+    let cases = [
+        ("+491702384590", "+49170***590"),
+        ("491702384590", "49170***590"),
+        ("41793026727", "4179***727"),
+        ("+1 (555) 010-9999", "+1555***999"),
+        ("123456", "12***56"),
+        ("12", "1***"),
+        ("", "***"),
+    ];
+
+    for (number, masked) in cases {
+        assert_eq!(mask_phone_number(number), masked, "{}", number);
+    }
+}
+
+#[test]
+fn mask_phone_numbers_hides_a_third() {
+    for length in 0..20 {
+        let number = "9".repeat(length);
+        let visible = mask_phone_number(&number).matches('9').count();
+
+        assert!(visible * 3 <= length * 2, "{} digits", length);
+    }
+}
+
+#[test]
+fn mask_email_addresses() {
+    assert_eq!(mask_email("jane.doe@example.com"), "j***@example.com");
+    assert_eq!(mask_email("j@example.com"), "j***@example.com");
+    assert_eq!(mask_email("\"a@b\"@example.com"), "\"***@example.com");
+    assert_eq!(mask_email("not-an-address"), "n***");
+    assert_eq!(mask_email(""), "***");
+}
+
+#[test]
+fn mask_destinations_of_any_channel() {
+    assert_eq!(mask_destination("jane@example.com"), "j***@example.com");
+    assert_eq!(mask_destination("+41793026727"), "+4179***727");
+}
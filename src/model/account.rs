@@ -0,0 +1,61 @@
+//! Models for reading details of the account and its API keys.
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyDetails {
+    /// Identifier of the API key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Name of the API key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Whether the API key can be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Roles of the API key, e.g. `PUBLIC_API`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<String>>,
+
+    /// Scopes the API key is allowed to use, e.g. `whatsapp:message:send`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_guids: Option<Vec<String>>,
+
+    /// IP addresses the API key can be used from. Any address is allowed when empty.
+    #[serde(rename = "allowedIPs", skip_serializing_if = "Option::is_none")]
+    pub allowed_ips: Option<Vec<String>>,
+
+    /// Date and time the API key is valid from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<String>,
+
+    /// Date and time the API key is valid until.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<String>,
+}
+
+impl ApiKeyDetails {
+    /// Returns the scopes of the API key, or an empty list if there are none.
+    pub fn scopes(&self) -> &[String] {
+        self.scope_guids.as_deref().unwrap_or_default()
+    }
+
+    /// Returns `true` if the API key is allowed to use `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().iter().any(|allowed| allowed == scope)
+    }
+
+    /// Returns the scopes from `required` the API key isn't allowed to use, in the given order.
+    pub fn missing_scopes(&self, required: &[&str]) -> Vec<String> {
+        required
+            .iter()
+            .filter(|scope| !self.has_scope(scope))
+            .map(|scope| scope.to_string())
+            .collect()
+    }
+}
@@ -0,0 +1,89 @@
+//! Typed values for the `callback_data` fields of sent messages.
+//!
+//! Infobip returns the callback data of a message unchanged in its delivery reports and in
+//! replies to it, which makes it a good place for the IDs an application needs to handle them.
+//! [`CallbackData`] serializes a value to JSON for sending, checking it fits in the field, and
+//! parses it back from a report.
+//!
+//! # Example
+//! ```
+//! # use infobip_sdk::model::callback_data::CallbackData;
+//! # use serde_derive::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Order {
+//!     order_id: u64,
+//!     customer: String,
+//! }
+//!
+//! let order = Order { order_id: 1234, customer: "c-42".to_string() };
+//! let callback_data = CallbackData::new(order).encode().unwrap();
+//! assert_eq!(callback_data, r#"{"order_id":1234,"customer":"c-42"}"#);
+//!
+//! // Later, in the delivery report handler:
+//! let order: CallbackData<Order> = CallbackData::decode(&callback_data).unwrap();
+//! assert_eq!(order.0.order_id, 1234);
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+/// Maximum number of characters of a `callback_data` field.
+pub const MAX_CALLBACK_DATA_LENGTH: usize = 4000;
+
+/// Holds the possible errors of encoding or decoding callback data.
+#[derive(Error, Debug)]
+pub enum CallbackDataError {
+    #[error("callback data is {0} characters long, more than the maximum of {max}", max = MAX_CALLBACK_DATA_LENGTH)]
+    TooLong(usize),
+
+    #[error("callback data is not valid JSON for the expected type: {0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+/// A value sent as JSON in a `callback_data` field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallbackData<T>(pub T);
+
+impl<T> CallbackData<T> {
+    pub fn new(data: T) -> Self {
+        CallbackData(data)
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize> CallbackData<T> {
+    /// Serializes the value to the string sent as callback data. Fails if it is longer than
+    /// [`MAX_CALLBACK_DATA_LENGTH`] characters.
+    pub fn encode(&self) -> Result<String, CallbackDataError> {
+        let encoded = serde_json::to_string(&self.0)?;
+
+        let length = encoded.chars().count();
+        if length > MAX_CALLBACK_DATA_LENGTH {
+            return Err(CallbackDataError::TooLong(length));
+        }
+
+        Ok(encoded)
+    }
+}
+
+impl<T: DeserializeOwned> CallbackData<T> {
+    /// Parses callback data received in a report or inbound message.
+    pub fn decode(callback_data: &str) -> Result<Self, CallbackDataError> {
+        Ok(CallbackData(serde_json::from_str(callback_data)?))
+    }
+}
+
+// Parses an optional callback data field of a report model.
+#[cfg(any(feature = "sms", feature = "whatsapp"))]
+pub(crate) fn parse_field<T: DeserializeOwned>(
+    callback_data: Option<&str>,
+) -> Result<Option<T>, CallbackDataError> {
+    callback_data
+        .map(|value| CallbackData::decode(value).map(CallbackData::into_inner))
+        .transpose()
+}
@@ -0,0 +1,324 @@
+//! Threading of SMS conversations, for building keyword-response apps like STOP/HELP handling.
+//!
+//! `Conversations` correlates outbound sends and inbound messages by the MSISDN of the other
+//! party, so each number has one thread of messages in both directions. Threads are kept in a
+//! [`ConversationStore`]. The SDK ships an in-memory store; implement the trait on top of a
+//! database to keep threads across restarts or share them between instances.
+//!
+//! Numbers are compared in international format without a leading `+` or `00` and without
+//! spaces, dashes, dots or parentheses, so `+41 79 302 67 27` and `41793026727` share a thread.
+//!
+//! # Example
+//! ```
+//! # use infobip_sdk::model::conversations::{Conversations, Keyword};
+//! # use infobip_sdk::model::sms::InboundSmsReport;
+//! let conversations = Conversations::in_memory();
+//!
+//! let mut report = InboundSmsReport::default();
+//! report.from = Some("41793026727".to_string());
+//! report.text = Some("stop".to_string());
+//!
+//! if let Some(Keyword::Stop) = conversations.record_inbound(&report).unwrap() {
+//!     // Opt the number out of further messages.
+//! }
+//!
+//! let thread = conversations.thread("+41 79 302 67 27").unwrap().unwrap();
+//! assert_eq!(thread.messages.len(), 1);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::model::schedule::parse_date_time;
+use crate::model::sms::{InboundSmsReport, SendRequestBody, SendResponseBody};
+use crate::queue::StoreError;
+
+/// Keywords recipients commonly send to opt out (`STOP`) or ask for information (`HELP`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Keyword {
+    Stop,
+    Help,
+}
+
+impl Keyword {
+    /// Words treated as an opt-out request.
+    pub const STOP_WORDS: &'static [&'static str] =
+        &["STOP", "STOPALL", "UNSUBSCRIBE", "CANCEL", "END", "QUIT"];
+
+    /// Words treated as a request for information.
+    pub const HELP_WORDS: &'static [&'static str] = &["HELP", "INFO"];
+
+    /// Detects a keyword in a message text. The whole text, without surrounding whitespace and
+    /// punctuation, must be one of the keywords, ignoring case, so a message like "don't stop"
+    /// is not an opt-out.
+    pub fn detect(text: &str) -> Option<Keyword> {
+        let word = text
+            .trim()
+            .trim_matches(|c: char| c.is_ascii_punctuation())
+            .to_ascii_uppercase();
+
+        if Keyword::STOP_WORDS.contains(&word.as_str()) {
+            Some(Keyword::Stop)
+        } else if Keyword::HELP_WORDS.contains(&word.as_str()) {
+            Some(Keyword::Help)
+        } else {
+            None
+        }
+    }
+
+    /// Detects a keyword in an inbound message. The keyword extracted by the platform is checked
+    /// first, then the full text of the message.
+    pub fn from_report(report: &InboundSmsReport) -> Option<Keyword> {
+        report
+            .keyword
+            .as_deref()
+            .and_then(Keyword::detect)
+            .or_else(|| report.text.as_deref().and_then(Keyword::detect))
+    }
+}
+
+/// Direction of a message in a conversation, from the point of view of the sender of outbound
+/// messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Direction {
+    Inbound,
+    Outbound,
+}
+
+/// A message in a conversation thread.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationMessage {
+    pub direction: Direction,
+
+    /// ID of the message, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Text of the message. Binary messages have no text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Keyword detected in an inbound message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<Keyword>,
+
+    /// When the message was received by the platform, or recorded if that is not known.
+    pub at: DateTime<Utc>,
+}
+
+/// All messages exchanged with one number, in the order they were recorded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct Conversation {
+    /// Normalized number of the other party.
+    pub msisdn: String,
+
+    pub messages: Vec<ConversationMessage>,
+}
+
+impl Conversation {
+    /// Returns the last inbound message with a keyword, e.g. to check whether the number opted
+    /// out.
+    pub fn last_keyword(&self) -> Option<Keyword> {
+        self.messages
+            .iter()
+            .rev()
+            .find_map(|message| message.keyword)
+    }
+}
+
+/// Persistence for conversation threads. Implementations must be safe to share between tasks.
+pub trait ConversationStore: Send + Sync {
+    /// Appends a message to the thread of the number, creating the thread if needed.
+    fn append(&self, msisdn: &str, message: ConversationMessage) -> Result<(), StoreError>;
+
+    /// Returns the thread of the number, if there is one.
+    fn get(&self, msisdn: &str) -> Result<Option<Conversation>, StoreError>;
+
+    /// Removes the thread of the number, returning it if it was present.
+    fn remove(&self, msisdn: &str) -> Result<Option<Conversation>, StoreError>;
+}
+
+/// A [`ConversationStore`] that keeps threads in memory. Threads are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct MemoryConversationStore {
+    threads: Mutex<HashMap<String, Vec<ConversationMessage>>>,
+}
+
+impl MemoryConversationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ConversationStore for MemoryConversationStore {
+    fn append(&self, msisdn: &str, message: ConversationMessage) -> Result<(), StoreError> {
+        self.threads
+            .lock()
+            .unwrap()
+            .entry(msisdn.to_string())
+            .or_default()
+            .push(message);
+
+        Ok(())
+    }
+
+    fn get(&self, msisdn: &str) -> Result<Option<Conversation>, StoreError> {
+        Ok(self
+            .threads
+            .lock()
+            .unwrap()
+            .get(msisdn)
+            .map(|messages| Conversation {
+                msisdn: msisdn.to_string(),
+                messages: messages.clone(),
+            }))
+    }
+
+    fn remove(&self, msisdn: &str) -> Result<Option<Conversation>, StoreError> {
+        Ok(self
+            .threads
+            .lock()
+            .unwrap()
+            .remove(msisdn)
+            .map(|messages| Conversation {
+                msisdn: msisdn.to_string(),
+                messages,
+            }))
+    }
+}
+
+/// Normalizes a number for correlation: removes whitespace, dashes, dots, parentheses and a
+/// leading `+` or `00`. Alphanumeric sender IDs are only trimmed.
+pub fn normalize_msisdn(msisdn: &str) -> String {
+    let trimmed = msisdn.trim();
+    let is_number = trimmed
+        .chars()
+        .all(|c| c.is_ascii_digit() || " +-.()".contains(c));
+    if !is_number {
+        return trimmed.to_string();
+    }
+
+    let digits: String = trimmed.chars().filter(char::is_ascii_digit).collect();
+    match digits.strip_prefix("00") {
+        Some(rest) if !trimmed.starts_with('+') => rest.to_string(),
+        _ => digits,
+    }
+}
+
+/// Correlates inbound and outbound SMS into conversation threads by number.
+#[derive(Debug)]
+pub struct Conversations<S: ConversationStore = MemoryConversationStore> {
+    store: S,
+}
+
+impl Conversations<MemoryConversationStore> {
+    /// Creates conversations kept in memory.
+    pub fn in_memory() -> Self {
+        Conversations::new(MemoryConversationStore::new())
+    }
+}
+
+impl<S: ConversationStore> Conversations<S> {
+    /// Creates conversations kept in the given store.
+    pub fn new(store: S) -> Self {
+        Conversations { store }
+    }
+
+    /// Returns the store of the conversations.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Adds an inbound message to the thread of its sender and returns the keyword detected in
+    /// it, if any. Messages without a sender are ignored.
+    pub fn record_inbound(&self, report: &InboundSmsReport) -> Result<Option<Keyword>, StoreError> {
+        let Some(from) = report.from.as_deref() else {
+            return Ok(None);
+        };
+        let keyword = Keyword::from_report(report);
+        let at = report
+            .received_at
+            .as_deref()
+            .and_then(parse_date_time)
+            .map(|received_at| received_at.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+
+        self.store.append(
+            &normalize_msisdn(from),
+            ConversationMessage {
+                direction: Direction::Inbound,
+                message_id: report.message_id.clone(),
+                text: report.text.clone(),
+                keyword,
+                at,
+            },
+        )?;
+
+        Ok(keyword)
+    }
+
+    /// Adds a sent request to the thread of every destination. Message IDs are taken from the
+    /// destinations, or from the response of the send if given.
+    pub fn record_outbound(
+        &self,
+        request_body: &SendRequestBody,
+        response_body: Option<&SendResponseBody>,
+    ) -> Result<(), StoreError> {
+        let sent_ids: HashMap<String, String> = response_body
+            .and_then(|body| body.messages.as_ref())
+            .into_iter()
+            .flatten()
+            .filter_map(|sent| {
+                Some((
+                    normalize_msisdn(sent.to.as_deref()?),
+                    sent.message_id.clone()?,
+                ))
+            })
+            .collect();
+        let now = Utc::now();
+
+        for message in &request_body.messages {
+            for destination in message.destinations.iter().flatten() {
+                let msisdn = normalize_msisdn(&destination.to);
+                let message_id = destination
+                    .message_id
+                    .clone()
+                    .or_else(|| sent_ids.get(&msisdn).cloned());
+
+                self.store.append(
+                    &msisdn,
+                    ConversationMessage {
+                        direction: Direction::Outbound,
+                        message_id,
+                        text: message.text.clone(),
+                        keyword: None,
+                        at: now,
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the thread of the number, if there is one.
+    pub fn thread(&self, msisdn: &str) -> Result<Option<Conversation>, StoreError> {
+        self.store.get(&normalize_msisdn(msisdn))
+    }
+
+    /// Removes the thread of the number, returning it if it was present.
+    pub fn forget(&self, msisdn: &str) -> Result<Option<Conversation>, StoreError> {
+        self.store.remove(&normalize_msisdn(msisdn))
+    }
+}
@@ -0,0 +1,188 @@
+//! Reading SMS destinations from CSV recipient files. Needs the `csv` feature.
+//!
+//! Numbers are normalized to the international format the API expects: spaces, dashes, dots
+//! and parentheses are removed, as well as a leading `+` or `00`. Rows whose number is not 7 to
+//! 15 digits long after that are skipped and reported, so one bad row doesn't stop a send.
+//!
+//! # Example
+//! ```
+//! # use infobip_sdk::model::sms::{Destination, Message, SendRequestBody};
+//! let file = "name,phone\nJane,+41 79 302 67 27\nJohn,not a number\n";
+//!
+//! let import = Destination::from_csv_reader(file.as_bytes(), "phone").unwrap();
+//!
+//! assert_eq!(import.destinations, vec![Destination::new("41793026727")]);
+//! assert_eq!(import.invalid_rows[0].line, 3);
+//!
+//! let mut message = Message::new(import.destinations);
+//! message.text = Some("Hello!".to_string());
+//! let request_body = SendRequestBody::new(vec![message]);
+//! ```
+
+use std::io;
+
+use thiserror::Error;
+
+use crate::model::sms::Destination;
+
+/// Default number of destinations per chunk read by `Destination::csv_chunks`.
+pub const DEFAULT_CHUNK_SIZE: usize = 1000;
+
+const MIN_NUMBER_LENGTH: usize = 7;
+const MAX_NUMBER_LENGTH: usize = 15;
+
+/// Holds the possible errors of reading destinations from CSV.
+#[derive(Error, Debug)]
+pub enum CsvImportError {
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("no column named {0} in the CSV header")]
+    MissingColumn(String),
+}
+
+/// A row skipped because its number is not valid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidRow {
+    /// Line of the row in the file, starting at 1 for the header.
+    pub line: u64,
+
+    /// Value of the number column, as read.
+    pub value: String,
+}
+
+/// Destinations read from CSV, and the rows skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DestinationImport {
+    pub destinations: Vec<Destination>,
+    pub invalid_rows: Vec<InvalidRow>,
+}
+
+/// Iterator over chunks of destinations read from CSV. Each chunk holds up to the chunk size of
+/// destinations, and the invalid rows found while reading them. See `Destination::csv_chunks`.
+pub struct DestinationChunks<R> {
+    records: csv::StringRecordsIntoIter<R>,
+    column: usize,
+    chunk_size: usize,
+    done: bool,
+}
+
+impl<R: io::Read> Iterator for DestinationChunks<R> {
+    type Item = Result<DestinationImport, CsvImportError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut chunk = DestinationImport::default();
+        while chunk.destinations.len() < self.chunk_size {
+            let record = match self.records.next() {
+                Some(Ok(record)) => record,
+                Some(Err(error)) => {
+                    self.done = true;
+                    return Some(Err(error.into()));
+                }
+                None => {
+                    self.done = true;
+                    break;
+                }
+            };
+
+            let value = record.get(self.column).unwrap_or_default();
+            match normalize_number(value) {
+                Some(number) => chunk.destinations.push(Destination::new(&number)),
+                None => chunk.invalid_rows.push(InvalidRow {
+                    line: record.position().map_or(0, |position| position.line()),
+                    value: value.to_string(),
+                }),
+            }
+        }
+
+        if chunk == DestinationImport::default() {
+            None
+        } else {
+            Some(Ok(chunk))
+        }
+    }
+}
+
+impl Destination {
+    /// Reads all destinations from the column with the given header name. Rows with an invalid
+    /// number are skipped and returned in `invalid_rows`.
+    pub fn from_csv_reader<R: io::Read>(
+        reader: R,
+        column: &str,
+    ) -> Result<DestinationImport, CsvImportError> {
+        let mut import = DestinationImport::default();
+        for chunk in Destination::csv_chunks(reader, column, usize::MAX)? {
+            let chunk = chunk?;
+            import.destinations.extend(chunk.destinations);
+            import.invalid_rows.extend(chunk.invalid_rows);
+        }
+
+        Ok(import)
+    }
+
+    /// Reads destinations from the column with the given header name in chunks of up to
+    /// `chunk_size` destinations, e.g. [`DEFAULT_CHUNK_SIZE`], so large files can be sent in
+    /// several requests without being read into memory at once. A `chunk_size` of 0 is treated
+    /// as 1.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use infobip_sdk::model::csv_import::DEFAULT_CHUNK_SIZE;
+    /// # use infobip_sdk::model::sms::Destination;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = File::open("recipients.csv")?;
+    ///
+    /// for chunk in Destination::csv_chunks(file, "phone", DEFAULT_CHUNK_SIZE)? {
+    ///     let chunk = chunk?;
+    ///     println!("{} destinations to send to", chunk.destinations.len());
+    ///     for row in chunk.invalid_rows {
+    ///         eprintln!("line {}: invalid number {}", row.line, row.value);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn csv_chunks<R: io::Read>(
+        reader: R,
+        column: &str,
+        chunk_size: usize,
+    ) -> Result<DestinationChunks<R>, CsvImportError> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let column = reader
+            .headers()?
+            .iter()
+            .position(|header| header.trim() == column)
+            .ok_or_else(|| CsvImportError::MissingColumn(column.to_string()))?;
+
+        Ok(DestinationChunks {
+            records: reader.into_records(),
+            column,
+            chunk_size: chunk_size.max(1),
+            done: false,
+        })
+    }
+}
+
+/// Normalizes a phone number to international format without a leading `+`. Returns `None` if
+/// the number is not valid.
+pub fn normalize_number(number: &str) -> Option<String> {
+    let number: String = number
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect();
+    let number = number
+        .strip_prefix('+')
+        .or_else(|| number.strip_prefix("00"))
+        .unwrap_or(&number);
+
+    let valid = (MIN_NUMBER_LENGTH..=MAX_NUMBER_LENGTH).contains(&number.len())
+        && number.chars().all(|c| c.is_ascii_digit());
+
+    valid.then(|| number.to_string())
+}
@@ -1,26 +1,161 @@
 //! Models for calling Email endpoints.
 
+use std::collections::HashMap;
+use std::path::Path;
+
 use serde_derive::{Deserialize, Serialize};
-use validator::Validate;
+use thiserror::Error;
+use validator::{validate_email, Validate, ValidationError};
+
+use crate::model::schedule::{Schedule, ScheduleError};
+use crate::model::status::{SendResult, StatusGroup};
+use crate::model::templating::{Template, TemplateError};
+use crate::model::{fill_platform_id, PlatformIds};
+
+impl_try_from_value!(
+    SendRequestBody,
+    ValidateAddressRequestBody,
+    AddDomainRequestBody
+);
+
+impl_query_params!(
+    BulksQueryParameters,
+    DeliveryReportsQueryParameters,
+    LogsQueryParameters,
+    TrackingReportsQueryParameters,
+    DomainsQueryParameters,
+);
+
+const CID_PREFIX: &str = "cid:";
+
+/// Returns the Content-ID under which an inline image file will be available to the HTML body,
+/// which is the name of the file without its directory. Reference it in the HTML body as
+/// `cid:<CONTENT_ID>`, e.g. `<img src="cid:logo.png">`.
+pub fn inline_image_cid(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// Returns all Content-IDs referenced with `cid:` in the given HTML, in order of appearance and
+/// without duplicates.
+pub fn referenced_cids(html: &str) -> Vec<String> {
+    let mut cids: Vec<String> = Vec::new();
+
+    for (index, _) in html.match_indices(CID_PREFIX) {
+        let rest = &html[index + CID_PREFIX.len()..];
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | ')' | '>' | '<'))
+            .unwrap_or(rest.len());
+        let cid = &rest[..end];
+
+        if !cid.is_empty() && !cids.iter().any(|existing| existing == cid) {
+            cids.push(cid.to_string());
+        }
+    }
+
+    cids
+}
+
+fn validate_inline_images(request_body: &SendRequestBody) -> Result<(), ValidationError> {
+    let missing = request_body.missing_inline_images();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("missing_inline_images");
+    error.message = Some(format!("no inline image found for cid: {}", missing.join(", ")).into());
+    Err(error)
+}
+
+fn validate_has_recipient(request_body: &SendRequestBody) -> Result<(), ValidationError> {
+    let has_recipients = request_body
+        .recipients
+        .as_ref()
+        .is_some_and(|recipients| !recipients.is_empty());
+    if !request_body.to.is_empty() || has_recipients {
+        return Ok(());
+    }
+
+    Err(ValidationError::new("missing_recipient"))
+}
+
+// An empty `to` is allowed when the recipients are given in `recipients`, which is checked by
+// `validate_has_recipient`.
+fn validate_to(to: &str) -> Result<(), ValidationError> {
+    if to.is_empty() {
+        return Ok(());
+    }
+
+    validate_email_addresses(to)
+}
+
+// Converts placeholder values to a JSON object, failing for values that don't serialize to one.
+fn placeholders_object<T: serde::Serialize>(
+    placeholders: &T,
+) -> Result<serde_json::Map<String, serde_json::Value>, serde_json::Error> {
+    match serde_json::to_value(placeholders)? {
+        serde_json::Value::Object(object) => Ok(object),
+        _ => Err(serde::ser::Error::custom(
+            "placeholders must serialize to a JSON object",
+        )),
+    }
+}
+
+// Checks a comma-separated list of addresses, each a plain email address or one with a name, like
+// `Jane Smith <jane.smith@company.com>`.
+fn validate_email_addresses(addresses: &str) -> Result<(), ValidationError> {
+    let invalid: Vec<&str> = addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|address| {
+            let email = match (address.rfind('<'), address.strip_suffix('>')) {
+                (Some(start), Some(rest)) => &rest[start + 1..],
+                _ => address,
+            };
+            !validate_email(email)
+        })
+        .collect();
+    if invalid.is_empty() {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("email");
+    error.message = Some(format!("invalid email address: {}", invalid.join(", ")).into());
+    Err(error)
+}
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_inline_images"))]
+#[validate(schema(function = "validate_has_recipient"))]
 pub struct SendRequestBody {
     /// Email address with optional sender name. This field is required if `templateId` is not
     /// present.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_email_addresses")]
     pub from: Option<String>,
 
-    /// Email address of the recipient.
-    #[validate(length(min = 1))]
+    /// Email address of the recipient. Can be empty if `recipients` are given.
+    #[validate(custom = "validate_to")]
     pub to: String,
 
+    /// Recipients with their own values for the placeholders of the template, sent in addition
+    /// to `to`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub recipients: Option<Vec<TemplateRecipient>>,
+
     /// CC recipient email address.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_email_addresses")]
     pub cc: Option<String>,
 
     /// BCC recipient email address.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_email_addresses")]
     pub bcc: Option<String>,
 
     /// Message subject. This field is required if `templateId` is not present.
@@ -52,7 +187,8 @@ pub struct SendRequestBody {
     pub attachments: Option<Vec<String>>,
 
     /// Allows for inserting an image file inside the HTML code of the email by using
-    /// `cid:FILENAME` instead of providing an external link to the image.
+    /// `cid:FILENAME` instead of providing an external link to the image. Every `cid:` referenced
+    /// in `html` must have a matching inline image.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_images: Option<Vec<String>>,
 
@@ -107,6 +243,7 @@ pub struct SendRequestBody {
 
     /// Email address to which recipients of the email can reply.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_email_addresses")]
     pub reply_to: Option<String>,
 
     /// General placeholder, given in a form of json example:
@@ -138,6 +275,82 @@ pub struct SendRequestBody {
     /// on IB’s portal and use the last 6 digits from URL to use that opt out page.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub landing_page_id: Option<String>,
+
+    /// ID of the CPaaS X application the email is sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// ID of the CPaaS X entity the email is sent for, e.g. a customer of the platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+}
+
+/// A recipient of a template email, with values for the placeholders of the template. Sent as a
+/// JSON `to` field, so every recipient of a request can get different values.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateRecipient {
+    /// Email address of the recipient.
+    #[validate(email)]
+    pub to: String,
+
+    /// Values of the template placeholders, by placeholder name.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[cfg_attr(
+        feature = "arbitrary",
+        arbitrary(with = crate::model::arbitrary_json_map)
+    )]
+    pub placeholders: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TemplateRecipient {
+    pub fn new(to: &str) -> Self {
+        Self {
+            to: to.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a recipient with placeholder values taken from the fields of a struct, or any
+    /// value that serializes to a JSON object.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::model::email::TemplateRecipient;
+    /// #[derive(serde::Serialize)]
+    /// struct Welcome {
+    ///     name: String,
+    ///     plan: String,
+    /// }
+    ///
+    /// let recipient = TemplateRecipient::with_placeholders(
+    ///     "jane.smith@somecompany.com",
+    ///     &Welcome {
+    ///         name: "Jane".to_string(),
+    ///         plan: "Premium".to_string(),
+    ///     },
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(recipient.placeholders["name"], "Jane");
+    /// ```
+    pub fn with_placeholders<T: serde::Serialize>(
+        to: &str,
+        placeholders: &T,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            to: to.into(),
+            placeholders: placeholders_object(placeholders)?,
+        })
+    }
+}
+
+impl PlatformIds for SendRequestBody {
+    fn fill_platform_ids(&mut self, application_id: Option<&str>, entity_id: Option<&str>) {
+        fill_platform_id(&mut self.application_id, application_id);
+        fill_platform_id(&mut self.entity_id, entity_id);
+    }
 }
 
 impl SendRequestBody {
@@ -147,10 +360,190 @@ impl SendRequestBody {
             ..Default::default()
         }
     }
+
+    /// Creates a request that sends the stored template to the recipients, each with their own
+    /// placeholder values.
+    pub fn from_template(template_id: i32, recipients: Vec<TemplateRecipient>) -> Self {
+        Self {
+            template_id: Some(template_id),
+            recipients: Some(recipients),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a recipient with their own placeholder values.
+    pub fn add_recipient(&mut self, recipient: TemplateRecipient) {
+        self.recipients.get_or_insert_with(Vec::new).push(recipient);
+    }
+
+    /// Sets `default_placeholders` from the fields of a struct, or any value that serializes to a
+    /// JSON object. Default placeholders apply to all recipients without their own value.
+    pub fn set_default_placeholders<T: serde::Serialize>(
+        &mut self,
+        placeholders: &T,
+    ) -> Result<(), serde_json::Error> {
+        let object = placeholders_object(placeholders)?;
+        self.default_placeholders = Some(serde_json::to_string(&object)?);
+
+        Ok(())
+    }
+
+    /// Sets `send_at` from the schedule, converted to UTC. Email has no delivery time windows,
+    /// so a schedule with one fails with `ScheduleError::WindowNotSupported`.
+    pub fn apply_schedule(&mut self, schedule: &Schedule) -> Result<(), ScheduleError> {
+        schedule.validate()?;
+        if schedule.delivery_time_window_utc()?.is_some() {
+            return Err(ScheduleError::WindowNotSupported);
+        }
+        self.send_at = schedule.send_at_formatted();
+
+        Ok(())
+    }
+
+    /// Adds a file attachment. Its name and content type are taken from the file when sending.
+    pub fn add_attachment(&mut self, path: &str) {
+        self.attachments
+            .get_or_insert_with(Vec::new)
+            .push(path.to_string());
+    }
+
+    /// Adds an inline image file and returns its Content-ID, to be referenced from the HTML body
+    /// as `cid:<CONTENT_ID>`.
+    pub fn add_inline_image(&mut self, path: &str) -> String {
+        self.inline_images
+            .get_or_insert_with(Vec::new)
+            .push(path.to_string());
+
+        inline_image_cid(path)
+    }
+
+    /// Returns the Content-IDs of all inline images.
+    pub fn inline_image_cids(&self) -> Vec<String> {
+        self.inline_images
+            .iter()
+            .flatten()
+            .map(|path| inline_image_cid(path))
+            .collect()
+    }
+
+    /// Returns the Content-IDs referenced in the HTML body that have no matching inline image.
+    pub fn missing_inline_images(&self) -> Vec<String> {
+        let available = self.inline_image_cids();
+
+        self.html
+            .as_deref()
+            .map(referenced_cids)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|cid| !available.contains(cid))
+            .collect()
+    }
+
+    /// Renders the subject and bodies locally as the recipient will get them, with the default
+    /// placeholders overridden by the placeholders of the matching entry of `recipients`. Values
+    /// are inserted as is, without HTML escaping, like the platform does. Fails with
+    /// `PreviewError::StoredTemplate` if the content comes from a stored template, as the API
+    /// has no endpoint for rendering one.
+    ///
+    /// # Example
+    /// ```
+    /// # use infobip_sdk::model::email::{SendRequestBody, TemplateRecipient};
+    /// # use serde_json::json;
+    /// let mut request_body = SendRequestBody::new("");
+    /// request_body.subject = Some("Welcome, {{name}}".to_string());
+    /// request_body.html = Some("<p>Your plan: {{plan}}</p>".to_string());
+    /// request_body
+    ///     .set_default_placeholders(&json!({"plan": "Basic"}))
+    ///     .unwrap();
+    /// request_body.add_recipient(
+    ///     TemplateRecipient::with_placeholders("jane@example.com", &json!({"name": "Jane"}))
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let preview = request_body.preview("jane@example.com").unwrap();
+    ///
+    /// assert_eq!(preview.subject.as_deref(), Some("Welcome, Jane"));
+    /// assert_eq!(preview.html.as_deref(), Some("<p>Your plan: Basic</p>"));
+    /// ```
+    pub fn preview(&self, recipient: &str) -> Result<EmailPreview, PreviewError> {
+        if let Some(template_id) = self.template_id {
+            return Err(PreviewError::StoredTemplate(template_id));
+        }
+
+        let mut values = match self.default_placeholders.as_deref() {
+            Some(json) => match serde_json::from_str(json) {
+                Ok(serde_json::Value::Object(object)) => placeholder_values(&object),
+                _ => return Err(PreviewError::InvalidDefaultPlaceholders),
+            },
+            None => HashMap::new(),
+        };
+        if let Some(entry) = self
+            .recipients
+            .iter()
+            .flatten()
+            .find(|entry| entry.to == recipient)
+        {
+            values.extend(placeholder_values(&entry.placeholders));
+        }
+
+        let render = |content: &Option<String>| {
+            content
+                .as_deref()
+                .map(|content| Template::new(content).render(&values))
+                .transpose()
+        };
+
+        Ok(EmailPreview {
+            subject: render(&self.subject)?,
+            text: render(&self.text)?,
+            html: render(&self.html)?,
+            amp_html: render(&self.amp_html)?,
+        })
+    }
+}
+
+// Converts placeholder values to text, keeping strings as they are.
+fn placeholder_values(
+    placeholders: &serde_json::Map<String, serde_json::Value>,
+) -> HashMap<String, String> {
+    placeholders
+        .iter()
+        .map(|(name, value)| {
+            let text = match value {
+                serde_json::Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            (name.clone(), text)
+        })
+        .collect()
+}
+
+/// Content of an email rendered for one recipient by `SendRequestBody::preview`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmailPreview {
+    pub subject: Option<String>,
+    pub text: Option<String>,
+    pub html: Option<String>,
+    pub amp_html: Option<String>,
+}
+
+/// Holds the possible errors of previewing an email.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PreviewError {
+    #[error("template {0} is stored on the platform and can't be rendered locally")]
+    StoredTemplate(i32),
+
+    #[error("default placeholders are not a JSON object")]
+    InvalidDefaultPlaceholders,
+
+    #[error(transparent)]
+    Template(#[from] TemplateError),
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct SentMessageDetails {
     /// The destination address of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -162,9 +555,23 @@ pub struct SentMessageDetails {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl SentMessageDetails {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct ReportError {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -187,6 +594,7 @@ pub struct ReportError {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Status {
     /// Status group ID.
@@ -214,8 +622,17 @@ pub struct Status {
     pub action: Option<String>,
 }
 
+impl Status {
+    /// Returns the typed status group, from `group_id` or `group_name`.
+    pub fn group(&self) -> Option<StatusGroup> {
+        StatusGroup::resolve(self.group_id, self.group_name.as_deref())
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct SendResponseBody {
     /// The ID that uniquely identifies a list of message responses.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,9 +641,27 @@ pub struct SendResponseBody {
     /// List of message response details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<SentMessageDetails>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl SendResponseBody {
+    /// Splits the sent messages into accepted and rejected ones, by the group of their status.
+    pub fn partition_by_status(&self) -> SendResult<'_, SentMessageDetails> {
+        SendResult::partition(
+            self.messages.iter().flatten(),
+            SentMessageDetails::status_group,
+        )
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
 pub struct BulksQueryParameters {
     #[validate(length(min = 1))]
     pub bulk_id: String,
@@ -241,6 +676,7 @@ impl BulksQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct BulksResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -251,6 +687,7 @@ pub struct BulksResponseBody {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct BulkInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -263,6 +700,7 @@ pub struct BulkInfo {
 pub type RescheduleQueryParameters = BulksQueryParameters;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct RescheduleRequestBody {
     #[validate(length(min = 1))]
@@ -282,7 +720,9 @@ pub type RescheduleResponseBody = BulkInfo;
 pub type ScheduledStatusQueryParameters = BulksQueryParameters;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum BulkStatus {
     Pending,
     Paused,
@@ -290,9 +730,14 @@ pub enum BulkStatus {
     Canceled,
     Finished,
     Failed,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
 pub struct BulkStatusInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
@@ -302,6 +747,7 @@ pub struct BulkStatusInfo {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct ScheduledStatusResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -314,6 +760,7 @@ pub struct ScheduledStatusResponseBody {
 pub type UpdateScheduledStatusQueryParameters = BulksQueryParameters;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateScheduledStatusRequestBody {
     pub status: BulkStatus,
@@ -328,6 +775,7 @@ impl UpdateScheduledStatusRequestBody {
 pub type UpdateScheduledStatusResponseBody = BulkStatusInfo;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryReportsQueryParameters {
     /// Bulk ID for which report is requested.
@@ -341,6 +789,18 @@ pub struct DeliveryReportsQueryParameters {
     /// Maximum number of reports.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+
+    /// Entity ID of the messages, to only include messages of one entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID of the messages, to only include messages of one application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID of the messages, to only include messages of one campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
 }
 
 impl DeliveryReportsQueryParameters {
@@ -350,10 +810,12 @@ impl DeliveryReportsQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Price {
     /// Price per one email request.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f32))]
     pub price_per_message: Option<f32>,
 
     /// The currency in which the price is expressed.
@@ -362,7 +824,9 @@ pub struct Price {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Report {
     /// The ID that uniquely identifies bulks of request.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -399,16 +863,50 @@ pub struct Report {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ReportError>,
+
+    /// Entity ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Report {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct DeliveryReportsResponseBody {
-    #[serde(rename = "results", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Report>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct LogsQueryParameters {
     /// The ID that uniquely identifies the sent email.
@@ -444,6 +942,18 @@ pub struct LogsQueryParameters {
     /// Maximum number of logs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+
+    /// Entity ID of the messages, to only include messages of one entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID of the messages, to only include messages of one application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID of the messages, to only include messages of one campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
 }
 
 impl LogsQueryParameters {
@@ -453,7 +963,9 @@ impl LogsQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Log {
     /// The ID that uniquely identifies the sent email request.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -495,16 +1007,159 @@ pub struct Log {
     /// The ID that uniquely identifies the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bulk_id: Option<String>,
+
+    /// Entity ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Log {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct LogsResponseBody {
-    #[serde(rename = "results", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Log>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingReportsQueryParameters {
+    /// Bulk ID for which events are requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the sent email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Lower limit of the time the event happened. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+
+    /// Upper limit of the time the event happened. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+
+    /// Maximum number of events per page. Default value is 100 with a maximum of 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 1000))]
+    pub size: Option<i32>,
+
+    /// Page number you want to see. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0))]
+    pub page: Option<i32>,
+}
+
+impl TrackingReportsQueryParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// An open, click, bounce or spam complaint of a sent email. Fields not relevant to the kind of
+/// event are not present.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TrackingReport {
+    /// The ID that uniquely identifies bulks of request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the sent email request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// The recipient email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Tells when the email was initiated. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
+
+    /// Tells when the event happened. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_at: Option<String>,
+
+    /// Clicked link, for click events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// User agent of the recipient's email client, for open and click events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// Type of the recipient's device, e.g. `Desktop` or `Phone`, for open and click events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<String>,
+
+    /// Operating system of the recipient's device, for open and click events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+
+    /// `HARD` or `SOFT`, for bounce events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounce_type: Option<String>,
+
+    /// Reason reported by the recipient's mail server, for bounce and complaint events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingReportsResponseBody {
+    /// Pagination details like page number, page size, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<TrackingReport>>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct ValidateAddressRequestBody {
     /// Email address of the recipient.
@@ -519,7 +1174,9 @@ impl ValidateAddressRequestBody {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ValidateAddressResponseBody {
     /// Email address of the recipient.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -549,18 +1206,26 @@ pub struct ValidateAddressResponseBody {
 
     /// Reason is provided when validMailbox status is unknown.
     /// 1. INBOX_FULL - The user quota exceeded / The user inbox is full / The user doesn't accept
-    /// any more requests.
+    ///    any more requests.
     /// 2. UNEXPECTED_FAILURE - The mail Server returned a temporary error.
     /// 3. THROTTLED - The mail server is not allowing us momentarily because of too many requests.
     /// 4. TIMED_OUT - The Mail Server took a longer time to respond / there was a delay in the
-    /// network.
+    ///    network.
     /// 5. TEMP_REJECTION - Mail server temporarily rejected.
     /// 6. UNABLE_TO_CONNECT - Unable to connect to the Mail Server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
 pub struct DomainsQueryParameters {
     /// Maximum number of domains to be viewed per page. Default value is 10 with a maximum of 20 records per page.
     #[validate(range(min = 1, max = 20))]
@@ -578,6 +1243,7 @@ impl DomainsQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Tracking {
     /// Indicates whether tracking of clicks is enabled.
@@ -594,6 +1260,7 @@ pub struct Tracking {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct DnsRecord {
     /// Type of the record.
@@ -614,6 +1281,7 @@ pub struct DnsRecord {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Domain {
     /// Id of the domain.
@@ -646,6 +1314,7 @@ pub struct Domain {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Paging {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -662,6 +1331,7 @@ pub struct Paging {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct DomainsResponseBody {
     /// Pagination details like page number, page size, etc.
@@ -674,6 +1344,7 @@ pub struct DomainsResponseBody {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DkimKeyLength {
     #[serde(rename = "1024")]
     L1024 = 1024,
@@ -682,6 +1353,7 @@ pub enum DkimKeyLength {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct AddDomainRequestBody {
     #[validate(length(min = 1))]
@@ -705,6 +1377,7 @@ pub type AddDomainResponseBody = Domain;
 pub type DomainResponseBody = Domain;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTrackingRequestBody {
     #[serde(rename = "open", skip_serializing_if = "Option::is_none")]
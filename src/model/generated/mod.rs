@@ -0,0 +1,13 @@
+//! Models generated from Infobip's published OpenAPI definitions, vendored in `openapi/`.
+//!
+//! The models follow the definitions as-is, without validation or helper constructors, so new
+//! API fields show up here first. The models of the channel modules, like `model::sms`, are the
+//! curated layer on top: they are written by hand, adopt fields from the generated models and
+//! add validation and convenience. Tests check that the curated models still cover every field
+//! of their generated counterpart.
+//!
+//! Do not edit the generated files; change the definition and regenerate them, see
+//! `model::tests::codegen`.
+
+#[cfg(feature = "sms")]
+pub mod sms;
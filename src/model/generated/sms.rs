@@ -0,0 +1,75 @@
+//! Models of the Infobip SMS API.
+//!
+//! Generated from `openapi/sms.json` by the `model::tests::codegen` test. Do not edit.
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsLanguage {
+    /// Language code for the correct character set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsLanguageConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<SmsLanguage>,
+
+    /// Conversion of a message text from one script to another.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transliteration: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsPreview {
+    /// Number of remaining characters in the last SMS part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub characters_remaining: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<SmsLanguageConfiguration>,
+
+    /// Number of SMS message parts required to deliver the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Preview of the text as it should appear on the recipient’s device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_preview: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsPreviewRequest {
+    /// Code for language character set of a message text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+
+    /// Message text to preview.
+    pub text: String,
+
+    /// Conversion of a message text from one script to another.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transliteration: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsPreviewResponse {
+    /// Text supplied in the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_text: Option<String>,
+
+    /// Previews of applying different configurations to the original text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previews: Option<Vec<SmsPreview>>,
+}
@@ -1,15 +1,261 @@
 //! Module that contains the modules with models to create payloads and query parameters to call
 //! endpoints and get responses from them with convenient, validated structs. There is one
 //! submodule for each channel.
+//!
+//! With the `arbitrary` feature, the models implement `arbitrary::Arbitrary`, so fuzz targets can
+//! build them from raw bytes, e.g. to feed webhook handlers with any report the API could send.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+// Implements `TryFrom<serde_json::Value>` for request bodies, deserializing and validating them.
+#[cfg(any(
+    feature = "email",
+    feature = "sms",
+    feature = "tfa",
+    feature = "whatsapp"
+))]
+macro_rules! impl_try_from_value {
+    ($($body:ty),+ $(,)?) => {
+        $(
+            impl TryFrom<serde_json::Value> for $body {
+                type Error = crate::api::SdkError;
+
+                /// Deserializes the body from JSON and validates it.
+                fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+                    let body: Self = serde_json::from_value(value)?;
+                    validator::Validate::validate(&body)?;
+
+                    Ok(body)
+                }
+            }
+        )+
+    };
+}
+
+// Implements `QueryParams` for query parameter structs.
+#[cfg(any(
+    feature = "email",
+    feature = "sms",
+    feature = "tfa",
+    feature = "whatsapp"
+))]
+macro_rules! impl_query_params {
+    ($($parameters:ty),+ $(,)?) => {
+        $(
+            impl crate::model::QueryParams for $parameters {}
+        )+
+    };
+}
+
+#[cfg(feature = "account")]
+pub mod account;
+
+pub mod callback_data;
+
+#[cfg(feature = "sms")]
+pub mod conversations;
+
+#[cfg(feature = "csv")]
+pub mod csv_import;
 
 #[cfg(feature = "email")]
 pub mod email;
 
+pub mod generated;
+
 #[cfg(feature = "sms")]
 pub mod sms;
 
+#[cfg(any(feature = "email", feature = "sms"))]
+pub mod schedule;
+
+pub mod status;
+
+#[cfg(any(feature = "email", feature = "sms"))]
+pub mod templating;
+
+#[cfg(feature = "tfa")]
+pub mod tfa;
+
 #[cfg(feature = "whatsapp")]
 pub mod whatsapp;
 
+/// Request bodies that can be attributed to an application and entity of a multi-tenant
+/// platform (CPaaS X). Clients fill in the IDs set with `Configuration::with_application_id` and
+/// `Configuration::with_entity_id`.
+///
+/// 2FA PIN requests only get the entity ID, as their `application_id` is the required ID of the
+/// 2FA application.
+pub trait PlatformIds {
+    /// Sets the application ID and entity ID of every message that doesn't have its own.
+    fn fill_platform_ids(&mut self, application_id: Option<&str>, entity_id: Option<&str>);
+}
+
+#[cfg(any(
+    feature = "email",
+    feature = "sms",
+    feature = "tfa",
+    feature = "whatsapp"
+))]
+fn fill_platform_id(field: &mut Option<String>, value: Option<&str>) {
+    if field.is_none() {
+        *field = value.map(str::to_string);
+    }
+}
+
+// Generates JSON objects for `Arbitrary` implementations, as `serde_json` has none. Values are
+// scalars without floats, so they survive a serialization round trip unchanged.
+#[cfg(all(feature = "arbitrary", any(feature = "email", feature = "whatsapp")))]
+fn arbitrary_json_map(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<serde_json::Map<String, Value>> {
+    let mut map = serde_json::Map::new();
+    for _ in 0..u.arbitrary_len::<(String, i64)>()? {
+        let value = match u.int_in_range(0..=3)? {
+            0 => Value::Null,
+            1 => Value::Bool(u.arbitrary()?),
+            2 => Value::from(u.arbitrary::<i64>()?),
+            _ => Value::String(u.arbitrary()?),
+        };
+        map.insert(u.arbitrary()?, value);
+    }
+
+    Ok(map)
+}
+
+// Generates finite floats for `Arbitrary` implementations, as JSON has no NaN or infinity.
+#[cfg(all(
+    feature = "arbitrary",
+    any(feature = "sms", feature = "tfa", feature = "whatsapp")
+))]
+fn arbitrary_finite_f64(u: &mut arbitrary::Unstructured) -> arbitrary::Result<f64> {
+    let value: f64 = u.arbitrary()?;
+    Ok(if value.is_finite() { value } else { 0.0 })
+}
+
+#[cfg(all(
+    feature = "arbitrary",
+    any(feature = "sms", feature = "tfa", feature = "whatsapp")
+))]
+fn arbitrary_optional_finite_f64(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<Option<f64>> {
+    if u.arbitrary()? {
+        Ok(Some(arbitrary_finite_f64(u)?))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(all(feature = "arbitrary", feature = "email"))]
+fn arbitrary_optional_finite_f32(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<Option<f32>> {
+    if u.arbitrary()? {
+        let value: f32 = u.arbitrary()?;
+        Ok(Some(if value.is_finite() { value } else { 0.0 }))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(all(feature = "arbitrary", feature = "whatsapp"))]
+fn arbitrary_optional_json_map(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<Option<HashMap<String, Value>>> {
+    if u.arbitrary()? {
+        Ok(Some(arbitrary_json_map(u)?.into_iter().collect()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Error parsing a code enum such as `TemplateLanguage` or `StatusGroup` from a string that
+/// isn't one of its codes.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("unknown {kind} `{code}`")]
+pub struct ParseCodeError {
+    /// What the code was parsed as, e.g. `template language`.
+    pub kind: &'static str,
+    /// The string that was parsed.
+    pub code: String,
+}
+
+impl ParseCodeError {
+    pub(crate) fn new(kind: &'static str, code: &str) -> Self {
+        Self {
+            kind,
+            code: code.to_string(),
+        }
+    }
+}
+
+/// Query parameters of an endpoint, encoded from their serde representation so the names sent
+/// are the same as the JSON field names. Fields that are `None` are left out, and lists are sent
+/// as comma-separated values. The client methods only accept query parameters implementing it.
+pub trait QueryParams: Serialize {
+    /// Returns the query parameters as name-value pairs.
+    fn to_query_map(&self) -> Result<HashMap<String, String>, QueryParamsError> {
+        encode_query_parameters(self)
+    }
+}
+
+// Endpoints without query parameters.
+impl QueryParams for () {}
+
+/// Error encoding query parameters, see `QueryParams`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum QueryParamsError {
+    #[error("query parameters must be a struct or a map")]
+    NotAStruct,
+
+    #[error("query parameter {0} has a nested value")]
+    NestedValue(String),
+
+    #[error("query parameters can't be serialized: {0}")]
+    Serialize(String),
+}
+
+// Encodes query parameters the way `QueryParams` describes. `()` encodes to no parameters.
+fn encode_query_parameters<Q: Serialize + ?Sized>(
+    query_parameters: &Q,
+) -> Result<HashMap<String, String>, QueryParamsError> {
+    let value = serde_json::to_value(query_parameters)
+        .map_err(|error| QueryParamsError::Serialize(error.to_string()))?;
+    let fields = match value {
+        Value::Object(fields) => fields,
+        Value::Null => return Ok(HashMap::new()),
+        _ => return Err(QueryParamsError::NotAStruct),
+    };
+
+    let mut parameters = HashMap::new();
+    for (name, value) in fields {
+        let value = match value {
+            Value::Null => continue,
+            Value::Array(values) => values
+                .into_iter()
+                .map(|value| query_value(&name, value))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(","),
+            value => query_value(&name, value)?,
+        };
+        parameters.insert(name, value);
+    }
+
+    Ok(parameters)
+}
+
+fn query_value(name: &str, value: Value) -> Result<String, QueryParamsError> {
+    match value {
+        Value::String(value) => Ok(value),
+        Value::Array(_) | Value::Object(_) => Err(QueryParamsError::NestedValue(name.to_string())),
+        value => Ok(value.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests;
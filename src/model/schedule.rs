@@ -0,0 +1,313 @@
+//! Scheduling of messages: when they are sent, and the time window in which they may be
+//! delivered.
+//!
+//! The API expects `send_at` and delivery time windows in UTC. A [`Schedule`] can instead be
+//! given in the local time of the recipients, as an offset from UTC, and converts both to UTC
+//! when applied to a message, e.g. with `sms::Message::apply_schedule`. A delivery time window
+//! can't span midnight UTC, so a local window that would is rejected rather than split.
+//!
+//! # Example
+//! ```
+//! # use chrono::{Duration, FixedOffset, Utc};
+//! # use infobip_sdk::model::schedule::{DeliveryDay, DeliveryTime, DeliveryTimeWindow, Schedule};
+//! // Business hours on weekdays in New York, 9:00 to 17:00 at UTC-05:00.
+//! let mut window = DeliveryTimeWindow::new(vec![DeliveryDay::Monday, DeliveryDay::Friday]);
+//! window.from = Some(DeliveryTime::new(9, 0));
+//! window.to = Some(DeliveryTime::new(17, 0));
+//!
+//! let schedule = Schedule::new()
+//!     .send_at(Utc::now() + Duration::days(1))
+//!     .within(window)
+//!     .in_time_zone(FixedOffset::west_opt(5 * 3600).unwrap());
+//!
+//! let utc_window = schedule.delivery_time_window_utc().unwrap().unwrap();
+//! assert_eq!(utc_window.from, Some(DeliveryTime::new(14, 0)));
+//! assert_eq!(utc_window.to, Some(DeliveryTime::new(22, 0)));
+//! ```
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, Offset, TimeZone, Utc};
+use serde_derive::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(feature = "sms")]
+use validator::ValidationError;
+use validator::{Validate, ValidationErrors};
+
+/// Format of the dates and times sent to the API, e.g. `2021-08-25T16:00:00.000+0000`.
+pub const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f%z";
+
+/// Maximum number of days a message can be scheduled in advance.
+pub const MAX_SCHEDULE_DAYS: i64 = 180;
+
+#[cfg(feature = "sms")]
+pub(crate) fn parse_date_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(value, DATE_TIME_FORMAT)
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .ok()
+}
+
+#[cfg(feature = "sms")]
+fn validate_send_at_relative_to(send_at: &str, now: DateTime<Utc>) -> Result<(), ValidationError> {
+    let send_at = match parse_date_time(send_at) {
+        Some(send_at) => send_at,
+        None => return Err(ValidationError::new("send_at_invalid_format")),
+    };
+
+    if send_at <= now {
+        Err(ValidationError::new("send_at_not_in_future"))
+    } else if send_at > now + Duration::days(MAX_SCHEDULE_DAYS) {
+        Err(ValidationError::new("send_at_too_far_in_future"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sms")]
+pub(crate) fn validate_send_at(send_at: &str) -> Result<(), ValidationError> {
+    validate_send_at_relative_to(send_at, Utc::now())
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryTime {
+    /// Hour when the time window opens when used in from property or closes when used into the
+    /// property.
+    #[validate(range(min = 0, max = 23))]
+    pub hour: i32,
+
+    /// Minute when the time window opens when used in from property or closes when used into the
+    /// property.
+    #[validate(range(min = 0, max = 59))]
+    pub minute: i32,
+}
+
+impl DeliveryTime {
+    pub fn new(hour: i32, minute: i32) -> Self {
+        Self { hour, minute }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeliveryDay {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryTimeWindow {
+    /// Days which are included in the delivery time window. Values are: `MONDAY`, `TUESDAY`,
+    /// `WEDNESDAY`, `THURSDAY`, `FRIDAY`, `SATURDAY`, `SUNDAY`. At least one day must be stated.
+    #[validate(length(min = 1, max = 7))]
+    pub days: Vec<DeliveryDay>,
+
+    /// Exact time of day in which the sending can start. Consists of hour and minute properties,
+    /// both mandatory. Time is expressed in the UTC time zone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub from: Option<DeliveryTime>,
+
+    /// Exact time of day in which the sending will end. Consists of an hour and minute properties,
+    /// both mandatory. Time is expressed in the UTC time zone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub to: Option<DeliveryTime>,
+}
+
+impl DeliveryTimeWindow {
+    pub fn new(days: Vec<DeliveryDay>) -> Self {
+        Self {
+            days,
+            ..Default::default()
+        }
+    }
+}
+
+const MINUTES_PER_DAY: i32 = 24 * 60;
+
+const WEEK: [DeliveryDay; 7] = [
+    DeliveryDay::Monday,
+    DeliveryDay::Tuesday,
+    DeliveryDay::Wednesday,
+    DeliveryDay::Thursday,
+    DeliveryDay::Friday,
+    DeliveryDay::Saturday,
+    DeliveryDay::Sunday,
+];
+
+impl DeliveryDay {
+    /// Returns the day the given number of days later, or earlier if negative.
+    pub fn shift(self, days: i32) -> DeliveryDay {
+        let index = WEEK.iter().position(|day| *day == self).unwrap_or_default() as i32;
+
+        WEEK[(index + days).rem_euclid(7) as usize]
+    }
+}
+
+impl DeliveryTime {
+    fn minutes(&self) -> i32 {
+        self.hour * 60 + self.minute
+    }
+}
+
+/// Error returned when a [`Schedule`] can't be applied to a message.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum ScheduleError {
+    #[error("send_at must be in the future")]
+    SendAtNotInFuture,
+
+    #[error("send_at must be at most {MAX_SCHEDULE_DAYS} days in the future")]
+    SendAtTooFarInFuture,
+
+    #[error("invalid delivery time window: {0}")]
+    InvalidWindow(#[from] ValidationErrors),
+
+    #[error("delivery time window must end after it starts")]
+    WindowEndsBeforeStart,
+
+    #[error("delivery time window spans midnight UTC in the recipient time zone")]
+    WindowSpansMidnight,
+
+    #[error("delivery time windows are not supported by this channel")]
+    WindowNotSupported,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SendAt {
+    Utc(DateTime<Utc>),
+    Local(NaiveDateTime),
+}
+
+/// When a message is sent and delivered, optionally in the local time of its recipients. Build
+/// it with the chained methods and apply it to a message.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schedule {
+    send_at: Option<SendAt>,
+    delivery_time_window: Option<DeliveryTimeWindow>,
+    time_zone: Option<FixedOffset>,
+}
+
+impl Schedule {
+    /// Creates a schedule that sends right away, at any time of day.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the message at the given time.
+    pub fn send_at(mut self, send_at: DateTime<Utc>) -> Self {
+        self.send_at = Some(SendAt::Utc(send_at));
+        self
+    }
+
+    /// Sends the message at the given local time of the recipients, see `in_time_zone`.
+    pub fn send_at_local(mut self, send_at: NaiveDateTime) -> Self {
+        self.send_at = Some(SendAt::Local(send_at));
+        self
+    }
+
+    /// Delivers the message only within the window, given in the local time of the recipients.
+    pub fn within(mut self, delivery_time_window: DeliveryTimeWindow) -> Self {
+        self.delivery_time_window = Some(delivery_time_window);
+        self
+    }
+
+    /// Sets the time zone of the recipients, used for local send times and the delivery time
+    /// window. Without it, local times are in UTC.
+    pub fn in_time_zone(mut self, time_zone: FixedOffset) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// Returns the time zone of the recipients, UTC if none was set.
+    pub fn time_zone(&self) -> FixedOffset {
+        self.time_zone.unwrap_or_else(|| Utc.fix())
+    }
+
+    /// Returns the send time in UTC, or `None` to send right away.
+    pub fn send_at_utc(&self) -> Option<DateTime<Utc>> {
+        match self.send_at? {
+            SendAt::Utc(send_at) => Some(send_at),
+            SendAt::Local(send_at) => Some(Utc.from_utc_datetime(&(send_at - self.time_zone()))),
+        }
+    }
+
+    /// Returns `send_at` in the format expected by the API.
+    pub fn send_at_formatted(&self) -> Option<String> {
+        self.send_at_utc()
+            .map(|send_at| send_at.format(DATE_TIME_FORMAT).to_string())
+    }
+
+    /// Returns the delivery time window converted to UTC. Days move along with the times, e.g.
+    /// Monday from 1:00 at UTC+02:00 is Sunday from 23:00 UTC. Missing `from` and `to` times
+    /// are taken as the start and end of the local day.
+    pub fn delivery_time_window_utc(&self) -> Result<Option<DeliveryTimeWindow>, ScheduleError> {
+        let Some(window) = &self.delivery_time_window else {
+            return Ok(None);
+        };
+        window.validate()?;
+        if let (Some(from), Some(to)) = (&window.from, &window.to) {
+            if to.minutes() <= from.minutes() {
+                return Err(ScheduleError::WindowEndsBeforeStart);
+            }
+        }
+
+        let offset_minutes = self.time_zone().local_minus_utc() / 60;
+        if offset_minutes == 0 {
+            return Ok(Some(window.clone()));
+        }
+
+        let from = window.from.as_ref().map_or(0, DeliveryTime::minutes) - offset_minutes;
+        let to = window
+            .to
+            .as_ref()
+            .map_or(MINUTES_PER_DAY - 1, DeliveryTime::minutes)
+            - offset_minutes;
+        let day_shift = from.div_euclid(MINUTES_PER_DAY);
+        if to.div_euclid(MINUTES_PER_DAY) != day_shift {
+            return Err(ScheduleError::WindowSpansMidnight);
+        }
+
+        let mut days: Vec<DeliveryDay> =
+            window.days.iter().map(|day| day.shift(day_shift)).collect();
+        days.sort();
+        days.dedup();
+        let to_time = |minutes: i32| {
+            let minutes = minutes.rem_euclid(MINUTES_PER_DAY);
+            DeliveryTime::new(minutes / 60, minutes % 60)
+        };
+
+        Ok(Some(DeliveryTimeWindow {
+            days,
+            from: Some(to_time(from)),
+            to: Some(to_time(to)),
+        }))
+    }
+
+    /// Checks that the send time is in the future and not too far ahead, and that the delivery
+    /// time window can be converted to UTC.
+    pub fn validate(&self) -> Result<(), ScheduleError> {
+        self.validate_relative_to(Utc::now())
+    }
+
+    pub(crate) fn validate_relative_to(&self, now: DateTime<Utc>) -> Result<(), ScheduleError> {
+        if let Some(send_at) = self.send_at_utc() {
+            if send_at <= now {
+                return Err(ScheduleError::SendAtNotInFuture);
+            }
+            if send_at > now + Duration::days(MAX_SCHEDULE_DAYS) {
+                return Err(ScheduleError::SendAtTooFarInFuture);
+            }
+        }
+        self.delivery_time_window_utc()?;
+
+        Ok(())
+    }
+}
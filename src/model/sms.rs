@@ -1,9 +1,41 @@
 //! Models for calling SMS endpoints.
 
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset, Utc};
 use regex::Regex;
+use serde::de::DeserializeOwned;
 use serde_derive::{Deserialize, Serialize};
-use std::collections::HashMap;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use crate::model::callback_data::{self, CallbackDataError};
+use crate::model::schedule::{parse_date_time, validate_send_at, Schedule, ScheduleError};
+use crate::model::status::{SendResult, StatusGroup};
+use crate::model::{fill_platform_id, ParseCodeError, PlatformIds};
+
+pub use crate::model::schedule::{
+    DeliveryDay, DeliveryTime, DeliveryTimeWindow, DATE_TIME_FORMAT, MAX_SCHEDULE_DAYS,
+};
+pub use crate::model::tfa::IndiaDlt;
+
+impl_try_from_value!(
+    PreviewRequestBody,
+    Message,
+    SendRequestBody,
+    BinaryMessage,
+    SendBinaryRequestBody,
+);
+
+impl_query_params!(
+    DeliveryReportsQueryParameters,
+    ScheduledQueryParameters,
+    LogsQueryParameters,
+    InboundReportsQueryParameters,
+    SendOverQueryParametersQueryParameters,
+    ClickReportsQueryParameters,
+);
 
 lazy_static::lazy_static! {
     static ref LANGUAGE_CODES: Regex = Regex::new(r"^(TR|ES|PT|AUTODETECT)$").unwrap();
@@ -15,7 +47,123 @@ lazy_static::lazy_static! {
     static ref TURKEY_RECIPIENT_TYPES: Regex = Regex::new(r"^(TACIR|BIREYSEL)$").unwrap();
 }
 
+/// Maximum validity period of a message in minutes, 48 hours. The API replaces longer periods
+/// with this one.
+pub const MAX_VALIDITY_PERIOD_MINUTES: i64 = 2880;
+
+// Converts a validity to whole minutes, rounding up so the message is not dropped early.
+fn validity_minutes(validity: std::time::Duration) -> i64 {
+    let mut minutes = validity.as_secs() / 60;
+    if validity.as_secs() % 60 != 0 || validity.subsec_nanos() != 0 {
+        minutes += 1;
+    }
+
+    i64::try_from(minutes).unwrap_or(i64::MAX)
+}
+
+const INDIA_COUNTRY_CODE: &str = "91";
+const TURKEY_COUNTRY_CODE: &str = "90";
+
+fn country_code_matches(destination: &str, country_code: &str) -> bool {
+    let destination = destination.trim_start_matches('+');
+    let destination = destination.strip_prefix("00").unwrap_or(destination);
+
+    destination.starts_with(country_code)
+}
+
+/// Checks that regional options are only used for destinations in their region, since the API
+/// rejects such messages.
+fn validate_regional_destinations(
+    regional: Option<&RegionalOptions>,
+    destinations: Option<&Vec<Destination>>,
+) -> Result<(), ValidationError> {
+    let regional = match regional {
+        Some(regional) => regional,
+        None => return Ok(()),
+    };
+    let destinations = destinations.map(Vec::as_slice).unwrap_or_default();
+
+    let all_in = |country_code: &str| {
+        destinations
+            .iter()
+            .all(|destination| country_code_matches(&destination.to, country_code))
+    };
+
+    if regional.india_dlt.is_some() && !all_in(INDIA_COUNTRY_CODE) {
+        return Err(ValidationError::new(
+            "india_dlt_requires_indian_destinations",
+        ));
+    }
+    if regional.turkey_iys.is_some() && !all_in(TURKEY_COUNTRY_CODE) {
+        return Err(ValidationError::new(
+            "turkey_iys_requires_turkish_destinations",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_message_regional(message: &Message) -> Result<(), ValidationError> {
+    validate_regional_destinations(message.regional.as_ref(), message.destinations.as_ref())
+}
+
+fn validate_binary_message_regional(message: &BinaryMessage) -> Result<(), ValidationError> {
+    validate_regional_destinations(message.regional.as_ref(), message.destinations.as_ref())
+}
+
+/// Checks that a bulk sent at the speed limit is sent completely before its messages expire.
+/// Each message is given as its number of destinations and its validity period.
+fn validate_speed_limit_for_bulk(
+    speed_limit: Option<&SpeedLimit>,
+    messages: impl Iterator<Item = (usize, Option<i64>)>,
+) -> Result<(), ValidationError> {
+    let speed_limit = match speed_limit {
+        Some(speed_limit) if speed_limit.amount > 0 => speed_limit,
+        _ => return Ok(()),
+    };
+
+    let mut destinations = 0;
+    let mut validity_period = MAX_VALIDITY_PERIOD_MINUTES;
+    for (count, validity) in messages {
+        destinations += count;
+        validity_period = validity_period.min(validity.unwrap_or(MAX_VALIDITY_PERIOD_MINUTES));
+    }
+
+    if speed_limit.sending_minutes(destinations) > validity_period {
+        return Err(ValidationError::new("speed_limit_exceeds_validity_period"));
+    }
+
+    Ok(())
+}
+
+fn validate_send_speed_limit(request_body: &SendRequestBody) -> Result<(), ValidationError> {
+    validate_speed_limit_for_bulk(
+        request_body.sending_speed_limit.as_ref(),
+        request_body.messages.iter().map(|message| {
+            (
+                message.destinations.as_ref().map_or(0, Vec::len),
+                message.validity_period,
+            )
+        }),
+    )
+}
+
+fn validate_send_binary_speed_limit(
+    request_body: &SendBinaryRequestBody,
+) -> Result<(), ValidationError> {
+    validate_speed_limit_for_bulk(
+        request_body.sending_speed_limit.as_ref(),
+        request_body.messages.iter().flatten().map(|message| {
+            (
+                message.destinations.as_ref().map_or(0, Vec::len),
+                message.validity_period,
+            )
+        }),
+    )
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct PreviewRequestBody {
     /// Code for language character set of a message text.
@@ -42,6 +190,7 @@ impl PreviewRequestBody {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Language {
     /// Language code for the correct character set.
@@ -59,6 +208,7 @@ impl Language {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct PreviewLanguageConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,6 +220,7 @@ pub struct PreviewLanguageConfiguration {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Preview {
     /// Number of remaining characters in the last SMS part.
@@ -90,7 +241,9 @@ pub struct Preview {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct PreviewResponseBody {
     /// Text supplied in the request.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -99,9 +252,16 @@ pub struct PreviewResponseBody {
     /// Previews of applying different configurations to the original text.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub previews: Option<Vec<Preview>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryReportsQueryParameters {
     /// Unique ID assigned to the request if messaging multiple recipients or sending multiple
@@ -118,6 +278,18 @@ pub struct DeliveryReportsQueryParameters {
     #[validate(range(max = 1000))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+
+    /// Entity ID of the messages, to only include messages of one entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID of the messages, to only include messages of one application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID of the messages, to only include messages of one campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
 }
 
 impl DeliveryReportsQueryParameters {
@@ -127,6 +299,7 @@ impl DeliveryReportsQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Status {
     /// Action that should be taken to eliminate the error.
@@ -151,7 +324,15 @@ pub struct Status {
     pub name: Option<String>,
 }
 
+impl Status {
+    /// Returns the typed status group, from `group_id` or `group_name`.
+    pub fn group(&self) -> Option<StatusGroup> {
+        StatusGroup::resolve(self.group_id, self.group_name.as_deref())
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Price {
     /// The currency in which the price is expressed.
@@ -159,10 +340,12 @@ pub struct Price {
     pub currency: Option<String>,
     /// Price per one SMS.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f64))]
     pub price_per_message: Option<f64>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Error {
     /// Human-readable description of the error.
@@ -191,7 +374,9 @@ pub struct Error {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Report {
     /// Bulk ID.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -243,16 +428,56 @@ pub struct Report {
     /// Destination address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<String>,
+
+    /// Entity ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Report {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
+
+    /// Parses the callback data of the message as JSON, see `model::callback_data`. Returns
+    /// `None` if the message had no callback data.
+    pub fn parse_callback_data<T: DeserializeOwned>(&self) -> Result<Option<T>, CallbackDataError> {
+        callback_data::parse_field(self.callback_data.as_deref())
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct DeliveryReportsResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Report>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Tracking {
     /// Custom base url used for shortening links from SMS text in `URL` Conversion rate tracking
@@ -260,7 +485,8 @@ pub struct Tracking {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub base_url: Option<String>,
 
-    /// Key that uniquely identifies Conversion tracking process.
+    /// Key that uniquely identifies Conversion tracking process. Report a conversion with
+    /// `SmsClient::confirm_conversion`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub process_key: Option<String>,
 
@@ -282,6 +508,7 @@ impl Tracking {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TimeUnit {
     Minute,
@@ -289,32 +516,26 @@ pub enum TimeUnit {
     Day,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-pub struct DeliveryTime {
-    /// Hour when the time window opens when used in from property or closes when used into the
-    /// property.
-    #[validate(range(min = 0, max = 23))]
-    pub hour: i32,
-
-    /// Minute when the time window opens when used in from property or closes when used into the
-    /// property.
-    #[validate(range(min = 0, max = 59))]
-    pub minute: i32,
-}
-
-impl DeliveryTime {
-    pub fn new(hour: i32, minute: i32) -> Self {
-        Self { hour, minute }
+impl TimeUnit {
+    /// Returns the length of the time unit in minutes.
+    pub fn minutes(self) -> i64 {
+        match self {
+            Self::Minute => 1,
+            Self::Hour => 60,
+            Self::Day => 1440,
+        }
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct SpeedLimit {
     /// The number of messages to be sent per timeUnit. By default, the system sends messages as
     /// fast as the infrastructure allows. Use this parameter to adapt sending capacity to your
     /// needs. The system is only able to work against its maximum capacity for ambitious message
     /// batches.
+    #[validate(range(min = 1))]
     pub amount: i32,
 
     /// The time unit in which the defined message amount will be sent. The default value is `MINUTE`.
@@ -329,74 +550,78 @@ impl SpeedLimit {
             ..Default::default()
         }
     }
+
+    /// Creates a limit of `amount` messages per minute.
+    pub fn per_minute(amount: i32) -> Self {
+        Self::with_time_unit(amount, TimeUnit::Minute)
+    }
+
+    /// Creates a limit of `amount` messages per hour.
+    pub fn per_hour(amount: i32) -> Self {
+        Self::with_time_unit(amount, TimeUnit::Hour)
+    }
+
+    /// Creates a limit of `amount` messages per day.
+    pub fn per_day(amount: i32) -> Self {
+        Self::with_time_unit(amount, TimeUnit::Day)
+    }
+
+    fn with_time_unit(amount: i32, time_unit: TimeUnit) -> Self {
+        Self {
+            amount,
+            time_unit: Some(time_unit),
+        }
+    }
+
+    /// Returns the time unit of the limit, `MINUTE` if none is set.
+    pub fn effective_time_unit(&self) -> TimeUnit {
+        self.time_unit.unwrap_or(TimeUnit::Minute)
+    }
+
+    /// Returns how many minutes sending the given number of messages takes at this limit,
+    /// counting started time units as whole ones. Returns 0 for limits without a positive
+    /// amount, which fail validation.
+    pub fn sending_minutes(&self, messages: usize) -> i64 {
+        let Ok(amount) = usize::try_from(self.amount) else {
+            return 0;
+        };
+        if amount == 0 {
+            return 0;
+        }
+
+        let units = i64::try_from(messages / amount + usize::from(messages % amount != 0))
+            .unwrap_or(i64::MAX);
+        units.saturating_mul(self.effective_time_unit().minutes())
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct UrlOptions {
     /// Enable shortening of the URLs within a message. Set this to `true`, if you want to set up other URL options.
-    #[serde(rename = "shortenUrl", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shorten_url: Option<bool>,
 
     /// Enable tracking of short URL clicks within a message: which URL was clicked, how many times, and by whom.
-    #[serde(rename = "trackClicks", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub track_clicks: Option<bool>,
 
     /// The URL of your callback server on to which the Click report will be sent.
-    #[serde(rename = "trackingUrl", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tracking_url: Option<String>,
 
     /// Remove a protocol, such as `https://`, from links to shorten a message. Note that some mobiles may not recognize such links as a URL.
-    #[serde(rename = "removeProtocol", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remove_protocol: Option<bool>,
 
     /// Select a predefined custom domain to use when generating a short URL.
-    #[serde(rename = "customDomain", skip_serializing_if = "Option::is_none")]
-    pub custom_domain: Option<String>,
-}
-
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum DeliveryDay {
-    Monday,
-    Tuesday,
-    Wednesday,
-    Thursday,
-    Friday,
-    Saturday,
-    Sunday,
-}
-
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-pub struct DeliveryTimeWindow {
-    /// Days which are included in the delivery time window. Values are: `MONDAY`, `TUESDAY`,
-    /// `WEDNESDAY`, `THURSDAY`, `FRIDAY`, `SATURDAY`, `SUNDAY`. At least one day must be stated.
-    #[validate(length(min = 1, max = 7))]
-    pub days: Vec<DeliveryDay>,
-
-    /// Exact time of day in which the sending can start. Consists of hour and minute properties,
-    /// both mandatory. Time is expressed in the UTC time zone.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate]
-    pub from: Option<DeliveryTime>,
-
-    /// Exact time of day in which the sending will end. Consists of an hour and minute properties,
-    /// both mandatory. Time is expressed in the UTC time zone.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate]
-    pub to: Option<DeliveryTime>,
-}
-
-impl DeliveryTimeWindow {
-    pub fn new(days: Vec<DeliveryDay>) -> Self {
-        Self {
-            days,
-            ..Default::default()
-        }
-    }
+    pub custom_domain: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Destination {
     /// The ID that uniquely identifies the message sent.
@@ -418,29 +643,48 @@ impl Destination {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct IndiaDlt {
-    /// Id of your registered DTL content template that matches this message's text.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate(length(max = 30))]
-    pub content_template_id: Option<String>,
+/// Type of the recipient of promotional SMS in Turkey.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TurkeyRecipientType {
+    /// Commercial recipient.
+    Tacir,
+    /// Individual recipient.
+    Bireysel,
+}
+
+impl TurkeyRecipientType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TurkeyRecipientType::Tacir => "TACIR",
+            TurkeyRecipientType::Bireysel => "BIREYSEL",
+        }
+    }
+}
 
-    /// Your assigned DTL principal entity id.
-    #[validate(length(min = 1))]
-    pub principal_entity_id: String,
+impl fmt::Display for TurkeyRecipientType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-impl IndiaDlt {
-    pub fn new(principal_entity_id: &str) -> Self {
-        Self {
-            principal_entity_id: principal_entity_id.into(),
-            ..Default::default()
+impl FromStr for TurkeyRecipientType {
+    type Err = ParseCodeError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        match code {
+            "TACIR" => Ok(Self::Tacir),
+            "BIREYSEL" => Ok(Self::Bireysel),
+            _ => Err(ParseCodeError::new("Turkey recipient type", code)),
         }
     }
 }
 
+/// IYS parameters are only needed for promotional traffic. Transactional messages to Turkey
+/// should be sent without them.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct TurkeyIys {
     /// Brand code is an ID of the company based on a company VAT number. If not provided in
@@ -460,9 +704,23 @@ impl TurkeyIys {
             ..Default::default()
         }
     }
+
+    pub fn with_recipient_type(recipient_type: TurkeyRecipientType) -> Self {
+        Self::new(recipient_type.as_str())
+    }
+
+    /// Returns the recipient type, or `None` if it is not a valid one.
+    pub fn parsed_recipient_type(&self) -> Option<TurkeyRecipientType> {
+        match self.recipient_type.as_str() {
+            "TACIR" => Some(TurkeyRecipientType::Tacir),
+            "BIREYSEL" => Some(TurkeyRecipientType::Bireysel),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct RegionalOptions {
     /// Distributed Ledger Technology (DLT) specific parameters required for sending SMS to phone
@@ -482,11 +740,95 @@ impl RegionalOptions {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn india(india_dlt: IndiaDlt) -> Self {
+        Self {
+            india_dlt: Some(india_dlt),
+            turkey_iys: None,
+        }
+    }
+
+    pub fn turkey(turkey_iys: TurkeyIys) -> Self {
+        Self {
+            india_dlt: None,
+            turkey_iys: Some(turkey_iys),
+        }
+    }
+}
+
+/// Registry of India DLT principal entity IDs by sender, used to fill in the regional options
+/// of messages sent to India.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EntityPrincipalRegistry {
+    principals: HashMap<String, String>,
+}
+
+impl EntityPrincipalRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the principal entity ID for a sender.
+    pub fn with_sender(mut self, sender: &str, principal_entity_id: &str) -> Self {
+        self.principals
+            .insert(sender.into(), principal_entity_id.into());
+        self
+    }
+
+    /// Returns the principal entity ID registered for a sender.
+    pub fn principal_for(&self, sender: &str) -> Option<&str> {
+        self.principals.get(sender).map(String::as_str)
+    }
+
+    /// Sets `regional.india_dlt` on every message sent only to India, that doesn't have it yet
+    /// and whose sender is registered. Returns the indexes of the messages sent only to India
+    /// that still have no DLT options, because their sender is unknown.
+    pub fn apply(&self, request_body: &mut SendRequestBody) -> Vec<usize> {
+        let mut missing = Vec::new();
+
+        for (index, message) in request_body.messages.iter_mut().enumerate() {
+            let destinations = message.destinations.as_deref().unwrap_or_default();
+            let to_india = !destinations.is_empty()
+                && destinations
+                    .iter()
+                    .all(|destination| country_code_matches(&destination.to, INDIA_COUNTRY_CODE));
+            let has_dlt = message
+                .regional
+                .as_ref()
+                .is_some_and(|regional| regional.india_dlt.is_some());
+
+            if !to_india || has_dlt {
+                continue;
+            }
+
+            match message
+                .from
+                .as_deref()
+                .and_then(|sender| self.principal_for(sender))
+            {
+                Some(principal_entity_id) => {
+                    message
+                        .regional
+                        .get_or_insert_with(RegionalOptions::new)
+                        .india_dlt = Some(IndiaDlt::new(principal_entity_id));
+                }
+                None => missing.push(index),
+            }
+        }
+
+        missing
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_message_regional"))]
 pub struct Message {
+    /// ID of the CPaaS X application the message is sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
     /// Additional data that can be used for identifying, managing, or monitoring a message.
     /// Data included here will also be automatically included in the message Delivery Report.
     /// The maximum value is 4000 characters and any overhead may be truncated.
@@ -506,6 +848,10 @@ pub struct Message {
     #[validate]
     pub destinations: Option<Vec<Destination>>,
 
+    /// ID of the CPaaS X entity the message is sent for, e.g. a customer of the platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
     /// Allows for sending a flash SMS to automatically appear on recipient devices without
     /// interaction. Set to true to enable flash SMS, or leave the default value, false to send a
     /// standard SMS.
@@ -549,8 +895,9 @@ pub struct Message {
     /// Date and time when the message is to be sent. Used for scheduled SMS (SMS not sent
     /// immediately, but at the scheduled time). Has the following format:
     /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`, and can only be scheduled for no later than 180 days in
-    /// advance.
+    /// advance. Must be in the future.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_send_at")]
     pub send_at: Option<String>,
 
     /// Content of the message that will be sent.
@@ -566,9 +913,10 @@ pub struct Message {
     pub transliteration: Option<String>,
 
     /// The message validity period in minutes. When the period expires, it will not be allowed for
-    /// the message to be sent. Validity period longer than 48h is not supported. Any bigger value
-    /// will automatically default back to 2880.
+    /// the message to be sent. Validity period longer than 48h is not supported, as the API would
+    /// silently replace it with 2880. Set it from a duration with `validity`.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 2880))]
     pub validity_period: Option<i64>,
 }
 
@@ -579,19 +927,155 @@ impl Message {
             ..Default::default()
         }
     }
+
+    /// Sets `validity_period` from a duration, rounded up to whole minutes. Durations longer
+    /// than `MAX_VALIDITY_PERIOD_MINUTES` fail validation.
+    pub fn validity(&mut self, validity: std::time::Duration) {
+        self.validity_period = Some(validity_minutes(validity));
+    }
+
+    /// Creates a flash SMS, shown on the recipient's screen without being stored.
+    pub fn new_flash(destinations: Vec<Destination>, text: &str) -> Self {
+        Self {
+            destinations: Some(destinations),
+            flash: Some(true),
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Schedules the message to be sent at the given time, by setting `send_at` in the format
+    /// expected by the API.
+    pub fn schedule_at(&mut self, send_at: DateTime<Utc>) {
+        self.send_at = Some(send_at.format(DATE_TIME_FORMAT).to_string());
+    }
+
+    /// Sets `send_at` and `delivery_time_window` from the schedule, converted to UTC. Fails
+    /// without changing the message if the schedule is invalid.
+    pub fn apply_schedule(&mut self, schedule: &Schedule) -> Result<(), ScheduleError> {
+        schedule.validate()?;
+        self.delivery_time_window = schedule.delivery_time_window_utc()?;
+        self.send_at = schedule.send_at_formatted();
+
+        Ok(())
+    }
+}
+
+/// Data coding scheme of binary content, sent as its SMPP `data_coding` value. Values without a
+/// variant are kept in `Other`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum DataCoding {
+    /// GSM 7-bit default alphabet (0).
+    #[default]
+    Gsm7,
+    /// ASCII, IA5 (1).
+    Ascii,
+    /// Latin-1, ISO-8859-1 (3).
+    Latin1,
+    /// 8-bit binary data (4).
+    Binary,
+    /// UCS-2, for Unicode text (8).
+    Ucs2,
+    Other(i32),
+}
+
+impl From<i32> for DataCoding {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DataCoding::Gsm7,
+            1 => DataCoding::Ascii,
+            3 => DataCoding::Latin1,
+            4 => DataCoding::Binary,
+            8 => DataCoding::Ucs2,
+            other => DataCoding::Other(other),
+        }
+    }
+}
+
+impl From<DataCoding> for i32 {
+    fn from(data_coding: DataCoding) -> Self {
+        match data_coding {
+            DataCoding::Gsm7 => 0,
+            DataCoding::Ascii => 1,
+            DataCoding::Latin1 => 3,
+            DataCoding::Binary => 4,
+            DataCoding::Ucs2 => 8,
+            DataCoding::Other(value) => value,
+        }
+    }
+}
+
+// Built from a one-byte value like the SMPP field, so values with a variant are never `Other`.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DataCoding {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(i32::from(u.arbitrary::<u8>()?)))
+    }
+}
+
+/// Special message attributes, sent as the SMPP `esm_class` value. Combine flags with `|`.
+///
+/// # Example
+/// ```
+/// # use infobip_sdk::model::sms::EsmClass;
+/// let esm_class = EsmClass::UDH_INDICATOR | EsmClass::REPLY_PATH;
+///
+/// assert!(esm_class.contains(EsmClass::UDH_INDICATOR));
+/// assert_eq!(i32::from(esm_class), 0xC0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(transparent)]
+pub struct EsmClass(i32);
+
+impl EsmClass {
+    /// No special attributes.
+    pub const DEFAULT: EsmClass = EsmClass(0);
+    /// The content starts with a user data header, e.g. for concatenated or port-addressed
+    /// messages.
+    pub const UDH_INDICATOR: EsmClass = EsmClass(0x40);
+    /// Replies are routed through the same message center.
+    pub const REPLY_PATH: EsmClass = EsmClass(0x80);
+
+    /// Returns `true` if all flags of `other` are set.
+    pub fn contains(&self, other: EsmClass) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for EsmClass {
+    type Output = EsmClass;
+
+    fn bitor(self, other: EsmClass) -> EsmClass {
+        EsmClass(self.0 | other.0)
+    }
+}
+
+impl From<i32> for EsmClass {
+    fn from(value: i32) -> Self {
+        EsmClass(value)
+    }
+}
+
+impl From<EsmClass> for i32 {
+    fn from(esm_class: EsmClass) -> Self {
+        esm_class.0
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct BinaryData {
-    /// Binary content data coding. The default value is (0) for GSM7. Example: (8) for Unicode
-    /// data.
+    /// Binary content data coding. The default value is GSM7. Example: UCS-2 for Unicode data.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_coding: Option<i32>,
+    pub data_coding: Option<DataCoding>,
 
-    /// Indicate special message attributes associated with the SMS. Default value is (0).
+    /// Indicate special message attributes associated with the SMS. The default value is no
+    /// special attributes.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub esm_class: Option<i32>,
+    pub esm_class: Option<EsmClass>,
 
     /// Hexadecimal string. This is the representation of your binary data. Two hex digits
     /// represent one byte. They should be separated by the space character (Example: `0f c2 4a bf
@@ -607,14 +1091,31 @@ impl BinaryData {
             ..Default::default()
         }
     }
+
+    /// Creates binary data from raw bytes, encoding them in the hexadecimal format of `hex`.
+    pub fn from_bytes(bytes: &[u8], data_coding: DataCoding) -> Self {
+        let hex: Vec<String> = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+        Self {
+            data_coding: Some(data_coding),
+            hex: hex.join(" "),
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_binary_message_regional"))]
 pub struct BinaryMessage {
     #[validate]
     pub binary: Option<BinaryData>,
 
+    /// ID of the CPaaS X application the message is sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
     /// Additional client data that will be sent on the notifyUrl. The maximum value is 4000
     /// characters.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -633,6 +1134,10 @@ pub struct BinaryMessage {
     #[validate]
     pub destinations: Option<Vec<Destination>>,
 
+    /// ID of the CPaaS X entity the message is sent for, e.g. a customer of the platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
     /// Allows for sending a flash SMS to automatically appear on recipient devices without
     /// interaction. Set to true to enable flash SMS, or leave the default value, false to send a
     /// standard SMS.
@@ -669,14 +1174,16 @@ pub struct BinaryMessage {
 
     /// Date and time when the message is to be sent. Used for scheduled SMS. Has the following
     /// format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`, and can only be scheduled for no later than
-    /// 180 days in advance.
+    /// 180 days in advance. Must be in the future.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_send_at")]
     pub send_at: Option<String>,
 
     /// The message validity period in minutes. When the period expires, it will not be allowed for
-    /// the message to be sent. Validity period longer than 48h is not supported (in this case,
-    /// it will be automatically set to 48h).
+    /// the message to be sent. Validity period longer than 48h is not supported, as the API would
+    /// silently replace it with 2880. Set it from a duration with `validity`.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 2880))]
     pub validity_period: Option<i64>,
 }
 
@@ -687,10 +1194,44 @@ impl BinaryMessage {
             ..Default::default()
         }
     }
+
+    /// Sets `validity_period` from a duration, rounded up to whole minutes. Durations longer
+    /// than `MAX_VALIDITY_PERIOD_MINUTES` fail validation.
+    pub fn validity(&mut self, validity: std::time::Duration) {
+        self.validity_period = Some(validity_minutes(validity));
+    }
+
+    /// Creates a flash binary SMS, shown on the recipient's screen without being stored.
+    pub fn new_flash(destinations: Vec<Destination>, binary: BinaryData) -> Self {
+        Self {
+            binary: Some(binary),
+            destinations: Some(destinations),
+            flash: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Schedules the message to be sent at the given time, by setting `send_at` in the format
+    /// expected by the API.
+    pub fn schedule_at(&mut self, send_at: DateTime<Utc>) {
+        self.send_at = Some(send_at.format(DATE_TIME_FORMAT).to_string());
+    }
+
+    /// Sets `send_at` and `delivery_time_window` from the schedule, converted to UTC. Fails
+    /// without changing the message if the schedule is invalid.
+    pub fn apply_schedule(&mut self, schedule: &Schedule) -> Result<(), ScheduleError> {
+        schedule.validate()?;
+        self.delivery_time_window = schedule.delivery_time_window_utc()?;
+        self.send_at = schedule.send_at_formatted();
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_send_speed_limit"))]
 pub struct SendRequestBody {
     /// Unique ID assigned to the request if messaging multiple recipients or sending multiple
     /// messages via a single API request. If not provided, it will be auto-generated and returned
@@ -709,6 +1250,7 @@ pub struct SendRequestBody {
     /// call-to-action option from a message you sent. Not setting a send speed limit can
     /// overwhelm your resources with incoming traffic.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub sending_speed_limit: Option<SpeedLimit>,
 
     /// Sets up URL shortening and tracking feature. Not compatible with old tracking feature.
@@ -727,10 +1269,212 @@ impl SendRequestBody {
             ..Default::default()
         }
     }
+
+    /// Sets a UUID as bulk ID and as message ID of every destination that doesn't have one yet,
+    /// and returns all the IDs.
+    pub fn generate_ids(&mut self) -> MessageIds {
+        fill_missing_ids(
+            &mut self.bulk_id,
+            self.messages
+                .iter_mut()
+                .flat_map(|message| message.destinations.iter_mut().flatten()),
+        )
+    }
+
+    /// Estimates the cost of sending this request with the given price table, without calling
+    /// the API. See `PriceTable::estimate_cost`.
+    pub fn estimate_cost(&self, price_table: &PriceTable) -> CostEstimate {
+        price_table.estimate_cost(self)
+    }
+}
+
+impl PlatformIds for SendRequestBody {
+    fn fill_platform_ids(&mut self, application_id: Option<&str>, entity_id: Option<&str>) {
+        for message in &mut self.messages {
+            fill_platform_id(&mut message.application_id, application_id);
+            fill_platform_id(&mut message.entity_id, entity_id);
+        }
+    }
+}
+
+const GSM7_BASIC_CHARS: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+    ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+const GSM7_EXTENDED_CHARS: &str = "^{}\\[~]|€\u{c}";
+
+/// Character encoding used to deliver a message text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SmsEncoding {
+    Gsm7,
+    Ucs2,
+}
+
+/// Local estimate of how a text is split into SMS parts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsParts {
+    /// Encoding needed for the text.
+    pub encoding: SmsEncoding,
+
+    /// Number of encoded characters. Extended GSM-7 characters count as two, and characters
+    /// outside of the Basic Multilingual Plane count as two in UCS-2.
+    pub characters: usize,
+
+    /// Number of SMS parts needed to deliver the text.
+    pub parts: usize,
+}
+
+impl SmsParts {
+    /// Estimates the encoding and number of parts of a text. Language tables and
+    /// transliteration are not taken into account, so use the preview endpoint for an exact
+    /// count.
+    pub fn estimate(text: &str) -> Self {
+        let gsm7_characters = text.chars().try_fold(0, |count, c| {
+            if GSM7_BASIC_CHARS.contains(c) {
+                Some(count + 1)
+            } else if GSM7_EXTENDED_CHARS.contains(c) {
+                Some(count + 2)
+            } else {
+                None
+            }
+        });
+
+        let (encoding, characters, single_length, part_length) = match gsm7_characters {
+            Some(characters) => (SmsEncoding::Gsm7, characters, 160, 153),
+            None => (SmsEncoding::Ucs2, text.encode_utf16().count(), 70, 67),
+        };
+
+        let parts = match characters {
+            0 => 0,
+            n if n <= single_length => 1,
+            n => (n + part_length - 1) / part_length,
+        };
+
+        Self {
+            encoding,
+            characters,
+            parts,
+        }
+    }
+}
+
+impl Message {
+    /// Estimates the number of parts this message is split into, for each destination.
+    pub fn estimate_parts(&self) -> SmsParts {
+        SmsParts::estimate(self.text.as_deref().unwrap_or_default())
+    }
+}
+
+/// Prices of a single SMS part, by destination prefix. Used for estimating the cost of a send
+/// request before sending it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PriceTable {
+    currency: String,
+    default_price: Option<f64>,
+    prices: Vec<(String, f64)>,
+}
+
+impl PriceTable {
+    /// Creates an empty price table with prices in the given currency.
+    pub fn new(currency: &str) -> Self {
+        Self {
+            currency: currency.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the price per part for destinations not matching any prefix.
+    pub fn with_default_price(mut self, price: f64) -> Self {
+        self.default_price = Some(price);
+        self
+    }
+
+    /// Sets the price per part for destinations starting with the given prefix, e.g. `"385"`.
+    /// The longest matching prefix wins.
+    pub fn with_price(mut self, prefix: &str, price: f64) -> Self {
+        self.prices.retain(|(existing, _)| existing != prefix);
+        self.prices.push((prefix.into(), price));
+        self
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Returns the price per part for a destination, if known.
+    pub fn price_for(&self, destination: &str) -> Option<f64> {
+        let destination = destination.trim_start_matches('+');
+
+        self.prices
+            .iter()
+            .filter(|(prefix, _)| destination.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, price)| *price)
+            .or(self.default_price)
+    }
+
+    /// Estimates the cost of a send request, counting the parts of every message for each of its
+    /// destinations. Destinations without a known price are listed in
+    /// `CostEstimate::unpriced_destinations` and are not included in the total.
+    pub fn estimate_cost(&self, request_body: &SendRequestBody) -> CostEstimate {
+        let mut estimate = CostEstimate {
+            currency: self.currency.clone(),
+            ..Default::default()
+        };
+
+        for message in &request_body.messages {
+            let parts = message.estimate_parts().parts;
+
+            for destination in message.destinations.iter().flatten() {
+                estimate.messages += 1;
+                estimate.parts += parts;
+
+                match self.price_for(&destination.to) {
+                    Some(price) => estimate.total += price * parts as f64,
+                    None => estimate.unpriced_destinations.push(destination.to.clone()),
+                }
+            }
+        }
+
+        estimate
+    }
+}
+
+/// Estimated cost of a send request.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct CostEstimate {
+    /// Currency of the total.
+    pub currency: String,
+
+    /// Number of messages, one per destination.
+    pub messages: usize,
+
+    /// Number of SMS parts over all messages.
+    pub parts: usize,
+
+    /// Estimated total price of all priced messages.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+    pub total: f64,
+
+    /// Destinations for which the price table has no price.
+    pub unpriced_destinations: Vec<String>,
+}
+
+impl CostEstimate {
+    /// Returns `true` if every destination has a price, so the total covers the whole request.
+    pub fn is_complete(&self) -> bool {
+        self.unpriced_destinations.is_empty()
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_send_binary_speed_limit"))]
 pub struct SendBinaryRequestBody {
     /// The ID which uniquely identifies the request. Bulk ID will be received only when you send a
     /// message to more than one destination address.
@@ -749,6 +1493,7 @@ pub struct SendBinaryRequestBody {
     /// period, allowing your systems or agents to handle incoming traffic in real-time,
     /// resulting in better customer satisfaction.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
     pub sending_speed_limit: Option<SpeedLimit>,
 }
 
@@ -759,10 +1504,33 @@ impl SendBinaryRequestBody {
             ..Default::default()
         }
     }
+
+    /// Sets a UUID as bulk ID and as message ID of every destination that doesn't have one yet,
+    /// and returns all the IDs.
+    pub fn generate_ids(&mut self) -> MessageIds {
+        fill_missing_ids(
+            &mut self.bulk_id,
+            self.messages
+                .iter_mut()
+                .flatten()
+                .flat_map(|message| message.destinations.iter_mut().flatten()),
+        )
+    }
+}
+
+impl PlatformIds for SendBinaryRequestBody {
+    fn fill_platform_ids(&mut self, application_id: Option<&str>, entity_id: Option<&str>) {
+        for message in self.messages.iter_mut().flatten() {
+            fill_platform_id(&mut message.application_id, application_id);
+            fill_platform_id(&mut message.entity_id, entity_id);
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct SentMessageDetails {
     /// The ID that uniquely identifies the message sent.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -776,10 +1544,25 @@ pub struct SentMessageDetails {
     /// The message destination address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl SentMessageDetails {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct SendResponseBody {
     /// The ID that uniquely identifies the request. Bulk ID will be received only when you send a
     /// message to more than one destination address.
@@ -789,11 +1572,74 @@ pub struct SendResponseBody {
     /// Array of sent message objects, one object per every message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<SentMessageDetails>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Bulk ID and message IDs of a send request, in the order of its destinations.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct MessageIds {
+    pub bulk_id: String,
+    pub message_ids: Vec<String>,
+}
+
+fn generate_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn fill_missing_ids<'a, I>(bulk_id: &mut Option<String>, destinations: I) -> MessageIds
+where
+    I: IntoIterator<Item = &'a mut Destination>,
+{
+    let message_ids = destinations
+        .into_iter()
+        .map(|destination| {
+            destination
+                .message_id
+                .get_or_insert_with(generate_id)
+                .clone()
+        })
+        .collect();
+
+    MessageIds {
+        bulk_id: bulk_id.get_or_insert_with(generate_id).clone(),
+        message_ids,
+    }
+}
+
+impl SendResponseBody {
+    /// Splits the sent messages into accepted and rejected ones, by the group of their status.
+    pub fn partition_by_status(&self) -> SendResult<'_, SentMessageDetails> {
+        SendResult::partition(
+            self.messages.iter().flatten(),
+            SentMessageDetails::status_group,
+        )
+    }
+
+    /// Returns `true` if every message was accepted. Messages without a known status group
+    /// count as not accepted.
+    pub fn all_accepted(&self) -> bool {
+        self.partition_by_status().all_accepted()
+    }
+
+    /// Returns the messages whose status is in the `UNDELIVERABLE`, `EXPIRED` or `REJECTED`
+    /// group, with their group.
+    pub fn rejected_messages(&self) -> Vec<(StatusGroup, &SentMessageDetails)> {
+        self.partition_by_status().rejected
+    }
 }
 
 pub type SendBinaryResponseBody = SendResponseBody;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
 pub struct ScheduledQueryParameters {
     #[validate(length(min = 1))]
     pub bulk_id: String,
@@ -808,6 +1654,7 @@ impl ScheduledQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct ScheduledResponseBody {
     pub bulk_id: String,
@@ -816,6 +1663,7 @@ pub struct ScheduledResponseBody {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct LogsQueryParameters {
     /// The sender ID which can be alphanumeric or numeric.
@@ -866,6 +1714,18 @@ pub struct LogsQueryParameters {
     /// Mobile Network Code.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mnc: Option<String>,
+
+    /// Entity ID of the messages, to only include messages of one entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID of the messages, to only include messages of one application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID of the messages, to only include messages of one campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
 }
 
 impl LogsQueryParameters {
@@ -875,7 +1735,9 @@ impl LogsQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Log {
     /// Unique ID assigned to the request if messaging multiple recipients or sending multiple
     /// messages via a single API request.
@@ -925,17 +1787,57 @@ pub struct Log {
     /// The destination address of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<String>,
+
+    /// Entity ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Log {
+    /// Returns the parsed `sent_at` time, or `None` if it is missing or malformed.
+    pub fn sent_at_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.sent_at.as_deref().and_then(parse_date_time)
+    }
+
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct LogsResponseBody {
     /// Collection of logs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Log>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
 pub struct InboundReportsQueryParameters {
     #[validate(range(max = 1000))]
     pub limit: Option<i32>,
@@ -948,7 +1850,9 @@ impl InboundReportsQueryParameters {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct InboundReportsResponseBody {
     /// The number of messages returned in the `results` array.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -961,10 +1865,18 @@ pub struct InboundReportsResponseBody {
     /// An array of result objects.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<InboundSmsReport>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct InboundSmsReport {
     /// Custom callback data sent over the notifyUrl.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1006,9 +1918,27 @@ pub struct InboundSmsReport {
     /// The destination address of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+impl InboundSmsReport {
+    /// Parses the callback data of the message as JSON, see `model::callback_data`. Returns
+    /// `None` if the inbound message had no callback data.
+    pub fn parse_callback_data<T: DeserializeOwned>(&self) -> Result<Option<T>, CallbackDataError> {
+        callback_data::parse_field(self.callback_data.as_deref())
+    }
+}
+
+/// Query parameters for sending over query parameters. The password is redacted in `Debug`
+/// output.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
 pub struct SendOverQueryParametersQueryParameters {
     /// Username for authentication.
     pub username: String,
@@ -1053,8 +1983,9 @@ pub struct SendOverQueryParametersQueryParameters {
     pub callback_data: Option<String>,
 
     /// The message validity period in minutes. When the period expires, it will not be allowed for
-    /// the message to be sent. Validity period longer than 48h is not supported. Any bigger value
-    /// will automatically default back to 2880.
+    /// the message to be sent. Validity period longer than 48h is not supported, as the API would
+    /// silently replace it with 2880.
+    #[validate(range(min = 1, max = 2880))]
     pub validity_period: Option<i32>,
 
     /// Date and time when the message is to be sent. Used for scheduled SMS. Has the following
@@ -1088,11 +2019,45 @@ impl SendOverQueryParametersQueryParameters {
     }
 }
 
+impl fmt::Debug for SendOverQueryParametersQueryParameters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SendOverQueryParametersQueryParameters")
+            .field("username", &self.username)
+            .field("password", &crate::configuration::REDACTED)
+            .field("bulk_id", &self.bulk_id)
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("text", &self.text)
+            .field("flash", &self.flash)
+            .field("transliteration", &self.transliteration)
+            .field("language_code", &self.language_code)
+            .field("intermediate_report", &self.intermediate_report)
+            .field("notify_url", &self.notify_url)
+            .field("notify_content_type", &self.notify_content_type)
+            .field("callback_data", &self.callback_data)
+            .field("validity_period", &self.validity_period)
+            .field("send_at", &self.send_at)
+            .field("track", &self.track)
+            .field("process_key", &self.process_key)
+            .field("tracking_type", &self.tracking_type)
+            .field(
+                "india_dlt_content_template_id",
+                &self.india_dlt_content_template_id,
+            )
+            .field(
+                "india_dlt_principal_entity_id",
+                &self.india_dlt_principal_entity_id,
+            )
+            .finish()
+    }
+}
+
 pub type SendOverQueryParametersResponseBody = SendResponseBody;
 
 pub type RescheduleQueryParameters = ScheduledQueryParameters;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct RescheduleRequestBody {
     /// Date and time when the message is to be sent. Used for scheduled SMS (see Scheduled SMS
@@ -1113,7 +2078,9 @@ impl RescheduleRequestBody {
 pub type RescheduleResponseBody = ScheduledResponseBody;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum ScheduledStatus {
     Pending,
     Paused,
@@ -1121,11 +2088,15 @@ pub enum ScheduledStatus {
     Canceled,
     Finished,
     Failed,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
 }
 
 pub type ScheduledStatusQueryParameters = ScheduledQueryParameters;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct ScheduledStatusResponseBody {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1138,6 +2109,7 @@ pub struct ScheduledStatusResponseBody {
 pub type UpdateScheduledStatusQueryParameters = RescheduleQueryParameters;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateScheduledStatusRequestBody {
     pub status: ScheduledStatus,
@@ -1151,402 +2123,276 @@ impl UpdateScheduledStatusRequestBody {
 
 pub type UpdateScheduledStatusResponseBody = ScheduledStatusResponseBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct TfaApplicationConfiguration {
-    /// Indicates whether multiple PIN verification is allowed.
-    #[serde(
-        rename = "allowMultiplePinVerifications",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub allow_multiple_pin_verifications: Option<bool>,
-
-    /// Number of possible PIN attempts.
-    #[serde(rename = "pinAttempts", skip_serializing_if = "Option::is_none")]
-    pub pin_attempts: Option<i32>,
-
-    /// Validity period of PIN in specified time unit. Required format: `{timeLength}{timeUnit}`. `timeLength` is optional with a default value of 1. `timeUnit` can be set to: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one year, although much lower value is recommended.
-    #[serde(rename = "pinTimeToLive", skip_serializing_if = "Option::is_none")]
-    pub pin_time_to_live: Option<String>,
-
-    /// Overall number of requests over a specified time period for generating a PIN and sending an SMS using a single application. Required format: `{attempts}/{timeLength}{timeUnit}`. `attempts` is mandatory and `timeLength` is optional with a default value of 1. `timeUnit` is one of: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one year, although much lower value is recommended.
-    #[serde(
-        rename = "sendPinPerApplicationLimit",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub send_pin_per_application_limit: Option<String>,
-
-    /// Number of requests over a specified time period for generating a PIN and sending an SMS to one phone number (MSISDN). Required format: `{attempts}/{timeLength}{timeUnit}`. `attempts` is mandatory and `timeLength` is optional with a default value of 1. `timeUnit` is one of: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one year, although much lower value is recommended.
-    #[serde(
-        rename = "sendPinPerPhoneNumberLimit",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub send_pin_per_phone_number_limit: Option<String>,
-
-    /// The number of PIN verification requests over a specified time period from one phone number (MSISDN). Required format: `{attempts}/{timeLength}{timeUnit}`. `attempts` is mandatory and `timeLength` is optional with a default value of 1. `timeUnit` is one of: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one day, although much lower value is recommended.
-    #[serde(rename = "verifyPinLimit", skip_serializing_if = "Option::is_none")]
-    pub verify_pin_limit: Option<String>,
-}
+// 2FA models were moved to `model::tfa`. These aliases keep the old paths working.
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+/// A domain used for short URLs in messages, see `UrlOptions::custom_domain`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
-pub struct TfaApplication {
-    /// The ID of the application that represents your service, e.g. 2FA for login, 2FA for changing the password, etc.
+pub struct TrackedDomain {
+    /// Name of the domain, e.g. `go.example.com`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
+    pub domain_name: Option<String>,
 
-    /// Created 2FA application configuration.
+    /// Whether the DNS records of the domain point to the platform, so it can be used.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration: Option<TfaApplicationConfiguration>,
+    pub verified: Option<bool>,
 
-    /// Indicates whether the created application is enabled.
+    /// Whether short URLs use the domain when no domain is selected in the message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
+    pub default: Option<bool>,
 
-    /// 2FA application name.
-    #[validate(length(min = 1))]
-    pub name: String,
+    /// When the domain was added. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
 }
 
-pub type TfaApplicationsResponseBody = Vec<TfaApplication>;
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedDomainsResponseBody {
+    /// Domains of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<TrackedDomain>>,
+}
 
-pub type CreateTfaApplicationRequestBody = TfaApplication;
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct AddTrackedDomainRequestBody {
+    /// Name of the domain, e.g. `go.example.com`. Its DNS records must point to the platform
+    /// before it is verified.
+    #[validate(length(min = 1, max = 253))]
+    pub domain_name: String,
 
-pub type CreateTfaApplicationResponseBody = TfaApplication;
+    /// Use the domain for short URLs when no domain is selected in the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
 
-impl CreateTfaApplicationRequestBody {
-    pub fn new(name: &str) -> Self {
+impl AddTrackedDomainRequestBody {
+    pub fn new(domain_name: &str) -> Self {
         Self {
-            name: name.into(),
+            domain_name: domain_name.into(),
             ..Default::default()
         }
     }
 }
 
-pub type TfaApplicationResponseBody = TfaApplication;
+pub type AddTrackedDomainResponseBody = TrackedDomain;
 
-pub type UpdateTfaApplicationRequestBody = TfaApplication;
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ClickReportsQueryParameters {
+    /// Bulk ID for which click reports are requested.
+    pub bulk_id: Option<String>,
 
-pub type UpdateTfaApplicationResponseBody = TfaApplication;
+    /// ID of the message for which click reports are requested.
+    pub message_id: Option<String>,
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
-pub enum TfaLanguage {
-    #[serde(rename = "en")]
-    En,
-    #[serde(rename = "es")]
-    Es,
-    #[serde(rename = "ca")]
-    Ca,
-    #[serde(rename = "da")]
-    Da,
-    #[serde(rename = "nl")]
-    Nl,
-    #[serde(rename = "fr")]
-    Fr,
-    #[serde(rename = "de")]
-    De,
-    #[serde(rename = "it")]
-    It,
-    #[serde(rename = "ja")]
-    Ja,
-    #[serde(rename = "ko")]
-    Ko,
-    #[serde(rename = "no")]
-    No,
-    #[serde(rename = "pl")]
-    Pl,
-    #[serde(rename = "ru")]
-    Ru,
-    #[serde(rename = "sv")]
-    Sv,
-    #[serde(rename = "fi")]
-    Fi,
-    #[serde(rename = "hr")]
-    Hr,
-    #[serde(rename = "sl")]
-    Sl,
-    #[serde(rename = "ro")]
-    Ro,
-    #[serde(rename = "pt-pt")]
-    PtPt,
-    #[serde(rename = "pt-br")]
-    PtBr,
-    #[serde(rename = "zh-cn")]
-    ZhCn,
-    #[serde(rename = "zh-tw")]
-    ZhTw,
-}
-
-#[derive(
-    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
-)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PinType {
-    #[default]
-    Numeric,
-    Alpha,
-    Hex,
-    Alphanumeric,
+    /// Only clicks at or after this time. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    pub clicked_since: Option<String>,
+
+    /// Only clicks before this time. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    pub clicked_until: Option<String>,
+
+    /// Maximum number of click reports to return. Default is 50, maximum is 1000.
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<i32>,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct TfaRegional {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate]
-    pub india_dlt: Option<IndiaDlt>,
+impl ClickReportsQueryParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+/// A click on a short URL in a message.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
-pub struct TfaMessageTemplate {
-    /// The ID of the application that represents your service (e.g. 2FA for login, 2FA for changing the password, etc.) for which the requested message has been created.
+pub struct ClickReport {
+    /// Bulk ID of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
-
-    /// The language code which message is written in used when sending text-to-speech messages. If not defined, it will default to English (`en`).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<TfaLanguage>,
+    pub bulk_id: Option<String>,
 
-    /// The ID of the message template (message body with the PIN placeholder) that is sent to the recipient.
+    /// ID of the message with the clicked URL.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
-    /// Text of a message that will be sent. Message text must contain `pinPlaceholder`.
-    #[validate(length(min = 1))]
-    pub message_text: String,
-
-    /// PIN code length.
-    #[validate(range(min = 1))]
-    pub pin_length: i32,
-
-    /// The PIN code placeholder that will be replaced with a generated PIN code.
+    /// Destination address of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pin_placeholder: Option<String>,
+    pub to: Option<String>,
 
-    /// The type of PIN code that will be generated and sent as part of 2FA message. You can set PIN type to numeric, alpha, alphanumeric or hex.
-    pub pin_type: PinType,
+    /// Original URL the short URL redirects to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 
-    /// Region-specific parameters, often imposed by local laws. Use this, if country or region that you are sending a message to requires additional information.
+    /// Short URL that was clicked.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[validate]
-    pub regional: Option<TfaRegional>,
+    pub short_url: Option<String>,
 
-    /// In case PIN message is sent by Voice, DTMF code will enable replaying the message.
-    #[serde(rename = "repeatDTMF", skip_serializing_if = "Option::is_none")]
-    pub repeat_dtmf: Option<String>,
+    /// When the URL was clicked. Has the following format: `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clicked_at: Option<String>,
 
-    /// The name that will appear as the sender of the 2FA message (Example: CompanyName).
+    /// Type of the device used to click, e.g. `MOBILE`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sender_id: Option<String>,
+    pub device_type: Option<String>,
 
-    /// In case PIN message is sent by Voice, the speed of speech can be set for the message. Supported range is from `0.5` to `2`.
+    /// Operating system of the device used to click.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_rate: Option<f64>,
+    pub os: Option<String>,
 }
 
-impl TfaMessageTemplate {
-    pub fn new(message_text: &str, pin_type: PinType, pin_length: i32) -> Self {
-        Self {
-            message_text: message_text.into(),
-            pin_length,
-            pin_type,
-            ..Default::default()
-        }
+impl ClickReport {
+    /// Returns the parsed `clicked_at` time, or `None` if it is missing or malformed.
+    pub fn clicked_at_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.clicked_at.as_deref().and_then(parse_date_time)
     }
 }
 
-pub type TfaMessageTemplatesResponseBody = Vec<TfaMessageTemplate>;
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ClickReportsResponseBody {
+    /// Clicks matching the query, newest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<ClickReport>>,
+}
+
+impl ClickReportsResponseBody {
+    /// Returns the number of distinct messages with at least one click. Divide it by the number
+    /// of messages sent to get the click-through rate.
+    pub fn unique_clicks(&self) -> usize {
+        self.results
+            .iter()
+            .flatten()
+            .filter_map(|report| report.message_id.as_deref())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
 
-pub type CreateTfaMessageTemplateRequestBody = TfaMessageTemplate;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaApplicationConfiguration = crate::model::tfa::TfaApplicationConfiguration;
 
-pub type CreateTfaMessageTemplateResponseBody = TfaMessageTemplate;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaApplication = crate::model::tfa::TfaApplication;
 
-pub type TfaMessageTemplateResponseBody = TfaMessageTemplate;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaApplicationsResponseBody = crate::model::tfa::TfaApplicationsResponseBody;
 
-pub type UpdateTfaMessageTemplateRequestBody = TfaMessageTemplate;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type CreateTfaApplicationRequestBody = crate::model::tfa::CreateTfaApplicationRequestBody;
 
-pub type UpdateTfaMessageTemplateResponseBody = TfaMessageTemplate;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type CreateTfaApplicationResponseBody = crate::model::tfa::CreateTfaApplicationResponseBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct SendPinOverSmsQueryParameters {
-    pub nc_needed: Option<bool>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaApplicationResponseBody = crate::model::tfa::TfaApplicationResponseBody;
 
-impl SendPinOverSmsQueryParameters {
-    pub fn new() -> Self {
-        Self { nc_needed: None }
-    }
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type UpdateTfaApplicationRequestBody = crate::model::tfa::UpdateTfaApplicationRequestBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct SendPinOverSmsRequestBody {
-    /// The ID of the application that represents your service, e.g. 2FA for login, 2FA for changing the password, etc.
-    #[validate(length(min = 1))]
-    pub application_id: String,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type UpdateTfaApplicationResponseBody = crate::model::tfa::UpdateTfaApplicationResponseBody;
 
-    /// Use this parameter if you wish to override the sender ID from the [created](#channels/sms/create-2fa-message-template) message template parameter `senderId`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub from: Option<String>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaLanguage = crate::model::tfa::TfaLanguage;
 
-    /// The ID of the message template (message body with the PIN placeholder) that is sent to the recipient.
-    #[validate(length(min = 1))]
-    pub message_id: String,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type PinType = crate::model::tfa::PinType;
 
-    /// Key value pairs that will be replaced during message sending. Placeholder keys should NOT contain curly brackets and should NOT contain a `pin` placeholder. Valid example: `\"placeholders\":{\"firstName\":\"John\"}`
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub placeholders: Option<HashMap<String, String>>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaRegional = crate::model::tfa::TfaRegional;
 
-    /// Phone number to which the 2FA message will be sent. Example: 41793026727.
-    #[validate(length(min = 1))]
-    pub to: String,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaMessageTemplate = crate::model::tfa::TfaMessageTemplate;
 
-impl SendPinOverSmsRequestBody {
-    pub fn new(application_id: &str, message_id: &str, to: &str) -> Self {
-        Self {
-            application_id: application_id.into(),
-            message_id: message_id.into(),
-            to: to.into(),
-            ..Default::default()
-        }
-    }
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaMessageTemplatesResponseBody = crate::model::tfa::TfaMessageTemplatesResponseBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct SendPinResponseBody {
-    /// Call status, e.g. `PENDING_ACCEPTED`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub call_status: Option<String>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type CreateTfaMessageTemplateRequestBody =
+    crate::model::tfa::CreateTfaMessageTemplateRequestBody;
 
-    /// Status of sent [Number Lookup](https://www.infobip.com/docs/number-lookup). Number Lookup status can have one of the following values: `NC_DESTINATION_UNKNOWN`, `NC_DESTINATION_REACHABLE`, `NC_DESTINATION_NOT_REACHABLE`, `NC_NOT_CONFIGURED`. Contact your Account Manager, if you get the `NC_NOT_CONFIGURED` status. SMS will not be sent only if Number Lookup status is `NC_NOT_REACHABLE`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub nc_status: Option<String>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type CreateTfaMessageTemplateResponseBody =
+    crate::model::tfa::CreateTfaMessageTemplateResponseBody;
 
-    /// Sent PIN code ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pin_id: Option<String>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaMessageTemplateResponseBody = crate::model::tfa::TfaMessageTemplateResponseBody;
 
-    /// Sent SMS status. Can have one of the following values: `MESSAGE_SENT`, `MESSAGE_NOT_SENT`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sms_status: Option<String>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type UpdateTfaMessageTemplateRequestBody =
+    crate::model::tfa::UpdateTfaMessageTemplateRequestBody;
 
-    /// Phone number to which the 2FA message will be sent. Example: `41793026727`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub to: Option<String>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type UpdateTfaMessageTemplateResponseBody =
+    crate::model::tfa::UpdateTfaMessageTemplateResponseBody;
 
-pub type SendPinOverSmsResponseBody = SendPinResponseBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverSmsQueryParameters = crate::model::tfa::SendPinOverSmsQueryParameters;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct ResendPinRequestBody {
-    /// Key value pairs that will be replaced during message sending. Placeholder keys should NOT contain curly brackets and should NOT contain a pin placeholder. Valid example: "placeholders":{"firstName":"John"}
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub placeholders: Option<HashMap<String, String>>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverSmsRequestBody = crate::model::tfa::SendPinOverSmsRequestBody;
 
-impl ResendPinRequestBody {
-    pub fn new() -> Self {
-        Self { placeholders: None }
-    }
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinResponseBody = crate::model::tfa::SendPinResponseBody;
 
-pub type ResendPinOverSmsRequestBody = ResendPinRequestBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverSmsResponseBody = crate::model::tfa::SendPinOverSmsResponseBody;
 
-pub type ResendPinOverSmsResponseBody = SendPinResponseBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinRequestBody = crate::model::tfa::ResendPinRequestBody;
 
-pub type SendPinOverVoiceRequestBody = SendPinOverSmsRequestBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinOverSmsRequestBody = crate::model::tfa::ResendPinOverSmsRequestBody;
 
-pub type SendPinOverVoiceResponseBody = SendPinResponseBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinOverSmsResponseBody = crate::model::tfa::ResendPinOverSmsResponseBody;
 
-pub type ResendPinOverVoiceRequestBody = ResendPinRequestBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverVoiceRequestBody = crate::model::tfa::SendPinOverVoiceRequestBody;
 
-pub type ResendPinOverVoiceResponseBody = SendPinResponseBody;
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverVoiceResponseBody = crate::model::tfa::SendPinOverVoiceResponseBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-pub struct VerifyPhoneNumberRequestBody {
-    /// ID of the pin code that has to be verified.
-    #[validate(length(min = 1))]
-    pub pin: String,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinOverVoiceRequestBody = crate::model::tfa::ResendPinOverVoiceRequestBody;
 
-impl VerifyPhoneNumberRequestBody {
-    pub fn new(pin: &str) -> Self {
-        Self { pin: pin.into() }
-    }
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinOverVoiceResponseBody = crate::model::tfa::ResendPinOverVoiceResponseBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct VerifyPhoneNumberResponseBody {
-    /// Number of remaining PIN attempts.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attempts_remaining: Option<i32>,
-    /// Phone number (`MSISDN`) to which the 2FA message was sent.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub msisdn: Option<String>,
-    /// Indicates whether an error has occurred during PIN verification.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pin_error: Option<String>,
-    /// Sent PIN code ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pin_id: Option<String>,
-    /// Indicates whether the phone number (`MSISDN`) was successfully verified.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verified: Option<bool>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverEmailRequestBody = crate::model::tfa::SendPinOverEmailRequestBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-pub struct TfaVerificationStatusQueryParameters {
-    /// Filter by msisdn (phone number) for which verification status is checked.
-    pub msisdn: String,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type EmailStatus = crate::model::tfa::EmailStatus;
 
-    /// Filter by verified (true or false).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verified: Option<bool>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type SendPinOverEmailResponseBody = crate::model::tfa::SendPinOverEmailResponseBody;
 
-    /// Filter by message sent status (true or false).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sent: Option<bool>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinOverEmailRequestBody = crate::model::tfa::ResendPinOverEmailRequestBody;
 
-impl TfaVerificationStatusQueryParameters {
-    pub fn new(msisdn: &str) -> Self {
-        Self {
-            msisdn: msisdn.into(),
-            ..Default::default()
-        }
-    }
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type ResendPinOverEmailResponseBody = crate::model::tfa::ResendPinOverEmailResponseBody;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct TfaVerification {
-    /// Phone number (MSISDN) for which verification status is checked.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub msisdn: Option<String>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type VerifyPhoneNumberRequestBody = crate::model::tfa::VerifyPhoneNumberRequestBody;
 
-    /// Sent UNIX timestamp (in millis), if the phone number (MSISDN) is verified.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sent_at: Option<i64>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type VerifyPhoneNumberResponseBody = crate::model::tfa::VerifyPhoneNumberResponseBody;
 
-    /// Indicates if the phone number (MSISDN) is already verified for 2FA application with given ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verified: Option<bool>,
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaVerificationStatusQueryParameters =
+    crate::model::tfa::TfaVerificationStatusQueryParameters;
 
-    /// Verification UNIX timestamp (in millis), if the phone number (MSISDN) is verified.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verified_at: Option<i64>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaVerification = crate::model::tfa::TfaVerification;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
-#[serde(rename_all = "camelCase")]
-pub struct TfaVerificationStatusResponseBody {
-    /// Collection of verifications
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verifications: Option<Vec<TfaVerification>>,
-}
+#[deprecated(since = "0.7.0", note = "moved to `model::tfa`")]
+pub type TfaVerificationStatusResponseBody = crate::model::tfa::TfaVerificationStatusResponseBody;
@@ -0,0 +1,160 @@
+//! Status groups shared by the responses and reports of all channels.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::model::ParseCodeError;
+
+/// Group of a message status. Infobip sends it as `groupId` and `groupName` in every status
+/// object.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StatusGroup {
+    /// Message was accepted and will be processed.
+    Accepted,
+    /// Message was sent and is waiting for a delivery report.
+    Pending,
+    /// Message could not be delivered.
+    Undeliverable,
+    /// Message was delivered.
+    Delivered,
+    /// Message was not delivered in time.
+    Expired,
+    /// Message was rejected before being sent.
+    Rejected,
+}
+
+const ALL_GROUPS: [StatusGroup; 6] = [
+    StatusGroup::Accepted,
+    StatusGroup::Pending,
+    StatusGroup::Undeliverable,
+    StatusGroup::Delivered,
+    StatusGroup::Expired,
+    StatusGroup::Rejected,
+];
+
+impl StatusGroup {
+    /// Returns the group with the given `groupId`.
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Self::Accepted),
+            1 => Some(Self::Pending),
+            2 => Some(Self::Undeliverable),
+            3 => Some(Self::Delivered),
+            4 => Some(Self::Expired),
+            5 => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+
+    /// Returns the group with the given `groupName`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_GROUPS
+            .iter()
+            .copied()
+            .find(|group| group.name() == name)
+    }
+
+    /// Returns the `groupId` of the group.
+    pub fn id(self) -> i32 {
+        match self {
+            Self::Accepted => 0,
+            Self::Pending => 1,
+            Self::Undeliverable => 2,
+            Self::Delivered => 3,
+            Self::Expired => 4,
+            Self::Rejected => 5,
+        }
+    }
+
+    /// Returns the group from a status `groupId`, falling back to `groupName` for payloads that
+    /// only have the name.
+    pub fn resolve(group_id: Option<i32>, group_name: Option<&str>) -> Option<Self> {
+        group_id
+            .and_then(Self::from_id)
+            .or_else(|| group_name.and_then(Self::from_name))
+    }
+
+    /// Returns the `groupName` of the group.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Accepted => "ACCEPTED",
+            Self::Pending => "PENDING",
+            Self::Undeliverable => "UNDELIVERABLE",
+            Self::Delivered => "DELIVERED",
+            Self::Expired => "EXPIRED",
+            Self::Rejected => "REJECTED",
+        }
+    }
+
+    /// Returns `true` if no further status updates are expected for the message.
+    pub fn is_final(self) -> bool {
+        !matches!(self, Self::Accepted | Self::Pending)
+    }
+
+    /// Returns `true` if the message was accepted, is on its way or was delivered.
+    pub fn is_successful(self) -> bool {
+        matches!(self, Self::Accepted | Self::Pending | Self::Delivered)
+    }
+}
+
+impl fmt::Display for StatusGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for StatusGroup {
+    type Err = ParseCodeError;
+
+    /// Parses a `groupName`, e.g. `DELIVERED`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| ParseCodeError::new("status group", name))
+    }
+}
+
+/// Messages of a multi-destination send, split by the group of their status.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SendResult<'a, T> {
+    /// Messages in the `ACCEPTED`, `PENDING` or `DELIVERED` group.
+    pub accepted: Vec<&'a T>,
+
+    /// Messages in the `UNDELIVERABLE`, `EXPIRED` or `REJECTED` group, with their group.
+    pub rejected: Vec<(StatusGroup, &'a T)>,
+
+    /// Messages without a status, or with a group unknown to the SDK.
+    pub unknown: Vec<&'a T>,
+}
+
+impl<'a, T> SendResult<'a, T> {
+    /// Splits messages using `group` to get the status group of each one.
+    pub fn partition<I, F>(messages: I, group: F) -> Self
+    where
+        I: IntoIterator<Item = &'a T>,
+        F: Fn(&T) -> Option<StatusGroup>,
+    {
+        let mut result = SendResult {
+            accepted: Vec::new(),
+            rejected: Vec::new(),
+            unknown: Vec::new(),
+        };
+
+        for message in messages {
+            match group(message) {
+                Some(group) if group.is_successful() => result.accepted.push(message),
+                Some(group) => result.rejected.push((group, message)),
+                None => result.unknown.push(message),
+            }
+        }
+
+        result
+    }
+
+    /// Returns `true` if every message was accepted.
+    pub fn all_accepted(&self) -> bool {
+        self.rejected.is_empty() && self.unknown.is_empty()
+    }
+}
@@ -0,0 +1,171 @@
+//! Personalization of message texts with placeholders.
+//!
+//! Placeholders use the syntax of 2FA message templates: a name of letters, digits and
+//! underscores between double braces, like `{{firstName}}`. Text that doesn't match, like
+//! `{{ first name }}`, is kept as is.
+//!
+//! # Example
+//! ```
+//! # use std::collections::HashMap;
+//! # use infobip_sdk::model::templating::render_for_destinations;
+//! let recipients = vec![
+//!     HashMap::from([
+//!         ("to".to_string(), "41793026727".to_string()),
+//!         ("firstName".to_string(), "Jane".to_string()),
+//!     ]),
+//!     HashMap::from([
+//!         ("to".to_string(), "41793026834".to_string()),
+//!         ("firstName".to_string(), "John".to_string()),
+//!     ]),
+//! ];
+//!
+//! let messages = render_for_destinations("Hello {{firstName}}!", recipients).unwrap();
+//!
+//! assert_eq!(messages[1].text.as_deref(), Some("Hello John!"));
+//! assert_eq!(messages[1].destinations.as_ref().unwrap()[0].to, "41793026834");
+//! ```
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use thiserror::Error;
+
+#[cfg(feature = "email")]
+use crate::model::email::SendRequestBody;
+#[cfg(feature = "sms")]
+use crate::model::sms::{Destination, Message};
+
+/// Key of the recipient values holding the destination address of the rendered message.
+pub const DESTINATION_KEY: &str = "to";
+
+lazy_static::lazy_static! {
+    static ref PLACEHOLDER: Regex = Regex::new(r"\{\{([A-Za-z0-9_]+)\}\}").unwrap();
+}
+
+/// Holds the possible errors of rendering a template.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    #[error("no value for placeholder {placeholder} of recipient {recipient}")]
+    MissingValue {
+        placeholder: String,
+        recipient: usize,
+    },
+
+    #[error("no \"{key}\" value for recipient {recipient}", key = DESTINATION_KEY)]
+    MissingDestination { recipient: usize },
+}
+
+/// A message text with placeholders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template {
+    text: String,
+}
+
+impl Template {
+    pub fn new(text: &str) -> Self {
+        Template {
+            text: text.to_string(),
+        }
+    }
+
+    /// Returns the names of the placeholders in the text, in order of first appearance.
+    pub fn placeholders(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for captures in PLACEHOLDER.captures_iter(&self.text) {
+            let name = captures.get(1).unwrap().as_str();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+    }
+
+    /// Replaces every placeholder with its value. Fails with the first placeholder without a
+    /// value.
+    pub fn render(&self, values: &HashMap<String, String>) -> Result<String, TemplateError> {
+        self.render_recipient(values, 0)
+    }
+
+    fn render_recipient(
+        &self,
+        values: &HashMap<String, String>,
+        recipient: usize,
+    ) -> Result<String, TemplateError> {
+        let mut rendered = String::with_capacity(self.text.len());
+        let mut last_end = 0;
+
+        for captures in PLACEHOLDER.captures_iter(&self.text) {
+            let placeholder = captures.get(0).unwrap();
+            let name = captures.get(1).unwrap().as_str();
+            let value = values
+                .get(name)
+                .ok_or_else(|| TemplateError::MissingValue {
+                    placeholder: name.to_string(),
+                    recipient,
+                })?;
+
+            rendered.push_str(&self.text[last_end..placeholder.start()]);
+            rendered.push_str(value);
+            last_end = placeholder.end();
+        }
+        rendered.push_str(&self.text[last_end..]);
+
+        Ok(rendered)
+    }
+}
+
+// Renders the template for every recipient, returning the destination and text of each.
+fn render_recipients(
+    template: &str,
+    recipients: Vec<HashMap<String, String>>,
+) -> Result<Vec<(String, String)>, TemplateError> {
+    let template = Template::new(template);
+
+    recipients
+        .iter()
+        .enumerate()
+        .map(|(recipient, values)| {
+            let destination = values
+                .get(DESTINATION_KEY)
+                .ok_or(TemplateError::MissingDestination { recipient })?;
+            let text = template.render_recipient(values, recipient)?;
+
+            Ok((destination.clone(), text))
+        })
+        .collect()
+}
+
+/// Renders one SMS message per recipient. The destination of each message is the recipient's
+/// [`DESTINATION_KEY`] value, which can also be used as a placeholder.
+#[cfg(feature = "sms")]
+pub fn render_for_destinations(
+    template: &str,
+    recipients: Vec<HashMap<String, String>>,
+) -> Result<Vec<Message>, TemplateError> {
+    Ok(render_recipients(template, recipients)?
+        .into_iter()
+        .map(|(to, text)| {
+            let mut message = Message::new(vec![Destination::new(&to)]);
+            message.text = Some(text);
+            message
+        })
+        .collect())
+}
+
+/// Renders one email per recipient, with the text as body. The recipient address of each email
+/// is the recipient's [`DESTINATION_KEY`] value.
+#[cfg(feature = "email")]
+pub fn render_emails(
+    template: &str,
+    recipients: Vec<HashMap<String, String>>,
+) -> Result<Vec<SendRequestBody>, TemplateError> {
+    Ok(render_recipients(template, recipients)?
+        .into_iter()
+        .map(|(to, text)| {
+            let mut request_body = SendRequestBody::new(&to);
+            request_body.text = Some(text);
+            request_body
+        })
+        .collect())
+}
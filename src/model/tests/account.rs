@@ -0,0 +1,33 @@
+use crate::model::account::ApiKeyDetails;
+
+#[test]
+fn api_key_details_without_scopes() {
+    let details: ApiKeyDetails = serde_json::from_str(r#"{"name": "Some key"}"#).unwrap();
+
+    assert!(details.scopes().is_empty());
+    assert!(!details.has_scope("sms:message:send"));
+    assert_eq!(
+        details.missing_scopes(&["sms:message:send"]),
+        vec!["sms:message:send"]
+    );
+}
+
+#[test]
+fn api_key_details_serialize_allowed_ips() {
+    let details = ApiKeyDetails {
+        allowed_ips: Some(vec!["203.0.113.7".to_string()]),
+        scope_guids: Some(vec!["whatsapp:message:send".to_string()]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&details).unwrap(),
+        serde_json::json!({
+            "allowedIPs": ["203.0.113.7"],
+            "scopeGuids": ["whatsapp:message:send"]
+        })
+    );
+    assert!(details
+        .missing_scopes(&["whatsapp:message:send"])
+        .is_empty());
+}
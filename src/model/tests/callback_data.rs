@@ -0,0 +1,65 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::model::callback_data::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+    order_id: u64,
+    tags: Vec<String>,
+}
+
+#[test]
+fn encode_decode_round_trip() {
+    let order = Order {
+        order_id: 7,
+        tags: vec!["vip".to_string()],
+    };
+
+    let encoded = CallbackData::new(order).encode().unwrap();
+    assert_eq!(encoded, r#"{"order_id":7,"tags":["vip"]}"#);
+
+    let decoded: CallbackData<Order> = CallbackData::decode(&encoded).unwrap();
+    assert_eq!(decoded.into_inner().tags, vec!["vip"]);
+}
+
+#[test]
+fn encode_too_long() {
+    let data = CallbackData::new("ü".repeat(MAX_CALLBACK_DATA_LENGTH - 1));
+
+    assert!(matches!(
+        data.encode(),
+        Err(CallbackDataError::TooLong(length)) if length == MAX_CALLBACK_DATA_LENGTH + 1
+    ));
+}
+
+#[test]
+fn encode_counts_characters() {
+    let data = CallbackData::new("ü".repeat(MAX_CALLBACK_DATA_LENGTH - 2));
+
+    assert_eq!(
+        data.encode().unwrap().chars().count(),
+        MAX_CALLBACK_DATA_LENGTH
+    );
+}
+
+#[test]
+fn decode_wrong_type() {
+    let result = CallbackData::<Order>::decode(r#"{"orderId": 7}"#);
+
+    assert!(matches!(result, Err(CallbackDataError::Serde(_))));
+}
+
+#[cfg(feature = "sms")]
+#[test]
+fn sms_report_callback_data() {
+    let mut report = crate::model::sms::Report {
+        callback_data: Some(r#"{"order_id":7,"tags":[]}"#.to_string()),
+        ..Default::default()
+    };
+
+    let order: Option<Order> = report.parse_callback_data().unwrap();
+    assert_eq!(order.unwrap().order_id, 7);
+
+    report.callback_data = None;
+    assert!(report.parse_callback_data::<Order>().unwrap().is_none());
+}
@@ -0,0 +1,321 @@
+//! Generator of the models in `model::generated` from the OpenAPI definitions in `openapi/`.
+//!
+//! The generated code is checked in, so building the crate needs neither the definitions nor
+//! network access. `generated_models_are_up_to_date` fails when a definition changed without
+//! regenerating; run it with `UPDATE_GENERATED_MODELS=1` to rewrite the generated files, and
+//! review the diff before adopting new fields in the curated models.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+// Definitions and the generated module for each of them, relative to the crate root.
+const DEFINITIONS: &[(&str, &str, &str, &str)] = &[(
+    "openapi/sms.json",
+    include_str!("../../../openapi/sms.json"),
+    "src/model/generated/sms.rs",
+    include_str!("../generated/sms.rs"),
+)];
+
+const LINE_WIDTH: usize = 100;
+
+const ARBITRARY: &str = r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#;
+
+fn generate(definition: &str, spec: &Value) -> Result<String, String> {
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .ok_or("definition has no `components.schemas`")?;
+
+    let mut code = String::new();
+    if let Some(title) = spec.pointer("/info/title").and_then(Value::as_str) {
+        writeln!(code, "//! Models of the {title}.").unwrap();
+        writeln!(code, "//!").unwrap();
+    }
+    writeln!(
+        code,
+        "//! Generated from `{definition}` by the `model::tests::codegen` test. Do not edit."
+    )
+    .unwrap();
+    writeln!(code).unwrap();
+    writeln!(code, "use serde_derive::{{Deserialize, Serialize}};").unwrap();
+
+    for (name, schema) in schemas {
+        writeln!(code).unwrap();
+        match schema.get("enum") {
+            Some(values) => write_enum(&mut code, name, schema, values)?,
+            None => write_struct(&mut code, name, schema)?,
+        }
+    }
+
+    Ok(code)
+}
+
+fn write_struct(code: &mut String, name: &str, schema: &Value) -> Result<(), String> {
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(Map::iter)
+        .into_iter()
+        .flatten();
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut fields = String::new();
+    let mut has_float = false;
+    for (property, property_schema) in properties {
+        let field_type =
+            rust_type(property_schema).map_err(|error| format!("{name}.{property}: {error}"))?;
+        has_float |= field_type.contains("f64");
+        let field = snake_case(property);
+
+        if !fields.is_empty() {
+            writeln!(fields).unwrap();
+        }
+        if let Some(description) = property_schema.get("description").and_then(Value::as_str) {
+            write_doc(&mut fields, "    ", description);
+        }
+        if camel_case(&field) != *property {
+            writeln!(fields, "    #[serde(rename = \"{property}\")]").unwrap();
+        }
+        if required.contains(&property.as_str()) {
+            writeln!(fields, "    pub {field}: {field_type},").unwrap();
+        } else {
+            writeln!(
+                fields,
+                "    #[serde(skip_serializing_if = \"Option::is_none\")]"
+            )
+            .unwrap();
+            writeln!(fields, "    pub {field}: Option<{field_type}>,").unwrap();
+        }
+    }
+
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        write_doc(code, "", description);
+    }
+    let eq = if has_float { "" } else { ", Eq" };
+    writeln!(
+        code,
+        "#[derive(Clone, Debug, Default, PartialEq{eq}, Serialize, Deserialize)]"
+    )
+    .unwrap();
+    writeln!(code, "{ARBITRARY}").unwrap();
+    writeln!(code, "#[serde(rename_all = \"camelCase\")]").unwrap();
+    writeln!(code, "pub struct {name} {{").unwrap();
+    code.push_str(&fields);
+    writeln!(code, "}}").unwrap();
+
+    Ok(())
+}
+
+fn write_enum(code: &mut String, name: &str, schema: &Value, values: &Value) -> Result<(), String> {
+    let values = values
+        .as_array()
+        .ok_or_else(|| format!("{name}: `enum` is not an array"))?;
+
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        write_doc(code, "", description);
+    }
+    writeln!(
+        code,
+        "#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]"
+    )
+    .unwrap();
+    writeln!(code, "{ARBITRARY}").unwrap();
+    writeln!(code, "pub enum {name} {{").unwrap();
+    for value in values {
+        let value = value
+            .as_str()
+            .ok_or_else(|| format!("{name}: only string enums are supported"))?;
+        writeln!(code, "    #[serde(rename = \"{value}\")]").unwrap();
+        writeln!(code, "    {},", pascal_case(value)).unwrap();
+    }
+    writeln!(code, "    #[default]").unwrap();
+    writeln!(code, "    #[serde(other)]").unwrap();
+    writeln!(code, "    Unknown,").unwrap();
+    writeln!(code, "}}").unwrap();
+
+    Ok(())
+}
+
+fn rust_type(schema: &Value) -> Result<String, String> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference
+            .strip_prefix("#/components/schemas/")
+            .map(str::to_string)
+            .ok_or_else(|| format!("unsupported reference `{reference}`"));
+    }
+
+    let format = schema.get("format").and_then(Value::as_str);
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => Ok("String".to_string()),
+        Some("boolean") => Ok("bool".to_string()),
+        Some("integer") if format == Some("int64") => Ok("i64".to_string()),
+        Some("integer") => Ok("i32".to_string()),
+        Some("number") => Ok("f64".to_string()),
+        Some("array") => {
+            let items = schema.get("items").ok_or("array without `items`")?;
+            Ok(format!("Vec<{}>", rust_type(items)?))
+        }
+        Some("object") => Ok("serde_json::Map<String, serde_json::Value>".to_string()),
+        other => Err(format!("unsupported type `{other:?}`")),
+    }
+}
+
+// Writes a description as doc comment lines, wrapped at the line width of the crate.
+fn write_doc(code: &mut String, indent: &str, description: &str) {
+    let prefix = format!("{indent}/// ");
+    let mut line = String::new();
+    for word in description.split_whitespace() {
+        if !line.is_empty() && prefix.len() + line.len() + 1 + word.len() > LINE_WIDTH {
+            writeln!(code, "{prefix}{line}").unwrap();
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    writeln!(code, "{prefix}{line}").unwrap();
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, character) in name.chars().enumerate() {
+        if character.is_ascii_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.push(character.to_ascii_lowercase());
+        } else {
+            snake.push(character);
+        }
+    }
+    match snake.as_str() {
+        "type" | "ref" | "match" | "move" => format!("r#{snake}"),
+        _ => snake,
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let name = name.trim_start_matches("r#");
+    let mut camel = String::new();
+    let mut upper = false;
+    for character in name.chars() {
+        if character == '_' {
+            upper = true;
+        } else if upper {
+            camel.push(character.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(character);
+        }
+    }
+    camel
+}
+
+fn pascal_case(value: &str) -> String {
+    value
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let word = word.to_ascii_lowercase();
+            let mut characters = word.chars();
+            characters
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + characters.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[test]
+fn generated_models_are_up_to_date() {
+    let update = std::env::var_os("UPDATE_GENERATED_MODELS").is_some();
+
+    for (definition, spec, module, checked_in) in DEFINITIONS {
+        let spec: Value = serde_json::from_str(spec).unwrap();
+        let generated = generate(definition, &spec).unwrap();
+
+        if update {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(module);
+            std::fs::write(path, &generated).unwrap();
+        } else {
+            assert!(
+                generated == *checked_in,
+                "{module} is out of date with {definition}; run the tests with \
+                 UPDATE_GENERATED_MODELS=1 to regenerate it"
+            );
+        }
+    }
+}
+
+#[test]
+fn converts_names() {
+    assert_eq!(snake_case("languageCode"), "language_code");
+    assert_eq!(snake_case("type"), "r#type");
+    assert_eq!(camel_case("r#type"), "type");
+    assert_eq!(camel_case("text_preview"), "textPreview");
+    assert_eq!(pascal_case("PENDING_ACCEPTED"), "PendingAccepted");
+    assert_eq!(pascal_case("en_GB"), "EnGb");
+}
+
+#[test]
+fn generates_structs_and_enums() {
+    let spec = serde_json::json!({
+        "components": {
+            "schemas": {
+                "Message": {
+                    "type": "object",
+                    "description": "A message.",
+                    "required": ["to"],
+                    "properties": {
+                        "to": {"type": "string", "description": "Recipient of the message."},
+                        "price": {"type": "number"},
+                        "SMSCount": {"type": "integer", "format": "int64"},
+                        "status": {"$ref": "#/components/schemas/Status"},
+                        "tags": {"type": "array", "items": {"type": "string"}}
+                    }
+                },
+                "Status": {
+                    "type": "string",
+                    "enum": ["PENDING", "DELIVERED"]
+                }
+            }
+        }
+    });
+
+    let code = generate("test.json", &spec).unwrap();
+
+    assert!(code.contains("/// A message.\n#[derive(Clone, Debug, Default, PartialEq, Serialize"));
+    assert!(code.contains("    /// Recipient of the message.\n    pub to: String,\n"));
+    assert!(code.contains("    pub price: Option<f64>,\n"));
+    assert!(code.contains("    #[serde(rename = \"SMSCount\")]\n"));
+    assert!(code.contains("    pub s_m_s_count: Option<i64>,\n"));
+    assert!(code.contains("    pub status: Option<Status>,\n"));
+    assert!(code.contains("    pub tags: Option<Vec<String>>,\n"));
+    assert!(code.contains("    #[serde(rename = \"DELIVERED\")]\n    Delivered,\n"));
+    assert!(code.contains("    #[serde(other)]\n    Unknown,\n"));
+}
+
+#[test]
+fn rejects_unsupported_schemas() {
+    let spec = serde_json::json!({
+        "components": {
+            "schemas": {
+                "Message": {
+                    "type": "object",
+                    "properties": {"content": {"oneOf": []}}
+                }
+            }
+        }
+    });
+
+    let error = generate("test.json", &spec).unwrap_err();
+
+    assert!(error.starts_with("Message.content:"));
+}
@@ -0,0 +1,102 @@
+use crate::model::conversations::*;
+use crate::model::sms::{
+    Destination, InboundSmsReport, Message, SendRequestBody, SendResponseBody, SentMessageDetails,
+};
+
+fn inbound(from: &str, text: &str) -> InboundSmsReport {
+    InboundSmsReport {
+        from: Some(from.to_string()),
+        text: Some(text.to_string()),
+        message_id: Some("inbound-1".to_string()),
+        received_at: Some("2021-08-25T16:00:00.000+0000".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn detect_keywords() {
+    assert_eq!(Keyword::detect("STOP"), Some(Keyword::Stop));
+    assert_eq!(Keyword::detect("  unsubscribe! "), Some(Keyword::Stop));
+    assert_eq!(Keyword::detect("Help?"), Some(Keyword::Help));
+    assert_eq!(Keyword::detect("info"), Some(Keyword::Help));
+    assert_eq!(Keyword::detect("please don't stop"), None);
+    assert_eq!(Keyword::detect(""), None);
+}
+
+#[test]
+fn detect_keyword_from_report_prefers_platform_keyword() {
+    let mut report = inbound("41793026727", "stop it please");
+    assert_eq!(Keyword::from_report(&report), None);
+
+    report.keyword = Some("STOP".to_string());
+    assert_eq!(Keyword::from_report(&report), Some(Keyword::Stop));
+}
+
+#[test]
+fn normalize_msisdns() {
+    assert_eq!(normalize_msisdn("+41 79 302 67 27"), "41793026727");
+    assert_eq!(normalize_msisdn("0041-79-302-67-27"), "41793026727");
+    assert_eq!(normalize_msisdn("(385) 91.234.5678"), "385912345678");
+    assert_eq!(normalize_msisdn(" InfoSMS "), "InfoSMS");
+}
+
+#[test]
+fn record_inbound_and_outbound_in_one_thread() {
+    let conversations = Conversations::in_memory();
+
+    let mut message = Message::new(vec![Destination::new("+41 79 302 67 27")]);
+    message.text = Some("Reply HELP for help".to_string());
+    let request_body = SendRequestBody::new(vec![message]);
+    let response_body = SendResponseBody {
+        messages: Some(vec![SentMessageDetails {
+            message_id: Some("outbound-1".to_string()),
+            to: Some("41793026727".to_string()),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    conversations
+        .record_outbound(&request_body, Some(&response_body))
+        .unwrap();
+
+    let keyword = conversations
+        .record_inbound(&inbound("41793026727", "help"))
+        .unwrap();
+    assert_eq!(keyword, Some(Keyword::Help));
+
+    let thread = conversations.thread("0041793026727").unwrap().unwrap();
+    assert_eq!(thread.msisdn, "41793026727");
+    assert_eq!(thread.messages.len(), 2);
+    assert_eq!(thread.messages[0].direction, Direction::Outbound);
+    assert_eq!(thread.messages[0].message_id.as_deref(), Some("outbound-1"));
+    assert_eq!(thread.messages[1].direction, Direction::Inbound);
+    assert_eq!(
+        thread.messages[1].at.to_rfc3339(),
+        "2021-08-25T16:00:00+00:00"
+    );
+    assert_eq!(thread.last_keyword(), Some(Keyword::Help));
+}
+
+#[test]
+fn record_inbound_without_sender_is_ignored() {
+    let conversations = Conversations::in_memory();
+    let report = InboundSmsReport {
+        text: Some("STOP".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(conversations.record_inbound(&report).unwrap(), None);
+    assert!(conversations.thread("").unwrap().is_none());
+}
+
+#[test]
+fn forget_thread() {
+    let conversations = Conversations::in_memory();
+    conversations
+        .record_inbound(&inbound("41793026727", "STOP"))
+        .unwrap();
+
+    let removed = conversations.forget("+41793026727").unwrap().unwrap();
+    assert_eq!(removed.last_keyword(), Some(Keyword::Stop));
+    assert!(conversations.thread("41793026727").unwrap().is_none());
+}
@@ -0,0 +1,77 @@
+use crate::model::csv_import::*;
+use crate::model::sms::Destination;
+
+const RECIPIENTS: &str = "\
+name,phone
+Jane,+41 79 302 67 27
+John,0041-79-302-68-34
+Bad,12345
+Empty,
+Short
+Ann,(385) 91 234 5678
+";
+
+#[test]
+fn normalize_numbers() {
+    assert_eq!(
+        normalize_number(" +41 79 302 67 27 "),
+        Some("41793026727".to_string())
+    );
+    assert_eq!(
+        normalize_number("0041.79.302.67.27"),
+        Some("41793026727".to_string())
+    );
+    assert_eq!(normalize_number("123456"), None);
+    assert_eq!(normalize_number("1234567890123456"), None);
+    assert_eq!(normalize_number("41 79 ABC 67 27"), None);
+}
+
+#[test]
+fn destinations_from_csv_reader() {
+    let import = Destination::from_csv_reader(RECIPIENTS.as_bytes(), "phone").unwrap();
+
+    assert_eq!(
+        import.destinations,
+        vec![
+            Destination::new("41793026727"),
+            Destination::new("41793026834"),
+            Destination::new("385912345678"),
+        ]
+    );
+    let invalid_lines: Vec<u64> = import.invalid_rows.iter().map(|row| row.line).collect();
+    assert_eq!(invalid_lines, vec![4, 5, 6]);
+    assert_eq!(import.invalid_rows[0].value, "12345");
+}
+
+#[test]
+fn destinations_from_csv_reader_missing_column() {
+    let error = Destination::from_csv_reader(RECIPIENTS.as_bytes(), "mobile").unwrap_err();
+
+    assert!(matches!(error, CsvImportError::MissingColumn(column) if column == "mobile"));
+}
+
+#[test]
+fn destinations_csv_chunks() {
+    let chunks: Vec<DestinationImport> = Destination::csv_chunks(RECIPIENTS.as_bytes(), "phone", 2)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].destinations.len(), 2);
+    assert!(chunks[0].invalid_rows.is_empty());
+    assert_eq!(chunks[1].destinations.len(), 1);
+    assert_eq!(chunks[1].invalid_rows.len(), 3);
+}
+
+#[test]
+fn destinations_csv_chunks_only_invalid_rows() {
+    let chunks: Vec<DestinationImport> =
+        Destination::csv_chunks("phone\n123\n".as_bytes(), "phone", 10)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+
+    assert_eq!(chunks.len(), 1);
+    assert!(chunks[0].destinations.is_empty());
+}
@@ -1,6 +1,10 @@
 use validator::Validate;
 
 use crate::model::email::*;
+use crate::model::schedule::{
+    DeliveryDay, DeliveryTimeWindow, Schedule, ScheduleError, DATE_TIME_FORMAT,
+};
+use crate::model::status::StatusGroup;
 
 pub fn get_dummy_send_email_request_body() -> SendRequestBody {
     let mut request = SendRequestBody::new("some@company.com");
@@ -35,6 +39,37 @@ pub fn get_dummy_send_email_request_body() -> SendRequestBody {
     request
 }
 
+#[test]
+fn test_send_request_inline_images_valid() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    let cid = request_body.add_inline_image("path/to/logo.png");
+    request_body.html = Some(format!(r#"<img src="cid:{cid}"><p>Some text</p>"#));
+
+    assert_eq!(cid, "logo.png");
+    assert!(request_body.missing_inline_images().is_empty());
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn test_send_request_inline_images_missing_cid() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.add_inline_image("path/to/logo.png");
+    request_body.html = Some(
+        r#"<img src="cid:logo.png"><img src='cid:banner.jpg'><img src="cid:banner.jpg">"#
+            .to_string(),
+    );
+
+    assert_eq!(request_body.missing_inline_images(), vec!["banner.jpg"]);
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn test_referenced_cids() {
+    let html = r#"<img src="cid:a.png"> <div style="background: url(cid:b.png)"></div> cid:"#;
+
+    assert_eq!(referenced_cids(html), vec!["a.png", "b.png"]);
+}
+
 #[test]
 fn test_send_request_valid() {
     let request_body = SendRequestBody::new("someone@company.com");
@@ -49,6 +84,33 @@ fn test_send_request_valid_full() {
     assert!(request_body.validate().is_ok());
 }
 
+#[test]
+fn test_send_request_named_addresses_valid() {
+    let mut request_body = SendRequestBody::new("Jane Smith <jane.smith@company.com>");
+    request_body.cc = Some("one@company.com, Two <two@company.com>".to_string());
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn test_send_request_invalid_addresses() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.from = Some("John Doe <john.company.com>".to_string());
+    request_body.cc = Some("one@company.com,two".to_string());
+    request_body.reply_to = Some("nobody".to_string());
+
+    let errors = request_body.validate().unwrap_err();
+    let fields = errors.field_errors();
+
+    assert!(fields.contains_key("from"));
+    assert!(fields.contains_key("cc"));
+    assert!(fields.contains_key("reply_to"));
+    assert_eq!(
+        fields["cc"][0].message.as_deref(),
+        Some("invalid email address: two")
+    );
+}
+
 #[test]
 fn test_send_request_body_long_subject() {
     let mut request_body = get_dummy_send_email_request_body();
@@ -157,3 +219,169 @@ fn test_add_domain_request_body_no_domain() {
 
     assert!(request_body.validate().is_err());
 }
+
+#[test]
+fn test_send_request_add_attachment() {
+    let mut request_body = SendRequestBody::new("someone@company.com");
+    request_body.add_attachment("path/to/report.pdf");
+    request_body.add_attachment("path/to/invoice.pdf");
+
+    assert_eq!(
+        request_body.attachments.unwrap(),
+        vec!["path/to/report.pdf", "path/to/invoice.pdf"]
+    );
+}
+
+#[test]
+fn test_report_status_group() {
+    let report: Report =
+        serde_json::from_str(r#"{"status": {"groupId": 2, "groupName": "UNDELIVERABLE"}}"#)
+            .unwrap();
+
+    assert_eq!(report.status_group(), Some(StatusGroup::Undeliverable));
+}
+
+#[test]
+fn test_send_request_apply_schedule() {
+    let send_at = chrono::Utc::now() + chrono::Duration::days(1);
+    let mut request_body = SendRequestBody::new("someone@example.com");
+    request_body
+        .apply_schedule(&Schedule::new().send_at(send_at))
+        .unwrap();
+
+    assert_eq!(
+        request_body.send_at,
+        Some(send_at.format(DATE_TIME_FORMAT).to_string())
+    );
+
+    let with_window = Schedule::new().within(DeliveryTimeWindow::new(vec![DeliveryDay::Monday]));
+    assert_eq!(
+        request_body.apply_schedule(&with_window),
+        Err(ScheduleError::WindowNotSupported)
+    );
+}
+
+#[test]
+fn bulk_status_unknown_value_deserializes() {
+    let response_body: ScheduledStatusResponseBody = serde_json::from_str(
+        r#"{"bulks": [{"bulkId": "some-bulk-id", "status": "SOME_NEW_STATUS"}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        response_body.bulks.unwrap()[0].status,
+        Some(BulkStatus::Unknown)
+    );
+}
+
+#[derive(serde::Serialize)]
+struct OrderPlaceholders {
+    name: String,
+    order_count: u32,
+}
+
+#[test]
+fn test_send_request_from_template_valid() {
+    let recipient = TemplateRecipient::with_placeholders(
+        "jane@company.com",
+        &OrderPlaceholders {
+            name: "Jane".to_string(),
+            order_count: 3,
+        },
+    )
+    .unwrap();
+    let mut request_body = SendRequestBody::from_template(42, vec![recipient]);
+    request_body.add_recipient(TemplateRecipient::new("john@company.com"));
+
+    assert!(request_body.validate().is_ok());
+    assert_eq!(request_body.template_id, Some(42));
+    assert_eq!(
+        serde_json::to_value(request_body.recipients.unwrap()).unwrap(),
+        serde_json::json!([
+            {"to": "jane@company.com", "placeholders": {"name": "Jane", "order_count": 3}},
+            {"to": "john@company.com"}
+        ])
+    );
+}
+
+#[test]
+fn test_send_request_without_recipient() {
+    let request_body = SendRequestBody::from_template(42, vec![]);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn test_send_request_template_recipient_invalid_email() {
+    let request_body =
+        SendRequestBody::from_template(42, vec![TemplateRecipient::new("not an email")]);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn test_template_placeholders_not_an_object() {
+    assert!(TemplateRecipient::with_placeholders("jane@company.com", &"Jane").is_err());
+}
+
+#[test]
+fn test_send_request_default_placeholders() {
+    let mut request_body = SendRequestBody::new("jane@company.com");
+
+    request_body
+        .set_default_placeholders(&OrderPlaceholders {
+            name: "customer".to_string(),
+            order_count: 0,
+        })
+        .unwrap();
+
+    assert_eq!(
+        request_body.default_placeholders.as_deref(),
+        Some(r#"{"name":"customer","order_count":0}"#)
+    );
+}
+
+#[test]
+fn test_preview_recipient_overrides_defaults() {
+    let mut request_body = SendRequestBody::new("");
+    request_body.subject = Some("Hi {{name}}".to_string());
+    request_body.text = Some("{{name}} has {{points}} points".to_string());
+    request_body
+        .set_default_placeholders(&serde_json::json!({"name": "there", "points": 0}))
+        .unwrap();
+    request_body.add_recipient(
+        TemplateRecipient::with_placeholders(
+            "jane@company.com",
+            &serde_json::json!({"name": "Jane", "points": 12}),
+        )
+        .unwrap(),
+    );
+
+    let jane = request_body.preview("jane@company.com").unwrap();
+    let other = request_body.preview("other@company.com").unwrap();
+
+    assert_eq!(jane.subject.as_deref(), Some("Hi Jane"));
+    assert_eq!(jane.text.as_deref(), Some("Jane has 12 points"));
+    assert_eq!(other.text.as_deref(), Some("there has 0 points"));
+    assert_eq!(other.html, None);
+}
+
+#[test]
+fn test_preview_missing_value() {
+    let mut request_body = SendRequestBody::new("jane@company.com");
+    request_body.html = Some("<b>{{code}}</b>".to_string());
+
+    let error = request_body.preview("jane@company.com").unwrap_err();
+
+    assert!(matches!(error, PreviewError::Template(_)));
+}
+
+#[test]
+fn test_preview_stored_template() {
+    let request_body = SendRequestBody::from_template(42, vec![]);
+
+    assert_eq!(
+        request_body.preview("jane@company.com"),
+        Err(PreviewError::StoredTemplate(42))
+    );
+}
@@ -1,8 +1,44 @@
+#[cfg(all(test, feature = "account"))]
+mod account;
+
+#[cfg(test)]
+mod callback_data;
+
+#[cfg(all(test, feature = "sms"))]
+mod conversations;
+
+#[cfg(all(test, feature = "sms"))]
+mod codegen;
+
+#[cfg(all(test, feature = "csv"))]
+mod csv_import;
+
+#[cfg(all(test, any(feature = "email", feature = "sms")))]
+mod schedule;
+
 #[cfg(test)]
 mod sms;
 
+#[cfg(test)]
+mod status;
+
+#[cfg(test)]
+mod templating;
+
+#[cfg(test)]
+mod tfa;
+
 #[cfg(test)]
 mod whatsapp;
 
 #[cfg(test)]
 mod email;
+
+#[cfg(test)]
+mod serde_audit;
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod serde_round_trip;
+
+#[cfg(all(test, feature = "sms", feature = "tfa", feature = "whatsapp"))]
+mod query_parameters;
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use proptest::prelude::*;
+
+use crate::model::sms::{LogsQueryParameters, SendOverQueryParametersQueryParameters};
+use crate::model::tfa::TfaVerificationStatusQueryParameters;
+use crate::model::whatsapp::{TemplateLanguage, TemplateLibraryQueryParameters};
+use crate::model::{encode_query_parameters, QueryParamsError};
+
+const TEMPLATE_LANGUAGES: &[TemplateLanguage] = &[
+    TemplateLanguage::Af,
+    TemplateLanguage::EnGb,
+    TemplateLanguage::EnUs,
+    TemplateLanguage::PtBr,
+    TemplateLanguage::ZhCn,
+];
+
+fn phone_number() -> impl Strategy<Value = String> {
+    "[1-9][0-9]{7,14}"
+}
+
+fn text() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9 _.,:+-]{0,20}"
+}
+
+#[test]
+fn unit_encodes_to_no_parameters() {
+    assert!(encode_query_parameters(&()).unwrap().is_empty());
+}
+
+#[test]
+fn maps_are_encoded_as_is() {
+    let parameters = HashMap::from([("bulkId".to_string(), "some-bulk-id".to_string())]);
+
+    assert_eq!(encode_query_parameters(&parameters).unwrap(), parameters);
+}
+
+#[test]
+fn nested_values_are_rejected() {
+    let parameters = serde_json::json!({"to": {"phoneNumber": "41793026727"}});
+
+    assert_eq!(
+        encode_query_parameters(&parameters),
+        Err(QueryParamsError::NestedValue("to".to_string()))
+    );
+    assert_eq!(
+        encode_query_parameters(&"41793026727"),
+        Err(QueryParamsError::NotAStruct)
+    );
+}
+
+proptest! {
+    // Documented as `/sms/1/logs?from=...&to=...&bulkId=...&generalStatus=...&limit=...`, with
+    // only the filters that are set.
+    #[test]
+    fn sms_logs_filters(
+        from in proptest::option::of(text()),
+        to in proptest::option::of(phone_number()),
+        general_status in proptest::option::of("ACCEPTED|PENDING|DELIVERED|REJECTED"),
+        sent_since in proptest::option::of("20[0-9]{2}-[01][0-9]-[0-3][0-9]T[0-2][0-9]:[0-5][0-9]:[0-5][0-9]\\.[0-9]{3}\\+0000"),
+        limit in proptest::option::of(1..=1000i32),
+    ) {
+        let mut query_parameters = LogsQueryParameters::new();
+        query_parameters.from = from.clone();
+        query_parameters.to = to.clone();
+        query_parameters.general_status = general_status.clone();
+        query_parameters.sent_since = sent_since.clone();
+        query_parameters.limit = limit;
+
+        let mut expected = HashMap::new();
+        for (name, value) in [
+            ("from", from),
+            ("to", to),
+            ("generalStatus", general_status),
+            ("sentSince", sent_since),
+            ("limit", limit.map(|limit| limit.to_string())),
+        ] {
+            if let Some(value) = value {
+                expected.insert(name.to_string(), value);
+            }
+        }
+
+        prop_assert_eq!(encode_query_parameters(&query_parameters).unwrap(), expected);
+    }
+
+    // Documented as `/sms/1/text/query?username=...&password=...&to=41793026727,41793026834`,
+    // with booleans as `true` or `false` and the validity period in minutes.
+    #[test]
+    fn sms_send_over_query_parameters(
+        to in proptest::collection::vec(phone_number(), 1..5),
+        flash in proptest::option::of(any::<bool>()),
+        validity_period in proptest::option::of(1..=2880i32),
+    ) {
+        let mut query_parameters =
+            SendOverQueryParametersQueryParameters::new("some-user", "some-password", to.clone());
+        query_parameters.flash = flash;
+        query_parameters.validity_period = validity_period;
+
+        let encoded = encode_query_parameters(&query_parameters).unwrap();
+
+        prop_assert_eq!(&encoded["username"], "some-user");
+        prop_assert_eq!(&encoded["password"], "some-password");
+        prop_assert_eq!(&encoded["to"], &to.join(","));
+        prop_assert_eq!(encoded.get("flash").cloned(), flash.map(|flash| flash.to_string()));
+        prop_assert_eq!(
+            encoded.get("validityPeriod").cloned(),
+            validity_period.map(|period| period.to_string())
+        );
+        prop_assert_eq!(
+            encoded.len(),
+            3 + usize::from(flash.is_some()) + usize::from(validity_period.is_some())
+        );
+    }
+
+    // Documented as `/2fa/2/applications/{appId}/verifications?msisdn=...&verified=true&sent=false`.
+    #[test]
+    fn tfa_verification_status_filters(
+        msisdn in phone_number(),
+        verified in proptest::option::of(any::<bool>()),
+        sent in proptest::option::of(any::<bool>()),
+    ) {
+        let mut query_parameters = TfaVerificationStatusQueryParameters::new(&msisdn);
+        query_parameters.verified = verified;
+        query_parameters.sent = sent;
+
+        let encoded = encode_query_parameters(&query_parameters).unwrap();
+
+        prop_assert_eq!(&encoded["msisdn"], &msisdn);
+        prop_assert_eq!(encoded.get("verified").cloned(), verified.map(|v| v.to_string()));
+        prop_assert_eq!(encoded.get("sent").cloned(), sent.map(|s| s.to_string()));
+    }
+
+    // Languages are documented as locale codes, e.g. `language=en_GB`.
+    #[test]
+    fn whatsapp_template_library_language(
+        language in proptest::sample::select(TEMPLATE_LANGUAGES),
+        page in proptest::option::of(0..100i32),
+    ) {
+        let mut query_parameters = TemplateLibraryQueryParameters::new();
+        query_parameters.language = Some(language);
+        query_parameters.page = page;
+
+        let encoded = encode_query_parameters(&query_parameters).unwrap();
+
+        prop_assert_eq!(&encoded["language"], &language.to_string());
+        prop_assert_eq!(encoded.get("page").cloned(), page.map(|page| page.to_string()));
+        prop_assert!(!encoded.contains_key("search"));
+    }
+}
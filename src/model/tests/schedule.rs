@@ -0,0 +1,142 @@
+use chrono::{Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use crate::model::schedule::*;
+
+fn window(days: Vec<DeliveryDay>, from: (i32, i32), to: (i32, i32)) -> DeliveryTimeWindow {
+    let mut window = DeliveryTimeWindow::new(days);
+    window.from = Some(DeliveryTime::new(from.0, from.1));
+    window.to = Some(DeliveryTime::new(to.0, to.1));
+    window
+}
+
+fn hours(hours: i32) -> FixedOffset {
+    FixedOffset::east_opt(hours * 3600).unwrap()
+}
+
+#[test]
+fn shift_delivery_days() {
+    assert_eq!(DeliveryDay::Monday.shift(-1), DeliveryDay::Sunday);
+    assert_eq!(DeliveryDay::Sunday.shift(1), DeliveryDay::Monday);
+    assert_eq!(DeliveryDay::Wednesday.shift(0), DeliveryDay::Wednesday);
+}
+
+#[test]
+fn window_without_time_zone_is_unchanged() {
+    let local = DeliveryTimeWindow::new(vec![DeliveryDay::Monday]);
+    let schedule = Schedule::new().within(local.clone());
+
+    assert_eq!(schedule.delivery_time_window_utc().unwrap(), Some(local));
+}
+
+#[test]
+fn window_converted_to_utc() {
+    let schedule = Schedule::new()
+        .within(window(vec![DeliveryDay::Monday], (9, 30), (17, 0)))
+        .in_time_zone(hours(2));
+
+    assert_eq!(
+        schedule.delivery_time_window_utc().unwrap(),
+        Some(window(vec![DeliveryDay::Monday], (7, 30), (15, 0)))
+    );
+}
+
+#[test]
+fn window_converted_to_utc_moves_days() {
+    let schedule = Schedule::new()
+        .within(window(
+            vec![DeliveryDay::Monday, DeliveryDay::Tuesday],
+            (1, 0),
+            (1, 30),
+        ))
+        .in_time_zone(hours(2));
+
+    assert_eq!(
+        schedule.delivery_time_window_utc().unwrap(),
+        Some(window(
+            vec![DeliveryDay::Monday, DeliveryDay::Sunday],
+            (23, 0),
+            (23, 30)
+        ))
+    );
+}
+
+#[test]
+fn window_spanning_midnight_utc() {
+    let schedule = Schedule::new()
+        .within(window(vec![DeliveryDay::Monday], (9, 0), (17, 0)))
+        .in_time_zone(hours(-10));
+
+    assert_eq!(
+        schedule.delivery_time_window_utc(),
+        Err(ScheduleError::WindowSpansMidnight)
+    );
+
+    let whole_day = Schedule::new()
+        .within(DeliveryTimeWindow::new(vec![DeliveryDay::Monday]))
+        .in_time_zone(hours(1));
+    assert_eq!(
+        whole_day.delivery_time_window_utc(),
+        Err(ScheduleError::WindowSpansMidnight)
+    );
+}
+
+#[test]
+fn window_ending_before_start() {
+    let schedule = Schedule::new().within(window(vec![DeliveryDay::Monday], (17, 0), (9, 0)));
+
+    assert_eq!(
+        schedule.validate(),
+        Err(ScheduleError::WindowEndsBeforeStart)
+    );
+}
+
+#[test]
+fn window_invalid() {
+    let schedule = Schedule::new().within(DeliveryTimeWindow::new(vec![]));
+
+    assert!(matches!(
+        schedule.validate(),
+        Err(ScheduleError::InvalidWindow(_))
+    ));
+}
+
+#[test]
+fn send_at_local() {
+    let local = NaiveDate::from_ymd_opt(2030, 1, 1)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    let schedule = Schedule::new().send_at_local(local).in_time_zone(hours(-5));
+
+    assert_eq!(
+        schedule.send_at_utc(),
+        Some(Utc.with_ymd_and_hms(2030, 1, 1, 14, 0, 0).unwrap())
+    );
+    assert_eq!(
+        schedule.send_at_formatted().as_deref(),
+        Some("2030-01-01T14:00:00.000+0000")
+    );
+}
+
+#[test]
+fn send_at_range() {
+    let now = Utc::now();
+
+    assert!(Schedule::new().validate_relative_to(now).is_ok());
+    assert!(Schedule::new()
+        .send_at(now + Duration::hours(1))
+        .validate_relative_to(now)
+        .is_ok());
+    assert_eq!(
+        Schedule::new()
+            .send_at(now - Duration::hours(1))
+            .validate_relative_to(now),
+        Err(ScheduleError::SendAtNotInFuture)
+    );
+    assert_eq!(
+        Schedule::new()
+            .send_at(now + Duration::days(MAX_SCHEDULE_DAYS + 1))
+            .validate_relative_to(now),
+        Err(ScheduleError::SendAtTooFarInFuture)
+    );
+}
@@ -0,0 +1,213 @@
+// Checks the serde attributes of the models in their sources: every serialized struct declares its
+// `rename_all`, and fields are only renamed when the name differs from the one `rename_all` gives.
+
+const SOURCES: &[(&str, &str)] = &[
+    ("account.rs", include_str!("../account.rs")),
+    ("callback_data.rs", include_str!("../callback_data.rs")),
+    ("conversations.rs", include_str!("../conversations.rs")),
+    ("csv_import.rs", include_str!("../csv_import.rs")),
+    ("email.rs", include_str!("../email.rs")),
+    ("generated/sms.rs", include_str!("../generated/sms.rs")),
+    ("schedule.rs", include_str!("../schedule.rs")),
+    ("sms.rs", include_str!("../sms.rs")),
+    ("status.rs", include_str!("../status.rs")),
+    ("templating.rs", include_str!("../templating.rs")),
+    ("tfa.rs", include_str!("../tfa.rs")),
+    (
+        "whatsapp/inbound.rs",
+        include_str!("../whatsapp/inbound.rs"),
+    ),
+    ("whatsapp/mod.rs", include_str!("../whatsapp/mod.rs")),
+];
+
+#[derive(Debug)]
+struct SerdeStruct {
+    location: String,
+    name: String,
+    rename_all: Option<String>,
+    fields: Vec<SerdeField>,
+}
+
+#[derive(Debug)]
+struct SerdeField {
+    location: String,
+    name: String,
+    rename: Option<String>,
+}
+
+fn attribute_value<'a>(attributes: &'a str, key: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{} = \"", key))? + key.len() + 4;
+    let end = attributes[start..].find('"')?;
+
+    Some(&attributes[start..start + end])
+}
+
+fn camel_case(name: &str) -> String {
+    let mut parts = name.split('_');
+    let mut camel = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+
+    camel
+}
+
+// Returns how many more brackets the line opens than it closes, to follow attributes spanning
+// several lines.
+fn brackets(line: &str) -> i32 {
+    line.matches('[').count() as i32 - line.matches(']').count() as i32
+}
+
+// Finds the top-level structs deriving `Serialize` or `Deserialize`, with their named fields.
+fn serde_structs(file: &str, source: &str) -> Vec<SerdeStruct> {
+    let mut structs = Vec::new();
+    let mut attributes = String::new();
+    let mut open_brackets = 0i32;
+    let mut current: Option<SerdeStruct> = None;
+    let mut field_attributes = String::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let location = format!("{}:{}", file, index + 1);
+
+        if let Some(serde_struct) = current.as_mut() {
+            let trimmed = line.trim();
+            if line.starts_with('}') {
+                structs.push(current.take().unwrap());
+            } else if open_brackets > 0 || trimmed.starts_with("#[") {
+                field_attributes.push_str(trimmed);
+                open_brackets += brackets(trimmed);
+            } else if let Some(field) = trimmed.strip_prefix("pub ") {
+                if let Some((name, _)) = field.split_once(':') {
+                    serde_struct.fields.push(SerdeField {
+                        location,
+                        name: name.to_string(),
+                        rename: attribute_value(&field_attributes, "rename").map(str::to_string),
+                    });
+                }
+                field_attributes.clear();
+            } else if trimmed.is_empty() {
+                field_attributes.clear();
+            }
+            continue;
+        }
+
+        if open_brackets > 0 || line.starts_with("#[") {
+            attributes.push_str(line);
+            open_brackets += brackets(line);
+            continue;
+        }
+        if line.starts_with("///") {
+            continue;
+        }
+
+        // Structs serialized through another type don't have serialized fields of their own.
+        let derives_serde = (attributes.contains("Serialize")
+            || attributes.contains("Deserialize"))
+            && !attributes.contains("try_from = ")
+            && !attributes.contains("into = ");
+        if let Some(declaration) = line.strip_prefix("pub struct ") {
+            if derives_serde && line.ends_with('{') {
+                current = Some(SerdeStruct {
+                    location,
+                    name: declaration.split([' ', '<']).next().unwrap().to_string(),
+                    rename_all: attribute_value(&attributes, "rename_all").map(str::to_string),
+                    fields: Vec::new(),
+                });
+                field_attributes.clear();
+            }
+        }
+        attributes.clear();
+    }
+
+    structs
+}
+
+fn all_serde_structs() -> Vec<SerdeStruct> {
+    SOURCES
+        .iter()
+        .flat_map(|(file, source)| serde_structs(file, source))
+        .collect()
+}
+
+#[test]
+fn finds_serde_structs() {
+    let structs = all_serde_structs();
+
+    let url_options = structs.iter().find(|s| s.name == "UrlOptions").unwrap();
+    assert_eq!(url_options.rename_all.as_deref(), Some("camelCase"));
+    assert!(url_options.fields.iter().any(|f| f.name == "shorten_url"));
+    assert!(structs.len() > 100);
+}
+
+#[test]
+fn finds_attributes_spanning_lines() {
+    let source = r#"
+#[derive(
+    Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Example {
+    #[serde(
+        rename = "someName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub some_name: Option<String>,
+}
+"#;
+
+    let structs = serde_structs("example.rs", source);
+
+    assert_eq!(structs.len(), 1);
+    assert_eq!(structs[0].rename_all.as_deref(), Some("camelCase"));
+    assert_eq!(structs[0].fields[0].rename.as_deref(), Some("someName"));
+}
+
+#[test]
+fn serde_structs_declare_rename_all() {
+    let missing: Vec<_> = all_serde_structs()
+        .into_iter()
+        .filter(|s| s.rename_all.is_none())
+        .map(|s| format!("{} {}", s.location, s.name))
+        .collect();
+
+    assert!(
+        missing.is_empty(),
+        "structs without rename_all: {:#?}",
+        missing
+    );
+}
+
+#[test]
+fn serde_fields_not_renamed_to_default_name() {
+    let mut redundant = Vec::new();
+    for serde_struct in all_serde_structs() {
+        for field in serde_struct.fields {
+            let default_name = match serde_struct.rename_all.as_deref() {
+                Some("camelCase") => camel_case(&field.name),
+                _ => field.name.clone(),
+            };
+            if field.rename.as_deref() == Some(default_name.as_str()) {
+                redundant.push(format!(
+                    "{} {}.{}",
+                    field.location, serde_struct.name, field.name
+                ));
+            }
+        }
+    }
+
+    assert!(redundant.is_empty(), "redundant renames: {:#?}", redundant);
+}
+
+#[test]
+fn camel_case_names() {
+    assert_eq!(camel_case("to"), "to");
+    assert_eq!(camel_case("pin_time_to_live"), "pinTimeToLive");
+    assert_eq!(
+        camel_case("india_dlt_content_template_id"),
+        "indiaDltContentTemplateId"
+    );
+}
@@ -0,0 +1,302 @@
+//! Serialization round trips of models built with `arbitrary`: every model serialized to JSON
+//! must deserialize to the same model. Fails when a field is renamed, skipped or dropped in only
+//! one direction, or has a representation that doesn't parse back.
+
+use std::fmt::Debug;
+
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::model::*;
+
+fn assert_round_trip<T>(data: &[u8]) -> Result<(), TestCaseError>
+where
+    T: for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    // Not enough data for a model.
+    let Ok(model) = T::arbitrary(&mut Unstructured::new(data)) else {
+        return Ok(());
+    };
+
+    let json = serde_json::to_string(&model).unwrap();
+    let parsed: T = serde_json::from_str(&json)
+        .map_err(|error| TestCaseError::fail(format!("{error}: {json}")))?;
+
+    prop_assert_eq!(parsed, model, "{}", json);
+    Ok(())
+}
+
+macro_rules! round_trip_tests {
+    ($($name:ident: $model:ty),+ $(,)?) => {
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(64))]
+
+            $(
+                #[test]
+                fn $name(data in proptest::collection::vec(any::<u8>(), 0..2048)) {
+                    assert_round_trip::<$model>(&data)?;
+                }
+            )+
+        }
+    };
+}
+
+round_trip_tests! {
+    sms_preview_request_body: sms::PreviewRequestBody,
+    sms_language: sms::Language,
+    sms_preview_language_configuration: sms::PreviewLanguageConfiguration,
+    sms_preview: sms::Preview,
+    sms_preview_response_body: sms::PreviewResponseBody,
+    sms_delivery_reports_query_parameters: sms::DeliveryReportsQueryParameters,
+    sms_status: sms::Status,
+    sms_price: sms::Price,
+    sms_error: sms::Error,
+    sms_report: sms::Report,
+    sms_delivery_reports_response_body: sms::DeliveryReportsResponseBody,
+    sms_tracking: sms::Tracking,
+    sms_time_unit: sms::TimeUnit,
+    sms_speed_limit: sms::SpeedLimit,
+    sms_url_options: sms::UrlOptions,
+    sms_destination: sms::Destination,
+    sms_turkey_recipient_type: sms::TurkeyRecipientType,
+    sms_turkey_iys: sms::TurkeyIys,
+    sms_regional_options: sms::RegionalOptions,
+    sms_message: sms::Message,
+    sms_data_coding: sms::DataCoding,
+    sms_esm_class: sms::EsmClass,
+    sms_binary_data: sms::BinaryData,
+    sms_binary_message: sms::BinaryMessage,
+    sms_send_request_body: sms::SendRequestBody,
+    sms_sms_encoding: sms::SmsEncoding,
+    sms_sms_parts: sms::SmsParts,
+    sms_cost_estimate: sms::CostEstimate,
+    sms_send_binary_request_body: sms::SendBinaryRequestBody,
+    sms_sent_message_details: sms::SentMessageDetails,
+    sms_send_response_body: sms::SendResponseBody,
+    sms_message_ids: sms::MessageIds,
+    sms_scheduled_query_parameters: sms::ScheduledQueryParameters,
+    sms_scheduled_response_body: sms::ScheduledResponseBody,
+    sms_logs_query_parameters: sms::LogsQueryParameters,
+    sms_log: sms::Log,
+    sms_logs_response_body: sms::LogsResponseBody,
+    sms_inbound_reports_query_parameters: sms::InboundReportsQueryParameters,
+    sms_inbound_reports_response_body: sms::InboundReportsResponseBody,
+    sms_inbound_sms_report: sms::InboundSmsReport,
+    sms_send_over_query_parameters_query_parameters: sms::SendOverQueryParametersQueryParameters,
+    sms_reschedule_request_body: sms::RescheduleRequestBody,
+    sms_scheduled_status: sms::ScheduledStatus,
+    sms_scheduled_status_response_body: sms::ScheduledStatusResponseBody,
+    sms_update_scheduled_status_request_body: sms::UpdateScheduledStatusRequestBody,
+    sms_tracked_domain: sms::TrackedDomain,
+    sms_tracked_domains_response_body: sms::TrackedDomainsResponseBody,
+    sms_add_tracked_domain_request_body: sms::AddTrackedDomainRequestBody,
+    sms_click_reports_query_parameters: sms::ClickReportsQueryParameters,
+    sms_click_report: sms::ClickReport,
+    sms_click_reports_response_body: sms::ClickReportsResponseBody,
+    email_send_request_body: email::SendRequestBody,
+    email_template_recipient: email::TemplateRecipient,
+    email_sent_message_details: email::SentMessageDetails,
+    email_report_error: email::ReportError,
+    email_status: email::Status,
+    email_send_response_body: email::SendResponseBody,
+    email_bulks_query_parameters: email::BulksQueryParameters,
+    email_bulks_response_body: email::BulksResponseBody,
+    email_bulk_info: email::BulkInfo,
+    email_reschedule_request_body: email::RescheduleRequestBody,
+    email_bulk_status: email::BulkStatus,
+    email_bulk_status_info: email::BulkStatusInfo,
+    email_scheduled_status_response_body: email::ScheduledStatusResponseBody,
+    email_update_scheduled_status_request_body: email::UpdateScheduledStatusRequestBody,
+    email_delivery_reports_query_parameters: email::DeliveryReportsQueryParameters,
+    email_price: email::Price,
+    email_report: email::Report,
+    email_delivery_reports_response_body: email::DeliveryReportsResponseBody,
+    email_logs_query_parameters: email::LogsQueryParameters,
+    email_log: email::Log,
+    email_logs_response_body: email::LogsResponseBody,
+    email_tracking_reports_query_parameters: email::TrackingReportsQueryParameters,
+    email_tracking_report: email::TrackingReport,
+    email_tracking_reports_response_body: email::TrackingReportsResponseBody,
+    email_validate_address_request_body: email::ValidateAddressRequestBody,
+    email_validate_address_response_body: email::ValidateAddressResponseBody,
+    email_domains_query_parameters: email::DomainsQueryParameters,
+    email_tracking: email::Tracking,
+    email_dns_record: email::DnsRecord,
+    email_domain: email::Domain,
+    email_paging: email::Paging,
+    email_domains_response_body: email::DomainsResponseBody,
+    email_dkim_key_length: email::DkimKeyLength,
+    email_add_domain_request_body: email::AddDomainRequestBody,
+    email_update_tracking_request_body: email::UpdateTrackingRequestBody,
+    tfa_india_dlt: tfa::IndiaDlt,
+    tfa_time_to_live: tfa::TimeToLive,
+    tfa_rate_limit: tfa::RateLimit,
+    tfa_tfa_application_configuration: tfa::TfaApplicationConfiguration,
+    tfa_tfa_application: tfa::TfaApplication,
+    tfa_tfa_language: tfa::TfaLanguage,
+    tfa_pin_type: tfa::PinType,
+    tfa_tfa_regional: tfa::TfaRegional,
+    tfa_speech_rate: tfa::SpeechRate,
+    tfa_tfa_message_template: tfa::TfaMessageTemplate,
+    tfa_send_pin_over_sms_query_parameters: tfa::SendPinOverSmsQueryParameters,
+    tfa_send_pin_over_sms_request_body: tfa::SendPinOverSmsRequestBody,
+    tfa_send_pin_response_body: tfa::SendPinResponseBody,
+    tfa_resend_pin_request_body: tfa::ResendPinRequestBody,
+    tfa_voice_delivery_reports: tfa::VoiceDeliveryReports,
+    tfa_voice_report: tfa::VoiceReport,
+    tfa_voice_call: tfa::VoiceCall,
+    tfa_voice_price: tfa::VoicePrice,
+    tfa_voice_status: tfa::VoiceStatus,
+    tfa_voice_error: tfa::VoiceError,
+    tfa_send_pin_over_email_request_body: tfa::SendPinOverEmailRequestBody,
+    tfa_email_status: tfa::EmailStatus,
+    tfa_send_pin_over_email_response_body: tfa::SendPinOverEmailResponseBody,
+    tfa_verify_phone_number_request_body: tfa::VerifyPhoneNumberRequestBody,
+    tfa_verify_phone_number_response_body: tfa::VerifyPhoneNumberResponseBody,
+    tfa_tfa_verification_status_query_parameters: tfa::TfaVerificationStatusQueryParameters,
+    tfa_tfa_verification: tfa::TfaVerification,
+    tfa_tfa_verification_status_response_body: tfa::TfaVerificationStatusResponseBody,
+    whatsapp_text_content: whatsapp::TextContent,
+    whatsapp_document_content: whatsapp::DocumentContent,
+    whatsapp_image_content: whatsapp::ImageContent,
+    whatsapp_audio_content: whatsapp::AudioContent,
+    whatsapp_video_content: whatsapp::VideoContent,
+    whatsapp_sticker_content: whatsapp::StickerContent,
+    whatsapp_location_content: whatsapp::LocationContent,
+    whatsapp_address_type: whatsapp::AddressType,
+    whatsapp_contact_address: whatsapp::ContactAddress,
+    whatsapp_contact_name: whatsapp::ContactName,
+    whatsapp_contact_organization: whatsapp::ContactOrganization,
+    whatsapp_phone_type: whatsapp::PhoneType,
+    whatsapp_contact_phone: whatsapp::ContactPhone,
+    whatsapp_contact_url: whatsapp::ContactUrl,
+    whatsapp_contact_email: whatsapp::ContactEmail,
+    whatsapp_contact: whatsapp::Contact,
+    whatsapp_contact_content: whatsapp::ContactContent,
+    whatsapp_send_content_request_body: whatsapp::SendContentRequestBody<whatsapp::TextContent>,
+    whatsapp_interactive_body: whatsapp::InteractiveBody,
+    whatsapp_interactive_button: whatsapp::InteractiveButton,
+    whatsapp_interactive_buttons_action: whatsapp::InteractiveButtonsAction,
+    whatsapp_interactive_buttons_header: whatsapp::InteractiveButtonsHeader,
+    whatsapp_interactive_footer: whatsapp::InteractiveFooter,
+    whatsapp_interactive_buttons_content: whatsapp::InteractiveButtonsContent,
+    whatsapp_interactive_row: whatsapp::InteractiveRow,
+    whatsapp_interactive_list_section: whatsapp::InteractiveListSection,
+    whatsapp_interactive_list_action: whatsapp::InteractiveListAction,
+    whatsapp_interactive_list_header: whatsapp::InteractiveListHeader,
+    whatsapp_interactive_list_content: whatsapp::InteractiveListContent,
+    whatsapp_interactive_product_action: whatsapp::InteractiveProductAction,
+    whatsapp_interactive_product_content: whatsapp::InteractiveProductContent,
+    whatsapp_interactive_multiproduct_header: whatsapp::InteractiveMultiproductHeader,
+    whatsapp_interactive_multiproduct_section: whatsapp::InteractiveMultiproductSection,
+    whatsapp_interactive_multiproduct_action: whatsapp::InteractiveMultiproductAction,
+    whatsapp_interactive_multiproduct_content: whatsapp::InteractiveMultiproductContent,
+    whatsapp_flow_mode: whatsapp::FlowMode,
+    whatsapp_flow_action: whatsapp::FlowAction,
+    whatsapp_interactive_flow_action_payload: whatsapp::InteractiveFlowActionPayload,
+    whatsapp_interactive_flow_action: whatsapp::InteractiveFlowAction,
+    whatsapp_interactive_flow_header: whatsapp::InteractiveFlowHeader,
+    whatsapp_interactive_flow_content: whatsapp::InteractiveFlowContent,
+    whatsapp_order_amount: whatsapp::OrderAmount,
+    whatsapp_order_item: whatsapp::OrderItem,
+    whatsapp_order_details: whatsapp::OrderDetails,
+    whatsapp_order_goods_type: whatsapp::OrderGoodsType,
+    whatsapp_order_payment: whatsapp::OrderPayment,
+    whatsapp_interactive_order_details_action: whatsapp::InteractiveOrderDetailsAction,
+    whatsapp_interactive_order_details_content: whatsapp::InteractiveOrderDetailsContent,
+    whatsapp_order_status: whatsapp::OrderStatus,
+    whatsapp_interactive_order_status_action: whatsapp::InteractiveOrderStatusAction,
+    whatsapp_interactive_order_status_content: whatsapp::InteractiveOrderStatusContent,
+    whatsapp_template_category: whatsapp::TemplateCategory,
+    whatsapp_template_language: whatsapp::TemplateLanguage,
+    whatsapp_template_header: whatsapp::TemplateHeader,
+    whatsapp_template_footer: whatsapp::TemplateFooter,
+    whatsapp_template_button: whatsapp::TemplateButton,
+    whatsapp_template_body: whatsapp::TemplateBody,
+    whatsapp_template_type: whatsapp::TemplateType,
+    whatsapp_template_card: whatsapp::TemplateCard,
+    whatsapp_template_carousel: whatsapp::TemplateCarousel,
+    whatsapp_template_structure: whatsapp::TemplateStructure,
+    whatsapp_create_template_request_body: whatsapp::CreateTemplateRequestBody,
+    whatsapp_update_template_request_body: whatsapp::UpdateTemplateRequestBody,
+    whatsapp_template_header_content: whatsapp::TemplateHeaderContent,
+    whatsapp_template_body_content: whatsapp::TemplateBodyContent,
+    whatsapp_template_button_content: whatsapp::TemplateButtonContent,
+    whatsapp_template_data: whatsapp::TemplateData,
+    whatsapp_template_card_content: whatsapp::TemplateCardContent,
+    whatsapp_template_carousel_content: whatsapp::TemplateCarouselContent,
+    whatsapp_template_content: whatsapp::TemplateContent,
+    whatsapp_sms_failover: whatsapp::SmsFailover,
+    whatsapp_failover_message: whatsapp::FailoverMessage,
+    whatsapp_send_template_request_body: whatsapp::SendTemplateRequestBody,
+    whatsapp_typing_indicator_type: whatsapp::TypingIndicatorType,
+    whatsapp_send_typing_indicator_request_body: whatsapp::SendTypingIndicatorRequestBody,
+    whatsapp_status: whatsapp::Status,
+    whatsapp_send_content_response_body: whatsapp::SendContentResponseBody,
+    whatsapp_template_quality_rating: whatsapp::TemplateQualityRating,
+    whatsapp_template_quality_score: whatsapp::TemplateQualityScore,
+    whatsapp_template_status: whatsapp::TemplateStatus,
+    whatsapp_create_template_response_body: whatsapp::CreateTemplateResponseBody,
+    whatsapp_template: whatsapp::Template,
+    whatsapp_templates_response_body: whatsapp::TemplatesResponseBody,
+    whatsapp_template_library_query_parameters: whatsapp::TemplateLibraryQueryParameters,
+    whatsapp_library_template: whatsapp::LibraryTemplate,
+    whatsapp_template_library_response_body: whatsapp::TemplateLibraryResponseBody,
+    whatsapp_library_button_type: whatsapp::LibraryButtonType,
+    whatsapp_library_template_button_input: whatsapp::LibraryTemplateButtonInput,
+    whatsapp_create_library_template_request_body: whatsapp::CreateLibraryTemplateRequestBody,
+    whatsapp_send_template_response_body: whatsapp::SendTemplateResponseBody,
+    whatsapp_send_template_batch_response_body: whatsapp::SendTemplateBatchResponseBody,
+    whatsapp_inbound_order_product_item: whatsapp::InboundOrderProductItem,
+    whatsapp_inbound_order_message: whatsapp::InboundOrderMessage,
+    whatsapp_inbound_contact: whatsapp::InboundContact,
+    whatsapp_inbound_price: whatsapp::InboundPrice,
+    whatsapp_inbound_order: whatsapp::InboundOrder,
+    whatsapp_inbound_orders_body: whatsapp::InboundOrdersBody,
+    whatsapp_delivery_reports_query_parameters: whatsapp::DeliveryReportsQueryParameters,
+    whatsapp_price: whatsapp::Price,
+    whatsapp_report_error: whatsapp::ReportError,
+    whatsapp_report: whatsapp::Report,
+    whatsapp_delivery_reports_response_body: whatsapp::DeliveryReportsResponseBody,
+    whatsapp_logs_query_parameters: whatsapp::LogsQueryParameters,
+    whatsapp_log: whatsapp::Log,
+    whatsapp_logs_response_body: whatsapp::LogsResponseBody,
+    whatsapp_check_contacts_request_body: whatsapp::CheckContactsRequestBody,
+    whatsapp_contact_status: whatsapp::ContactStatus,
+    whatsapp_contact_check: whatsapp::ContactCheck,
+    whatsapp_check_contacts_response_body: whatsapp::CheckContactsResponseBody,
+    whatsapp_sender_quality_query_parameters: whatsapp::SenderQualityQueryParameters,
+    whatsapp_sender_quality_rating: whatsapp::SenderQualityRating,
+    whatsapp_sender_status: whatsapp::SenderStatus,
+    whatsapp_messaging_limit: whatsapp::MessagingLimit,
+    whatsapp_sender_quality: whatsapp::SenderQuality,
+    whatsapp_sender_quality_response_body: whatsapp::SenderQualityResponseBody,
+    whatsapp_inbound_media: whatsapp::inbound::InboundMedia,
+    whatsapp_inbound_location: whatsapp::inbound::InboundLocation,
+    whatsapp_inbound_contacts: whatsapp::inbound::InboundContacts,
+    whatsapp_inbound_button: whatsapp::inbound::InboundButton,
+    whatsapp_inbound_reply: whatsapp::inbound::InboundReply,
+    whatsapp_inbound_order_content: whatsapp::inbound::InboundOrderContent,
+    whatsapp_inbound_kind: whatsapp::inbound::InboundKind,
+    whatsapp_inbound_context: whatsapp::inbound::InboundContext,
+    whatsapp_inbound_referral: whatsapp::inbound::InboundReferral,
+    whatsapp_inbound_content: whatsapp::inbound::InboundContent,
+    whatsapp_inbound_message: whatsapp::inbound::InboundMessage,
+    whatsapp_inbound_messages_body: whatsapp::inbound::InboundMessagesBody,
+    conversations_keyword: conversations::Keyword,
+    conversations_direction: conversations::Direction,
+    conversations_conversation_message: conversations::ConversationMessage,
+    conversations_conversation: conversations::Conversation,
+    schedule_delivery_time: schedule::DeliveryTime,
+    schedule_delivery_day: schedule::DeliveryDay,
+    schedule_delivery_time_window: schedule::DeliveryTimeWindow,
+    status_status_group: status::StatusGroup,
+    account_api_key_details: account::ApiKeyDetails,
+    generated_sms_sms_language: generated::sms::SmsLanguage,
+    generated_sms_sms_language_configuration: generated::sms::SmsLanguageConfiguration,
+    generated_sms_sms_preview: generated::sms::SmsPreview,
+    generated_sms_sms_preview_request: generated::sms::SmsPreviewRequest,
+    generated_sms_sms_preview_response: generated::sms::SmsPreviewResponse,
+}
@@ -1,6 +1,10 @@
+use std::time::Duration;
+
 use validator::Validate;
 
 use crate::model::sms::*;
+use crate::model::status::StatusGroup;
+use crate::model::QueryParams;
 
 const DUMMY_TEXT: &str = "Dummy text for tests. Some special chars: áéíø";
 
@@ -137,7 +141,45 @@ fn send_request_body_zero_speed_limit_amount() {
     let mut request_body = SendRequestBody::new(vec![message]);
     request_body.sending_speed_limit = Some(SpeedLimit::new(0));
 
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn speed_limit_constructors() {
+    assert_eq!(SpeedLimit::per_hour(10).time_unit, Some(TimeUnit::Hour));
+    assert_eq!(SpeedLimit::per_day(10).sending_minutes(25), 3 * 1440);
+    assert_eq!(SpeedLimit::per_minute(10).sending_minutes(20), 2);
+    assert_eq!(SpeedLimit::new(10).sending_minutes(0), 0);
+}
+
+#[test]
+fn send_request_body_speed_limit_exceeds_validity_period() {
+    let destinations = (0..3)
+        .map(|index| Destination::new(&format!("12345678901{}", index)))
+        .collect();
+    let mut message = Message::new(destinations);
+    message.validity_period = Some(120);
+    let mut request_body = SendRequestBody::new(vec![message]);
+
+    request_body.sending_speed_limit = Some(SpeedLimit::per_hour(2));
     assert!(request_body.validate().is_ok());
+
+    request_body.sending_speed_limit = Some(SpeedLimit::per_hour(1));
+    let errors = request_body.validate().unwrap_err();
+    assert!(errors.errors().contains_key("__all__"));
+}
+
+#[test]
+fn send_binary_request_body_speed_limit_exceeds_validity_period() {
+    let message = BinaryMessage::new(vec![
+        Destination::new("123456789012"),
+        Destination::new("123456789013"),
+        Destination::new("123456789014"),
+    ]);
+    let mut request_body = SendBinaryRequestBody::new(vec![message]);
+    request_body.sending_speed_limit = Some(SpeedLimit::per_day(1));
+
+    assert!(request_body.validate().is_err());
 }
 
 #[test]
@@ -289,6 +331,47 @@ fn send_binary_request_body_empty_hex() {
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn binary_data_from_bytes() {
+    let mut binary_data = BinaryData::from_bytes(&[0x0f, 0xc2, 0x4a], DataCoding::Binary);
+    binary_data.esm_class = Some(EsmClass::UDH_INDICATOR);
+
+    assert_eq!(
+        serde_json::to_value(&binary_data).unwrap(),
+        serde_json::json!({"dataCoding": 4, "esmClass": 64, "hex": "0f c2 4a"})
+    );
+}
+
+#[test]
+fn binary_data_integer_interop() {
+    let binary_data: BinaryData =
+        serde_json::from_str(r#"{"dataCoding": 8, "esmClass": 192, "hex": "0f"}"#).unwrap();
+
+    assert_eq!(binary_data.data_coding, Some(DataCoding::Ucs2));
+    assert_eq!(
+        binary_data.esm_class,
+        Some(EsmClass::UDH_INDICATOR | EsmClass::REPLY_PATH)
+    );
+    assert_eq!(DataCoding::from(245), DataCoding::Other(245));
+    assert_eq!(i32::from(DataCoding::Other(245)), 245);
+}
+
+#[test]
+fn send_flash_messages() {
+    let message = Message::new_flash(vec![Destination::new("41793026727")], DUMMY_TEXT);
+    let binary_message = BinaryMessage::new_flash(
+        vec![Destination::new("41793026727")],
+        BinaryData::new("0f c2 4a bf 34 13 ba"),
+    );
+
+    assert_eq!(message.flash, Some(true));
+    assert!(SendRequestBody::new(vec![message]).validate().is_ok());
+    assert_eq!(binary_message.flash, Some(true));
+    assert!(SendBinaryRequestBody::new(vec![binary_message])
+        .validate()
+        .is_ok());
+}
+
 #[test]
 fn reschedule_request_body_valid() {
     let request_body = RescheduleRequestBody::new("2021-08-25T16:00:00.000+0000");
@@ -334,94 +417,476 @@ fn inbound_reports_query_parameters_big_limit() {
 }
 
 #[test]
-fn create_tfa_application_request_body_valid() {
-    let request_body = CreateTfaApplicationRequestBody::new("some_name");
+fn test_sms_parts_gsm7() {
+    let parts = SmsParts::estimate("Hello world!");
 
-    assert!(request_body.validate().is_ok());
+    assert_eq!(parts.encoding, SmsEncoding::Gsm7);
+    assert_eq!(parts.characters, 12);
+    assert_eq!(parts.parts, 1);
 }
 
 #[test]
-fn create_tfa_application_request_body_empty_name() {
-    let request_body = CreateTfaApplicationRequestBody::new("");
+fn test_sms_parts_gsm7_extended_and_multipart() {
+    assert_eq!(SmsParts::estimate(&"a".repeat(160)).parts, 1);
+    assert_eq!(SmsParts::estimate(&"a".repeat(161)).parts, 2);
+    assert_eq!(SmsParts::estimate(&"a".repeat(306)).parts, 2);
+    assert_eq!(SmsParts::estimate(&"a".repeat(307)).parts, 3);
 
-    assert!(request_body.validate().is_err());
+    let parts = SmsParts::estimate(&"€".repeat(80));
+    assert_eq!(parts.encoding, SmsEncoding::Gsm7);
+    assert_eq!(parts.characters, 160);
+    assert_eq!(parts.parts, 1);
+}
+
+#[test]
+fn test_sms_parts_ucs2() {
+    let parts = SmsParts::estimate("Čestitamo! 🎉");
+
+    assert_eq!(parts.encoding, SmsEncoding::Ucs2);
+    assert_eq!(parts.characters, 13);
+    assert_eq!(parts.parts, 1);
+    assert_eq!(SmsParts::estimate(&"ć".repeat(71)).parts, 2);
+    assert_eq!(SmsParts::estimate("").parts, 0);
+}
+
+#[test]
+fn test_estimate_cost() {
+    let mut long_message = Message::new(vec![Destination::new("385911111111")]);
+    long_message.text = Some("a".repeat(200));
+    let mut short_message = Message::new(vec![
+        Destination::new("+41793026727"),
+        Destination::new("38599000000"),
+        Destination::new("1555000000"),
+    ]);
+    short_message.text = Some("Hello".to_string());
+    let request_body = SendRequestBody::new(vec![long_message, short_message]);
+
+    let price_table = PriceTable::new("EUR")
+        .with_price("385", 0.05)
+        .with_price("38599", 0.1)
+        .with_price("41", 0.07);
+
+    let estimate = request_body.estimate_cost(&price_table);
+
+    assert_eq!(estimate.currency, "EUR");
+    assert_eq!(estimate.messages, 4);
+    assert_eq!(estimate.parts, 5);
+    assert!((estimate.total - 0.27).abs() < 1e-9);
+    assert_eq!(estimate.unpriced_destinations, vec!["1555000000"]);
+    assert!(!estimate.is_complete());
+
+    let estimate = request_body.estimate_cost(&price_table.with_default_price(0.01));
+
+    assert!((estimate.total - 0.28).abs() < 1e-9);
+    assert!(estimate.is_complete());
 }
 
 #[test]
-fn create_tfa_message_template_request_body_valid() {
-    let request_body = CreateTfaMessageTemplateRequestBody::new("some_name", PinType::Alpha, 6);
+fn send_request_body_india_dlt_valid() {
+    let mut message = Message::new(vec![Destination::new("+919876543210")]);
+    message.regional = Some(RegionalOptions::india(IndiaDlt::with_content_template(
+        "1201159178000001234",
+        "1207161234567890123",
+    )));
+    let request_body = SendRequestBody::new(vec![message]);
 
     assert!(request_body.validate().is_ok());
 }
 
 #[test]
-fn create_tfa_message_template_request_body_empty_name() {
-    let request_body = CreateTfaMessageTemplateRequestBody::new("", PinType::Alpha, 6);
+fn send_request_body_india_dlt_non_numeric_template_id() {
+    let mut message = Message::new(vec![Destination::new("919876543210")]);
+    message.regional = Some(RegionalOptions::india(IndiaDlt::with_content_template(
+        "1201159178000001234",
+        "template-1",
+    )));
+    let request_body = SendRequestBody::new(vec![message]);
 
     assert!(request_body.validate().is_err());
 }
 
 #[test]
-fn create_tfa_message_template_request_body_no_principal_entity_id() {
-    let mut request_body = CreateTfaMessageTemplateRequestBody::new("some_name", PinType::Alpha, 6);
-    let regional = TfaRegional {
-        india_dlt: Some(IndiaDlt::new("")),
-    };
-    request_body.regional = Some(regional);
+fn send_request_body_india_dlt_non_indian_destination() {
+    let mut message = Message::new(vec![
+        Destination::new("919876543210"),
+        Destination::new("41793026727"),
+    ]);
+    message.regional = Some(RegionalOptions::india(IndiaDlt::new("1201159178000001234")));
+    let request_body = SendRequestBody::new(vec![message]);
 
     assert!(request_body.validate().is_err());
 }
 
 #[test]
-fn update_tfa_message_template_request_body_no_principal_entity_id() {
-    let mut request_body = UpdateTfaMessageTemplateRequestBody::new("some_name", PinType::Alpha, 6);
-    let regional = TfaRegional {
-        india_dlt: Some(IndiaDlt::new("")),
-    };
-    request_body.regional = Some(regional);
+fn send_request_body_turkey_iys_valid() {
+    let mut message = Message::new(vec![Destination::new("00905321234567")]);
+    message.regional = Some(RegionalOptions::turkey(TurkeyIys::with_recipient_type(
+        TurkeyRecipientType::Bireysel,
+    )));
+    let request_body = SendRequestBody::new(vec![message]);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_binary_request_body_turkey_iys_non_turkish_destination() {
+    let mut message = BinaryMessage::new(vec![Destination::new("41793026727")]);
+    message.binary = Some(BinaryData::new("0f c2 4a bf 34 13 ba"));
+    message.regional = Some(RegionalOptions::turkey(TurkeyIys::with_recipient_type(
+        TurkeyRecipientType::Tacir,
+    )));
+    let request_body = SendBinaryRequestBody::new(vec![message]);
 
     assert!(request_body.validate().is_err());
 }
 
 #[test]
-fn send_pin_over_sms_request_body_valid() {
-    let request_body =
-        SendPinOverSmsRequestBody::new("some-app-id", "some-message-id", "555555555555");
+fn turkey_iys_parsed_recipient_type() {
+    assert_eq!(
+        TurkeyIys::new("TACIR").parsed_recipient_type(),
+        Some(TurkeyRecipientType::Tacir)
+    );
+    assert_eq!(TurkeyIys::new("BAD").parsed_recipient_type(), None);
+}
+
+#[test]
+fn entity_principal_registry_apply() {
+    let registry = EntityPrincipalRegistry::new().with_sender("InfoSMS", "1201159178000001234");
+
+    let mut registered = Message::new(vec![Destination::new("919876543210")]);
+    registered.from = Some("InfoSMS".to_string());
+    let mut unregistered = Message::new(vec![Destination::new("919876543210")]);
+    unregistered.from = Some("OtherSMS".to_string());
+    let mut not_india = Message::new(vec![Destination::new("41793026727")]);
+    not_india.from = Some("InfoSMS".to_string());
+    let mut request_body = SendRequestBody::new(vec![registered, unregistered, not_india]);
+
+    let missing = registry.apply(&mut request_body);
 
+    assert_eq!(missing, vec![1]);
+    assert_eq!(
+        request_body.messages[0].regional,
+        Some(RegionalOptions::india(IndiaDlt::new("1201159178000001234")))
+    );
+    assert!(request_body.messages[2].regional.is_none());
+}
+
+#[test]
+fn message_schedule_at_valid() {
+    let send_at = chrono::Utc::now() + chrono::Duration::days(1);
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.schedule_at(send_at);
+    let request_body = SendRequestBody::new(vec![message]);
+
+    let expected = send_at.format("%Y-%m-%dT%H:%M:%S%.3f+0000").to_string();
+    assert_eq!(
+        request_body.messages[0].send_at.as_deref(),
+        Some(&*expected)
+    );
     assert!(request_body.validate().is_ok());
 }
 
 #[test]
-fn send_pin_over_sms_request_body_empty_app_id() {
-    let request_body = SendPinOverSmsRequestBody::new("", "some-message-id", "555555555555");
+fn message_schedule_at_past() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.schedule_at(chrono::Utc::now() - chrono::Duration::minutes(1));
+    let request_body = SendRequestBody::new(vec![message]);
 
-    assert!(request_body.validate().is_err());
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("send_at_not_in_future"));
 }
 
 #[test]
-fn send_pin_over_sms_request_body_empty_message_id() {
-    let request_body = SendPinOverSmsRequestBody::new("some-app-id", "", "555555555555");
+fn message_schedule_at_too_far() {
+    let mut message = BinaryMessage::new(vec![Destination::new("41793026727")]);
+    message.binary = Some(BinaryData::new("0f c2 4a bf 34 13 ba"));
+    message.schedule_at(chrono::Utc::now() + chrono::Duration::days(MAX_SCHEDULE_DAYS + 1));
+    let request_body = SendBinaryRequestBody::new(vec![message]);
 
-    assert!(request_body.validate().is_err());
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("send_at_too_far_in_future"));
 }
 
 #[test]
-fn send_pin_over_sms_request_body_empty_to() {
-    let request_body = SendPinOverSmsRequestBody::new("some-app-id", "some-message-id", "");
+fn message_apply_schedule() {
+    let mut window = DeliveryTimeWindow::new(vec![DeliveryDay::Monday]);
+    window.from = Some(DeliveryTime::new(9, 0));
+    window.to = Some(DeliveryTime::new(17, 0));
+    let schedule = crate::model::schedule::Schedule::new()
+        .send_at(chrono::Utc::now() + chrono::Duration::days(1))
+        .within(window)
+        .in_time_zone(chrono::FixedOffset::east_opt(3600).unwrap());
 
-    assert!(request_body.validate().is_err());
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.apply_schedule(&schedule).unwrap();
+
+    let window = message.delivery_time_window.as_ref().unwrap();
+    assert_eq!(window.from, Some(DeliveryTime::new(8, 0)));
+    assert_eq!(window.to, Some(DeliveryTime::new(16, 0)));
+    assert_eq!(message.send_at, schedule.send_at_formatted());
+    assert!(SendRequestBody::new(vec![message]).validate().is_ok());
 }
 
 #[test]
-fn verify_phone_number_request_body_valid() {
-    let request_body = VerifyPhoneNumberRequestBody::new("1234");
+fn message_validity_rounds_up_to_minutes() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.text = Some(DUMMY_TEXT.to_string());
 
-    assert!(request_body.validate().is_ok());
+    message.validity(Duration::from_secs(90));
+    assert_eq!(message.validity_period, Some(2));
+
+    message.validity(Duration::from_secs(48 * 60 * 60));
+    assert_eq!(message.validity_period, Some(MAX_VALIDITY_PERIOD_MINUTES));
+    assert!(SendRequestBody::new(vec![message]).validate().is_ok());
 }
 
 #[test]
-fn verify_phone_number_request_body_empty_pin() {
-    let request_body = VerifyPhoneNumberRequestBody::new("");
+fn message_validity_too_long() {
+    let mut message = BinaryMessage::new(vec![Destination::new("41793026727")]);
+    message.binary = Some(BinaryData::new("0f c2 4a bf 34 13 ba"));
+    message.validity(Duration::from_secs(48 * 60 * 60 + 1));
+    let request_body = SendBinaryRequestBody::new(vec![message]);
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("validity_period"));
+}
+
+#[test]
+fn message_send_at_invalid_format() {
+    let mut message = Message::new(vec![Destination::new("41793026727")]);
+    message.send_at = Some("tomorrow".to_string());
+    let request_body = SendRequestBody::new(vec![message]);
 
     assert!(request_body.validate().is_err());
 }
+
+#[test]
+fn send_response_partition_by_status() {
+    let response: SendResponseBody = serde_json::from_str(
+        r#"
+        {
+          "bulkId": "some-bulk-id",
+          "messages": [
+            {
+              "messageId": "accepted-id",
+              "status": { "groupId": 1, "groupName": "PENDING", "id": 26, "name": "PENDING_ACCEPTED" },
+              "to": "41793026727"
+            },
+            {
+              "messageId": "rejected-id",
+              "status": { "groupId": 5, "groupName": "REJECTED", "id": 51, "name": "REJECTED_DESTINATION" },
+              "to": "41793026834"
+            }
+          ]
+        }
+        "#,
+    )
+    .unwrap();
+
+    let result = response.partition_by_status();
+
+    assert_eq!(result.accepted.len(), 1);
+    assert_eq!(
+        result.accepted[0].message_id.as_deref(),
+        Some("accepted-id")
+    );
+    assert_eq!(result.rejected.len(), 1);
+    assert_eq!(result.rejected[0].0, StatusGroup::Rejected);
+    assert_eq!(result.rejected[0].1.to.as_deref(), Some("41793026834"));
+    assert!(result.unknown.is_empty());
+    assert!(!response.all_accepted());
+    assert_eq!(
+        response.rejected_messages()[0].1.message_id.as_deref(),
+        Some("rejected-id")
+    );
+}
+
+#[test]
+fn send_response_all_accepted() {
+    let response: SendResponseBody = serde_json::from_str(
+        r#"{"messages": [{"messageId": "some-id", "status": {"groupId": 1, "groupName": "PENDING"}}]}"#,
+    )
+    .unwrap();
+    let without_status: SendResponseBody =
+        serde_json::from_str(r#"{"messages": [{"messageId": "some-id"}]}"#).unwrap();
+
+    assert!(response.all_accepted());
+    assert!(response.rejected_messages().is_empty());
+    assert!(!without_status.all_accepted());
+    assert!(without_status.rejected_messages().is_empty());
+}
+
+#[test]
+fn delivery_report_status_group() {
+    let report: Report = serde_json::from_str(
+        r#"{"messageId": "some-id", "status": {"groupId": 3, "groupName": "DELIVERED", "id": 5}}"#,
+    )
+    .unwrap();
+    let log: Log = serde_json::from_str(r#"{"status": {"groupName": "EXPIRED"}}"#).unwrap();
+
+    assert_eq!(report.status_group(), Some(StatusGroup::Delivered));
+    assert_eq!(log.status_group(), Some(StatusGroup::Expired));
+    assert_eq!(Report::default().status_group(), None);
+}
+
+#[test]
+fn send_request_generate_ids() {
+    let mut destination = Destination::new("41793026834");
+    destination.message_id = Some("existing-message-id".to_string());
+    let message = Message::new(vec![Destination::new("41793026727"), destination]);
+    let mut request_body = SendRequestBody::new(vec![message]);
+
+    let ids = request_body.generate_ids();
+
+    assert_eq!(request_body.bulk_id.as_deref(), Some(ids.bulk_id.as_str()));
+    assert_eq!(ids.message_ids.len(), 2);
+    assert_eq!(ids.message_ids[1], "existing-message-id");
+    assert_eq!(
+        request_body.messages[0].destinations.as_ref().unwrap()[0].message_id,
+        Some(ids.message_ids[0].clone())
+    );
+    assert_eq!(request_body.generate_ids(), ids);
+}
+
+#[test]
+fn message_try_from_value() {
+    let message = Message::try_from(serde_json::json!({
+        "destinations": [{"to": "41793026727"}],
+        "text": DUMMY_TEXT,
+    }))
+    .unwrap();
+
+    assert_eq!(message.text.as_deref(), Some(DUMMY_TEXT));
+    assert_eq!(
+        message.destinations.unwrap()[0].to,
+        "41793026727".to_string()
+    );
+}
+
+#[test]
+fn message_try_from_value_invalid() {
+    let error = Message::try_from(serde_json::json!({"destinations": []})).unwrap_err();
+    assert!(matches!(error, crate::api::SdkError::Validation(_)));
+
+    let error = Message::try_from(serde_json::json!({"destinations": "41793026727"})).unwrap_err();
+    assert!(matches!(error, crate::api::SdkError::Serde(_)));
+}
+
+#[test]
+fn scheduled_status_unknown_value_deserializes() {
+    let response_body: ScheduledStatusResponseBody =
+        serde_json::from_str(r#"{"bulkId": "some-bulk-id", "status": "SOME_NEW_STATUS"}"#).unwrap();
+
+    assert_eq!(response_body.status, Some(ScheduledStatus::Unknown));
+}
+
+#[test]
+fn logs_query_map_uses_camel_case_names() {
+    let mut query_parameters = LogsQueryParameters::new();
+    query_parameters.bulk_id = Some("some-bulk-id".to_string());
+    query_parameters.general_status = Some("DELIVERED".to_string());
+    query_parameters.limit = Some(10);
+
+    let query_map = query_parameters.to_query_map().unwrap();
+
+    assert_eq!(query_map.len(), 3);
+    assert_eq!(query_map["bulkId"], "some-bulk-id");
+    assert_eq!(query_map["generalStatus"], "DELIVERED");
+    assert_eq!(query_map["limit"], "10");
+}
+
+#[test]
+fn send_over_query_parameters_query_map() {
+    let mut query_parameters = SendOverQueryParametersQueryParameters::new(
+        "some-user",
+        "some-password",
+        vec!["41793026727".to_string(), "41793026834".to_string()],
+    );
+    query_parameters.flash = Some(true);
+    query_parameters.india_dlt_content_template_id = Some("1111".to_string());
+
+    let query_map = query_parameters.to_query_map().unwrap();
+
+    assert_eq!(query_map["to"], "41793026727,41793026834");
+    assert_eq!(query_map["flash"], "true");
+    assert_eq!(query_map["indiaDltContentTemplateId"], "1111");
+    assert!(!query_map.contains_key("bulkId"));
+    assert!(!query_map.contains_key("validityPeriod"));
+}
+
+#[test]
+fn send_over_query_parameters_debug_redacts_password() {
+    let query_parameters = SendOverQueryParametersQueryParameters::new(
+        "some-user",
+        "some-password",
+        vec!["41793026727".to_string()],
+    );
+
+    let debug = format!("{:?}", query_parameters);
+
+    assert!(debug.contains(r#"username: "some-user", password: "***redacted***""#));
+    assert!(!debug.contains("some-password"));
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn report_keeps_unmodeled_fields() {
+    let report: Report =
+        serde_json::from_str(r#"{"messageId": "some-message-id", "someNewField": {"value": 42}}"#)
+            .unwrap();
+
+    assert_eq!(report.message_id.as_deref(), Some("some-message-id"));
+    assert_eq!(
+        report.extra.get("someNewField"),
+        Some(&serde_json::json!({"value": 42}))
+    );
+    assert_eq!(
+        serde_json::to_value(&report).unwrap()["someNewField"]["value"],
+        42
+    );
+}
+
+#[test]
+fn turkey_recipient_type_parses_its_code() {
+    assert_eq!("TACIR".parse(), Ok(TurkeyRecipientType::Tacir));
+    assert_eq!("BIREYSEL".parse(), Ok(TurkeyRecipientType::Bireysel));
+    assert!("tacir".parse::<TurkeyRecipientType>().is_err());
+}
+
+// The curated preview models must keep every field of the generated ones, or adopt new fields
+// when the definition changes.
+#[test]
+fn sms_preview_models_cover_generated_fields() {
+    use crate::model::generated::sms::*;
+
+    let generated = SmsPreviewResponse {
+        original_text: Some(DUMMY_TEXT.to_string()),
+        previews: Some(vec![SmsPreview {
+            characters_remaining: Some(120),
+            configuration: Some(SmsLanguageConfiguration {
+                language: Some(SmsLanguage {
+                    language_code: Some("TR".to_string()),
+                }),
+                transliteration: Some("TURKISH".to_string()),
+            }),
+            message_count: Some(1),
+            text_preview: Some(DUMMY_TEXT.to_string()),
+        }]),
+    };
+    let generated_json = serde_json::to_value(&generated).unwrap();
+
+    let curated: PreviewResponseBody = serde_json::from_value(generated_json.clone()).unwrap();
+    #[cfg(feature = "extra-fields")]
+    assert!(curated.extra.is_empty());
+    assert_eq!(serde_json::to_value(&curated).unwrap(), generated_json);
+
+    let generated = SmsPreviewRequest {
+        language_code: Some("TR".to_string()),
+        text: DUMMY_TEXT.to_string(),
+        transliteration: Some("TURKISH".to_string()),
+    };
+    let generated_json = serde_json::to_value(&generated).unwrap();
+
+    let curated: PreviewRequestBody = serde_json::from_value(generated_json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&curated).unwrap(), generated_json);
+}
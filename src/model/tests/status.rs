@@ -0,0 +1,71 @@
+use crate::model::status::*;
+
+#[test]
+fn status_group_from_id_and_name() {
+    for id in 0..6 {
+        let group = StatusGroup::from_id(id).unwrap();
+        let name = serde_json::to_value(group).unwrap();
+
+        assert_eq!(group.id(), id);
+        assert_eq!(StatusGroup::from_name(name.as_str().unwrap()), Some(group));
+    }
+
+    assert_eq!(StatusGroup::from_id(6), None);
+    assert_eq!(StatusGroup::from_name("UNKNOWN"), None);
+}
+
+#[test]
+fn status_group_resolve_prefers_id() {
+    assert_eq!(
+        StatusGroup::resolve(Some(3), Some("PENDING")),
+        Some(StatusGroup::Delivered)
+    );
+    assert_eq!(
+        StatusGroup::resolve(None, Some("REJECTED")),
+        Some(StatusGroup::Rejected)
+    );
+    assert_eq!(
+        StatusGroup::resolve(Some(42), Some("EXPIRED")),
+        Some(StatusGroup::Expired)
+    );
+    assert_eq!(StatusGroup::resolve(None, None), None);
+}
+
+#[test]
+fn send_result_partition() {
+    let messages = [Some(1), Some(5), None, Some(0), Some(2)];
+
+    let result = SendResult::partition(&messages, |id| id.and_then(StatusGroup::from_id));
+
+    assert_eq!(result.accepted, vec![&Some(1), &Some(0)]);
+    assert_eq!(
+        result.rejected,
+        vec![
+            (StatusGroup::Rejected, &Some(5)),
+            (StatusGroup::Undeliverable, &Some(2))
+        ]
+    );
+    assert_eq!(result.unknown, vec![&None]);
+    assert!(!result.all_accepted());
+}
+
+#[test]
+fn status_group_display_and_final() {
+    assert_eq!(StatusGroup::Undeliverable.to_string(), "UNDELIVERABLE");
+    assert!(StatusGroup::Delivered.is_final());
+    assert!(StatusGroup::Expired.is_final());
+    assert!(!StatusGroup::Pending.is_final());
+    assert!(!StatusGroup::Accepted.is_final());
+}
+
+#[test]
+fn status_group_parses_its_name() {
+    assert_eq!("DELIVERED".parse(), Ok(StatusGroup::Delivered));
+    assert_eq!(
+        "DELIVERED_TO_HANDSET"
+            .parse::<StatusGroup>()
+            .unwrap_err()
+            .to_string(),
+        "unknown status group `DELIVERED_TO_HANDSET`"
+    );
+}
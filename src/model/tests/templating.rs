@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use crate::model::templating::*;
+
+fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn template_render() {
+    let template = Template::new("Hi {{firstName}}, your order {{order_1}} shipped. {{ kept }}");
+
+    let rendered = template
+        .render(&values(&[("firstName", "Jane"), ("order_1", "A-1")]))
+        .unwrap();
+
+    assert_eq!(rendered, "Hi Jane, your order A-1 shipped. {{ kept }}");
+}
+
+#[test]
+fn template_placeholders() {
+    let template = Template::new("{{b}} {{a}} {{b}} {{not valid}}");
+
+    assert_eq!(template.placeholders(), vec!["b", "a"]);
+}
+
+#[test]
+fn template_render_missing_value() {
+    let template = Template::new("Hi {{firstName}} {{lastName}}");
+
+    let error = template
+        .render(&values(&[("firstName", "Jane")]))
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        TemplateError::MissingValue {
+            placeholder: "lastName".to_string(),
+            recipient: 0
+        }
+    );
+}
+
+#[test]
+fn render_for_destinations_valid() {
+    let recipients = vec![
+        values(&[("to", "41793026727"), ("code", "1234")]),
+        values(&[("to", "41793026834"), ("code", "5678")]),
+    ];
+
+    let messages = render_for_destinations("{{code}} is your code for {{to}}", recipients).unwrap();
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(
+        messages[1].text.as_deref(),
+        Some("5678 is your code for 41793026834")
+    );
+    assert_eq!(
+        messages[1].destinations.as_ref().unwrap()[0].to,
+        "41793026834"
+    );
+}
+
+#[test]
+fn render_for_destinations_missing_destination() {
+    let recipients = vec![
+        values(&[("to", "41793026727"), ("code", "1234")]),
+        values(&[("code", "5678")]),
+    ];
+
+    let error = render_for_destinations("{{code}}", recipients).unwrap_err();
+
+    assert_eq!(error, TemplateError::MissingDestination { recipient: 1 });
+}
+
+#[test]
+fn render_emails_valid() {
+    let recipients = vec![values(&[("to", "jane@example.com"), ("name", "Jane")])];
+
+    let request_bodies = render_emails("Dear {{name}},", recipients).unwrap();
+
+    assert_eq!(request_bodies[0].to, "jane@example.com");
+    assert_eq!(request_bodies[0].text.as_deref(), Some("Dear Jane,"));
+}
@@ -0,0 +1,437 @@
+use validator::Validate;
+
+use crate::model::tfa::*;
+use crate::model::PlatformIds;
+
+#[test]
+fn create_tfa_application_request_body_valid() {
+    let request_body = CreateTfaApplicationRequestBody::new("some_name");
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn create_tfa_application_request_body_empty_name() {
+    let request_body = CreateTfaApplicationRequestBody::new("");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn create_tfa_message_template_request_body_valid() {
+    let request_body = CreateTfaMessageTemplateRequestBody::new("some_name", PinType::Alpha, 6);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn create_tfa_message_template_request_body_empty_name() {
+    let request_body = CreateTfaMessageTemplateRequestBody::new("", PinType::Alpha, 6);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn create_tfa_message_template_request_body_no_principal_entity_id() {
+    let mut request_body = CreateTfaMessageTemplateRequestBody::new("some_name", PinType::Alpha, 6);
+    let regional = TfaRegional {
+        india_dlt: Some(IndiaDlt::new("")),
+    };
+    request_body.regional = Some(regional);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn update_tfa_message_template_request_body_no_principal_entity_id() {
+    let mut request_body = UpdateTfaMessageTemplateRequestBody::new("some_name", PinType::Alpha, 6);
+    let regional = TfaRegional {
+        india_dlt: Some(IndiaDlt::new("")),
+    };
+    request_body.regional = Some(regional);
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_pin_over_sms_request_body_valid() {
+    let request_body =
+        SendPinOverSmsRequestBody::new("some-app-id", "some-message-id", "555555555555");
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_pin_over_sms_request_body_platform_ids() {
+    let mut request_body =
+        SendPinOverSmsRequestBody::new("some-app-id", "some-message-id", "555555555555");
+    assert!(serde_json::to_value(&request_body)
+        .unwrap()
+        .get("entityId")
+        .is_none());
+
+    request_body.fill_platform_ids(Some("platform-app-id"), Some("some-entity-id"));
+    assert_eq!(request_body.application_id, "some-app-id");
+    assert_eq!(request_body.entity_id.as_deref(), Some("some-entity-id"));
+
+    request_body.fill_platform_ids(None, Some("other-entity-id"));
+    assert_eq!(request_body.entity_id.as_deref(), Some("some-entity-id"));
+}
+
+#[test]
+fn send_pin_over_sms_request_body_empty_app_id() {
+    let request_body = SendPinOverSmsRequestBody::new("", "some-message-id", "555555555555");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_pin_over_sms_request_body_empty_message_id() {
+    let request_body = SendPinOverSmsRequestBody::new("some-app-id", "", "555555555555");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_pin_over_sms_request_body_empty_to() {
+    let request_body = SendPinOverSmsRequestBody::new("some-app-id", "some-message-id", "");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_pin_over_email_request_body_valid() {
+    let request_body = SendPinOverEmailRequestBody::new(
+        "some-app-id",
+        "some-message-id",
+        "company@example.com",
+        "john.smith@example.com",
+    );
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_pin_over_email_request_body_invalid_to() {
+    let request_body = SendPinOverEmailRequestBody::new(
+        "some-app-id",
+        "some-message-id",
+        "company@example.com",
+        "not-an-email",
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn verify_phone_number_request_body_valid() {
+    let request_body = VerifyPhoneNumberRequestBody::new("1234");
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn verify_phone_number_request_body_empty_pin() {
+    let request_body = VerifyPhoneNumberRequestBody::new("");
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn id_types_conversions() {
+    let application_id = AppId::from("some-app-id");
+    let template_id = TemplateId::new(String::from("some-template-id"));
+
+    assert_eq!(application_id.as_str(), "some-app-id");
+    assert_eq!(template_id.to_string(), "some-template-id");
+    assert_eq!(String::from(PinId::from("some-pin-id")), "some-pin-id");
+    assert_eq!(
+        serde_json::to_string(&application_id).unwrap(),
+        r#""some-app-id""#
+    );
+}
+
+#[test]
+fn send_pin_response_body_pin_statuses() {
+    let response_body: SendPinResponseBody = serde_json::from_str(
+        r#"{"pinId": "some-pin-id", "smsStatus": "MESSAGE_SENT", "callStatus": "SOME_NEW_STATUS"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(response_body.sms_pin_status(), Some(PinStatus::MessageSent));
+    assert_eq!(
+        response_body.call_pin_status(),
+        Some(PinStatus::Other("SOME_NEW_STATUS".to_string()))
+    );
+    assert!(!PinStatus::from("MESSAGE_NOT_SENT").is_sent());
+    assert_eq!(PinStatus::PendingAccepted.to_string(), "PENDING_ACCEPTED");
+}
+
+#[test]
+fn verify_phone_number_response_body_outcome() {
+    let outcome = |json: &str| {
+        serde_json::from_str::<VerifyPhoneNumberResponseBody>(json)
+            .unwrap()
+            .outcome()
+    };
+
+    assert_eq!(
+        outcome(r#"{"verified": true, "attemptsRemaining": 0}"#),
+        VerifyOutcome::Verified
+    );
+    assert_eq!(
+        outcome(r#"{"verified": false, "pinError": "WRONG_PIN", "attemptsRemaining": 2}"#),
+        VerifyOutcome::WrongPin {
+            attempts_remaining: 2
+        }
+    );
+    assert_eq!(
+        outcome(r#"{"verified": false, "pinError": "TTL_EXPIRED"}"#),
+        VerifyOutcome::Expired
+    );
+    assert_eq!(
+        outcome(r#"{"verified": false, "pinError": "NO_MORE_PIN_ATTEMPTS"}"#),
+        VerifyOutcome::AttemptsExhausted
+    );
+    assert_eq!(
+        outcome(r#"{"verified": false}"#),
+        VerifyOutcome::Failed { pin_error: None }
+    );
+}
+
+#[test]
+fn message_template_unknown_values_deserialize() {
+    let template: TfaMessageTemplate = serde_json::from_str(
+        r#"{"messageText": "Your PIN is {{pin}}", "pinLength": 4, "pinType": "SOME_NEW_TYPE", "language": "xx"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(template.pin_type, PinType::Unknown);
+    assert_eq!(template.language, Some(TfaLanguage::Unknown));
+}
+
+#[test]
+fn time_to_live_parses_and_displays() {
+    assert_eq!("10m".parse(), Ok(TimeToLive::minutes(10)));
+    assert_eq!(
+        "250ms".parse(),
+        Ok(TimeToLive::new(250, TfaTimeUnit::Milliseconds))
+    );
+    assert_eq!("h".parse(), Ok(TimeToLive::hours(1)));
+    assert_eq!(TimeToLive::days(2).to_string(), "2d");
+    assert_eq!(
+        TimeToLive::minutes(10).as_duration(),
+        std::time::Duration::from_secs(600)
+    );
+
+    for invalid in ["", "10", "10 m", "10w", "-1m", "1.5h"] {
+        assert_eq!(
+            invalid.parse::<TimeToLive>(),
+            Err(TfaValueError::InvalidTimeToLive(invalid.to_string()))
+        );
+    }
+}
+
+#[test]
+fn rate_limit_parses_and_displays() {
+    assert_eq!("3/1d".parse(), Ok(RateLimit::new(3, TimeToLive::days(1))));
+    assert_eq!("1/3s".parse::<RateLimit>().unwrap().to_string(), "1/3s");
+    assert_eq!(
+        "10000/d".parse::<RateLimit>().unwrap().to_string(),
+        "10000/1d"
+    );
+
+    for invalid in ["", "3", "/1d", "3/", "3/1w", "3d"] {
+        assert!(invalid.parse::<RateLimit>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn application_configuration_serializes_formats() {
+    let configuration: TfaApplicationConfiguration = serde_json::from_str(
+        r#"{"pinTimeToLive": "2h", "verifyPinLimit": "1/3s", "sendPinPerPhoneNumberLimit": "3/d"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(configuration.pin_time_to_live, Some(TimeToLive::hours(2)));
+    assert_eq!(
+        serde_json::to_value(&configuration).unwrap(),
+        serde_json::json!({
+            "pinTimeToLive": "2h",
+            "verifyPinLimit": "1/3s",
+            "sendPinPerPhoneNumberLimit": "3/1d"
+        })
+    );
+    assert!(
+        serde_json::from_str::<TfaApplicationConfiguration>(r#"{"pinTimeToLive": "2 hours"}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn application_configuration_limits_validated() {
+    let mut request_body = CreateTfaApplicationRequestBody::new("some_name");
+    request_body.configuration = Some(TfaApplicationConfiguration {
+        verify_pin_limit: Some(RateLimit::new(3, TimeToLive::hours(25))),
+        ..Default::default()
+    });
+    assert!(request_body.validate().is_err());
+
+    request_body.configuration = Some(TfaApplicationConfiguration {
+        pin_time_to_live: Some(TimeToLive::days(366)),
+        ..Default::default()
+    });
+    assert!(request_body.validate().is_err());
+
+    request_body.configuration = Some(TfaApplicationConfiguration {
+        send_pin_per_application_limit: Some(RateLimit::new(0, TimeToLive::days(1))),
+        ..Default::default()
+    });
+    assert!(request_body.validate().is_err());
+
+    request_body.configuration = Some(TfaApplicationConfiguration {
+        pin_time_to_live: Some(TimeToLive::minutes(10)),
+        verify_pin_limit: Some(RateLimit::new(1, TimeToLive::days(1))),
+        send_pin_per_phone_number_limit: Some(RateLimit::new(3, TimeToLive::days(365))),
+        ..Default::default()
+    });
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn speech_rate_range() {
+    assert_eq!(SpeechRate::new(1.5).unwrap().value(), 1.5);
+    assert_eq!(
+        SpeechRate::new(2.5),
+        Err(TfaValueError::SpeechRateOutOfRange(2.5))
+    );
+    assert!(SpeechRate::new(f64::NAN).is_err());
+    assert_eq!("0.5".parse(), SpeechRate::new(0.5));
+    assert!(matches!(
+        "fast".parse::<SpeechRate>(),
+        Err(TfaValueError::InvalidSpeechRate(_))
+    ));
+}
+
+#[test]
+fn deserialized_speech_rate_validated() {
+    let mut request_body = CreateTfaMessageTemplateRequestBody::new("{{pin}}", PinType::Numeric, 4);
+    request_body.speech_rate = Some(SpeechRate::NORMAL);
+    assert!(request_body.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&request_body).unwrap()["speechRate"],
+        1.0
+    );
+
+    let request_body = CreateTfaMessageTemplateRequestBody::try_from(serde_json::json!({
+        "messageText": "{{pin}}",
+        "pinLength": 4,
+        "pinType": "NUMERIC",
+        "speechRate": 3
+    }));
+    assert!(request_body.is_err());
+}
+
+#[test]
+fn unknown_pin_type_not_accepted_in_request() {
+    let error = CreateTfaMessageTemplateRequestBody::try_from(serde_json::json!({
+        "messageText": "{{pin}}",
+        "pinLength": 4,
+        "pinType": "NUMERICC"
+    }))
+    .unwrap_err();
+    assert!(matches!(error, crate::api::SdkError::Validation(_)));
+
+    let mut request_body = CreateTfaMessageTemplateRequestBody::new("{{pin}}", PinType::Numeric, 4);
+    request_body.language = Some(TfaLanguage::Unknown);
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn voice_message_template_options() {
+    let mut request_body = CreateTfaMessageTemplateRequestBody::new(
+        "Your PIN is {{pin}}, press 1# to repeat.",
+        PinType::Numeric,
+        4,
+    );
+    request_body.repeat_dtmf = Some("1#".to_string());
+    request_body.voice_name = Some("Joanna".to_string());
+    assert!(request_body.validate().is_ok());
+
+    let value = serde_json::to_value(&request_body).unwrap();
+    assert_eq!(value["repeatDTMF"], "1#");
+    assert_eq!(value["voiceName"], "Joanna");
+
+    request_body.repeat_dtmf = Some("one".to_string());
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn voice_delivery_reports() {
+    let reports: VoiceDeliveryReports = serde_json::from_str(
+        r#"{
+          "results": [
+            {
+              "bulkId": "BULK-ID-123-xyz",
+              "messageId": "MESSAGE-ID-123-xyz",
+              "to": "41793026727",
+              "from": "41793026700",
+              "sentAt": "2024-03-14T11:18:10.131+0000",
+              "doneAt": "2024-03-14T11:18:32.553+0000",
+              "duration": 19,
+              "mccMnc": "22801",
+              "voiceCall": {
+                "feature": "Text-to-Speech",
+                "startTime": "2024-03-14T11:18:10.131+0000",
+                "answerTime": "2024-03-14T11:18:13.210+0000",
+                "endTime": "2024-03-14T11:18:32.553+0000",
+                "duration": 19,
+                "chargedDuration": 30,
+                "fileDuration": 8.4,
+                "dtmfCodes": "1#, 1#"
+              },
+              "price": {"pricePerSecond": 0.01, "currency": "EUR"},
+              "status": {"groupId": 3, "groupName": "DELIVERED", "id": 5, "name": "DELIVERED_TO_HANDSET"},
+              "error": {"groupId": 0, "groupName": "OK", "id": 5000, "name": "VOICE_ANSWERED", "permanent": true}
+            },
+            {
+              "messageId": "MESSAGE-ID-456-xyz",
+              "to": "41793026834",
+              "voiceCall": {"feature": "Text-to-Speech", "duration": 0},
+              "status": {"groupId": 5, "groupName": "REJECTED", "id": 12, "name": "REJECTED_NOT_ENOUGH_CREDITS"},
+              "error": {"groupId": 2, "groupName": "USER_ERRORS", "id": 5003, "name": "EC_NO_ANSWER", "permanent": true}
+            }
+          ]
+        }"#,
+    )
+    .unwrap();
+    let results = reports.results.unwrap();
+
+    let answered = &results[0];
+    assert!(answered.is_answered());
+    assert_eq!(
+        answered.voice_call.as_ref().unwrap().dtmf_inputs(),
+        vec!["1#", "1#"]
+    );
+    assert_eq!(
+        answered.status.as_ref().unwrap().group(),
+        Some(crate::model::status::StatusGroup::Delivered)
+    );
+    assert_eq!(
+        answered.price.as_ref().unwrap().price_per_second,
+        Some(0.01)
+    );
+
+    let unanswered = &results[1];
+    assert!(!unanswered.is_answered());
+    assert!(unanswered
+        .voice_call
+        .as_ref()
+        .unwrap()
+        .dtmf_inputs()
+        .is_empty());
+    assert_eq!(
+        unanswered.error.as_ref().unwrap().name.as_deref(),
+        Some("EC_NO_ANSWER")
+    );
+}
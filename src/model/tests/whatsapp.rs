@@ -1,5 +1,7 @@
 use validator::Validate;
 
+use crate::model::status::StatusGroup;
+use crate::model::whatsapp::inbound::*;
 use crate::model::whatsapp::*;
 
 fn dummy_send_template_request_body() -> SendTemplateRequestBody {
@@ -13,11 +15,13 @@ fn dummy_send_template_request_body() -> SendTemplateRequestBody {
             filename: "file.txt".to_string(),
         }),
         buttons: Some(vec![TemplateButtonContent::new_url("https://some.url")]),
+        carousel: None,
     };
     let content = TemplateContent {
         template_name: "template_name1".to_string(),
         template_data,
         language: TemplateLanguage::EnUs.to_string(),
+        ..Default::default()
     };
     let message = FailoverMessage {
         from: "444444444444".to_string(),
@@ -30,6 +34,8 @@ fn dummy_send_template_request_body() -> SendTemplateRequestBody {
             from: "666666666666".to_string(),
             text: "message text".to_string(),
         }),
+        entity_id: None,
+        application_id: None,
     };
 
     SendTemplateRequestBody {
@@ -49,6 +55,8 @@ fn dummy_send_text_request_body() -> SendTextRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -64,6 +72,8 @@ fn dummy_send_document_request_body() -> SendDocumentRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -78,6 +88,8 @@ fn dummy_send_image_request_body() -> SendImageRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -91,6 +103,8 @@ fn dummy_send_audio_request_body() -> SendAudioRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -105,6 +119,8 @@ fn dummy_send_video_request_body() -> SendVideoRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -118,6 +134,8 @@ fn dummy_send_sticker_request_body() -> SendStickerRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -134,6 +152,8 @@ fn dummy_send_location_request_body() -> SendLocationRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -199,6 +219,8 @@ fn dummy_send_contact_request_body() -> SendContactRequestBody {
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -227,6 +249,8 @@ fn dummy_send_interactive_buttons_request_body() -> SendInteractiveButtonsReques
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -261,6 +285,8 @@ fn dummy_send_interactive_list_request_body() -> SendInteractiveListRequestBody
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -283,6 +309,8 @@ fn dummy_send_interactive_product_request_body() -> SendInteractiveProductReques
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -312,6 +340,8 @@ fn dummy_send_interactive_multiproduct_request_body() -> SendInteractiveMultipro
         },
         callback_data: Some("callback_data".to_string()),
         notify_url: Some("https://some.url".to_string()),
+        entity_id: None,
+        application_id: None,
     }
 }
 
@@ -335,6 +365,7 @@ fn dummy_create_template_request_body() -> CreateTemplateRequestBody {
             buttons: Some(vec![TemplateButton::QuickReply {
                 text: "reply text".to_string(),
             }]),
+            carousel: None,
             template_type: Some(TemplateType::Text),
         },
     }
@@ -883,6 +914,18 @@ fn send_contact_request_content_no_contacts() {
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn send_contact_request_invalid_email() {
+    let mut request_body = dummy_send_contact_request_body();
+
+    request_body.content.contacts[0].emails = Some(vec![ContactEmail {
+        email: Some("not-an-email".to_string()),
+        email_type: None,
+    }]);
+
+    assert!(request_body.validate().is_err());
+}
+
 #[test]
 fn send_interactive_buttons_request_body_valid() {
     let button = InteractiveButton::new_reply_button("1", "Button Title");
@@ -1111,6 +1154,182 @@ fn send_interactive_list_request_content_long_footer_text() {
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn send_interactive_buttons_request_content_long_button_title() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![InteractiveButton::new_reply_button(
+        "id1",
+        &"t".repeat(21usize),
+    )];
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("button 1: title must have 1 to 20 characters, has 21"));
+}
+
+#[test]
+fn send_interactive_buttons_request_content_long_button_id() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![InteractiveButton::new_reply_button(
+        &"i".repeat(257usize),
+        "title",
+    )];
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_buttons_request_content_button_title_emoji() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![
+        InteractiveButton::new_reply_button("id1", "Yes"),
+        InteractiveButton::new_reply_button("id2", "No \u{1F44E}"),
+    ];
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("button 2: emojis are not allowed"));
+}
+
+#[test]
+fn send_interactive_buttons_request_content_button_title_markdown() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![InteractiveButton::new_reply_button("id1", "*Yes*")];
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_buttons_request_content_button_title_plain_symbols() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![
+        InteractiveButton::new_reply_button("id1", "5 * 3"),
+        InteractiveButton::new_reply_button("id2", "snake_case"),
+    ];
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_interactive_buttons_request_content_duplicate_button_ids() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![
+        InteractiveButton::new_reply_button("id1", "Yes"),
+        InteractiveButton::new_reply_button("id1", "No"),
+    ];
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("button 2: ID id1 is used by another button"));
+}
+
+#[test]
+fn send_interactive_buttons_request_content_duplicate_button_titles() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.action.buttons = vec![
+        InteractiveButton::new_reply_button("id1", "Yes"),
+        InteractiveButton::new_reply_button("id2", "Yes"),
+    ];
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("button 2: title Yes is used by another button"));
+}
+
+#[test]
+fn send_interactive_buttons_request_content_long_text_header() {
+    let mut request_body = dummy_send_interactive_buttons_request_body();
+
+    request_body.content.header = Some(InteractiveButtonsHeader::TextHeader {
+        text: "t".repeat(61usize),
+    });
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("header text must have 1 to 60 characters, has 61"));
+}
+
+#[test]
+fn send_interactive_list_request_content_empty_text_header() {
+    let mut request_body = dummy_send_interactive_list_request_body();
+
+    request_body.content.header = Some(InteractiveListHeader::TextHeader {
+        text: "".to_string(),
+    });
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_list_request_content_action_title_emoji() {
+    let mut request_body = dummy_send_interactive_list_request_body();
+
+    request_body.content.action.title = "Menu \u{2615}".to_string();
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_list_request_content_too_many_rows() {
+    let mut request_body = dummy_send_interactive_list_request_body();
+
+    let rows = (1..=6)
+        .map(|i| InteractiveRow::new(&format!("id{}", i), "title"))
+        .collect::<Vec<_>>();
+    let mut first = InteractiveListSection::new(rows.clone());
+    first.title = Some("first".to_string());
+    let mut second = InteractiveListSection::new(
+        rows.into_iter()
+            .map(|mut row| {
+                row.id = format!("other-{}", row.id);
+                row
+            })
+            .collect(),
+    );
+    second.title = Some("second".to_string());
+    request_body.content.action.sections = vec![first, second];
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("up to 10 rows in all sections, has 12"));
+}
+
+#[test]
+fn send_interactive_list_request_content_duplicate_row_ids() {
+    let mut request_body = dummy_send_interactive_list_request_body();
+
+    request_body.content.action.sections[0].rows = vec![
+        InteractiveRow::new("id1", "title1"),
+        InteractiveRow::new("id1", "title2"),
+    ];
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_list_request_content_sections_without_titles() {
+    let mut request_body = dummy_send_interactive_list_request_body();
+
+    request_body.content.action.sections = vec![
+        InteractiveListSection::new(vec![InteractiveRow::new("id1", "title1")]),
+        InteractiveListSection::new(vec![InteractiveRow::new("id2", "title2")]),
+    ];
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_multiproduct_request_body_content_long_header() {
+    let mut request_body = dummy_send_interactive_multiproduct_request_body();
+
+    request_body.content.header = InteractiveMultiproductHeader::TextHeader {
+        text: "t".repeat(61usize),
+    };
+
+    assert!(request_body.validate().is_err());
+}
+
 #[test]
 fn send_interactive_product_request_valid() {
     let request_body = SendInteractiveProductRequestBody::new(
@@ -1251,6 +1470,190 @@ fn send_interactive_multiproduct_request_body_content_action_section_long_title(
     assert!(request_body.validate().is_err());
 }
 
+#[test]
+fn send_interactive_flow_request_body_valid() {
+    let mut action = InteractiveFlowAction::new("1234567890", "Book now");
+    action.flow_action = Some(FlowAction::Navigate);
+    action.flow_action_payload = Some(InteractiveFlowActionPayload::new("BOOKING"));
+
+    let request_body = SendInteractiveFlowRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveFlowContent::new(InteractiveBody::new("body text"), action),
+    );
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_interactive_flow_request_body_content_no_flow_id() {
+    let action = InteractiveFlowAction::new("", "Book now");
+    let request_body = SendInteractiveFlowRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveFlowContent::new(InteractiveBody::new("body text"), action),
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_flow_request_body_content_long_call_to_action_button() {
+    let action = InteractiveFlowAction::new("1234567890", &"t".repeat(21usize));
+    let request_body = SendInteractiveFlowRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveFlowContent::new(InteractiveBody::new("body text"), action),
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+fn dummy_order_details_action() -> InteractiveOrderDetailsAction {
+    let item = OrderItem::new("T-shirt", OrderAmount::new(50000, 100), 2);
+    InteractiveOrderDetailsAction::new(
+        OrderPayment::new("payment-configuration", "order-1234"),
+        "INR",
+        OrderAmount::new(100000, 100),
+        OrderDetails::new(vec![item], OrderAmount::new(100000, 100)),
+    )
+}
+
+#[test]
+fn send_interactive_order_details_request_body_valid() {
+    let request_body = SendInteractiveOrderDetailsRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveOrderDetailsContent::new(
+            InteractiveBody::new("body text"),
+            dummy_order_details_action(),
+        ),
+    );
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_interactive_order_details_request_body_content_no_items() {
+    let mut action = dummy_order_details_action();
+    action.order.items.clear();
+    let request_body = SendInteractiveOrderDetailsRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveOrderDetailsContent::new(InteractiveBody::new("body text"), action),
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_order_details_request_body_content_item_zero_quantity() {
+    let mut action = dummy_order_details_action();
+    action.order.items[0].quantity = 0;
+    let request_body = SendInteractiveOrderDetailsRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveOrderDetailsContent::new(InteractiveBody::new("body text"), action),
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_order_details_request_body_content_bad_currency() {
+    let mut action = dummy_order_details_action();
+    action.currency = "RUPEE".to_string();
+    let request_body = SendInteractiveOrderDetailsRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveOrderDetailsContent::new(InteractiveBody::new("body text"), action),
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_interactive_order_status_request_body_valid() {
+    let request_body = SendInteractiveOrderStatusRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveOrderStatusContent::new(
+            InteractiveBody::new("body text"),
+            InteractiveOrderStatusAction::new("order-1234", OrderStatus::Shipped),
+        ),
+    );
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_interactive_order_status_request_body_content_no_reference_id() {
+    let request_body = SendInteractiveOrderStatusRequestBody::new(
+        "555555555555",
+        "444444444444",
+        InteractiveOrderStatusContent::new(
+            InteractiveBody::new("body text"),
+            InteractiveOrderStatusAction::new("", OrderStatus::Canceled),
+        ),
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn inbound_orders_body_deserialize() {
+    let body: InboundOrdersBody = serde_json::from_str(
+        r#"
+            {
+              "results": [
+                {
+                  "from": "441134960001",
+                  "to": "441134960000",
+                  "integrationType": "WHATSAPP",
+                  "receivedAt": "2023-06-20T14:10:05.000+0000",
+                  "messageId": "ABEGVUGWh3gEAgo-sFB0EFJ9vhHA",
+                  "message": {
+                    "catalogId": "1234567890",
+                    "text": "Please deliver before noon",
+                    "productItems": [
+                      {
+                        "productRetailerId": "shirt-1",
+                        "quantity": 2,
+                        "itemPrice": 500.0,
+                        "currency": "INR"
+                      }
+                    ],
+                    "type": "ORDER"
+                  },
+                  "contact": {
+                    "name": "John Doe"
+                  },
+                  "price": {
+                    "pricePerMessage": 0,
+                    "currency": "EUR"
+                  }
+                }
+              ],
+              "messageCount": 1,
+              "pendingMessageCount": 0
+            }
+        "#,
+    )
+    .unwrap();
+
+    let order = &body.results[0];
+    let message = order.message.as_ref().unwrap();
+    let items = message.product_items.as_ref().unwrap();
+
+    assert_eq!(message.catalog_id.as_deref(), Some("1234567890"));
+    assert_eq!(items[0].product_retailer_id.as_deref(), Some("shirt-1"));
+    assert_eq!(items[0].quantity, Some(2));
+    assert_eq!(
+        order.contact.as_ref().unwrap().name.as_deref(),
+        Some("John Doe")
+    );
+}
+
 #[test]
 fn create_template_request_body_valid() {
     let structure = TemplateStructure::new(TemplateBody::new("hello"));
@@ -1311,3 +1714,637 @@ fn create_template_request_body_structure_many_buttons() {
 
     assert!(request_body.validate().is_err());
 }
+
+#[test]
+fn create_template_request_body_structure_call_to_action_buttons() {
+    let mut request_body = dummy_create_template_request_body();
+
+    request_body.structure.buttons = Some(vec![
+        TemplateButton::new_url("Visit", "https://www.example.com"),
+        TemplateButton::new_phone_number("Call", "41793026727"),
+    ]);
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn create_template_request_body_structure_mixed_buttons() {
+    let mut request_body = dummy_create_template_request_body();
+
+    request_body.structure.buttons = Some(vec![
+        TemplateButton::new_quick_reply("Yes"),
+        TemplateButton::new_url("Visit", "https://www.example.com"),
+    ]);
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(
+        errors.contains("buttons must be either all quick replies or all call to action buttons")
+    );
+}
+
+#[test]
+fn create_template_request_body_structure_too_many_call_to_action_buttons() {
+    let mut request_body = dummy_create_template_request_body();
+
+    request_body.structure.buttons = Some(vec![
+        TemplateButton::new_url("Visit", "https://www.example.com"),
+        TemplateButton::new_phone_number("Call", "41793026727"),
+        TemplateButton::new_url("Shop", "https://shop.example.com"),
+    ]);
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("buttons can have up to 2 call to action buttons, has 3"));
+}
+
+#[test]
+fn create_template_request_body_structure_duplicate_call_to_action_type() {
+    let mut request_body = dummy_create_template_request_body();
+
+    request_body.structure.buttons = Some(vec![
+        TemplateButton::new_url("Visit", "https://www.example.com"),
+        TemplateButton::new_url("Shop", "https://shop.example.com"),
+    ]);
+
+    let errors = request_body.validate().unwrap_err().to_string();
+    assert!(errors.contains("buttons can have only one phone number and one URL button"));
+}
+
+#[test]
+fn update_template_request_body_valid() {
+    let mut request_body = UpdateTemplateRequestBody::new();
+    request_body.category = Some(TemplateCategory::Utility);
+    request_body.structure = Some(TemplateStructure::new(TemplateBody::new("hello")));
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn update_template_request_body_structure_body_no_text() {
+    let mut request_body = UpdateTemplateRequestBody::new();
+    request_body.structure = Some(TemplateStructure::new(TemplateBody::new("")));
+
+    assert!(request_body.validate().is_err());
+}
+
+fn dummy_template_card() -> TemplateCard {
+    let mut card = TemplateCard::new(
+        TemplateHeader::Image {
+            example: Some("https://some.url/image.png".to_string()),
+        },
+        TemplateBody::new("card body"),
+    );
+    card.buttons = Some(vec![TemplateButton::new_quick_reply("Buy")]);
+
+    card
+}
+
+fn dummy_template_card_content() -> TemplateCardContent {
+    TemplateCardContent::new(
+        TemplateHeaderContent::new_image("https://some.url/image.png"),
+        TemplateBodyContent::new(vec![]),
+    )
+}
+
+#[test]
+fn create_template_request_body_carousel_valid() {
+    let mut structure = TemplateStructure::new(TemplateBody::new("hello"));
+    structure.carousel = Some(TemplateCarousel::new(vec![
+        dummy_template_card(),
+        dummy_template_card(),
+    ]));
+    structure.template_type = Some(TemplateType::Carousel);
+    let request_body = CreateTemplateRequestBody::new(
+        "carousel_template",
+        TemplateLanguage::EnUs,
+        TemplateCategory::Marketing,
+        structure,
+    );
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn create_template_request_body_carousel_single_card() {
+    let mut structure = TemplateStructure::new(TemplateBody::new("hello"));
+    structure.carousel = Some(TemplateCarousel::new(vec![dummy_template_card()]));
+    let request_body = CreateTemplateRequestBody::new(
+        "carousel_template",
+        TemplateLanguage::EnUs,
+        TemplateCategory::Marketing,
+        structure,
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn create_template_request_body_carousel_many_cards() {
+    let mut structure = TemplateStructure::new(TemplateBody::new("hello"));
+    structure.carousel = Some(TemplateCarousel::new(vec![dummy_template_card(); 11]));
+    let request_body = CreateTemplateRequestBody::new(
+        "carousel_template",
+        TemplateLanguage::EnUs,
+        TemplateCategory::Marketing,
+        structure,
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn create_template_request_body_carousel_card_text_header() {
+    let mut card = dummy_template_card();
+    card.header = TemplateHeader::new_text("header text");
+    let mut structure = TemplateStructure::new(TemplateBody::new("hello"));
+    structure.carousel = Some(TemplateCarousel::new(vec![card, dummy_template_card()]));
+    let request_body = CreateTemplateRequestBody::new(
+        "carousel_template",
+        TemplateLanguage::EnUs,
+        TemplateCategory::Marketing,
+        structure,
+    );
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_template_request_body_carousel_valid() {
+    let mut request_body = dummy_send_template_request_body();
+    request_body.messages[0].content.template_data.carousel =
+        Some(TemplateCarouselContent::new(vec![
+            dummy_template_card_content(),
+            dummy_template_card_content(),
+        ]));
+
+    assert!(request_body.validate().is_ok());
+}
+
+#[test]
+fn send_template_request_body_carousel_single_card() {
+    let mut request_body = dummy_send_template_request_body();
+    request_body.messages[0].content.template_data.carousel =
+        Some(TemplateCarouselContent::new(vec![
+            dummy_template_card_content(),
+        ]));
+
+    assert!(request_body.validate().is_err());
+}
+
+#[test]
+fn send_template_response_partition_by_status() {
+    let sent = |id: &str, group_name: &str| SentMessageInfo {
+        message_id: Some(id.to_string()),
+        status: Some(Status {
+            group_name: Some(group_name.to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let response = SendTemplateResponseBody {
+        messages: Some(vec![
+            sent("first", "PENDING"),
+            sent("second", "UNDELIVERABLE"),
+            SentMessageInfo::new(),
+        ]),
+        bulk_id: Some("some-bulk-id".to_string()),
+        ..Default::default()
+    };
+
+    let result = response.partition_by_status();
+
+    assert_eq!(result.accepted[0].message_id.as_deref(), Some("first"));
+    assert_eq!(result.rejected[0].0, StatusGroup::Undeliverable);
+    assert_eq!(result.unknown.len(), 1);
+}
+
+fn sent_info(id: Option<&str>, group_name: &str) -> SentMessageInfo {
+    SentMessageInfo {
+        message_id: id.map(str::to_string),
+        status: Some(Status {
+            group_name: Some(group_name.to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn request_with_ids(ids: &[Option<&str>]) -> SendTemplateRequestBody {
+    let message = dummy_send_template_request_body().messages.remove(0);
+    let messages = ids
+        .iter()
+        .map(|id| FailoverMessage {
+            message_id: id.map(str::to_string),
+            ..message.clone()
+        })
+        .collect();
+
+    SendTemplateRequestBody::new(messages)
+}
+
+#[test]
+fn send_template_response_correlate_by_id() {
+    let request_body = request_with_ids(&[Some("first"), Some("second")]);
+    let response = SendTemplateResponseBody {
+        messages: Some(vec![
+            sent_info(Some("second"), "REJECTED"),
+            sent_info(Some("first"), "PENDING"),
+        ]),
+        ..Default::default()
+    };
+
+    let sent = response.correlate(&request_body).unwrap();
+
+    assert_eq!(sent[0].info.message_id.as_deref(), Some("first"));
+    assert!(!sent[0].is_rejected());
+    assert_eq!(sent[1].message.message_id.as_deref(), Some("second"));
+    assert!(sent[1].is_rejected());
+}
+
+#[test]
+fn send_template_response_correlate_by_index() {
+    let request_body = request_with_ids(&[None, Some("second")]);
+    let response = SendTemplateResponseBody {
+        messages: Some(vec![
+            sent_info(Some("generated"), "PENDING"),
+            sent_info(Some("other"), "PENDING"),
+        ]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        response.correlate(&request_body),
+        Err(CorrelationError::MessageIdMismatch {
+            index: 1,
+            expected: "second".to_string(),
+            received: Some("other".to_string()),
+        })
+    );
+}
+
+#[test]
+fn send_template_response_correlate_count_mismatch() {
+    let request_body = request_with_ids(&[None, None]);
+    let response = SendTemplateResponseBody {
+        messages: Some(vec![sent_info(None, "PENDING")]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        response.correlate(&request_body),
+        Err(CorrelationError::CountMismatch {
+            sent: 2,
+            received: 1
+        })
+    );
+}
+
+#[test]
+fn send_template_batch_response_correlate_missing_id() {
+    let request_body = request_with_ids(&[Some("first")]);
+    let response = SendTemplateBatchResponseBody {
+        messages: vec![sent_info(Some("other"), "PENDING")],
+        bulk_ids: vec![],
+    };
+
+    assert_eq!(
+        response.correlate(&request_body),
+        Err(CorrelationError::MissingMessageId("first".to_string()))
+    );
+}
+
+#[test]
+fn send_text_request_body_try_from_value() {
+    let request_body = SendTextRequestBody::try_from(serde_json::json!({
+        "from": "44444444444",
+        "to": "55555555555",
+        "content": {"text": "Some text"},
+    }))
+    .unwrap();
+
+    assert_eq!(request_body.content.text, "Some text");
+}
+
+#[test]
+fn send_text_request_body_try_from_value_invalid_content() {
+    let error = SendTextRequestBody::try_from(serde_json::json!({
+        "from": "44444444444",
+        "to": "55555555555",
+        "content": {"text": ""},
+    }))
+    .unwrap_err();
+
+    assert!(matches!(error, crate::api::SdkError::Validation(_)));
+}
+
+#[test]
+fn create_template_request_body_unknown_values_not_accepted() {
+    let mut request_body = dummy_create_template_request_body();
+    assert!(request_body.validate().is_ok());
+
+    request_body.category = TemplateCategory::Unknown;
+    assert!(request_body.validate().is_err());
+
+    let mut request_body = dummy_create_template_request_body();
+    request_body.language = TemplateLanguage::Unknown;
+    assert!(request_body.validate().is_err());
+
+    let mut request_body = dummy_create_template_request_body();
+    request_body.structure.buttons = Some(vec![TemplateButton::Unknown]);
+    assert!(request_body.validate().is_err());
+
+    let error = CreateTemplateRequestBody::try_from(serde_json::json!({
+        "name": "template_name",
+        "language": "xx_YY",
+        "category": "MARKETING",
+        "structure": {"body": {"text": "body content"}}
+    }))
+    .unwrap_err();
+    assert!(matches!(error, crate::api::SdkError::Validation(_)));
+}
+
+#[test]
+fn create_library_template_request_body_from_library() {
+    let template = LibraryTemplate {
+        name: Some("delivery_update_1".to_string()),
+        language: Some(TemplateLanguage::EnUs),
+        category: Some(TemplateCategory::Utility),
+        ..Default::default()
+    };
+
+    let request_body =
+        CreateLibraryTemplateRequestBody::from_library(&template, "delivery_update").unwrap();
+
+    assert!(request_body.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&request_body).unwrap(),
+        serde_json::json!({
+            "name": "delivery_update",
+            "language": "en_US",
+            "category": "UTILITY",
+            "libraryTemplateName": "delivery_update_1"
+        })
+    );
+    assert!(CreateLibraryTemplateRequestBody::from_library(
+        &LibraryTemplate::default(),
+        "delivery_update"
+    )
+    .is_none());
+}
+
+fn inbound_message(message: serde_json::Value) -> InboundMessage {
+    serde_json::from_value(serde_json::json!({
+        "from": "385977666618",
+        "to": "447860099299",
+        "integrationType": "WHATSAPP",
+        "receivedAt": "2021-08-25T16:00:00.000+0000",
+        "messageId": "ABEGOFl3ZmYfAgo-sF_kfQ3ihYC9",
+        "message": message,
+        "contact": { "name": "Frank" },
+        "price": { "pricePerMessage": 0, "currency": "EUR" }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn inbound_text_message() {
+    let message = inbound_message(serde_json::json!({ "type": "TEXT", "text": "Hello" }));
+
+    assert_eq!(
+        message.message.kind,
+        InboundKind::Text {
+            text: "Hello".to_string()
+        }
+    );
+    assert_eq!(message.text(), Some("Hello"));
+    assert_eq!(message.reply_id(), None);
+    assert_eq!(message.contact.unwrap().name.as_deref(), Some("Frank"));
+}
+
+#[test]
+fn inbound_media_message() {
+    let message = inbound_message(serde_json::json!({
+        "type": "IMAGE",
+        "url": "https://api.infobip.com/whatsapp/1/senders/447860099299/media/abc",
+        "caption": "My receipt"
+    }));
+
+    assert!(matches!(message.message.kind, InboundKind::Image(_)));
+    assert_eq!(message.text(), Some("My receipt"));
+}
+
+#[test]
+fn inbound_reply_messages() {
+    let button = inbound_message(serde_json::json!({
+        "type": "INTERACTIVE_BUTTON_REPLY",
+        "id": "confirm",
+        "title": "Confirm",
+        "context": { "from": "447860099299", "id": "sent-message-id" }
+    }));
+    assert_eq!(button.reply_id(), Some("confirm"));
+    assert_eq!(
+        button.message.context.unwrap().id.as_deref(),
+        Some("sent-message-id")
+    );
+
+    let list = inbound_message(serde_json::json!({
+        "type": "INTERACTIVE_LIST_REPLY",
+        "id": "row-2",
+        "title": "Tomorrow",
+        "description": "Between 9 and 12"
+    }));
+    assert_eq!(list.reply_id(), Some("row-2"));
+
+    let template_button = inbound_message(serde_json::json!({
+        "type": "BUTTON",
+        "text": "Stop promotions",
+        "payload": "opt-out"
+    }));
+    assert_eq!(template_button.reply_id(), Some("opt-out"));
+}
+
+#[test]
+fn inbound_location_and_contact_messages() {
+    let location = inbound_message(serde_json::json!({
+        "type": "LOCATION",
+        "latitude": 45.815,
+        "longitude": 15.9819,
+        "name": "Zagreb"
+    }));
+    match location.message.kind {
+        InboundKind::Location(location) => assert_eq!(location.name.as_deref(), Some("Zagreb")),
+        kind => panic!("unexpected kind {kind:?}"),
+    }
+
+    let contact = inbound_message(serde_json::json!({
+        "type": "CONTACT",
+        "contacts": [{ "name": { "firstName": "John", "formattedName": "John Smith" } }]
+    }));
+    match contact.message.kind {
+        InboundKind::Contact(shared) => {
+            assert_eq!(shared.contacts[0].name.formatted_name, "John Smith")
+        }
+        kind => panic!("unexpected kind {kind:?}"),
+    }
+}
+
+#[test]
+fn inbound_message_with_referral() {
+    let message = inbound_message(serde_json::json!({
+        "type": "TEXT",
+        "text": "I'm interested",
+        "referral": {
+            "sourceUrl": "https://fb.me/ad",
+            "sourceId": "ad-id",
+            "sourceType": "AD",
+            "ctwaClid": "click-id"
+        }
+    }));
+
+    let referral = message.message.referral.unwrap();
+    assert_eq!(referral.source_type.as_deref(), Some("AD"));
+    assert_eq!(referral.ctwa_clid.as_deref(), Some("click-id"));
+}
+
+#[test]
+fn inbound_unknown_message_type() {
+    let message = inbound_message(serde_json::json!({ "type": "REACTION", "emoji": "👍" }));
+
+    assert_eq!(message.message.kind, InboundKind::Unsupported);
+}
+
+#[test]
+fn template_language_parses_its_code() {
+    for code in ["af", "en_GB", "en_US", "pt_BR", "zh_CN", "rw_RW", "fil"] {
+        let language: TemplateLanguage = code.parse().unwrap();
+
+        assert_eq!(language.to_string(), code);
+        assert_eq!(
+            serde_json::to_string(&language).unwrap(),
+            format!("\"{}\"", code)
+        );
+    }
+}
+
+#[test]
+fn template_language_unknown_code_is_error() {
+    let error = "xx_YY".parse::<TemplateLanguage>().unwrap_err();
+
+    assert_eq!(error.code, "xx_YY");
+    assert_eq!(error.to_string(), "unknown template language `xx_YY`");
+    assert!("unknown".parse::<TemplateLanguage>().is_err());
+    assert!("en_gb".parse::<TemplateLanguage>().is_err());
+}
+
+#[test]
+fn template_namespace_and_meta_id_deserialize() {
+    let template: Template = serde_json::from_str(
+        r#"
+        {
+          "id": "111",
+          "name": "order_shipped",
+          "language": "en_GB",
+          "namespace": "a1b2c3d4_e5f6_7890_abcd_ef1234567890",
+          "metaTemplateId": "1234567890123456"
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        template.namespace.as_deref(),
+        Some("a1b2c3d4_e5f6_7890_abcd_ef1234567890")
+    );
+    assert_eq!(
+        template.meta_template_id.as_deref(),
+        Some("1234567890123456")
+    );
+}
+
+#[test]
+fn template_content_namespace_serialized_when_set() {
+    let mut content = TemplateContent::new(
+        "order_shipped",
+        TemplateData::new(TemplateBodyContent::new(vec![])),
+        TemplateLanguage::EnGb,
+    );
+    let json = serde_json::to_value(&content).unwrap();
+    assert!(json.get("namespace").is_none());
+    assert!(json.get("metaTemplateId").is_none());
+
+    content.namespace = Some("some_namespace".to_string());
+    content.meta_template_id = Some("1234567890123456".to_string());
+    let json = serde_json::to_value(&content).unwrap();
+    assert_eq!(json["namespace"], "some_namespace");
+    assert_eq!(json["metaTemplateId"], "1234567890123456");
+}
+
+#[test]
+fn template_with_unknown_values_deserializes() {
+    let template: Template = serde_json::from_str(
+        r#"
+        {
+          "id": "111",
+          "name": "some_template",
+          "language": "xx_YY",
+          "status": "SOME_NEW_STATUS",
+          "category": "SOME_NEW_CATEGORY",
+          "structure": {
+            "header": {"format": "SOME_NEW_FORMAT", "example": "something"},
+            "body": {"text": "Hello {{1}}"},
+            "buttons": [
+              {"type": "QUICK_REPLY", "text": "Yes"},
+              {"type": "SOME_NEW_BUTTON", "text": "Copy", "code": "ABC"}
+            ],
+            "type": "SOME_NEW_TYPE"
+          },
+          "qualityScore": {"score": "SOME_NEW_SCORE"}
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(template.language, Some(TemplateLanguage::Unknown));
+    assert_eq!(template.status, Some(TemplateStatus::Unknown));
+    assert_eq!(template.category, Some(TemplateCategory::Unknown));
+    let structure = template.structure.unwrap();
+    assert_eq!(structure.header, Some(TemplateHeader::Unknown));
+    assert_eq!(structure.template_type, Some(TemplateType::Unsupported));
+    assert_eq!(
+        structure.buttons,
+        Some(vec![
+            TemplateButton::new_quick_reply("Yes"),
+            TemplateButton::Unknown
+        ])
+    );
+    assert_eq!(
+        template.quality_score.unwrap().score,
+        Some(TemplateQualityRating::Unknown)
+    );
+}
+
+#[test]
+fn sender_quality_unknown_values() {
+    let response_body: SenderQualityResponseBody = serde_json::from_str(
+        r#"{"results": [{"sender": "441134960000", "qualityRating": "NEW_RATING", "status": "NEW_STATUS", "currentLimit": "TIER_2K"}]}"#,
+    )
+    .unwrap();
+
+    let quality = response_body.sender("441134960000").unwrap();
+    assert_eq!(quality.quality_rating, Some(SenderQualityRating::Unknown));
+    assert_eq!(quality.status, Some(SenderStatus::Unknown));
+    assert_eq!(quality.current_limit, Some(MessagingLimit::Unknown));
+    assert!(response_body.at_risk().is_empty());
+}
+
+#[test]
+fn messaging_limit_recipients_per_day() {
+    let limits: Vec<MessagingLimit> =
+        serde_json::from_str(r#"["TIER_50", "TIER_250", "TIER_100K", "TIER_UNLIMITED"]"#).unwrap();
+
+    let recipients: Vec<_> = limits
+        .iter()
+        .map(MessagingLimit::recipients_per_day)
+        .collect();
+
+    assert_eq!(recipients, vec![Some(50), Some(250), Some(100_000), None]);
+}
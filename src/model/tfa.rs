@@ -0,0 +1,1341 @@
+//! Models for calling 2FA (two-factor authentication) endpoints.
+
+use regex::Regex;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+use validator::{Validate, ValidationError};
+
+use crate::model::status::StatusGroup;
+use crate::model::{fill_platform_id, PlatformIds};
+
+impl_try_from_value!(
+    TfaApplication,
+    TfaMessageTemplate,
+    SendPinOverSmsRequestBody,
+    SendPinOverEmailRequestBody,
+    ResendPinRequestBody,
+    VerifyPhoneNumberRequestBody,
+);
+
+impl_query_params!(
+    SendPinOverSmsQueryParameters,
+    TfaVerificationStatusQueryParameters
+);
+
+lazy_static::lazy_static! {
+    static ref DLT_TEMPLATE_ID: Regex = Regex::new(r"^[0-9]+$").unwrap();
+    static ref DTMF: Regex = Regex::new(r"^[0-9*#]+$").unwrap();
+    static ref TIME_TO_LIVE: Regex = Regex::new(r"^([0-9]+)?(ms|s|m|h|d)$").unwrap();
+    static ref RATE_LIMIT: Regex = Regex::new(r"^([0-9]+)/([0-9]+)?(ms|s|m|h|d)$").unwrap();
+}
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+        )]
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.into())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// ID of a 2FA application.
+    AppId
+);
+
+id_type!(
+    /// ID of a 2FA message template.
+    TemplateId
+);
+
+id_type!(
+    /// ID of a sent PIN code.
+    PinId
+);
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct IndiaDlt {
+    /// Id of your registered DTL content template that matches this message's text. Only
+    /// contains digits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 30), regex = "DLT_TEMPLATE_ID")]
+    pub content_template_id: Option<String>,
+
+    /// Your assigned DTL principal entity id.
+    #[validate(length(min = 1))]
+    pub principal_entity_id: String,
+}
+
+impl IndiaDlt {
+    pub fn new(principal_entity_id: &str) -> Self {
+        Self {
+            principal_entity_id: principal_entity_id.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_content_template(principal_entity_id: &str, content_template_id: &str) -> Self {
+        Self {
+            principal_entity_id: principal_entity_id.into(),
+            content_template_id: Some(content_template_id.into()),
+        }
+    }
+}
+
+/// Error parsing or building a typed 2FA setting.
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum TfaValueError {
+    #[error("invalid time to live `{0}`, expected a length and a unit, e.g. `10m`")]
+    InvalidTimeToLive(String),
+    #[error("invalid rate limit `{0}`, expected attempts and a period, e.g. `3/1d`")]
+    InvalidRateLimit(String),
+    #[error("invalid speech rate `{0}`, expected a number")]
+    InvalidSpeechRate(String),
+    #[error("speech rate {0} is outside of the supported range from 0.5 to 2")]
+    SpeechRateOutOfRange(f64),
+}
+
+/// Unit of 2FA time settings, see [`TimeToLive`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TfaTimeUnit {
+    Milliseconds,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl TfaTimeUnit {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "m",
+            Self::Hours => "h",
+            Self::Days => "d",
+        }
+    }
+
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "ms" => Some(Self::Milliseconds),
+            "s" => Some(Self::Seconds),
+            "m" => Some(Self::Minutes),
+            "h" => Some(Self::Hours),
+            "d" => Some(Self::Days),
+            _ => None,
+        }
+    }
+
+    fn milliseconds(&self) -> u64 {
+        match self {
+            Self::Milliseconds => 1,
+            Self::Seconds => 1_000,
+            Self::Minutes => 60_000,
+            Self::Hours => 3_600_000,
+            Self::Days => 86_400_000,
+        }
+    }
+}
+
+/// A period of time in the `{timeLength}{timeUnit}` format of 2FA settings, e.g. `10m` for the
+/// validity of a PIN. When parsing, the length can be left out and defaults to 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(try_from = "String", into = "String")]
+pub struct TimeToLive {
+    pub length: u32,
+    pub unit: TfaTimeUnit,
+}
+
+impl TimeToLive {
+    pub fn new(length: u32, unit: TfaTimeUnit) -> Self {
+        Self { length, unit }
+    }
+
+    pub fn minutes(length: u32) -> Self {
+        Self::new(length, TfaTimeUnit::Minutes)
+    }
+
+    pub fn hours(length: u32) -> Self {
+        Self::new(length, TfaTimeUnit::Hours)
+    }
+
+    pub fn days(length: u32) -> Self {
+        Self::new(length, TfaTimeUnit::Days)
+    }
+
+    /// Returns the period as a `Duration`.
+    pub fn as_duration(&self) -> Duration {
+        Duration::from_millis(u64::from(self.length) * self.unit.milliseconds())
+    }
+}
+
+impl fmt::Display for TimeToLive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.length, self.unit.symbol())
+    }
+}
+
+impl FromStr for TimeToLive {
+    type Err = TfaValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || TfaValueError::InvalidTimeToLive(value.to_string());
+        let captures = TIME_TO_LIVE.captures(value).ok_or_else(invalid)?;
+
+        Ok(Self {
+            length: parse_length(captures.get(1).map(|length| length.as_str()))
+                .ok_or_else(invalid)?,
+            unit: TfaTimeUnit::from_symbol(&captures[2]).ok_or_else(invalid)?,
+        })
+    }
+}
+
+impl TryFrom<String> for TimeToLive {
+    type Error = TfaValueError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<TimeToLive> for String {
+    fn from(time_to_live: TimeToLive) -> Self {
+        time_to_live.to_string()
+    }
+}
+
+/// A number of attempts per period in the `{attempts}/{timeLength}{timeUnit}` format of 2FA
+/// limits, e.g. `3/1d` for three PINs a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(try_from = "String", into = "String")]
+pub struct RateLimit {
+    pub attempts: u32,
+    pub period: TimeToLive,
+}
+
+impl RateLimit {
+    pub fn new(attempts: u32, period: TimeToLive) -> Self {
+        Self { attempts, period }
+    }
+}
+
+impl fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.attempts, self.period)
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = TfaValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || TfaValueError::InvalidRateLimit(value.to_string());
+        let captures = RATE_LIMIT.captures(value).ok_or_else(invalid)?;
+
+        Ok(Self {
+            attempts: captures[1].parse().map_err(|_| invalid())?,
+            period: TimeToLive {
+                length: parse_length(captures.get(2).map(|length| length.as_str()))
+                    .ok_or_else(invalid)?,
+                unit: TfaTimeUnit::from_symbol(&captures[3]).ok_or_else(invalid)?,
+            },
+        })
+    }
+}
+
+impl TryFrom<String> for RateLimit {
+    type Error = TfaValueError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<RateLimit> for String {
+    fn from(rate_limit: RateLimit) -> Self {
+        rate_limit.to_string()
+    }
+}
+
+fn parse_length(length: Option<&str>) -> Option<u32> {
+    length.map_or(Some(1), |length| length.parse().ok())
+}
+
+const ONE_DAY: Duration = Duration::from_secs(24 * 60 * 60);
+const ONE_YEAR: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+fn validate_period(period: &TimeToLive, max: Duration) -> Result<(), ValidationError> {
+    if period.length == 0 {
+        return Err(ValidationError::new("period_is_zero"));
+    }
+    if period.as_duration() > max {
+        return Err(ValidationError::new("period_too_long"));
+    }
+
+    Ok(())
+}
+
+fn validate_pin_time_to_live(time_to_live: &TimeToLive) -> Result<(), ValidationError> {
+    validate_period(time_to_live, ONE_YEAR)
+}
+
+fn validate_send_pin_limit(limit: &RateLimit) -> Result<(), ValidationError> {
+    if limit.attempts == 0 {
+        return Err(ValidationError::new("attempts_is_zero"));
+    }
+
+    validate_period(&limit.period, ONE_YEAR)
+}
+
+fn validate_verify_pin_limit(limit: &RateLimit) -> Result<(), ValidationError> {
+    if limit.attempts == 0 {
+        return Err(ValidationError::new("attempts_is_zero"));
+    }
+
+    validate_period(&limit.period, ONE_DAY)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TfaApplicationConfiguration {
+    /// Indicates whether multiple PIN verification is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_multiple_pin_verifications: Option<bool>,
+
+    /// Number of possible PIN attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_attempts: Option<i32>,
+
+    /// Validity period of PIN in specified time unit. Required format: `{timeLength}{timeUnit}`. `timeLength` is optional with a default value of 1. `timeUnit` can be set to: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one year, although much lower value is recommended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_pin_time_to_live")]
+    pub pin_time_to_live: Option<TimeToLive>,
+
+    /// Overall number of requests over a specified time period for generating a PIN and sending an SMS using a single application. Required format: `{attempts}/{timeLength}{timeUnit}`. `attempts` is mandatory and `timeLength` is optional with a default value of 1. `timeUnit` is one of: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one year, although much lower value is recommended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_send_pin_limit")]
+    pub send_pin_per_application_limit: Option<RateLimit>,
+
+    /// Number of requests over a specified time period for generating a PIN and sending an SMS to one phone number (MSISDN). Required format: `{attempts}/{timeLength}{timeUnit}`. `attempts` is mandatory and `timeLength` is optional with a default value of 1. `timeUnit` is one of: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one year, although much lower value is recommended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_send_pin_limit")]
+    pub send_pin_per_phone_number_limit: Option<RateLimit>,
+
+    /// The number of PIN verification requests over a specified time period from one phone number (MSISDN). Required format: `{attempts}/{timeLength}{timeUnit}`. `attempts` is mandatory and `timeLength` is optional with a default value of 1. `timeUnit` is one of: `ms`, `s`, `m`, `h` or `d` representing milliseconds, seconds, minutes, hours, and days respectively. Must not exceed one day, although much lower value is recommended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_verify_pin_limit")]
+    pub verify_pin_limit: Option<RateLimit>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TfaApplication {
+    /// The ID of the application that represents your service, e.g. 2FA for login, 2FA for changing the password, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Created 2FA application configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub configuration: Option<TfaApplicationConfiguration>,
+
+    /// Indicates whether the created application is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// 2FA application name.
+    #[validate(length(min = 1))]
+    pub name: String,
+}
+
+pub type TfaApplicationsResponseBody = Vec<TfaApplication>;
+
+/// Filter for 2FA applications, see `TfaClient::tfa_applications_filtered`. The API has no
+/// filters for applications, so they are applied after fetching the list.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TfaApplicationsFilter {
+    /// Only enabled or only disabled applications.
+    pub enabled: Option<bool>,
+
+    /// Only applications whose name contains the text, ignoring case.
+    pub name_contains: Option<String>,
+}
+
+impl TfaApplicationsFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the application passes the filter.
+    pub fn matches(&self, application: &TfaApplication) -> bool {
+        if let Some(enabled) = self.enabled {
+            if application.enabled != Some(enabled) {
+                return false;
+            }
+        }
+        if let Some(name_contains) = &self.name_contains {
+            if !application
+                .name
+                .to_lowercase()
+                .contains(&name_contains.to_lowercase())
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+pub type CreateTfaApplicationRequestBody = TfaApplication;
+
+pub type CreateTfaApplicationResponseBody = TfaApplication;
+
+impl CreateTfaApplicationRequestBody {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+pub type TfaApplicationResponseBody = TfaApplication;
+
+pub type UpdateTfaApplicationRequestBody = TfaApplication;
+
+pub type UpdateTfaApplicationResponseBody = TfaApplication;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TfaLanguage {
+    #[serde(rename = "en")]
+    En,
+    #[serde(rename = "es")]
+    Es,
+    #[serde(rename = "ca")]
+    Ca,
+    #[serde(rename = "da")]
+    Da,
+    #[serde(rename = "nl")]
+    Nl,
+    #[serde(rename = "fr")]
+    Fr,
+    #[serde(rename = "de")]
+    De,
+    #[serde(rename = "it")]
+    It,
+    #[serde(rename = "ja")]
+    Ja,
+    #[serde(rename = "ko")]
+    Ko,
+    #[serde(rename = "no")]
+    No,
+    #[serde(rename = "pl")]
+    Pl,
+    #[serde(rename = "ru")]
+    Ru,
+    #[serde(rename = "sv")]
+    Sv,
+    #[serde(rename = "fi")]
+    Fi,
+    #[serde(rename = "hr")]
+    Hr,
+    #[serde(rename = "sl")]
+    Sl,
+    #[serde(rename = "ro")]
+    Ro,
+    #[serde(rename = "pt-pt")]
+    PtPt,
+    #[serde(rename = "pt-br")]
+    PtBr,
+    #[serde(rename = "zh-cn")]
+    ZhCn,
+    #[serde(rename = "zh-tw")]
+    ZhTw,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PinType {
+    #[default]
+    Numeric,
+    Alpha,
+    Hex,
+    Alphanumeric,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TfaRegional {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub india_dlt: Option<IndiaDlt>,
+}
+
+/// Speed of speech of PIN messages sent by Voice, from `0.5` to `2`, where `1` is the normal
+/// speed.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SpeechRate(f64);
+
+impl SpeechRate {
+    pub const MIN: f64 = 0.5;
+    pub const MAX: f64 = 2.0;
+    pub const NORMAL: SpeechRate = SpeechRate(1.0);
+
+    /// Returns the speech rate, or an error if it's outside of the supported range.
+    pub fn new(rate: f64) -> Result<Self, TfaValueError> {
+        if (Self::MIN..=Self::MAX).contains(&rate) {
+            Ok(Self(rate))
+        } else {
+            Err(TfaValueError::SpeechRateOutOfRange(rate))
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+// Stays in the supported range, in steps of `0.01`.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SpeechRate {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let steps = u.int_in_range(0..=150u8)?;
+        Ok(Self(Self::MIN + f64::from(steps) / 100.0))
+    }
+}
+
+impl Default for SpeechRate {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+impl fmt::Display for SpeechRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for SpeechRate {
+    type Err = TfaValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let rate = value
+            .parse()
+            .map_err(|_| TfaValueError::InvalidSpeechRate(value.to_string()))?;
+
+        Self::new(rate)
+    }
+}
+
+// Checks rates that were deserialized rather than built with `SpeechRate::new`.
+fn validate_speech_rate(rate: &SpeechRate) -> Result<(), ValidationError> {
+    if SpeechRate::new(rate.0).is_err() {
+        return Err(ValidationError::new("speech_rate_out_of_range"));
+    }
+
+    Ok(())
+}
+
+// `Unknown` only stands for values received in responses, it can't be sent back.
+fn validate_known_language(language: &TfaLanguage) -> Result<(), ValidationError> {
+    if *language == TfaLanguage::Unknown {
+        return Err(ValidationError::new("unknown_language"));
+    }
+
+    Ok(())
+}
+
+fn validate_known_pin_type(pin_type: &PinType) -> Result<(), ValidationError> {
+    if *pin_type == PinType::Unknown {
+        return Err(ValidationError::new("unknown_pin_type"));
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TfaMessageTemplate {
+    /// The ID of the application that represents your service (e.g. 2FA for login, 2FA for changing the password, etc.) for which the requested message has been created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// The language code which message is written in used when sending text-to-speech messages. If not defined, it will default to English (`en`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_known_language")]
+    pub language: Option<TfaLanguage>,
+
+    /// The ID of the message template (message body with the PIN placeholder) that is sent to the recipient.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Text of a message that will be sent. Message text must contain `pinPlaceholder`.
+    #[validate(length(min = 1))]
+    pub message_text: String,
+
+    /// PIN code length.
+    #[validate(range(min = 1))]
+    pub pin_length: i32,
+
+    /// The PIN code placeholder that will be replaced with a generated PIN code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_placeholder: Option<String>,
+
+    /// The type of PIN code that will be generated and sent as part of 2FA message. You can set PIN type to numeric, alpha, alphanumeric or hex.
+    #[validate(custom = "validate_known_pin_type")]
+    pub pin_type: PinType,
+
+    /// Region-specific parameters, often imposed by local laws. Use this, if country or region that you are sending a message to requires additional information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub regional: Option<TfaRegional>,
+
+    /// In case PIN message is sent by Voice, DTMF code will enable replaying the message. Digits,
+    /// `*` and `#`, e.g. `1#`.
+    #[serde(rename = "repeatDTMF", skip_serializing_if = "Option::is_none")]
+    #[validate(regex = "DTMF")]
+    pub repeat_dtmf: Option<String>,
+
+    /// The name that will appear as the sender of the 2FA message (Example: CompanyName).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_id: Option<String>,
+
+    /// In case PIN message is sent by Voice, the speed of speech can be set for the message. Supported range is from `0.5` to `2`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_speech_rate")]
+    pub speech_rate: Option<SpeechRate>,
+
+    /// In case PIN message is sent by Voice, the name of the text-to-speech voice, e.g. `Joanna`.
+    /// The voice must support the `language` of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_name: Option<String>,
+}
+
+impl TfaMessageTemplate {
+    pub fn new(message_text: &str, pin_type: PinType, pin_length: i32) -> Self {
+        Self {
+            message_text: message_text.into(),
+            pin_length,
+            pin_type,
+            ..Default::default()
+        }
+    }
+}
+
+pub type TfaMessageTemplatesResponseBody = Vec<TfaMessageTemplate>;
+
+pub type CreateTfaMessageTemplateRequestBody = TfaMessageTemplate;
+
+pub type CreateTfaMessageTemplateResponseBody = TfaMessageTemplate;
+
+pub type TfaMessageTemplateResponseBody = TfaMessageTemplate;
+
+pub type UpdateTfaMessageTemplateRequestBody = TfaMessageTemplate;
+
+pub type UpdateTfaMessageTemplateResponseBody = TfaMessageTemplate;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendPinOverSmsQueryParameters {
+    pub nc_needed: Option<bool>,
+}
+
+impl SendPinOverSmsQueryParameters {
+    pub fn new() -> Self {
+        Self { nc_needed: None }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendPinOverSmsRequestBody {
+    /// The ID of the application that represents your service, e.g. 2FA for login, 2FA for changing the password, etc.
+    #[validate(length(min = 1))]
+    pub application_id: String,
+
+    /// Entity ID of the PIN, for attributing traffic on a multi-tenant platform (CPaaS X).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Use this parameter if you wish to override the sender ID from the [created](#channels/sms/create-2fa-message-template) message template parameter `senderId`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// The ID of the message template (message body with the PIN placeholder) that is sent to the recipient.
+    #[validate(length(min = 1))]
+    pub message_id: String,
+
+    /// Key value pairs that will be replaced during message sending. Placeholder keys should NOT contain curly brackets and should NOT contain a `pin` placeholder. Valid example: `\"placeholders\":{\"firstName\":\"John\"}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholders: Option<HashMap<String, String>>,
+
+    /// Phone number to which the 2FA message will be sent. Example: 41793026727.
+    #[validate(length(min = 1))]
+    pub to: String,
+}
+
+impl SendPinOverSmsRequestBody {
+    pub fn new(application_id: &str, message_id: &str, to: &str) -> Self {
+        Self {
+            application_id: application_id.into(),
+            message_id: message_id.into(),
+            to: to.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl PlatformIds for SendPinOverSmsRequestBody {
+    // `application_id` is the 2FA application and always set, so only the entity ID is filled.
+    fn fill_platform_ids(&mut self, _application_id: Option<&str>, entity_id: Option<&str>) {
+        fill_platform_id(&mut self.entity_id, entity_id);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SendPinResponseBody {
+    /// Call status, e.g. `PENDING_ACCEPTED`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_status: Option<String>,
+
+    /// Status of sent [Number Lookup](https://www.infobip.com/docs/number-lookup). Number Lookup status can have one of the following values: `NC_DESTINATION_UNKNOWN`, `NC_DESTINATION_REACHABLE`, `NC_DESTINATION_NOT_REACHABLE`, `NC_NOT_CONFIGURED`. Contact your Account Manager, if you get the `NC_NOT_CONFIGURED` status. SMS will not be sent only if Number Lookup status is `NC_NOT_REACHABLE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nc_status: Option<String>,
+
+    /// Sent PIN code ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_id: Option<String>,
+
+    /// Sent SMS status. Can have one of the following values: `MESSAGE_SENT`, `MESSAGE_NOT_SENT`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_status: Option<String>,
+
+    /// Phone number to which the 2FA message will be sent. Example: `41793026727`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl SendPinResponseBody {
+    /// Returns the typed status of the sent SMS, if any.
+    pub fn sms_pin_status(&self) -> Option<PinStatus> {
+        self.sms_status.as_deref().map(PinStatus::from)
+    }
+
+    /// Returns the typed status of the voice call, if any.
+    pub fn call_pin_status(&self) -> Option<PinStatus> {
+        self.call_status.as_deref().map(PinStatus::from)
+    }
+}
+
+/// Status of a sent PIN, from the `sms_status` or `call_status` of a send response.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PinStatus {
+    /// The message or call was accepted and is pending, `PENDING_ACCEPTED`.
+    PendingAccepted,
+
+    /// The message was sent, `MESSAGE_SENT`.
+    MessageSent,
+
+    /// The message was not sent, `MESSAGE_NOT_SENT`.
+    MessageNotSent,
+
+    /// A status not known to this version of the SDK.
+    Other(String),
+}
+
+impl PinStatus {
+    /// Returns the status as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PinStatus::PendingAccepted => "PENDING_ACCEPTED",
+            PinStatus::MessageSent => "MESSAGE_SENT",
+            PinStatus::MessageNotSent => "MESSAGE_NOT_SENT",
+            PinStatus::Other(status) => status,
+        }
+    }
+
+    /// Returns `true` unless the PIN is known not to have been sent.
+    pub fn is_sent(&self) -> bool {
+        !matches!(self, PinStatus::MessageNotSent)
+    }
+}
+
+impl From<&str> for PinStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "PENDING_ACCEPTED" => PinStatus::PendingAccepted,
+            "MESSAGE_SENT" => PinStatus::MessageSent,
+            "MESSAGE_NOT_SENT" => PinStatus::MessageNotSent,
+            other => PinStatus::Other(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PinStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+pub type SendPinOverSmsResponseBody = SendPinResponseBody;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ResendPinRequestBody {
+    /// Key value pairs that will be replaced during message sending. Placeholder keys should NOT contain curly brackets and should NOT contain a pin placeholder. Valid example: "placeholders":{"firstName":"John"}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholders: Option<HashMap<String, String>>,
+}
+
+impl ResendPinRequestBody {
+    pub fn new() -> Self {
+        Self { placeholders: None }
+    }
+}
+
+pub type ResendPinOverSmsRequestBody = ResendPinRequestBody;
+
+pub type ResendPinOverSmsResponseBody = SendPinResponseBody;
+
+pub type SendPinOverVoiceRequestBody = SendPinOverSmsRequestBody;
+
+pub type SendPinOverVoiceResponseBody = SendPinResponseBody;
+
+pub type ResendPinOverVoiceRequestBody = ResendPinRequestBody;
+
+pub type ResendPinOverVoiceResponseBody = SendPinResponseBody;
+
+/// Delivery reports of voice calls, e.g. of PINs sent by Voice, as sent to the notify URL of the
+/// voice messages.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceDeliveryReports {
+    /// Reports of the calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<VoiceReport>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct VoiceReport {
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// Callback data sent with the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+
+    /// Date and time when the call ended. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+
+    /// Duration of the call in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+
+    /// Error of the call, if it failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<VoiceError>,
+
+    /// Number the call was made from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Mobile country and network codes of the called number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcc_mnc: Option<String>,
+
+    /// The ID that uniquely identifies the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Price of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<VoicePrice>,
+
+    /// Date and time when the call was made. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
+
+    /// Status of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<VoiceStatus>,
+
+    /// Called number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Details of the call, like when it was answered and the keys pressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_call: Option<VoiceCall>,
+
+    /// Fields of the report not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl VoiceReport {
+    /// Returns `true` if the call was answered, by a person or a machine.
+    pub fn is_answered(&self) -> bool {
+        self.voice_call
+            .as_ref()
+            .is_some_and(|call| call.answer_time.is_some())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceCall {
+    /// Date and time when the call was answered. Not set for unanswered calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer_time: Option<String>,
+
+    /// Duration of the call that was charged, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charged_duration: Option<i32>,
+
+    /// DTMF codes entered by the called person during the call, separated by commas. See
+    /// `dtmf_inputs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dtmf_codes: Option<String>,
+
+    /// Duration of the call in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+
+    /// Date and time when the call ended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+
+    /// Voice feature of the call, e.g. `Text-to-Speech`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+
+    /// Duration of the played message in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f64))]
+    pub file_duration: Option<f64>,
+
+    /// Date and time when the call started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+}
+
+impl VoiceCall {
+    /// Returns the DTMF codes entered during the call, in order, e.g. `["1#"]` when the called
+    /// person asked to replay the PIN.
+    pub fn dtmf_inputs(&self) -> Vec<&str> {
+        self.dtmf_codes
+            .iter()
+            .flat_map(|codes| codes.split(','))
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VoicePrice {
+    /// The currency in which the price is expressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    /// Price per second of the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f64))]
+    pub price_per_second: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceStatus {
+    /// Action that should be taken to eliminate the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Human-readable description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Status group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Status group name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    /// Status ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Status name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl VoiceStatus {
+    /// Returns the typed status group, from `group_id` or `group_name`.
+    pub fn group(&self) -> Option<StatusGroup> {
+        StatusGroup::resolve(self.group_id, self.group_name.as_deref())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceError {
+    /// Human-readable description of the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Error group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Error group name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    /// Error ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Error name, e.g. `EC_NO_ANSWER` for unanswered calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Tells if the error is permanent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permanent: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendPinOverEmailRequestBody {
+    /// The ID of the application that represents your service, e.g. 2FA for login, 2FA for changing the password, etc.
+    #[validate(length(min = 1))]
+    pub application_id: String,
+
+    /// Entity ID of the PIN, for attributing traffic on a multi-tenant platform (CPaaS X).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Email address of the sender. Must be from a verified sending domain. Example: `company@example.com`.
+    #[validate(email)]
+    pub from: String,
+
+    /// The ID of the message template (with the email template ID and PIN placeholder) that is sent to the recipient.
+    #[validate(length(min = 1))]
+    pub message_id: String,
+
+    /// Key value pairs that will be replaced during message sending. Placeholder keys should NOT contain curly brackets and should NOT contain a `pin` placeholder. Valid example: `\"placeholders\":{\"firstName\":\"John\"}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholders: Option<HashMap<String, String>>,
+
+    /// Email address to which the 2FA message will be sent. Example: `john.smith@example.com`.
+    #[validate(email)]
+    pub to: String,
+}
+
+impl SendPinOverEmailRequestBody {
+    pub fn new(application_id: &str, message_id: &str, from: &str, to: &str) -> Self {
+        Self {
+            application_id: application_id.into(),
+            from: from.into(),
+            message_id: message_id.into(),
+            to: to.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl PlatformIds for SendPinOverEmailRequestBody {
+    // `application_id` is the 2FA application and always set, so only the entity ID is filled.
+    fn fill_platform_ids(&mut self, _application_id: Option<&str>, entity_id: Option<&str>) {
+        fill_platform_id(&mut self.entity_id, entity_id);
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct EmailStatus {
+    /// Status name, e.g. `PENDING_ACCEPTED`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Human-readable description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendPinOverEmailResponseBody {
+    /// Status of the sent email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_status: Option<EmailStatus>,
+
+    /// Sent PIN code ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_id: Option<String>,
+
+    /// Email address to which the 2FA message was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+pub type ResendPinOverEmailRequestBody = ResendPinRequestBody;
+
+pub type ResendPinOverEmailResponseBody = SendPinOverEmailResponseBody;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyPhoneNumberRequestBody {
+    /// ID of the pin code that has to be verified.
+    #[validate(length(min = 1))]
+    pub pin: String,
+}
+
+impl VerifyPhoneNumberRequestBody {
+    pub fn new(pin: &str) -> Self {
+        Self { pin: pin.into() }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct VerifyPhoneNumberResponseBody {
+    /// Number of remaining PIN attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts_remaining: Option<i32>,
+    /// Phone number (`MSISDN`) to which the 2FA message was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msisdn: Option<String>,
+    /// Indicates whether an error has occurred during PIN verification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_error: Option<String>,
+    /// Sent PIN code ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_id: Option<String>,
+    /// Indicates whether the phone number (`MSISDN`) was successfully verified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl VerifyPhoneNumberResponseBody {
+    /// Returns the outcome of the verification, so callers don't have to match `pin_error`
+    /// strings.
+    pub fn outcome(&self) -> VerifyOutcome {
+        if self.verified == Some(true) {
+            return VerifyOutcome::Verified;
+        }
+
+        match self.pin_error.as_deref() {
+            Some("WRONG_PIN") => VerifyOutcome::WrongPin {
+                attempts_remaining: self.attempts_remaining.unwrap_or(0).max(0) as u32,
+            },
+            Some("TTL_EXPIRED") => VerifyOutcome::Expired,
+            Some("NO_MORE_PIN_ATTEMPTS") => VerifyOutcome::AttemptsExhausted,
+            pin_error => VerifyOutcome::Failed {
+                pin_error: pin_error.map(str::to_string),
+            },
+        }
+    }
+}
+
+/// Outcome of a PIN verification, see `VerifyPhoneNumberResponseBody::outcome`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyOutcome {
+    /// The PIN was correct and the phone number is verified.
+    Verified,
+
+    /// The PIN was wrong. It can be verified again while attempts remain.
+    WrongPin { attempts_remaining: u32 },
+
+    /// The PIN expired before it was verified. A new PIN has to be sent.
+    Expired,
+
+    /// All attempts to verify the PIN were used. A new PIN has to be sent.
+    AttemptsExhausted,
+
+    /// The PIN was not verified for another reason, given by the API in `pin_error`.
+    Failed { pin_error: Option<String> },
+}
+
+impl VerifyOutcome {
+    /// Returns `true` if the phone number was verified.
+    pub fn is_verified(&self) -> bool {
+        matches!(self, VerifyOutcome::Verified)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TfaVerificationStatusQueryParameters {
+    /// Filter by msisdn (phone number) for which verification status is checked.
+    pub msisdn: String,
+
+    /// Filter by verified (true or false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+
+    /// Filter by message sent status (true or false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent: Option<bool>,
+}
+
+impl TfaVerificationStatusQueryParameters {
+    pub fn new(msisdn: &str) -> Self {
+        Self {
+            msisdn: msisdn.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TfaVerification {
+    /// Phone number (MSISDN) for which verification status is checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msisdn: Option<String>,
+
+    /// Sent UNIX timestamp (in millis), if the phone number (MSISDN) is verified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<i64>,
+
+    /// Indicates if the phone number (MSISDN) is already verified for 2FA application with given ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+
+    /// Verification UNIX timestamp (in millis), if the phone number (MSISDN) is verified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_at: Option<i64>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TfaVerificationStatusResponseBody {
+    /// Collection of verifications
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verifications: Option<Vec<TfaVerification>>,
+}
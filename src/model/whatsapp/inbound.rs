@@ -0,0 +1,338 @@
+//! Typed models of the inbound messages Infobip sends to webhooks when customers write to a
+//! WhatsApp sender, for routing them by type in bots.
+//!
+//! Each received message is an [`InboundMessage`], whose content is one of the variants of
+//! [`InboundKind`], selected by the `type` field of the payload. Message types the SDK doesn't
+//! know yet are kept as `InboundKind::Unsupported`, so new types don't fail the whole payload.
+//!
+//! # Example
+//! ```
+//! # use infobip_sdk::model::whatsapp::inbound::{InboundKind, InboundMessagesBody};
+//! let payload = r#"{
+//!     "results": [{
+//!         "from": "385977666618",
+//!         "to": "447860099299",
+//!         "integrationType": "WHATSAPP",
+//!         "messageId": "ABEGOFl3ZmYfAgo-sF_kfQ3ihYC9",
+//!         "message": {
+//!             "type": "INTERACTIVE_BUTTON_REPLY",
+//!             "id": "confirm",
+//!             "title": "Confirm"
+//!         }
+//!     }],
+//!     "messageCount": 1,
+//!     "pendingMessageCount": 0
+//! }"#;
+//!
+//! let body: InboundMessagesBody = serde_json::from_str(payload).unwrap();
+//!
+//! match &body.results[0].message.kind {
+//!     InboundKind::InteractiveButtonReply(reply) => assert_eq!(reply.id, "confirm"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+#[cfg(feature = "extra-fields")]
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::model::callback_data::{self, CallbackDataError};
+use crate::model::whatsapp::{Contact, InboundContact, InboundOrderProductItem, InboundPrice};
+
+/// A media file sent by the customer. The URL needs authentication to download.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMedia {
+    /// URL of the media file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Caption of an image, video or document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// A location shared by the customer.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundLocation {
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+    pub latitude: f64,
+
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+    pub longitude: f64,
+
+    /// Name of the location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Address of the location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// URL of the location, e.g. of the business.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Contacts shared by the customer.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundContacts {
+    #[serde(default)]
+    pub contacts: Vec<Contact>,
+}
+
+/// A click on a quick reply button of a template message.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundButton {
+    /// Text of the button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Payload of the button, as set when sending the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+}
+
+/// A reply to an interactive buttons or list message.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundReply {
+    /// ID of the chosen button or list row, as set when sending the message.
+    pub id: String,
+
+    /// Title of the chosen button or list row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Description of the chosen list row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// An order placed from a catalog message.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundOrderContent {
+    /// The ID of the catalog the ordered products belong to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_id: Option<String>,
+
+    /// Text sent by the customer along with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Ordered products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_items: Option<Vec<InboundOrderProductItem>>,
+}
+
+/// Content of an inbound message, by message type.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InboundKind {
+    Text {
+        text: String,
+    },
+    Image(InboundMedia),
+    Document(InboundMedia),
+    Video(InboundMedia),
+    Audio(InboundMedia),
+    Voice(InboundMedia),
+    Sticker(InboundMedia),
+    Location(InboundLocation),
+    Contact(InboundContacts),
+    Button(InboundButton),
+    InteractiveButtonReply(InboundReply),
+    InteractiveListReply(InboundReply),
+    Order(InboundOrderContent),
+    /// A message type the SDK doesn't know.
+    #[default]
+    #[serde(other)]
+    Unsupported,
+}
+
+/// The message the inbound message replies to.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundContext {
+    /// Sender of the message replied to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// ID of the message replied to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// The ad or post the customer clicked to start the conversation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundReferral {
+    /// URL of the ad or post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+
+    /// ID of the ad or post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+
+    /// Type of the source, `AD` or `POST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+
+    /// Headline of the ad or post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+
+    /// Body of the ad or post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Type of the media of the ad or post, `IMAGE` or `VIDEO`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+
+    /// URL of the image of the ad or post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+
+    /// URL of the video of the ad or post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_url: Option<String>,
+
+    /// ID of the click on a click-to-WhatsApp ad.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctwa_clid: Option<String>,
+}
+
+/// Content of an inbound message, with the message it replies to and the ad that started the
+/// conversation, if any.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundContent {
+    #[serde(flatten)]
+    pub kind: InboundKind,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<InboundContext>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referral: Option<InboundReferral>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct InboundMessage {
+    /// Number of the customer who sent the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Registered WhatsApp sender number that received the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Type of the integration. Always `WHATSAPP`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_type: Option<String>,
+
+    /// Date and time when the message was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_at: Option<String>,
+
+    /// The ID that uniquely identifies the received message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// The ID of the sent message the customer replied to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paired_message_id: Option<String>,
+
+    /// Callback data sent through the `callbackData` field when sending the paired message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+
+    /// Content of the message.
+    #[serde(default)]
+    pub message: InboundContent,
+
+    /// Information about the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<InboundContact>,
+
+    /// Price of the received message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<InboundPrice>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl InboundMessage {
+    /// Returns the text the customer wrote: the text of a text message, the caption of media,
+    /// or the text of an order.
+    pub fn text(&self) -> Option<&str> {
+        match &self.message.kind {
+            InboundKind::Text { text } => Some(text),
+            InboundKind::Image(media)
+            | InboundKind::Document(media)
+            | InboundKind::Video(media) => media.caption.as_deref(),
+            InboundKind::Order(order) => order.text.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the chosen interactive button or list row, or the payload of the
+    /// clicked template button, for routing replies.
+    pub fn reply_id(&self) -> Option<&str> {
+        match &self.message.kind {
+            InboundKind::InteractiveButtonReply(reply)
+            | InboundKind::InteractiveListReply(reply) => Some(&reply.id),
+            InboundKind::Button(button) => button.payload.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Parses the callback data of the message as JSON, see `model::callback_data`. Returns
+    /// `None` if the paired message had no callback data.
+    pub fn parse_callback_data<T: DeserializeOwned>(&self) -> Result<Option<T>, CallbackDataError> {
+        callback_data::parse_field(self.callback_data.as_deref())
+    }
+}
+
+/// Payload of the webhook request sent by Infobip when customers send messages.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMessagesBody {
+    /// Received messages.
+    #[serde(default)]
+    pub results: Vec<InboundMessage>,
+
+    /// Number of returned messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Number of messages that have not been pulled in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_message_count: Option<i32>,
+}
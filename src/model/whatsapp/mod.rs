@@ -0,0 +1,4144 @@
+//! Models for calling WhatsApp endpoints.
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+use validator::{Validate, ValidationError};
+
+use crate::model::callback_data::{self, CallbackDataError};
+use crate::model::status::{SendResult, StatusGroup};
+use crate::model::{fill_platform_id, ParseCodeError, PlatformIds};
+
+pub mod inbound;
+
+impl_try_from_value!(
+    SendTemplateRequestBody,
+    CreateTemplateRequestBody,
+    UpdateTemplateRequestBody,
+);
+
+impl_query_params!(
+    TemplateLibraryQueryParameters,
+    DeliveryReportsQueryParameters,
+    LogsQueryParameters,
+    SenderQualityQueryParameters,
+);
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TextContent {
+    /// Content of the message being sent.
+    #[validate(length(min = 1, max = 4096))]
+    pub text: String,
+
+    /// Allows for URL preview from within the message. If set to true, the message content must
+    /// contain a URL starting with https:// or http://. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_url: Option<bool>,
+}
+
+impl TextContent {
+    pub fn new(text: &str) -> Self {
+        TextContent {
+            text: text.into(),
+            preview_url: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentContent {
+    /// URL of a document sent in a WhatsApp message. Must be a valid URL starting with `https://`
+    /// or `http://`. Maximum document size is 100MB.
+    #[validate(url)]
+    pub media_url: String,
+
+    /// Caption of the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 3000))]
+    pub caption: Option<String>,
+
+    /// File name of the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 240))]
+    pub filename: Option<String>,
+}
+
+impl DocumentContent {
+    pub fn new(media_url: &str) -> Self {
+        DocumentContent {
+            media_url: media_url.into(),
+            caption: None,
+            filename: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ImageContent {
+    /// URL of an image sent in a WhatsApp message. Must be a valid URL starting with `https://`
+    /// or `http://`. Supported image types are `JPG`, `JPEG`, `PNG`. Maximum image size is 5MB.
+    #[validate(url)]
+    pub media_url: String,
+
+    /// Caption of the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 3000))]
+    pub caption: Option<String>,
+}
+
+impl ImageContent {
+    pub fn new(media_url: &str) -> Self {
+        ImageContent {
+            media_url: media_url.into(),
+            caption: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct AudioContent {
+    /// URL of an audio sent in a WhatsApp message. Must be a valid URL starting with `https://`
+    /// or `http://`. Supported audio types are `AAC`, `AMR`, `MP3`, `MP4`, `OPUS`. Maximum audio
+    /// size is 16MB.
+    #[validate(url)]
+    pub media_url: String,
+}
+
+impl AudioContent {
+    pub fn new(media_url: &str) -> Self {
+        AudioContent {
+            media_url: media_url.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct VideoContent {
+    /// URL of a video sent in a WhatsApp message. Must be a valid URL starting with `https://` or
+    /// `http://`. Supported video types are `MP4`, `3GPP`. Maximum video size is 16MB.
+    #[validate(url)]
+    pub media_url: String,
+
+    /// Caption of the video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 3000))]
+    pub caption: Option<String>,
+}
+
+impl VideoContent {
+    pub fn new(media_url: &str) -> Self {
+        VideoContent {
+            media_url: media_url.into(),
+            caption: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct StickerContent {
+    /// URL of a sticker sent in a WhatsApp message. Must be a valid URL starting with `https://`
+    /// or `http://`. Supported sticker type is `WebP`. Sticker file should be 512x512 pixels.
+    /// Maximum sticker size is 100KB.
+    #[validate(url)]
+    pub media_url: String,
+}
+
+impl StickerContent {
+    pub fn new(media_url: &str) -> Self {
+        StickerContent {
+            media_url: media_url.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct LocationContent {
+    /// Latitude of a location sent in the WhatsApp message.
+    #[validate(range(min = -90.0, max = 90.0))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+    pub latitude: f64,
+
+    /// Longitude of a location sent in the WhatsApp message.
+    #[validate(range(min = -180.0, max = 180.0))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+    pub longitude: f64,
+
+    /// Location name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 1000))]
+    pub name: Option<String>,
+
+    /// Location address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 1000))]
+    pub address: Option<String>,
+}
+
+impl LocationContent {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        LocationContent {
+            latitude,
+            longitude,
+            name: None,
+            address: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AddressType {
+    Home,
+    Work,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactAddress {
+    /// Street name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+
+    /// City name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// State name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Zip code value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+
+    /// Country name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Country code value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+
+    /// Type of the address. Can be `HOME` or `WORK`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub address_type: Option<AddressType>,
+}
+
+impl ContactAddress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+pub type EmailType = AddressType;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactName {
+    /// Contact's first name.
+    #[validate(length(min = 1))]
+    pub first_name: String,
+
+    /// Contact's last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Contact's middle name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<String>,
+
+    /// Contact's name prefix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_suffix: Option<String>,
+
+    /// Contact's name prefix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_prefix: Option<String>,
+
+    /// Contact's full name as it normally appears.
+    #[validate(length(min = 1))]
+    pub formatted_name: String,
+}
+
+impl ContactName {
+    pub fn new(first_name: &str, formatted_name: &str) -> Self {
+        Self {
+            first_name: first_name.into(),
+            formatted_name: formatted_name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactOrganization {
+    /// Company name.
+    pub company: Option<String>,
+
+    /// Department name.
+    pub department: Option<String>,
+
+    /// Title value
+    pub title: Option<String>,
+}
+
+impl ContactOrganization {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PhoneType {
+    Cell,
+    Main,
+    Iphone,
+    Home,
+    Work,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactPhone {
+    /// Contact's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// Type of the phone number. Can be `CELL`, `MAIN`, `IPHONE`, `HOME` or `WORK`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub phone_type: Option<PhoneType>,
+
+    /// Contact's WhatsApp ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wa_id: Option<String>,
+}
+
+impl ContactPhone {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+pub type UrlType = AddressType;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactUrl {
+    /// Contact's url.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url)]
+    pub url: Option<String>,
+
+    /// Type of the url. Can be `HOME` or `WORK`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub url_type: Option<UrlType>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactEmail {
+    /// Contact's email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(email)]
+    pub email: Option<String>,
+
+    /// Type of the email. Can be HOME or WORK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<EmailType>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+    /// Array of addresses information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<Vec<ContactAddress>>,
+
+    /// Date of birth in `YYYY-MM-DD` format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<String>,
+
+    /// Array of emails information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub emails: Option<Vec<ContactEmail>>,
+
+    /// Contains information about contact's name.
+    pub name: ContactName,
+
+    /// Contains information about contact's company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org: Option<ContactOrganization>,
+
+    /// Array of phones information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phones: Option<Vec<ContactPhone>>,
+
+    /// Array of urls information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urls: Option<Vec<ContactUrl>>,
+}
+
+impl Contact {
+    pub fn new(name: ContactName) -> Self {
+        Contact {
+            addresses: None,
+            birthday: None,
+            emails: None,
+            name,
+            org: None,
+            phones: None,
+            urls: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactContent {
+    /// An array of contacts sent in a WhatsApp message.
+    #[validate(length(min = 1))]
+    #[validate]
+    pub contacts: Vec<Contact>,
+}
+
+impl ContactContent {
+    pub fn new(contacts: Vec<Contact>) -> Self {
+        ContactContent { contacts }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendContentRequestBody<T: serde::Serialize + Validate> {
+    /// Registered WhatsApp sender number. Must be in international format and comply with
+    /// WhatsApp's requirements.
+    #[validate(length(min = 1, max = 24))]
+    pub from: String,
+
+    /// Message recipient number. Must be in international format.
+    #[validate(length(min = 1, max = 24))]
+    pub to: String,
+
+    /// The ID that uniquely identifies the message sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 50))]
+    pub message_id: Option<String>,
+
+    /// The content object to build a message that will be sent.
+    #[validate]
+    pub content: T,
+
+    /// Custom client data that will be included in a Delivery Report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 0, max = 4000))]
+    pub callback_data: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url)]
+    pub notify_url: Option<String>,
+
+    /// ID of the CPaaS X entity the message is sent for, e.g. a customer of the platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// ID of the CPaaS X application the message is sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+}
+
+impl<T> TryFrom<serde_json::Value> for SendContentRequestBody<T>
+where
+    T: serde::Serialize + DeserializeOwned + Validate,
+{
+    type Error = crate::api::SdkError;
+
+    /// Deserializes the body from JSON and validates it.
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        let body: Self = serde_json::from_value(value)?;
+        body.validate()?;
+
+        Ok(body)
+    }
+}
+
+impl<T: serde::Serialize + Validate> SendContentRequestBody<T> {
+    /// Creates a request body with any content type, like the `new` function of each message
+    /// type.
+    pub fn with_content(from: &str, to: &str, content: T) -> Self {
+        SendContentRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+
+    /// Sets the ID of the message.
+    pub fn with_message_id(mut self, message_id: &str) -> Self {
+        self.message_id = Some(message_id.into());
+        self
+    }
+
+    /// Sets the data included in the delivery report.
+    pub fn with_callback_data(mut self, callback_data: &str) -> Self {
+        self.callback_data = Some(callback_data.into());
+        self
+    }
+
+    /// Sets the URL the delivery report is sent to.
+    pub fn with_notify_url(mut self, notify_url: &str) -> Self {
+        self.notify_url = Some(notify_url.into());
+        self
+    }
+}
+
+impl<T: serde::Serialize + Validate> PlatformIds for SendContentRequestBody<T> {
+    fn fill_platform_ids(&mut self, application_id: Option<&str>, entity_id: Option<&str>) {
+        fill_platform_id(&mut self.application_id, application_id);
+        fill_platform_id(&mut self.entity_id, entity_id);
+    }
+}
+
+pub type SendTextRequestBody = SendContentRequestBody<TextContent>;
+
+impl SendTextRequestBody {
+    pub fn new(from: &str, to: &str, content: TextContent) -> Self {
+        SendTextRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendDocumentRequestBody = SendContentRequestBody<DocumentContent>;
+
+impl SendDocumentRequestBody {
+    pub fn new(from: &str, to: &str, content: DocumentContent) -> Self {
+        SendDocumentRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendImageRequestBody = SendContentRequestBody<ImageContent>;
+
+impl SendImageRequestBody {
+    pub fn new(from: &str, to: &str, content: ImageContent) -> Self {
+        SendImageRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendAudioRequestBody = SendContentRequestBody<AudioContent>;
+
+impl SendAudioRequestBody {
+    pub fn new(from: &str, to: &str, content: AudioContent) -> Self {
+        SendAudioRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendVideoRequestBody = SendContentRequestBody<VideoContent>;
+
+impl SendVideoRequestBody {
+    pub fn new(from: &str, to: &str, content: VideoContent) -> Self {
+        SendVideoRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendStickerRequestBody = SendContentRequestBody<StickerContent>;
+
+impl SendStickerRequestBody {
+    pub fn new(from: &str, to: &str, content: StickerContent) -> Self {
+        SendStickerRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendLocationRequestBody = SendContentRequestBody<LocationContent>;
+
+impl SendLocationRequestBody {
+    pub fn new(from: &str, to: &str, content: LocationContent) -> Self {
+        SendLocationRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+pub type SendContactRequestBody = SendContentRequestBody<ContactContent>;
+
+impl SendContactRequestBody {
+    pub fn new(from: &str, to: &str, content: ContactContent) -> Self {
+        SendContactRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+/// Maximum number of characters in the text header of an interactive message.
+pub const MAX_INTERACTIVE_HEADER_TEXT_LENGTH: usize = 60;
+
+/// Maximum number of characters in the title of a reply button.
+pub const MAX_BUTTON_TITLE_LENGTH: usize = 20;
+
+/// Maximum number of characters in the ID of a reply button.
+pub const MAX_BUTTON_ID_LENGTH: usize = 256;
+
+/// Maximum number of rows in all sections of an interactive list.
+pub const MAX_LIST_ROWS: usize = 10;
+
+lazy_static::lazy_static! {
+    // WhatsApp formatting: *bold*, _italic_, ~strikethrough~ and ```monospace```.
+    static ref MARKDOWN: Regex = Regex::new(r"\*[^*\s][^*]*\*|_[^_\s][^_]*_|~[^~\s][^~]*~|```").unwrap();
+}
+
+// Returns `true` for pictographic emoji, flags, and the joiners and selectors that combine them.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0x200D | 0xFE0F | 0x20E3
+    )
+}
+
+fn validation_error(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(message.into());
+    error
+}
+
+// Checks text that WhatsApp shows without formatting, like button and list titles.
+fn validate_plain_text(text: &str) -> Result<(), ValidationError> {
+    if text.chars().any(is_emoji) {
+        return Err(validation_error(
+            "emoji_not_allowed",
+            format!("emojis are not allowed: {}", text),
+        ));
+    }
+    if MARKDOWN.is_match(text) {
+        return Err(validation_error(
+            "markdown_not_allowed",
+            format!("markdown is not allowed: {}", text),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_header_text(text: &str) -> Result<(), ValidationError> {
+    let length = text.chars().count();
+    if length == 0 || length > MAX_INTERACTIVE_HEADER_TEXT_LENGTH {
+        return Err(validation_error(
+            "header_text_length",
+            format!(
+                "header text must have 1 to {} characters, has {}",
+                MAX_INTERACTIVE_HEADER_TEXT_LENGTH, length
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_reply_buttons(buttons: &[InteractiveButton]) -> Result<(), ValidationError> {
+    for (index, InteractiveButton::ReplyButton { id, title }) in buttons.iter().enumerate() {
+        let position = index + 1;
+        let id_length = id.chars().count();
+        if id_length == 0 || id_length > MAX_BUTTON_ID_LENGTH || id.trim() != id {
+            return Err(validation_error(
+                "button_id",
+                format!(
+                    "button {}: ID must have 1 to {} characters, without leading or trailing \
+                     whitespace",
+                    position, MAX_BUTTON_ID_LENGTH
+                ),
+            ));
+        }
+        let title_length = title.chars().count();
+        if title_length == 0 || title_length > MAX_BUTTON_TITLE_LENGTH {
+            return Err(validation_error(
+                "button_title_length",
+                format!(
+                    "button {}: title must have 1 to {} characters, has {}",
+                    position, MAX_BUTTON_TITLE_LENGTH, title_length
+                ),
+            ));
+        }
+        validate_plain_text(title).map_err(|mut error| {
+            let message = error.message.take().unwrap_or_default();
+            error.message = Some(format!("button {}: {}", position, message).into());
+            error
+        })?;
+
+        let previous = &buttons[..index];
+        if previous.iter().any(|other| other.id() == id) {
+            return Err(validation_error(
+                "duplicate_button_id",
+                format!("button {}: ID {} is used by another button", position, id),
+            ));
+        }
+        if previous.iter().any(|other| other.title() == title) {
+            return Err(validation_error(
+                "duplicate_button_title",
+                format!(
+                    "button {}: title {} is used by another button",
+                    position, title
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_buttons_header(header: &InteractiveButtonsHeader) -> Result<(), ValidationError> {
+    match header {
+        InteractiveButtonsHeader::TextHeader { text } => validate_header_text(text),
+        _ => Ok(()),
+    }
+}
+
+fn validate_list_header(header: &InteractiveListHeader) -> Result<(), ValidationError> {
+    let InteractiveListHeader::TextHeader { text } = header;
+    validate_header_text(text)
+}
+
+fn validate_multiproduct_header(
+    header: &InteractiveMultiproductHeader,
+) -> Result<(), ValidationError> {
+    let InteractiveMultiproductHeader::TextHeader { text } = header;
+    validate_header_text(text)
+}
+
+fn validate_flow_header(header: &InteractiveFlowHeader) -> Result<(), ValidationError> {
+    let InteractiveFlowHeader::TextHeader { text } = header;
+    validate_header_text(text)
+}
+
+fn validate_list_sections(sections: &[InteractiveListSection]) -> Result<(), ValidationError> {
+    let rows: Vec<&InteractiveRow> = sections.iter().flat_map(|section| &section.rows).collect();
+    if rows.len() > MAX_LIST_ROWS {
+        return Err(validation_error(
+            "too_many_rows",
+            format!(
+                "a list can have up to {} rows in all sections, has {}",
+                MAX_LIST_ROWS,
+                rows.len()
+            ),
+        ));
+    }
+    if sections.len() > 1 {
+        if let Some(position) = sections.iter().position(|section| section.title.is_none()) {
+            return Err(validation_error(
+                "missing_section_title",
+                format!(
+                    "section {}: sections need a title when there are several",
+                    position + 1
+                ),
+            ));
+        }
+    }
+    for (index, row) in rows.iter().enumerate() {
+        if rows[..index].iter().any(|other| other.id == row.id) {
+            return Err(validation_error(
+                "duplicate_row_id",
+                format!("row ID {} is used by another row", row.id),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveBody {
+    /// Content of the message body.
+    #[validate(length(min = 1, max = 1024))]
+    pub text: String,
+}
+
+impl InteractiveBody {
+    pub fn new(text: &str) -> Self {
+        InteractiveBody { text: text.into() }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum InteractiveButton {
+    #[serde(rename = "REPLY")]
+    ReplyButton {
+        /// Unique identifier of the button containing no leading nor trailing white spaces.
+        #[serde(rename = "id")]
+        id: String,
+
+        /// Unique title of the button. Doesn't allow emojis or markdown.
+        #[serde(rename = "title")]
+        title: String,
+    },
+}
+
+impl InteractiveButton {
+    pub fn new_reply_button(id: &str, title: &str) -> Self {
+        InteractiveButton::ReplyButton {
+            id: id.into(),
+            title: title.into(),
+        }
+    }
+
+    /// Returns the ID of the button.
+    pub fn id(&self) -> &str {
+        match self {
+            InteractiveButton::ReplyButton { id, .. } => id,
+        }
+    }
+
+    /// Returns the title of the button.
+    pub fn title(&self) -> &str {
+        match self {
+            InteractiveButton::ReplyButton { title, .. } => title,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveButtonsAction {
+    /// An array of buttons sent in a message. It can have up to three buttons, with unique IDs
+    /// and titles.
+    #[validate(length(min = 1, max = 3), custom = "validate_reply_buttons")]
+    pub buttons: Vec<InteractiveButton>,
+}
+
+impl InteractiveButtonsAction {
+    pub fn new(buttons: Vec<InteractiveButton>) -> Self {
+        InteractiveButtonsAction { buttons }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum InteractiveButtonsHeader {
+    #[serde(rename = "DOCUMENT")]
+    DocumentHeader {
+        /// URL of a document sent in the header of a message containing one or more interactive
+        /// buttons. Must be a valid URL starting with `https://` or `http://`. Supported document
+        /// type is `PDF`. Maximum document size is 100MB.
+        #[serde(rename = "mediaUrl")]
+        media_url: String,
+
+        /// Filename of the document.
+        #[serde(rename = "filename", skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+    },
+
+    #[serde(rename = "IMAGE")]
+    ImageHeader {
+        /// URL of an image sent in the header of a message containing one or more interactive
+        /// buttons. Must be a valid URL starting with `https://` or `http://`. Supported image
+        /// types are `JPG`, `JPEG`, `PNG`. Maximum image size is 5MB.
+        #[serde(rename = "mediaUrl")]
+        media_url: String,
+    },
+
+    #[serde(rename = "TEXT")]
+    TextHeader {
+        /// Content of the header used when creating interactive buttons.
+        #[serde(rename = "text")]
+        text: String,
+    },
+
+    #[serde(rename = "VIDEO")]
+    VideoHeader {
+        /// URL of a video sent in the header of a message containing one or more interactive
+        /// buttons. Must be a valid URL starting with `https://` or `http://`. Supported video
+        /// types are `MP4`, `3GPP`. Maximum video size is 16MB.
+        #[serde(rename = "mediaUrl")]
+        media_url: String,
+    },
+}
+
+impl InteractiveButtonsHeader {
+    pub fn new_document_header(media_url: &str, filename: Option<String>) -> Self {
+        InteractiveButtonsHeader::DocumentHeader {
+            media_url: media_url.into(),
+            filename,
+        }
+    }
+
+    pub fn new_image_header(media_url: &str) -> Self {
+        InteractiveButtonsHeader::ImageHeader {
+            media_url: media_url.into(),
+        }
+    }
+
+    pub fn new_text_header(text: &str) -> Self {
+        InteractiveButtonsHeader::TextHeader { text: text.into() }
+    }
+
+    pub fn new_video_header(media_url: &str) -> Self {
+        InteractiveButtonsHeader::VideoHeader {
+            media_url: media_url.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveFooter {
+    /// Content of the message footer.
+    #[validate(length(min = 1, max = 60))]
+    pub text: String,
+}
+
+impl InteractiveFooter {
+    pub fn new(text: &str) -> Self {
+        InteractiveFooter { text: text.into() }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveButtonsContent {
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: InteractiveBody,
+
+    /// Allows you to specify buttons sent in the message.
+    #[validate]
+    pub action: InteractiveButtonsAction,
+
+    /// Header of a message containing one or more interactive elements. A text header can have
+    /// up to 60 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_buttons_header")]
+    pub header: Option<InteractiveButtonsHeader>,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveButtonsContent {
+    pub fn new(body: InteractiveBody, action: InteractiveButtonsAction) -> Self {
+        InteractiveButtonsContent {
+            body,
+            action,
+            header: None,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveButtonsRequestBody = SendContentRequestBody<InteractiveButtonsContent>;
+
+impl SendInteractiveButtonsRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveButtonsContent) -> Self {
+        SendInteractiveButtonsRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveRow {
+    /// Identifier of the row. It must be unique across all sections.
+    #[validate(length(min = 1, max = 200))]
+    pub id: String,
+
+    /// Title of the row.
+    #[validate(length(min = 1, max = 24))]
+    pub title: String,
+
+    /// Description of the row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 72))]
+    pub description: Option<String>,
+}
+
+impl InteractiveRow {
+    pub fn new(id: &str, title: &str) -> Self {
+        InteractiveRow {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveListSection {
+    /// Title of the section. Required, if the message has more than one section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 24))]
+    pub title: Option<String>,
+
+    /// An array of rows sent within a section. Section must contain at least one row. Message can have up to ten rows.
+    #[validate(length(min = 1))]
+    #[validate]
+    pub rows: Vec<InteractiveRow>,
+}
+
+impl InteractiveListSection {
+    pub fn new(rows: Vec<InteractiveRow>) -> Self {
+        InteractiveListSection { title: None, rows }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveListAction {
+    /// Title of the list. Does not allow emojis or markdown.
+    #[validate(length(min = 1, max = 20), custom = "validate_plain_text")]
+    pub title: String,
+
+    /// Array of sections in the list. Sections need titles if there are several, and can have up
+    /// to ten rows with unique IDs in total.
+    #[validate(length(min = 1, max = 10), custom = "validate_list_sections")]
+    #[validate]
+    pub sections: Vec<InteractiveListSection>,
+}
+
+impl InteractiveListAction {
+    pub fn new(title: &str, sections: Vec<InteractiveListSection>) -> Self {
+        InteractiveListAction {
+            title: title.into(),
+            sections,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum InteractiveListHeader {
+    #[serde(rename = "TEXT")]
+    TextHeader {
+        /// Content of the header used when creating an [interactive list](https://www.infobip.com/docs/whatsapp/message-types#interactive-lists-free-form-messages).
+        text: String,
+    },
+}
+
+impl InteractiveListHeader {
+    pub fn new_text_header(text: &str) -> Self {
+        InteractiveListHeader::TextHeader { text: text.into() }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveListContent {
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: InteractiveBody,
+
+    /// Allows you to specify the title of the list and its sections. Each section can have a title
+    /// and multiple rows to select.
+    #[validate]
+    pub action: InteractiveListAction,
+
+    /// Header of a message containing one or more interactive elements. A text header can have
+    /// up to 60 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_list_header")]
+    pub header: Option<InteractiveListHeader>,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveListContent {
+    pub fn new(body: InteractiveBody, action: InteractiveListAction) -> Self {
+        InteractiveListContent {
+            body,
+            action,
+            header: None,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveListRequestBody = SendContentRequestBody<InteractiveListContent>;
+
+impl SendInteractiveListRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveListContent) -> Self {
+        SendInteractiveListRequestBody {
+            from: from.into(),
+            to: to.into(),
+            message_id: None,
+            content,
+            callback_data: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveProductAction {
+    /// The ID that uniquely identifies the catalog registered with Meta and connected to the
+    /// WhatsApp Business Account the sender belongs to.
+    #[validate(length(min = 1))]
+    pub catalog_id: String,
+
+    /// Product-unique identifier, as defined in catalog.
+    #[validate(length(min = 1))]
+    pub product_retailer_id: String,
+}
+
+impl InteractiveProductAction {
+    pub fn new(catalog_id: &str, product_retailer_id: &str) -> Self {
+        InteractiveProductAction {
+            catalog_id: catalog_id.into(),
+            product_retailer_id: product_retailer_id.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveProductContent {
+    /// Allows you to specify catalog and product details sent in the product message.
+    #[validate]
+    pub action: InteractiveProductAction,
+
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: Option<InteractiveBody>,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveProductContent {
+    pub fn new(action: InteractiveProductAction) -> Self {
+        InteractiveProductContent {
+            action,
+            body: None,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveProductRequestBody = SendContentRequestBody<InteractiveProductContent>;
+
+impl SendInteractiveProductRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveProductContent) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            content,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum InteractiveMultiproductHeader {
+    #[serde(rename = "TEXT")]
+    TextHeader {
+        /// Content of the multi-product message header.
+        text: String,
+    },
+}
+
+impl InteractiveMultiproductHeader {
+    pub fn new_text_header(text: &str) -> Self {
+        InteractiveMultiproductHeader::TextHeader { text: text.into() }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveMultiproductSection {
+    /// Title of the section. Required, if the message has more than one section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 24))]
+    pub title: Option<String>,
+
+    /// An array of product-unique identifiers as defined in the catalog. If product retailer ID
+    /// doesn't exist in your catalog, the product won't be displayed.
+    pub product_retailer_ids: Vec<String>,
+}
+
+impl InteractiveMultiproductSection {
+    pub fn new(product_retailer_ids: Vec<String>) -> Self {
+        Self {
+            product_retailer_ids,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveMultiproductAction {
+    /// The ID that uniquely identifies the catalog registered with Meta and connected to the
+    /// WhatsApp Business Account the sender belongs to.
+    #[validate(length(min = 1))]
+    pub catalog_id: String,
+
+    /// An array of multi-product sections.
+    #[validate(length(min = 1, max = 10))]
+    #[validate]
+    pub sections: Vec<InteractiveMultiproductSection>,
+}
+
+impl InteractiveMultiproductAction {
+    pub fn new(catalog_id: &str, sections: Vec<InteractiveMultiproductSection>) -> Self {
+        Self {
+            catalog_id: catalog_id.into(),
+            sections,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveMultiproductContent {
+    /// Header of a message containing one or more interactive elements. The text can have up to
+    /// 60 characters.
+    #[validate(custom = "validate_multiproduct_header")]
+    pub header: InteractiveMultiproductHeader,
+
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: InteractiveBody,
+
+    /// Allows you to specify catalog and product details sent in the multi-product message.
+    #[validate]
+    pub action: InteractiveMultiproductAction,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveMultiproductContent {
+    pub fn new(
+        header: InteractiveMultiproductHeader,
+        body: InteractiveBody,
+        action: InteractiveMultiproductAction,
+    ) -> Self {
+        Self {
+            header,
+            body,
+            action,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveMultiproductRequestBody =
+    SendContentRequestBody<InteractiveMultiproductContent>;
+
+impl SendInteractiveMultiproductRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveMultiproductContent) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            content,
+            callback_data: None,
+            message_id: None,
+            notify_url: None,
+            entity_id: None,
+            application_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlowMode {
+    Draft,
+    Published,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlowAction {
+    Navigate,
+    DataExchange,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveFlowActionPayload {
+    /// ID of the first screen of the flow. Required when the flow action is `NAVIGATE`.
+    #[validate(length(min = 1))]
+    pub screen: String,
+
+    /// Input data for the first screen of the flow. Must be a non-empty object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "arbitrary",
+        arbitrary(with = crate::model::arbitrary_optional_json_map)
+    )]
+    pub data: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl InteractiveFlowActionPayload {
+    pub fn new(screen: &str) -> Self {
+        Self {
+            screen: screen.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveFlowAction {
+    /// The mode of the flow. `DRAFT` flows can only be sent for testing. Defaults to
+    /// `PUBLISHED`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<FlowMode>,
+
+    /// Version of the flow message. Currently, only `3` is supported.
+    #[validate(range(min = 1))]
+    pub flow_message_version: i32,
+
+    /// Token generated by the business to act as an identifier of the flow session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_token: Option<String>,
+
+    /// ID of the flow, as registered with Meta.
+    #[validate(length(min = 1))]
+    pub flow_id: String,
+
+    /// Text of the button that opens the flow. Doesn't allow emojis or markdown.
+    #[validate(length(min = 1, max = 20))]
+    pub call_to_action_button: String,
+
+    /// Action taken when the user opens the flow. Defaults to `NAVIGATE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_action: Option<FlowAction>,
+
+    /// Payload of the flow action. Required when the flow action is `NAVIGATE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub flow_action_payload: Option<InteractiveFlowActionPayload>,
+}
+
+impl InteractiveFlowAction {
+    pub fn new(flow_id: &str, call_to_action_button: &str) -> Self {
+        Self {
+            flow_message_version: 3,
+            flow_id: flow_id.into(),
+            call_to_action_button: call_to_action_button.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type")]
+pub enum InteractiveFlowHeader {
+    #[serde(rename = "TEXT")]
+    TextHeader {
+        /// Content of the flow message header.
+        text: String,
+    },
+}
+
+impl InteractiveFlowHeader {
+    pub fn new_text_header(text: &str) -> Self {
+        InteractiveFlowHeader::TextHeader { text: text.into() }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveFlowContent {
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: InteractiveBody,
+
+    /// Allows you to specify the flow to open and how to open it.
+    #[validate]
+    pub action: InteractiveFlowAction,
+
+    /// Header of a message containing one or more interactive elements. A text header can have
+    /// up to 60 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_flow_header")]
+    pub header: Option<InteractiveFlowHeader>,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveFlowContent {
+    pub fn new(body: InteractiveBody, action: InteractiveFlowAction) -> Self {
+        Self {
+            body,
+            action,
+            header: None,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveFlowRequestBody = SendContentRequestBody<InteractiveFlowContent>;
+
+impl SendInteractiveFlowRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveFlowContent) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            content,
+            ..Default::default()
+        }
+    }
+}
+
+/// Monetary amount, expressed as `value` divided by `offset`. E.g. `12.50` is value `1250` with
+/// offset `100`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmount {
+    /// Amount multiplied by the offset.
+    #[validate(range(min = 0))]
+    pub value: i64,
+
+    /// Offset of the value. Must be `100` for most currencies.
+    #[validate(range(min = 1))]
+    pub offset: i32,
+
+    /// Description of the amount, e.g. the reason of a discount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 60))]
+    pub description: Option<String>,
+}
+
+impl OrderAmount {
+    pub fn new(value: i64, offset: i32) -> Self {
+        Self {
+            value,
+            offset,
+            description: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct OrderItem {
+    /// Product-unique identifier, as defined in catalog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retailer_id: Option<String>,
+
+    /// Name of the item.
+    #[validate(length(min = 1, max = 60))]
+    pub name: String,
+
+    /// Price of a single item.
+    #[validate]
+    pub amount: OrderAmount,
+
+    /// Number of items ordered.
+    #[validate(range(min = 1))]
+    pub quantity: i32,
+
+    /// Discounted price of a single item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub sale_amount: Option<OrderAmount>,
+}
+
+impl OrderItem {
+    pub fn new(name: &str, amount: OrderAmount, quantity: i32) -> Self {
+        Self {
+            name: name.into(),
+            amount,
+            quantity,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct OrderDetails {
+    /// The ID of the catalog the items belong to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_id: Option<String>,
+
+    /// Items of the order.
+    #[validate(length(min = 1))]
+    #[validate]
+    pub items: Vec<OrderItem>,
+
+    /// Sum of the item amounts.
+    #[validate]
+    pub subtotal: OrderAmount,
+
+    /// Tax applied to the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub tax: Option<OrderAmount>,
+
+    /// Shipping costs of the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub shipping: Option<OrderAmount>,
+
+    /// Discount applied to the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub discount: Option<OrderAmount>,
+}
+
+impl OrderDetails {
+    pub fn new(items: Vec<OrderItem>, subtotal: OrderAmount) -> Self {
+        Self {
+            items,
+            subtotal,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderGoodsType {
+    DigitalGoods,
+    #[default]
+    PhysicalGoods,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPayment {
+    /// Name of the payment configuration set up in the WhatsApp Business Manager.
+    #[validate(length(min = 1, max = 60))]
+    pub payment_configuration: String,
+
+    /// Unique identifier of the order or invoice, used to match payment notifications.
+    #[validate(length(min = 1, max = 35))]
+    pub reference_id: String,
+}
+
+impl OrderPayment {
+    pub fn new(payment_configuration: &str, reference_id: &str) -> Self {
+        Self {
+            payment_configuration: payment_configuration.into(),
+            reference_id: reference_id.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveOrderDetailsAction {
+    /// Text of the button that opens the order details. Defaults to `Review and Pay`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 20))]
+    pub display_text: Option<String>,
+
+    /// Type of goods being ordered.
+    #[serde(rename = "type")]
+    pub goods_type: OrderGoodsType,
+
+    /// Payment details of the order.
+    #[validate]
+    pub payment: OrderPayment,
+
+    /// Currency of the order in ISO 4217 format, e.g. `INR`.
+    #[validate(length(equal = 3))]
+    pub currency: String,
+
+    /// Total amount of the order, including tax, shipping and discount.
+    #[validate]
+    pub total_amount: OrderAmount,
+
+    /// Details of the ordered items.
+    #[validate]
+    pub order: OrderDetails,
+}
+
+impl InteractiveOrderDetailsAction {
+    pub fn new(
+        payment: OrderPayment,
+        currency: &str,
+        total_amount: OrderAmount,
+        order: OrderDetails,
+    ) -> Self {
+        Self {
+            payment,
+            currency: currency.into(),
+            total_amount,
+            order,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveOrderDetailsContent {
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: InteractiveBody,
+
+    /// Allows you to specify the order and how it will be paid.
+    #[validate]
+    pub action: InteractiveOrderDetailsAction,
+
+    /// Header of a message containing one or more interactive elements. A text header can have
+    /// up to 60 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_buttons_header")]
+    pub header: Option<InteractiveButtonsHeader>,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveOrderDetailsContent {
+    pub fn new(body: InteractiveBody, action: InteractiveOrderDetailsAction) -> Self {
+        Self {
+            body,
+            action,
+            header: None,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveOrderDetailsRequestBody =
+    SendContentRequestBody<InteractiveOrderDetailsContent>;
+
+impl SendInteractiveOrderDetailsRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveOrderDetailsContent) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            content,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    #[default]
+    Pending,
+    Processing,
+    PartiallyShipped,
+    Shipped,
+    Completed,
+    Canceled,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveOrderStatusAction {
+    /// Reference ID of the order, as sent in the order details message.
+    #[validate(length(min = 1, max = 35))]
+    pub reference_id: String,
+
+    /// New status of the order.
+    pub status: OrderStatus,
+
+    /// Description of the status change, e.g. the reason of a cancellation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 120))]
+    pub description: Option<String>,
+}
+
+impl InteractiveOrderStatusAction {
+    pub fn new(reference_id: &str, status: OrderStatus) -> Self {
+        Self {
+            reference_id: reference_id.into(),
+            status,
+            description: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InteractiveOrderStatusContent {
+    /// Body of a message containing one or more interactive elements.
+    #[validate]
+    pub body: InteractiveBody,
+
+    /// Allows you to specify the order and its new status.
+    #[validate]
+    pub action: InteractiveOrderStatusAction,
+
+    /// Footer of a message containing one or more interactive elements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub footer: Option<InteractiveFooter>,
+}
+
+impl InteractiveOrderStatusContent {
+    pub fn new(body: InteractiveBody, action: InteractiveOrderStatusAction) -> Self {
+        Self {
+            body,
+            action,
+            footer: None,
+        }
+    }
+}
+
+pub type SendInteractiveOrderStatusRequestBody =
+    SendContentRequestBody<InteractiveOrderStatusContent>;
+
+impl SendInteractiveOrderStatusRequestBody {
+    pub fn new(from: &str, to: &str, content: InteractiveOrderStatusContent) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            content,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TemplateCategory {
+    AccountUpdate,
+    PaymentUpdate,
+    PersonalFinanceUpdate,
+    ShippingUpdate,
+    ReservationUpdate,
+    IssueResolution,
+    AppointmentUpdate,
+    TransportationUpdate,
+    TicketUpdate,
+    AlertUpdate,
+    AutoReply,
+    Marketing,
+    Transactional,
+    Otp,
+    Authentication,
+    Utility,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+// Declares `TemplateLanguage` from its variants and codes, so the serde names, `as_str` and
+// `FromStr` can't drift apart.
+macro_rules! template_languages {
+    ($($variant:ident => $code:literal,)+) => {
+        /// Language of a WhatsApp template, sent as its code, e.g. `en_GB`. Parse a code with
+        /// `str::parse` and get it back with `as_str` or `to_string`.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        #[non_exhaustive]
+        pub enum TemplateLanguage {
+            $(
+                #[serde(rename = $code)]
+                $variant,
+            )+
+            /// Also used for languages not known to this version of the SDK, received in a
+            /// response.
+            #[serde(rename = "unknown", other)]
+            Unknown,
+        }
+
+        impl TemplateLanguage {
+            /// Returns the code of the language, e.g. `en_GB`.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $code,)+
+                    Self::Unknown => "unknown",
+                }
+            }
+        }
+
+        impl FromStr for TemplateLanguage {
+            type Err = ParseCodeError;
+
+            /// Parses a language code, e.g. `en_GB`. Codes not known to this version of the SDK
+            /// are an error rather than `Unknown`.
+            fn from_str(code: &str) -> Result<Self, Self::Err> {
+                match code {
+                    $($code => Ok(Self::$variant),)+
+                    _ => Err(ParseCodeError::new("template language", code)),
+                }
+            }
+        }
+    };
+}
+
+template_languages! {
+    Af => "af",
+    Sq => "sq",
+    Ar => "ar",
+    Az => "az",
+    Bn => "bn",
+    Bg => "bg",
+    Ca => "ca",
+    ZhCn => "zh_CN",
+    ZhHk => "zh_HK",
+    ZhTw => "zh_TW",
+    Hr => "hr",
+    Cs => "cs",
+    Da => "da",
+    Nl => "nl",
+    En => "en",
+    EnGb => "en_GB",
+    EnUs => "en_US",
+    Et => "et",
+    Fil => "fil",
+    Fi => "fi",
+    Fr => "fr",
+    Ka => "ka",
+    De => "de",
+    El => "el",
+    Gu => "gu",
+    Ha => "ha",
+    He => "he",
+    Hi => "hi",
+    Hu => "hu",
+    Id => "id",
+    Ga => "ga",
+    It => "it",
+    Ja => "ja",
+    Kn => "kn",
+    Kk => "kk",
+    RwRw => "rw_RW",
+    Ko => "ko",
+    KyKg => "ky_KG",
+    Lo => "lo",
+    Lv => "lv",
+    Lt => "lt",
+    Mk => "mk",
+    Ms => "ms",
+    Ml => "ml",
+    Mr => "mr",
+    Nb => "nb",
+    Fa => "fa",
+    Pl => "pl",
+    PtBr => "pt_BR",
+    PtPt => "pt_PT",
+    Pa => "pa",
+    Ro => "ro",
+    Ru => "ru",
+    Sr => "sr",
+    Sk => "sk",
+    Sl => "sl",
+    Es => "es",
+    EsAr => "es_AR",
+    EsEs => "es_ES",
+    EsMx => "es_MX",
+    Sw => "sw",
+    Sv => "sv",
+    Ta => "ta",
+    Te => "te",
+    Th => "th",
+    Tr => "tr",
+    Uk => "uk",
+    Ur => "ur",
+    Uz => "uz",
+    Vi => "vi",
+    Zu => "zu",
+}
+
+impl fmt::Display for TemplateLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "format", rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TemplateHeader {
+    Document {
+        /// An example of a template header document a user could create. Should be a valid URL
+        /// that starts with `http` or `https`. Supported document type is `PDF`. Maximum document
+        /// size is 16MB. Cannot contain placeholders.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        example: Option<String>,
+    },
+    Image {
+        /// An example of a template header image a user could create. Should be a valid URL that
+        /// starts with `http` or `https`. Supported image types are `JPG`, `JPEG`, `PNG`. Maximum
+        /// image size is 16MB. Cannot contain placeholders.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        example: Option<String>,
+    },
+    Location,
+    Text {
+        /// Template header text. Can contain up to 60 characters, with one placeholder {{1}}.
+        text: String,
+        /// An example of the content for the template header a user could use. Cannot contain
+        /// placeholders.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        example: Option<String>,
+    },
+    Video {
+        /// An example of a template header video a user could use. Should be a valid URL that
+        /// starts with `http` or `https`. Supported video type is `MP4`. Maximum video size is
+        /// 16MB. Cannot contain placeholders.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        example: Option<String>,
+    },
+    /// A format not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+impl TemplateHeader {
+    pub fn new_text(text: &str) -> Self {
+        Self::Text {
+            text: text.into(),
+            example: None,
+        }
+    }
+
+    pub fn new_image(example: &str) -> Self {
+        Self::Image {
+            example: Some(example.into()),
+        }
+    }
+
+    pub fn new_video(example: &str) -> Self {
+        Self::Video {
+            example: Some(example.into()),
+        }
+    }
+
+    pub fn new_document(example: &str) -> Self {
+        Self::Document {
+            example: Some(example.into()),
+        }
+    }
+
+    pub fn new_location() -> Self {
+        Self::Location
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateFooter {
+    /// Plain text, up to 60 characters.
+    #[validate(length(max = 60))]
+    pub text: String,
+}
+
+impl TemplateFooter {
+    pub fn new(text: &str) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TemplateButton {
+    PhoneNumber {
+        /// Button text.
+        text: String,
+        /// Phone number to which a phone call would be placed by end-user when hitting the button.
+        #[serde(rename = "phoneNumber")]
+        phone_number: String,
+    },
+    QuickReply {
+        /// Button text.
+        text: String,
+    },
+    Url {
+        /// Button text.
+        text: String,
+        /// URL to which the end-user will be directed when hitting the button. URL is expected to
+        /// start with `https://` or `http://`. Can be static or dynamic. For dynamic URL
+        /// registration, add a placeholder {{1}} at the end of the link. Example:
+        /// `https://www.infobip.com/{{1}}`.
+        url: String,
+        /// An example of a URL a user could use. Should be a valid URL that starts with
+        /// `https://` or `http://`. Cannot contain placeholders.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        example: Option<String>,
+    },
+    /// A format not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+impl TemplateButton {
+    pub fn new_phone_number(text: &str, phone_number: &str) -> Self {
+        Self::PhoneNumber {
+            text: text.into(),
+            phone_number: phone_number.into(),
+        }
+    }
+
+    pub fn new_quick_reply(text: &str) -> Self {
+        Self::QuickReply { text: text.into() }
+    }
+
+    pub fn new_url(text: &str, url: &str) -> Self {
+        Self::Url {
+            text: text.into(),
+            url: url.into(),
+            example: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateBody {
+    /// Plain text or text with placeholders. Placeholders have to be correctly formatted and in
+    /// the correct order, regardless of other sections. Example: {{1}}, {{2}}, {{3}}...
+    #[validate(length(min = 1))]
+    pub text: String,
+
+    /// Placeholders examples. The number of examples has to be the same as the number of
+    /// placeholders. Examples cannot contain placeholders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<String>>,
+}
+
+impl TemplateBody {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.into(),
+            examples: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TemplateType {
+    Text,
+    Media,
+    Carousel,
+    /// Also used for types not known to this version of the SDK.
+    #[serde(other)]
+    Unsupported,
+}
+
+const MAX_CALL_TO_ACTION_BUTTONS: usize = 2;
+
+// The `Unknown` variants only stand for values received in responses, they can't be sent back.
+fn validate_known_language(language: &TemplateLanguage) -> Result<(), ValidationError> {
+    if *language == TemplateLanguage::Unknown {
+        return Err(ValidationError::new("unknown_template_language"));
+    }
+
+    Ok(())
+}
+
+fn validate_known_category(category: &TemplateCategory) -> Result<(), ValidationError> {
+    if *category == TemplateCategory::Unknown {
+        return Err(ValidationError::new("unknown_template_category"));
+    }
+
+    Ok(())
+}
+
+fn validate_known_header(header: &TemplateHeader) -> Result<(), ValidationError> {
+    if matches!(header, TemplateHeader::Unknown) {
+        return Err(ValidationError::new("unknown_template_header"));
+    }
+
+    Ok(())
+}
+
+fn validate_known_buttons(buttons: &[TemplateButton]) -> Result<(), ValidationError> {
+    if buttons
+        .iter()
+        .any(|button| matches!(button, TemplateButton::Unknown))
+    {
+        return Err(ValidationError::new("unknown_template_button"));
+    }
+
+    Ok(())
+}
+
+// Checks the combinations of template buttons the API accepts: quick replies alone, or call to
+// action buttons of different types.
+fn validate_template_buttons(buttons: &[TemplateButton]) -> Result<(), ValidationError> {
+    validate_known_buttons(buttons)?;
+
+    let quick_replies = buttons
+        .iter()
+        .filter(|button| matches!(button, TemplateButton::QuickReply { .. }))
+        .count();
+    let call_to_action: Vec<&TemplateButton> = buttons
+        .iter()
+        .filter(|button| {
+            matches!(
+                button,
+                TemplateButton::PhoneNumber { .. } | TemplateButton::Url { .. }
+            )
+        })
+        .collect();
+
+    if quick_replies > 0 && !call_to_action.is_empty() {
+        return Err(validation_error(
+            "mixed_template_buttons",
+            "buttons must be either all quick replies or all call to action buttons".to_string(),
+        ));
+    }
+    if call_to_action.len() > MAX_CALL_TO_ACTION_BUTTONS {
+        return Err(validation_error(
+            "too_many_call_to_action_buttons",
+            format!(
+                "buttons can have up to {} call to action buttons, has {}",
+                MAX_CALL_TO_ACTION_BUTTONS,
+                call_to_action.len()
+            ),
+        ));
+    }
+    for (index, button) in call_to_action.iter().enumerate() {
+        let same_type = call_to_action[..index]
+            .iter()
+            .any(|other| std::mem::discriminant(*other) == std::mem::discriminant(*button));
+        if same_type {
+            return Err(validation_error(
+                "duplicate_call_to_action_button",
+                "buttons can have only one phone number and one URL button".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_card_header(header: &TemplateHeader) -> Result<(), ValidationError> {
+    match header {
+        TemplateHeader::Image { .. } | TemplateHeader::Video { .. } => Ok(()),
+        _ => Err(ValidationError::new("card_header_must_be_image_or_video")),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCard {
+    /// Card header. Can be `image` or `video`, and must be of the same format for all cards.
+    #[validate(custom = "validate_card_header")]
+    pub header: TemplateHeader,
+
+    /// Card body.
+    #[validate]
+    pub body: TemplateBody,
+
+    /// Card buttons. Up to 2 buttons, all cards must have the same number and types of buttons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 2), custom = "validate_known_buttons")]
+    pub buttons: Option<Vec<TemplateButton>>,
+}
+
+impl TemplateCard {
+    pub fn new(header: TemplateHeader, body: TemplateBody) -> Self {
+        Self {
+            header,
+            body,
+            buttons: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCarousel {
+    /// Carousel cards, shown in a horizontally scrollable view. Between 2 and 10 cards.
+    #[validate(length(min = 2, max = 10))]
+    #[validate]
+    pub cards: Vec<TemplateCard>,
+}
+
+impl TemplateCarousel {
+    pub fn new(cards: Vec<TemplateCard>) -> Self {
+        Self { cards }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateStructure {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_known_header")]
+    /// Template header. Can be `image`, `document`, `video`, `location` or `text`.
+    pub header: Option<TemplateHeader>,
+
+    #[validate]
+    /// Template body.
+    pub body: TemplateBody,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    /// Template footer.
+    pub footer: Option<TemplateFooter>,
+
+    /// Template buttons. Can be either up to 3 `quick reply` buttons or up to 2 `call to action`
+    /// buttons. Call to action buttons must be unique in type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 3), custom = "validate_template_buttons")]
+    pub buttons: Option<Vec<TemplateButton>>,
+
+    /// Template carousel, only available for `MARKETING` templates. Shown after the body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub carousel: Option<TemplateCarousel>,
+
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub template_type: Option<TemplateType>,
+}
+
+impl TemplateStructure {
+    pub fn new(body: TemplateBody) -> Self {
+        Self {
+            body,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTemplateRequestBody {
+    /// Template name. Must only contain lowercase alphanumeric characters and underscores.
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Template language, one template with same name can have multiple transliterations.
+    #[validate(custom = "validate_known_language")]
+    pub language: TemplateLanguage,
+
+    /// Category of the template.
+    #[validate(custom = "validate_known_category")]
+    pub category: TemplateCategory,
+
+    /// Template structure.
+    #[validate]
+    pub structure: TemplateStructure,
+}
+
+impl CreateTemplateRequestBody {
+    pub fn new(
+        name: &str,
+        language: TemplateLanguage,
+        category: TemplateCategory,
+        structure: TemplateStructure,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            language,
+            category,
+            structure,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTemplateRequestBody {
+    /// New category of the template. Only templates that were not approved yet can change
+    /// category.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(custom = "validate_known_category")]
+    pub category: Option<TemplateCategory>,
+
+    /// New template structure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub structure: Option<TemplateStructure>,
+}
+
+impl UpdateTemplateRequestBody {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TemplateHeaderContent {
+    Document {
+        /// URL of a document sent in the header. It is expected to be a valid URL starting with
+        /// `https://` or `http://`. Supported document type is `PDF`. Maximum document size
+        /// is 100MB.
+        #[serde(rename = "mediaUrl")]
+        media_url: String,
+
+        /// Filename of the document.
+        #[serde(rename = "filename")]
+        filename: String,
+    },
+    Image {
+        /// URL of an image sent in the header. It is expected to be a valid URL starting with
+        /// `https://` or `http://`. Supported image types are `JPG`, `JPEG`, `PNG`. Maximum image
+        /// size is 5MB.
+        #[serde(rename = "mediaUrl")]
+        media_url: String,
+    },
+    Location {
+        /// Latitude of a location sent in the header.
+        #[serde(rename = "latitude")]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+        latitude: f64,
+
+        /// Longitude of a location sent in the header.
+        #[serde(rename = "longitude")]
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_finite_f64))]
+        longitude: f64,
+    },
+    Text {
+        /// Value of a placeholder in the text header.
+        #[serde(rename = "placeholder")]
+        placeholder: String,
+    },
+    Video {
+        /// URL of a video sent in the header. It is expected to be a valid URL starting with
+        /// `https://` or `http://`. Supported video types are `MP4`, `3GPP`. Maximum video size
+        /// is 16MB.
+        #[serde(rename = "mediaUrl")]
+        media_url: String,
+    },
+}
+
+impl TemplateHeaderContent {
+    pub fn new_document(media_url: &str, filename: &str) -> Self {
+        Self::Document {
+            media_url: media_url.into(),
+            filename: filename.into(),
+        }
+    }
+
+    pub fn new_image(media_url: &str) -> Self {
+        Self::Image {
+            media_url: media_url.into(),
+        }
+    }
+
+    pub fn new_location(latitude: f64, longitude: f64) -> Self {
+        Self::Location {
+            latitude,
+            longitude,
+        }
+    }
+
+    pub fn new_text(placeholder: &str) -> Self {
+        Self::Text {
+            placeholder: placeholder.into(),
+        }
+    }
+
+    pub fn new_video(media_url: &str) -> Self {
+        Self::Video {
+            media_url: media_url.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateBodyContent {
+    /// Template's parameter values submitted in the same order as in the registered template.
+    /// The value must not be null, but it can be an empty array, if the template was registered
+    /// without placeholders. Values within the array must not be null or empty.
+    pub placeholders: Vec<String>,
+}
+
+impl TemplateBodyContent {
+    pub fn new(placeholders: Vec<String>) -> Self {
+        Self { placeholders }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TemplateButtonContent {
+    QuickReply {
+        /// Payload of a `quick reply` button.
+        #[serde(rename = "parameter")]
+        parameter: String,
+    },
+    Url {
+        /// URL extension of a `dynamic URL` defined in the registered template.
+        #[serde(rename = "parameter")]
+        parameter: String,
+    },
+}
+
+impl TemplateButtonContent {
+    pub fn new_quick_reply(parameter: &str) -> Self {
+        TemplateButtonContent::QuickReply {
+            parameter: parameter.into(),
+        }
+    }
+
+    pub fn new_url(parameter: &str) -> Self {
+        TemplateButtonContent::Url {
+            parameter: parameter.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateData {
+    /// Template body.
+    #[validate]
+    pub body: TemplateBodyContent,
+
+    /// Template header. Should be defined, only if placeholders or media have been registered in
+    /// the template's header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<TemplateHeaderContent>,
+
+    /// Template buttons. Should be defined in correct order, only if `quick reply` or
+    /// `dynamic URL` buttons have been registered. It can have up to three `quick reply` buttons
+    /// or only one `dynamic URL` button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buttons: Option<Vec<TemplateButtonContent>>,
+
+    /// Template carousel. Should be defined only if the template was registered with a carousel,
+    /// with one card per registered card, in the same order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub carousel: Option<TemplateCarouselContent>,
+}
+
+impl TemplateData {
+    pub fn new(body: TemplateBodyContent) -> Self {
+        Self {
+            body,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCardContent {
+    /// Card header. Must be of the same format as the registered card header.
+    pub header: TemplateHeaderContent,
+
+    /// Card body.
+    #[validate]
+    pub body: TemplateBodyContent,
+
+    /// Card buttons. Should be defined in correct order, only if `quick reply` or `dynamic URL`
+    /// buttons have been registered for the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 2))]
+    pub buttons: Option<Vec<TemplateButtonContent>>,
+}
+
+impl TemplateCardContent {
+    pub fn new(header: TemplateHeaderContent, body: TemplateBodyContent) -> Self {
+        Self {
+            header,
+            body,
+            buttons: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCarouselContent {
+    /// Carousel cards. Between 2 and 10 cards.
+    #[validate(length(min = 2, max = 10))]
+    #[validate]
+    pub cards: Vec<TemplateCardContent>,
+}
+
+impl TemplateCarouselContent {
+    pub fn new(cards: Vec<TemplateCardContent>) -> Self {
+        Self { cards }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateContent {
+    /// Template name. Should only contain lowercase alphanumeric characters and underscores.
+    #[validate(length(min = 1, max = 512))]
+    pub template_name: String,
+
+    /// Template data. Values have to be set as registered in the template.
+    #[validate]
+    pub template_data: TemplateData,
+
+    /// The code of language or locale to use. Must be the same code used when registering the
+    /// template. A string rather than a `TemplateLanguage`, so codes newer than the SDK can be
+    /// sent; `TemplateContent::new` takes a `TemplateLanguage`.
+    pub language: String,
+
+    /// Namespace of the template in the WhatsApp Business Account, as returned with the
+    /// template. Not needed, templates are found by name and language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// ID of the template at Meta, as returned with the template. Not needed, templates are
+    /// found by name and language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta_template_id: Option<String>,
+}
+
+impl TemplateContent {
+    pub fn new(
+        template_name: &str,
+        template_data: TemplateData,
+        language: TemplateLanguage,
+    ) -> Self {
+        Self {
+            template_name: template_name.into(),
+            template_data,
+            language: language.to_string(),
+            namespace: None,
+            meta_template_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SmsFailover {
+    /// SMS sender number. Must be in international format.
+    #[validate(length(min = 1, max = 24))]
+    pub from: String,
+
+    /// Content of the SMS that will be sent.
+    #[validate(length(min = 1, max = 4096))]
+    pub text: String,
+}
+
+impl SmsFailover {
+    pub fn new(from: &str, text: &str) -> Self {
+        Self {
+            from: from.into(),
+            text: text.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct FailoverMessage {
+    /// Registered WhatsApp sender number. Must be in international format and comply with
+    /// WhatsApp's requirements.
+    #[validate(length(min = 1, max = 24))]
+    pub from: String,
+
+    /// Message recipient number. Must be in international format.
+    #[validate(length(min = 1, max = 24))]
+    pub to: String,
+
+    /// The ID that uniquely identifies the message sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 50))]
+    pub message_id: Option<String>,
+
+    /// The content object to build a message that will be sent.
+    #[validate]
+    pub content: TemplateContent,
+
+    /// Custom client data that will be included in a Delivery Report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(max = 4000))]
+    pub callback_data: Option<String>,
+
+    /// The URL on your callback server to which delivery and seen reports will be sent. Delivery
+    /// report format, Seen report format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url)]
+    pub notify_url: Option<String>,
+
+    /// SMS message to be sent if the WhatsApp template message could not be delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub sms_failover: Option<SmsFailover>,
+
+    /// ID of the CPaaS X entity the message is sent for, e.g. a customer of the platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// ID of the CPaaS X application the message is sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+}
+
+impl FailoverMessage {
+    pub fn new(from: &str, to: &str, content: TemplateContent) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            content,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendTemplateRequestBody {
+    /// An array of messages being sent.
+    #[validate(length(min = 1))]
+    #[validate]
+    pub messages: Vec<FailoverMessage>,
+
+    /// The ID that uniquely identifies the request. Bulk ID will be received only when you send a
+    /// message to more than one destination address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+}
+
+impl SendTemplateRequestBody {
+    pub fn new(messages: Vec<FailoverMessage>) -> Self {
+        Self {
+            messages,
+            ..Default::default()
+        }
+    }
+}
+
+impl PlatformIds for SendTemplateRequestBody {
+    fn fill_platform_ids(&mut self, application_id: Option<&str>, entity_id: Option<&str>) {
+        for message in &mut self.messages {
+            fill_platform_id(&mut message.application_id, application_id);
+            fill_platform_id(&mut message.entity_id, entity_id);
+        }
+    }
+}
+
+/// Kind of activity shown to the user by a typing indicator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TypingIndicatorType {
+    #[default]
+    Text,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendTypingIndicatorRequestBody {
+    /// Kind of activity to show. The indicator is shown until a reply is sent, or for up to 25
+    /// seconds.
+    #[serde(rename = "type")]
+    pub indicator_type: TypingIndicatorType,
+}
+
+impl SendTypingIndicatorRequestBody {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    /// Status group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Status group name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    /// Action that should be taken to eliminate the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Status ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Status name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Human-readable description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Status {
+    /// Returns the typed status group, from `group_id` or `group_name`.
+    pub fn group(&self) -> Option<StatusGroup> {
+        StatusGroup::resolve(self.group_id, self.group_name.as_deref())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SendContentResponseBody {
+    /// The destination address of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Number of messages required to deliver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// The ID that uniquely identifies the message sent. If not passed, it will be automatically
+    /// generated and returned in a response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Indicates the status of the message and how to recover from an error should there be any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl SendContentResponseBody {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
+}
+
+pub type SendTextResponseBody = SendContentResponseBody;
+
+pub type SendDocumentResponseBody = SendContentResponseBody;
+
+pub type SendImageResponseBody = SendContentResponseBody;
+
+pub type SendAudioResponseBody = SendContentResponseBody;
+
+pub type SendVideoResponseBody = SendContentResponseBody;
+
+pub type SendStickerResponseBody = SendContentResponseBody;
+
+pub type SendLocationResponseBody = SendContentResponseBody;
+
+pub type SendContactResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveButtonsResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveListResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveProductResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveMultiproductResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveFlowResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveOrderDetailsResponseBody = SendContentResponseBody;
+
+pub type SendInteractiveOrderStatusResponseBody = SendContentResponseBody;
+
+/// Quality rating of the template, based on how recipients have been responding to it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TemplateQualityRating {
+    Green,
+    Yellow,
+    Red,
+    /// Also used for ratings not known to this version of the SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateQualityScore {
+    /// Quality rating of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<TemplateQualityRating>,
+
+    /// Date and time when the quality rating was last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+/// Status of the template.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TemplateStatus {
+    #[serde(rename = "APPROVED")]
+    Approved,
+    #[serde(rename = "IN_APPEAL")]
+    InAppeal,
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "REJECTED")]
+    Rejected,
+    #[serde(rename = "PENDING_DELETION")]
+    PendingDeletion,
+    #[serde(rename = "DELETED")]
+    Deleted,
+    #[serde(rename = "DISABLED")]
+    Disabled,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTemplateResponseBody {
+    /// Template ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Business account ID to which template belongs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_account_id: Option<i64>,
+
+    /// Name of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<TemplateLanguage>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TemplateStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<TemplateCategory>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structure: Option<TemplateStructure>,
+
+    /// Namespace of the template in the WhatsApp Business Account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// ID of the template at Meta.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta_template_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Template {
+    /// Template ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Business account ID to which template belongs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_account_id: Option<i64>,
+
+    /// Name of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Template language, one template with same name can have multiple transliterations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<TemplateLanguage>,
+
+    /// Status of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TemplateStatus>,
+
+    /// Category of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<TemplateCategory>,
+
+    /// Template structure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structure: Option<TemplateStructure>,
+
+    /// Quality score of the template. Only available for approved templates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality_score: Option<TemplateQualityScore>,
+
+    /// Reason why WhatsApp rejected the template. Only available for rejected templates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejection_reason: Option<String>,
+
+    /// Namespace of the template in the WhatsApp Business Account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// ID of the template at Meta.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta_template_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+pub type TemplateResponseBody = Template;
+
+pub type UpdateTemplateResponseBody = Template;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TemplatesResponseBody {
+    /// List of all templates for given sender.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub templates: Option<Vec<Template>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl TemplatesResponseBody {
+    /// Returns the template with the given name and language, if present.
+    pub fn find(&self, name: &str, language: TemplateLanguage) -> Option<&Template> {
+        self.templates.iter().flatten().find(|template| {
+            template.name.as_deref() == Some(name) && template.language == Some(language)
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateLibraryQueryParameters {
+    /// Text to search for in template names and contents.
+    pub search: Option<String>,
+
+    /// Category of the templates, e.g. `UTILITY` or `AUTHENTICATION`.
+    pub category: Option<String>,
+
+    /// Topic of the templates, e.g. `ORDER_MANAGEMENT` or `PAYMENTS`.
+    pub topic: Option<String>,
+
+    /// Industry the templates are meant for, e.g. `E_COMMERCE`.
+    pub industry: Option<String>,
+
+    /// Language of the templates.
+    pub language: Option<TemplateLanguage>,
+
+    /// Page number, starting from 0.
+    #[validate(range(min = 0))]
+    pub page: Option<i32>,
+
+    /// Number of templates per page. Default is 20, maximum is 100.
+    #[validate(range(min = 1, max = 100))]
+    pub size: Option<i32>,
+}
+
+impl TemplateLibraryQueryParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A pre-approved template example from the template library. Registering it for a sender with
+/// `WhatsAppClient::create_template_from_library` skips the review of the content.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryTemplate {
+    /// Name of the template in the library, used to register it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Template language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<TemplateLanguage>,
+
+    /// Category of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<TemplateCategory>,
+
+    /// Topic of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// Industries the template is meant for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industries: Option<Vec<String>>,
+
+    /// Template structure, with example values for its placeholders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structure: Option<TemplateStructure>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateLibraryResponseBody {
+    /// Library templates matching the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub templates: Option<Vec<LibraryTemplate>>,
+
+    /// Total number of library templates matching the query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i64>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum LibraryButtonType {
+    Url,
+    PhoneNumber,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Value of a button of a library template that the sender must provide, like the URL of a
+/// website button.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryTemplateButtonInput {
+    #[serde(rename = "type")]
+    pub button_type: LibraryButtonType,
+
+    /// URL of a `URL` button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(url)]
+    pub url: Option<String>,
+
+    /// Phone number of a `PHONE_NUMBER` button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1))]
+    pub phone_number: Option<String>,
+}
+
+impl LibraryTemplateButtonInput {
+    pub fn url(url: &str) -> Self {
+        Self {
+            button_type: LibraryButtonType::Url,
+            url: Some(url.into()),
+            phone_number: None,
+        }
+    }
+
+    pub fn phone_number(phone_number: &str) -> Self {
+        Self {
+            button_type: LibraryButtonType::PhoneNumber,
+            url: None,
+            phone_number: Some(phone_number.into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLibraryTemplateRequestBody {
+    /// Name of the new template. Must only contain lowercase alphanumeric characters and
+    /// underscores.
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Template language.
+    #[validate(custom = "validate_known_language")]
+    pub language: TemplateLanguage,
+
+    /// Category of the template.
+    #[validate(custom = "validate_known_category")]
+    pub category: TemplateCategory,
+
+    /// Name of the template in the library.
+    #[validate(length(min = 1))]
+    pub library_template_name: String,
+
+    /// Values of the buttons of the library template that the sender must provide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub library_template_button_inputs: Option<Vec<LibraryTemplateButtonInput>>,
+}
+
+impl CreateLibraryTemplateRequestBody {
+    pub fn new(
+        name: &str,
+        language: TemplateLanguage,
+        category: TemplateCategory,
+        library_template_name: &str,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            language,
+            category,
+            library_template_name: library_template_name.into(),
+            library_template_button_inputs: None,
+        }
+    }
+
+    /// Creates a request to register the library template under the given name, in the
+    /// language and category of the library template. Returns `None` if the library template
+    /// has no name, language or category.
+    pub fn from_library(template: &LibraryTemplate, name: &str) -> Option<Self> {
+        Some(Self::new(
+            name,
+            template.language?,
+            template.category?,
+            template.name.as_deref()?,
+        ))
+    }
+}
+
+pub type CreateLibraryTemplateResponseBody = Template;
+
+pub type SentMessageInfo = SendContentResponseBody;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SendTemplateResponseBody {
+    /// Array of sent message objects, one object per every message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<SentMessageInfo>>,
+
+    /// The ID that uniquely identifies the request. Bulk ID will be received only when you send a
+    /// message to more than one destination address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl SendTemplateResponseBody {
+    /// Splits the sent messages into accepted and rejected ones, by the group of their status.
+    pub fn partition_by_status(&self) -> SendResult<'_, SentMessageInfo> {
+        SendResult::partition(
+            self.messages.iter().flatten(),
+            SentMessageInfo::status_group,
+        )
+    }
+
+    /// Pairs every message of the request with its sent message info. See
+    /// [`SentTemplateMessage`] for how they are matched.
+    pub fn correlate<'a>(
+        &'a self,
+        request_body: &'a SendTemplateRequestBody,
+    ) -> Result<Vec<SentTemplateMessage<'a>>, CorrelationError> {
+        correlate(
+            &request_body.messages,
+            self.messages.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+/// A message of a template request with the sent message info the API returned for it, e.g. to
+/// retry the rejected messages.
+///
+/// If every message of the request has a message ID, infos are matched by ID, so their order
+/// doesn't matter. Otherwise they are matched by position, and messages with an ID must have
+/// the same ID in their info.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SentTemplateMessage<'a> {
+    pub message: &'a FailoverMessage,
+    pub info: &'a SentMessageInfo,
+}
+
+impl SentTemplateMessage<'_> {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.info.status_group()
+    }
+
+    /// Returns `true` if the message status is in the `UNDELIVERABLE`, `EXPIRED` or `REJECTED`
+    /// group. Messages without a known status are not rejected.
+    pub fn is_rejected(&self) -> bool {
+        self.status_group()
+            .is_some_and(|group| !group.is_successful())
+    }
+}
+
+/// Holds the possible errors of matching a template response to its request.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum CorrelationError {
+    #[error("the response has {received} messages for {sent} sent")]
+    CountMismatch { sent: usize, received: usize },
+
+    #[error("the response has no message with ID {0}")]
+    MissingMessageId(String),
+
+    #[error("message {index} has ID {expected}, but the response has {received:?}")]
+    MessageIdMismatch {
+        index: usize,
+        expected: String,
+        received: Option<String>,
+    },
+}
+
+fn correlate<'a>(
+    messages: &'a [FailoverMessage],
+    infos: &'a [SentMessageInfo],
+) -> Result<Vec<SentTemplateMessage<'a>>, CorrelationError> {
+    if messages.len() != infos.len() {
+        return Err(CorrelationError::CountMismatch {
+            sent: messages.len(),
+            received: infos.len(),
+        });
+    }
+
+    if messages.iter().all(|message| message.message_id.is_some()) {
+        return messages
+            .iter()
+            .map(|message| {
+                let message_id = message.message_id.as_deref().unwrap();
+                infos
+                    .iter()
+                    .find(|info| info.message_id.as_deref() == Some(message_id))
+                    .map(|info| SentTemplateMessage { message, info })
+                    .ok_or_else(|| CorrelationError::MissingMessageId(message_id.to_string()))
+            })
+            .collect();
+    }
+
+    messages
+        .iter()
+        .zip(infos)
+        .enumerate()
+        .map(|(index, (message, info))| match &message.message_id {
+            Some(expected) if info.message_id.as_ref() != Some(expected) => {
+                Err(CorrelationError::MessageIdMismatch {
+                    index,
+                    expected: expected.clone(),
+                    received: info.message_id.clone(),
+                })
+            }
+            _ => Ok(SentTemplateMessage { message, info }),
+        })
+        .collect()
+}
+
+/// Aggregated result of sending a template batch split into several requests.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SendTemplateBatchResponseBody {
+    /// Sent message objects of all chunks, in the same order as the messages of the request.
+    pub messages: Vec<SentMessageInfo>,
+
+    /// Bulk IDs returned for each chunk, in sending order.
+    pub bulk_ids: Vec<String>,
+}
+
+impl SendTemplateBatchResponseBody {
+    /// Returns the sent message info of the request message at position `index`. This assumes
+    /// the API returned one result per message; use `correlate` to check it.
+    pub fn message_at(&self, index: usize) -> Option<&SentMessageInfo> {
+        self.messages.get(index)
+    }
+
+    /// Splits the sent messages of all chunks into accepted and rejected ones, by the group of
+    /// their status.
+    pub fn partition_by_status(&self) -> SendResult<'_, SentMessageInfo> {
+        SendResult::partition(&self.messages, SentMessageInfo::status_group)
+    }
+
+    /// Returns the sent message info with the given message ID.
+    pub fn message_by_id(&self, message_id: &str) -> Option<&SentMessageInfo> {
+        self.messages
+            .iter()
+            .find(|message| message.message_id.as_deref() == Some(message_id))
+    }
+
+    /// Pairs every message of the request sent in batches with its sent message info. See
+    /// [`SentTemplateMessage`] for how they are matched.
+    pub fn correlate<'a>(
+        &'a self,
+        request_body: &'a SendTemplateRequestBody,
+    ) -> Result<Vec<SentTemplateMessage<'a>>, CorrelationError> {
+        correlate(&request_body.messages, &self.messages)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundOrderProductItem {
+    /// Product-unique identifier, as defined in catalog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_retailer_id: Option<String>,
+
+    /// Number of ordered items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i32>,
+
+    /// Price of a single item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f64))]
+    pub item_price: Option<f64>,
+
+    /// Currency of the item price in ISO 4217 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundOrderMessage {
+    /// The ID of the catalog the ordered products belong to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_id: Option<String>,
+
+    /// Text sent by the customer along with the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Ordered products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_items: Option<Vec<InboundOrderProductItem>>,
+
+    /// Message type. Always `ORDER` for order messages.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundContact {
+    /// Name of the contact, as set in the WhatsApp profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundPrice {
+    /// Price per one message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f64))]
+    pub price_per_message: Option<f64>,
+
+    /// The currency in which the price is expressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundOrder {
+    /// Number of the customer who placed the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Registered WhatsApp sender number that received the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Type of the integration. Always `WHATSAPP`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_type: Option<String>,
+
+    /// Date and time when the order was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_at: Option<String>,
+
+    /// The ID that uniquely identifies the received message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Callback data sent through the `callbackData` field when sending the paired message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+
+    /// Order details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<InboundOrderMessage>,
+
+    /// Information about the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<InboundContact>,
+
+    /// Price of the received message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<InboundPrice>,
+}
+
+impl InboundOrder {
+    /// Parses the callback data of the message as JSON, see `model::callback_data`. Returns
+    /// `None` if the paired message had no callback data.
+    pub fn parse_callback_data<T: DeserializeOwned>(&self) -> Result<Option<T>, CallbackDataError> {
+        callback_data::parse_field(self.callback_data.as_deref())
+    }
+}
+
+/// Payload of the webhook request sent by Infobip when a customer places an order.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct InboundOrdersBody {
+    /// Received orders.
+    #[serde(default)]
+    pub results: Vec<InboundOrder>,
+
+    /// Number of returned messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Number of messages that have not been pulled in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_message_count: Option<i32>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryReportsQueryParameters {
+    /// The ID that uniquely identifies the request. Only set when messages were sent to more than
+    /// one destination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID of the message for which a report is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Maximum number of delivery reports to be returned. If not set, the latest 50 records are
+    /// returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<i32>,
+
+    /// Entity ID of the messages, to only include messages of one entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID of the messages, to only include messages of one application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID of the messages, to only include messages of one campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+}
+
+impl DeliveryReportsQueryParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct Price {
+    /// Price per one message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::model::arbitrary_optional_finite_f64))]
+    pub price_per_message: Option<f64>,
+
+    /// The currency in which the price is expressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ReportError {
+    /// Error group ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i32>,
+
+    /// Error group name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+
+    /// Error ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+
+    /// Error name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Human-readable description of the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Tells if the error is permanent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permanent: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Report {
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Message recipient number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Registered WhatsApp sender number the message was sent from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Date and time when the message was sent. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
+
+    /// Date and time when Infobip finished processing the message. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+
+    /// The number of messages sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Price of the sent message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Price>,
+
+    /// Indicates whether the message was sent, delivered, not delivered, waiting for delivery or
+    /// any other possible status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReportError>,
+
+    /// Callback data sent through the `callbackData` field when sending the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+
+    /// Entity ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Report {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
+
+    /// Parses the callback data of the message as JSON, see `model::callback_data`. Returns
+    /// `None` if the message had no callback data.
+    pub fn parse_callback_data<T: DeserializeOwned>(&self) -> Result<Option<T>, CallbackDataError> {
+        callback_data::parse_field(self.callback_data.as_deref())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DeliveryReportsResponseBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Report>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct LogsQueryParameters {
+    /// Registered WhatsApp sender number the messages were sent from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Message recipient number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID of the message for which a log is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Sent message status group. Possible values: ACCEPTED, PENDING, UNDELIVERABLE, DELIVERED,
+    /// REJECTED, EXPIRED.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub general_status: Option<String>,
+
+    /// The logs will only include messages sent after this date. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_since: Option<String>,
+
+    /// The logs will only include messages sent before this date. Use it together with
+    /// `sent_since` to page through more logs than the limit. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_until: Option<String>,
+
+    /// Maximum number of logs to be returned. If not set, the latest 50 records are returned.
+    /// Logs are available for the last 48 hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<i32>,
+
+    /// Entity ID of the messages, to only include messages of one entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID of the messages, to only include messages of one application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID of the messages, to only include messages of one campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+}
+
+impl LogsQueryParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Log {
+    /// The ID that uniquely identifies the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_id: Option<String>,
+
+    /// The ID that uniquely identifies the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Message recipient number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Registered WhatsApp sender number the message was sent from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Text of the message, for text messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Date and time when the message was sent. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<String>,
+
+    /// Date and time when Infobip finished processing the message. Has the following format:
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+
+    /// The number of messages sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<i32>,
+
+    /// Price of the sent message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Price>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReportError>,
+
+    /// Entity ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+
+    /// Application ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+
+    /// Campaign reference ID the message was sent with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_reference_id: Option<String>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Log {
+    /// Returns the group of the message status.
+    pub fn status_group(&self) -> Option<StatusGroup> {
+        self.status.as_ref().and_then(Status::group)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct LogsResponseBody {
+    /// Collection of logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Log>>,
+
+    /// Fields of the response not modeled by the SDK.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Maximum number of phone numbers in one contact check request.
+pub const MAX_CONTACTS_PER_CHECK: usize = 1000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckContactsRequestBody {
+    /// Phone numbers to check, in international format. Example: `441134960001`.
+    #[validate(length(min = 1, max = 1000))]
+    pub contacts: Vec<String>,
+}
+
+impl CheckContactsRequestBody {
+    pub fn new(contacts: Vec<String>) -> Self {
+        Self { contacts }
+    }
+}
+
+/// Whether a phone number can receive WhatsApp messages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ContactStatus {
+    /// The number has a WhatsApp account.
+    Valid,
+    /// The number has no WhatsApp account, or is not a valid phone number.
+    Invalid,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct ContactCheck {
+    /// The phone number as given in the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ContactStatus>,
+
+    /// WhatsApp ID of a valid number, the number in the format WhatsApp uses for it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wa_id: Option<String>,
+}
+
+impl ContactCheck {
+    /// Returns `true` if the number can receive WhatsApp messages.
+    pub fn is_whatsapp_enabled(&self) -> bool {
+        self.status == Some(ContactStatus::Valid)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckContactsResponseBody {
+    /// Results of the check, one per requested number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<ContactCheck>>,
+}
+
+impl CheckContactsResponseBody {
+    /// Returns the requested numbers that can receive WhatsApp messages.
+    pub fn enabled(&self) -> Vec<&str> {
+        self.inputs(true)
+    }
+
+    /// Returns the requested numbers that can't receive WhatsApp messages, e.g. to send them SMS
+    /// instead. Numbers with an unknown status are included.
+    pub fn not_enabled(&self) -> Vec<&str> {
+        self.inputs(false)
+    }
+
+    fn inputs(&self, enabled: bool) -> Vec<&str> {
+        self.contacts
+            .iter()
+            .flatten()
+            .filter(|contact| contact.is_whatsapp_enabled() == enabled)
+            .filter_map(|contact| contact.input.as_deref())
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SenderQualityQueryParameters {
+    /// Senders to get the quality of, in international format. Sent as a comma-separated list.
+    #[validate(length(min = 1, max = 100))]
+    pub senders: Vec<String>,
+}
+
+impl SenderQualityQueryParameters {
+    pub fn new(senders: Vec<String>) -> Self {
+        Self { senders }
+    }
+}
+
+/// Quality rating of a sender, based on how recipients have been responding to its messages in
+/// the last days. Senders with a low rating may be limited or flagged by WhatsApp.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum SenderQualityRating {
+    High,
+    Medium,
+    Low,
+    /// Also used for ratings not known to this version of the SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a sender in WhatsApp.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum SenderStatus {
+    Connected,
+    /// The quality rating dropped to low, and the messaging limit is lowered if it doesn't
+    /// improve within 7 days.
+    Flagged,
+    /// The sender reached its messaging limit and can't start new conversations until it resets.
+    Restricted,
+    Disconnected,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Messaging limit tier of a sender: the number of unique recipients it can start business
+/// initiated conversations with in a rolling 24 hour period.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum MessagingLimit {
+    #[serde(rename = "TIER_50")]
+    Tier50,
+    #[serde(rename = "TIER_250")]
+    Tier250,
+    #[serde(rename = "TIER_1K")]
+    Tier1K,
+    #[serde(rename = "TIER_10K")]
+    Tier10K,
+    #[serde(rename = "TIER_100K")]
+    Tier100K,
+    #[serde(rename = "TIER_UNLIMITED")]
+    TierUnlimited,
+    /// A value not known to this version of the SDK, received in a response.
+    #[serde(other)]
+    Unknown,
+}
+
+impl MessagingLimit {
+    /// Returns the number of unique recipients per 24 hours, or `None` for an unlimited or
+    /// unknown tier.
+    pub fn recipients_per_day(&self) -> Option<u32> {
+        match self {
+            Self::Tier50 => Some(50),
+            Self::Tier250 => Some(250),
+            Self::Tier1K => Some(1_000),
+            Self::Tier10K => Some(10_000),
+            Self::Tier100K => Some(100_000),
+            Self::TierUnlimited | Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SenderQuality {
+    /// The sender, in international format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality_rating: Option<SenderQualityRating>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SenderStatus>,
+
+    /// Messaging limit tier the sender is currently in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_limit: Option<MessagingLimit>,
+
+    /// Date and time when the quality rating or limit was last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "camelCase")]
+pub struct SenderQualityResponseBody {
+    /// Quality of the requested senders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<SenderQuality>>,
+}
+
+impl SenderQualityResponseBody {
+    /// Returns the quality of `sender`, if it was in the response.
+    pub fn sender(&self, sender: &str) -> Option<&SenderQuality> {
+        self.results
+            .iter()
+            .flatten()
+            .find(|quality| quality.sender.as_deref() == Some(sender))
+    }
+
+    /// Returns the senders that can't currently start conversations as usual: flagged,
+    /// restricted or disconnected ones, and those rated low.
+    pub fn at_risk(&self) -> Vec<&SenderQuality> {
+        self.results
+            .iter()
+            .flatten()
+            .filter(|quality| {
+                quality.quality_rating == Some(SenderQualityRating::Low)
+                    || matches!(
+                        quality.status,
+                        Some(
+                            SenderStatus::Flagged
+                                | SenderStatus::Restricted
+                                | SenderStatus::Disconnected
+                        )
+                    )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,284 @@
+//! Storage for messages that are sent later, e.g. by the
+//! [`WhatsAppScheduler`](crate::api::whatsapp_scheduler::WhatsAppScheduler).
+//!
+//! Queued items are kept in a [`QueueStore`]. The SDK ships an in-memory store and a store
+//! backed by a JSON file; implement the trait on top of a database to keep queued messages across
+//! restarts of several instances.
+
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+
+/// Error returned by a [`QueueStore`] implementation.
+pub type StoreError = Box<dyn Error + Send + Sync>;
+
+/// An item waiting in a queue until it is due.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedItem<T> {
+    /// Unique ID of the queued item, assigned when it is queued.
+    pub id: String,
+
+    /// Time after which the item should be processed.
+    pub due_at: DateTime<Utc>,
+
+    /// The queued item.
+    pub item: T,
+}
+
+/// Persistence for queued items. Implementations must be safe to share between tasks.
+pub trait QueueStore<T>: Send + Sync {
+    /// Adds an item to the store.
+    fn push(&self, item: QueuedItem<T>) -> Result<(), StoreError>;
+
+    /// Removes an item by ID, returning it if it was present.
+    fn remove(&self, id: &str) -> Result<Option<QueuedItem<T>>, StoreError>;
+
+    /// Removes and returns all items due at or before `now`, ordered by due time.
+    fn take_due(&self, now: DateTime<Utc>) -> Result<Vec<QueuedItem<T>>, StoreError>;
+
+    /// Returns the number of items in the store.
+    fn len(&self) -> Result<usize, StoreError>;
+
+    /// Replaces the item with the same ID, or adds it if there is none. The default
+    /// implementation removes and pushes the item, which is not atomic; stores that persist items
+    /// should override it.
+    fn replace(&self, item: QueuedItem<T>) -> Result<(), StoreError> {
+        self.remove(&item.id)?;
+        self.push(item)
+    }
+
+    /// Returns up to `limit` items due at or before `now`, ordered by due time, and moves them to
+    /// `lease_until` instead of removing them. Items that are not removed before the lease ends
+    /// are returned again, so they are not lost if the process stops while handling them. The
+    /// default implementation takes and pushes back the items, which is not atomic; stores that
+    /// persist items should override it.
+    fn lease_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<QueuedItem<T>>, StoreError>
+    where
+        T: Clone,
+    {
+        let mut due = self.take_due(now)?;
+        let later = due.split_off(limit.min(due.len()));
+        for item in later {
+            self.push(item)?;
+        }
+        for item in &due {
+            self.push(QueuedItem {
+                due_at: lease_until,
+                ..item.clone()
+            })?;
+        }
+
+        Ok(due)
+    }
+
+    /// Returns `true` if the store has no items.
+    fn is_empty(&self) -> Result<bool, StoreError> {
+        Ok(self.len()? == 0)
+    }
+}
+
+/// A [`QueueStore`] that keeps items in memory. Queued items are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct MemoryQueueStore<T> {
+    items: Mutex<Vec<QueuedItem<T>>>,
+}
+
+impl<T> MemoryQueueStore<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Send> QueueStore<T> for MemoryQueueStore<T> {
+    fn push(&self, item: QueuedItem<T>) -> Result<(), StoreError> {
+        self.items.lock().unwrap().push(item);
+        Ok(())
+    }
+
+    fn remove(&self, id: &str) -> Result<Option<QueuedItem<T>>, StoreError> {
+        let mut items = self.items.lock().unwrap();
+
+        Ok(items
+            .iter()
+            .position(|item| item.id == id)
+            .map(|index| items.remove(index)))
+    }
+
+    fn take_due(&self, now: DateTime<Utc>) -> Result<Vec<QueuedItem<T>>, StoreError> {
+        let mut items = self.items.lock().unwrap();
+
+        let (mut due, pending): (Vec<_>, Vec<_>) =
+            items.drain(..).partition(|item| item.due_at <= now);
+        *items = pending;
+        due.sort_by_key(|item| item.due_at);
+
+        Ok(due)
+    }
+
+    fn len(&self) -> Result<usize, StoreError> {
+        Ok(self.items.lock().unwrap().len())
+    }
+
+    fn replace(&self, item: QueuedItem<T>) -> Result<(), StoreError> {
+        replace_item(&mut self.items.lock().unwrap(), item);
+        Ok(())
+    }
+
+    fn lease_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<QueuedItem<T>>, StoreError>
+    where
+        T: Clone,
+    {
+        Ok(lease_items(
+            &mut self.items.lock().unwrap(),
+            now,
+            lease_until,
+            limit,
+        ))
+    }
+}
+
+/// A [`QueueStore`] that keeps items in a JSON file, so they survive restarts. The whole file is
+/// written to a temporary file and renamed over the old one on every change, which suits queues
+/// of up to a few thousand items used by one process.
+#[derive(Debug)]
+pub struct FileQueueStore<T> {
+    path: PathBuf,
+    items: Mutex<Vec<QueuedItem<T>>>,
+}
+
+impl<T: serde::Serialize + DeserializeOwned> FileQueueStore<T> {
+    /// Opens the store kept in the file at `path`, or starts an empty one if the file doesn't
+    /// exist. The file is created on the first change.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let items = match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error),
+        };
+
+        Ok(Self {
+            path,
+            items: Mutex::new(items),
+        })
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Applies a change to the items and writes them to the file. The change is rolled back if
+    // the file can't be written.
+    fn update<R, F>(&self, change: F) -> Result<R, StoreError>
+    where
+        T: Clone,
+        F: FnOnce(&mut Vec<QueuedItem<T>>) -> R,
+    {
+        let mut items = self.items.lock().unwrap();
+        let mut changed = items.clone();
+        let result = change(&mut changed);
+
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, serde_json::to_vec(&changed)?)?;
+        fs::rename(&temporary, &self.path)?;
+        *items = changed;
+
+        Ok(result)
+    }
+}
+
+impl<T> QueueStore<T> for FileQueueStore<T>
+where
+    T: Clone + Send + serde::Serialize + DeserializeOwned,
+{
+    fn push(&self, item: QueuedItem<T>) -> Result<(), StoreError> {
+        self.update(|items| items.push(item))
+    }
+
+    fn remove(&self, id: &str) -> Result<Option<QueuedItem<T>>, StoreError> {
+        if !self.items.lock().unwrap().iter().any(|item| item.id == id) {
+            return Ok(None);
+        }
+
+        self.update(|items| {
+            items
+                .iter()
+                .position(|item| item.id == id)
+                .map(|index| items.remove(index))
+        })
+    }
+
+    fn take_due(&self, now: DateTime<Utc>) -> Result<Vec<QueuedItem<T>>, StoreError> {
+        self.update(|items| {
+            let (mut due, pending): (Vec<_>, Vec<_>) =
+                items.drain(..).partition(|item| item.due_at <= now);
+            *items = pending;
+            due.sort_by_key(|item| item.due_at);
+            due
+        })
+    }
+
+    fn len(&self) -> Result<usize, StoreError> {
+        Ok(self.items.lock().unwrap().len())
+    }
+
+    fn replace(&self, item: QueuedItem<T>) -> Result<(), StoreError> {
+        self.update(|items| replace_item(items, item))
+    }
+
+    fn lease_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<QueuedItem<T>>, StoreError> {
+        self.update(|items| lease_items(items, now, lease_until, limit))
+    }
+}
+
+fn replace_item<T>(items: &mut Vec<QueuedItem<T>>, item: QueuedItem<T>) {
+    match items.iter_mut().find(|queued| queued.id == item.id) {
+        Some(queued) => *queued = item,
+        None => items.push(item),
+    }
+}
+
+fn lease_items<T: Clone>(
+    items: &mut [QueuedItem<T>],
+    now: DateTime<Utc>,
+    lease_until: DateTime<Utc>,
+    limit: usize,
+) -> Vec<QueuedItem<T>> {
+    let mut due: Vec<&mut QueuedItem<T>> =
+        items.iter_mut().filter(|item| item.due_at <= now).collect();
+    due.sort_by_key(|item| item.due_at);
+    due.truncate(limit);
+
+    due.into_iter()
+        .map(|item| {
+            let leased = item.clone();
+            item.due_at = lease_until;
+            leased
+        })
+        .collect()
+}
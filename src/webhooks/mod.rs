@@ -0,0 +1,342 @@
+//! Helpers for handling callback (webhook) requests sent by Infobip, like delivery reports and
+//! inbound messages.
+//!
+//! Signed callbacks carry an HMAC-SHA256 of the raw request body, computed with the secret
+//! configured for the subscription. Check it with [`verify_signature`] before parsing the body
+//! into a model, so forged requests never reach your handlers:
+//!
+//! ```
+//! use infobip_sdk::webhooks::{compute_signature, verify_signature, WebhookError, SIGNATURE_HEADER};
+//! use reqwest::header::HeaderMap;
+//!
+//! let body = br#"{"results":[]}"#;
+//! let mut headers = HeaderMap::new();
+//! headers.insert(SIGNATURE_HEADER, compute_signature("secret", body).parse().unwrap());
+//!
+//! assert!(verify_signature("secret", &headers, body).is_ok());
+//! assert_eq!(
+//!     verify_signature("other-secret", &headers, body),
+//!     Err(WebhookError::InvalidSignature)
+//! );
+//! ```
+//!
+//! A signature over the body alone stays valid if the timestamp header of a captured request is
+//! refreshed, so prefer [`verify_signature_with_timestamp`] when the callbacks are signed together
+//! with their [`TIMESTAMP_HEADER`]. It checks an HMAC-SHA256 of `timestamp + "." + body`:
+//!
+//! ```
+//! use infobip_sdk::webhooks::{
+//!     compute_signature_with_timestamp, verify_signature_with_timestamp, WebhookError,
+//!     SIGNATURE_HEADER, TIMESTAMP_HEADER,
+//! };
+//! use reqwest::header::HeaderMap;
+//!
+//! let body = br#"{"results":[]}"#;
+//! let signature = compute_signature_with_timestamp("secret", "1700000000", body);
+//! let mut headers = HeaderMap::new();
+//! headers.insert(TIMESTAMP_HEADER, "1700000000".parse().unwrap());
+//! headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+//!
+//! assert!(verify_signature_with_timestamp("secret", &headers, body).is_ok());
+//!
+//! headers.insert(TIMESTAMP_HEADER, "1700000300".parse().unwrap());
+//! assert_eq!(
+//!     verify_signature_with_timestamp("secret", &headers, body),
+//!     Err(WebhookError::InvalidSignature)
+//! );
+//! ```
+//!
+//! Callbacks often trigger state transitions in your application (e.g. marking an OTP as
+//! consumed), so a request that is captured and sent again must not be processed twice. The
+//! [`ReplayGuard`] rejects callbacks with a stale timestamp, and callbacks whose signature (or any
+//! other unique value, used as a nonce) was already seen inside the accepted time window.
+//!
+//! # Example
+//! ```
+//! use std::time::Duration;
+//! use infobip_sdk::webhooks::ReplayGuard;
+//! use reqwest::header::HeaderMap;
+//!
+//! let guard = ReplayGuard::new(Duration::from_secs(300));
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.insert("X-Timestamp", "1700000000".parse().unwrap());
+//! headers.insert("X-Signature", "3a4f0c1d".parse().unwrap());
+//!
+//! // In a real handler you would call `guard.check_headers(&headers)`.
+//! assert!(guard.check_headers_at(&headers, 1_700_000_010).is_ok());
+//! assert!(guard.check_headers_at(&headers, 1_700_000_020).is_err());
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
+use sha2::Sha256;
+use thiserror::Error;
+
+/// Default name of the header holding the UNIX timestamp (in seconds) of the callback.
+pub const TIMESTAMP_HEADER: &str = "X-Timestamp";
+
+/// Default name of the header holding the signature of the callback.
+pub const SIGNATURE_HEADER: &str = "X-Signature";
+
+/// Default maximum number of nonces kept in memory by a [`ReplayGuard`].
+pub const DEFAULT_NONCE_CAPACITY: usize = 10_000;
+
+/// Holds the possible errors that can happen when checking a callback request.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum WebhookError {
+    #[error("missing header: {0}")]
+    MissingHeader(String),
+
+    #[error("invalid header value: {0}")]
+    InvalidHeader(String),
+
+    #[error("callback timestamp is outside of the accepted window")]
+    StaleTimestamp,
+
+    #[error("callback was already processed")]
+    Replayed,
+
+    #[error("too many callbacks inside the accepted window")]
+    TooManyCallbacks,
+
+    #[error("callback signature does not match its body")]
+    InvalidSignature,
+}
+
+/// Rejects replayed callback requests by checking timestamp freshness and keeping a cache of
+/// already seen nonces. A single instance should be shared by all handlers of the same endpoint.
+#[derive(Debug)]
+pub struct ReplayGuard {
+    max_age: Duration,
+    capacity: usize,
+    timestamp_header: String,
+    nonce_header: String,
+    nonces: Mutex<Nonces>,
+}
+
+// Seen nonces, indexed by nonce for lookups and ordered by time for expiring them.
+#[derive(Debug, Default)]
+struct Nonces {
+    seen_at: HashMap<String, u64>,
+    by_time: BTreeSet<(u64, String)>,
+}
+
+impl Nonces {
+    fn expire(&mut self, oldest_accepted: u64) {
+        while let Some((seen_at, _)) = self.by_time.first() {
+            if *seen_at >= oldest_accepted {
+                break;
+            }
+            if let Some((_, nonce)) = self.by_time.pop_first() {
+                self.seen_at.remove(&nonce);
+            }
+        }
+    }
+
+    fn insert(&mut self, nonce: &str, seen_at: u64) {
+        self.seen_at.insert(nonce.into(), seen_at);
+        self.by_time.insert((seen_at, nonce.into()));
+    }
+}
+
+impl ReplayGuard {
+    /// Creates a guard that accepts callbacks whose timestamp is at most `max_age` away from the
+    /// current time. The same tolerance is applied to timestamps in the future, to allow for
+    /// clock skew.
+    pub fn new(max_age: Duration) -> Self {
+        ReplayGuard {
+            max_age,
+            capacity: DEFAULT_NONCE_CAPACITY,
+            timestamp_header: TIMESTAMP_HEADER.into(),
+            nonce_header: SIGNATURE_HEADER.into(),
+            nonces: Mutex::new(Nonces::default()),
+        }
+    }
+
+    /// Sets the maximum number of nonces remembered. Nonces are only forgotten once they leave
+    /// the accepted window, so when full, new callbacks are rejected with
+    /// [`WebhookError::TooManyCallbacks`] until older nonces expire.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Sets the names of the headers used by `check_headers`.
+    pub fn with_headers(mut self, timestamp_header: &str, nonce_header: &str) -> Self {
+        self.timestamp_header = timestamp_header.into();
+        self.nonce_header = nonce_header.into();
+        self
+    }
+
+    /// Checks a callback given its UNIX timestamp (in seconds) and nonce, using the system clock.
+    pub fn check(&self, timestamp: u64, nonce: &str) -> Result<(), WebhookError> {
+        self.check_at(timestamp, nonce, now_seconds())
+    }
+
+    /// Same as `check`, but with an explicit current UNIX time (in seconds).
+    pub fn check_at(&self, timestamp: u64, nonce: &str, now: u64) -> Result<(), WebhookError> {
+        let max_age = self.max_age.as_secs();
+        if timestamp.abs_diff(now) > max_age {
+            return Err(WebhookError::StaleTimestamp);
+        }
+
+        let mut nonces = self.nonces.lock().unwrap_or_else(|e| e.into_inner());
+        nonces.expire(now.saturating_sub(max_age));
+
+        if nonces.seen_at.contains_key(nonce) {
+            return Err(WebhookError::Replayed);
+        }
+        if nonces.seen_at.len() >= self.capacity {
+            return Err(WebhookError::TooManyCallbacks);
+        }
+        nonces.insert(nonce, timestamp.max(now));
+
+        Ok(())
+    }
+
+    /// Checks a callback reading the timestamp and nonce from the request headers.
+    pub fn check_headers(&self, headers: &HeaderMap) -> Result<(), WebhookError> {
+        self.check_headers_at(headers, now_seconds())
+    }
+
+    /// Same as `check_headers`, but with an explicit current UNIX time (in seconds).
+    pub fn check_headers_at(&self, headers: &HeaderMap, now: u64) -> Result<(), WebhookError> {
+        let timestamp = header_str(headers, &self.timestamp_header)?
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| WebhookError::InvalidHeader(self.timestamp_header.clone()))?;
+        let nonce = header_str(headers, &self.nonce_header)?;
+
+        self.check_at(timestamp, nonce, now)
+    }
+}
+
+/// Checks the HMAC-SHA256 signature of a callback, read from the [`SIGNATURE_HEADER`] header.
+/// `body` must be the raw request body, before any parsing. The signature can be hex or base64
+/// encoded, optionally prefixed with `sha256=`.
+pub fn verify_signature(
+    secret: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<(), WebhookError> {
+    verify_signature_with_header(secret, headers, SIGNATURE_HEADER, body)
+}
+
+/// Same as `verify_signature`, but reads the signature from the given header.
+pub fn verify_signature_with_header(
+    secret: &str,
+    headers: &HeaderMap,
+    header_name: &str,
+    body: &[u8],
+) -> Result<(), WebhookError> {
+    let signature = read_signature(headers, header_name)?;
+
+    let mut mac = hmac_sha256(secret);
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Checks the HMAC-SHA256 signature of a callback computed over `timestamp + "." + body`, with
+/// the timestamp read from the [`TIMESTAMP_HEADER`] header and the signature from the
+/// [`SIGNATURE_HEADER`] header. Binding the timestamp to the signature means a captured request
+/// can't be made fresh again by changing its timestamp header.
+pub fn verify_signature_with_timestamp(
+    secret: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<(), WebhookError> {
+    verify_signature_with_timestamp_headers(
+        secret,
+        headers,
+        TIMESTAMP_HEADER,
+        SIGNATURE_HEADER,
+        body,
+    )
+}
+
+/// Same as `verify_signature_with_timestamp`, but reads the timestamp and signature from the
+/// given headers.
+pub fn verify_signature_with_timestamp_headers(
+    secret: &str,
+    headers: &HeaderMap,
+    timestamp_header: &str,
+    signature_header: &str,
+    body: &[u8],
+) -> Result<(), WebhookError> {
+    let timestamp = header_str(headers, timestamp_header)?.trim();
+    let signature = read_signature(headers, signature_header)?;
+
+    let mut mac = hmac_sha256(secret);
+    update_with_timestamp(&mut mac, timestamp, body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Computes the hex encoded HMAC-SHA256 signature of a body, e.g. to sign callbacks in tests.
+pub fn compute_signature(secret: &str, body: &[u8]) -> String {
+    let mut mac = hmac_sha256(secret);
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Computes the hex encoded HMAC-SHA256 signature of `timestamp + "." + body`, as checked by
+/// `verify_signature_with_timestamp`.
+pub fn compute_signature_with_timestamp(secret: &str, timestamp: &str, body: &[u8]) -> String {
+    let mut mac = hmac_sha256(secret);
+    update_with_timestamp(&mut mac, timestamp, body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn update_with_timestamp(mac: &mut Hmac<Sha256>, timestamp: &str, body: &[u8]) {
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+}
+
+fn read_signature(headers: &HeaderMap, header_name: &str) -> Result<Vec<u8>, WebhookError> {
+    let signature = header_str(headers, header_name)?.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    decode_signature(signature).ok_or_else(|| WebhookError::InvalidHeader(header_name.into()))
+}
+
+fn hmac_sha256(secret: &str) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length")
+}
+
+// A SHA-256 digest is 64 characters in hex, and 44 in base64.
+fn decode_signature(signature: &str) -> Option<Vec<u8>> {
+    if signature.len() == 64 {
+        if let Ok(decoded) = hex::decode(signature) {
+            return Some(decoded);
+        }
+    }
+
+    base64::engine::general_purpose::STANDARD
+        .decode(signature)
+        .ok()
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Result<&'a str, WebhookError> {
+    headers
+        .get(name)
+        .ok_or_else(|| WebhookError::MissingHeader(name.into()))?
+        .to_str()
+        .map_err(|_| WebhookError::InvalidHeader(name.into()))
+}
+
+fn now_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use base64::Engine;
+use reqwest::header::HeaderMap;
+
+use crate::webhooks::*;
+
+const NOW: u64 = 1_700_000_000;
+
+fn test_headers(timestamp: &str, signature: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(TIMESTAMP_HEADER, timestamp.parse().unwrap());
+    headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+    headers
+}
+
+#[test]
+fn replay_guard_accepts_fresh_callback() {
+    let guard = ReplayGuard::new(Duration::from_secs(300));
+
+    assert!(guard.check_at(NOW - 10, "some-nonce", NOW).is_ok());
+}
+
+#[test]
+fn replay_guard_rejects_stale_callback() {
+    let guard = ReplayGuard::new(Duration::from_secs(300));
+
+    assert_eq!(
+        guard.check_at(NOW - 301, "some-nonce", NOW),
+        Err(WebhookError::StaleTimestamp)
+    );
+    assert_eq!(
+        guard.check_at(NOW + 301, "some-nonce", NOW),
+        Err(WebhookError::StaleTimestamp)
+    );
+}
+
+#[test]
+fn replay_guard_rejects_replayed_callback() {
+    let guard = ReplayGuard::new(Duration::from_secs(300));
+
+    assert!(guard.check_at(NOW, "some-nonce", NOW).is_ok());
+    assert_eq!(
+        guard.check_at(NOW, "some-nonce", NOW + 1),
+        Err(WebhookError::Replayed)
+    );
+    assert!(guard.check_at(NOW, "other-nonce", NOW + 1).is_ok());
+}
+
+#[test]
+fn replay_guard_rejects_callbacks_when_full() {
+    let guard = ReplayGuard::new(Duration::from_secs(300)).with_capacity(2);
+
+    assert!(guard.check_at(NOW, "first", NOW).is_ok());
+    assert!(guard.check_at(NOW, "second", NOW + 1).is_ok());
+    assert_eq!(
+        guard.check_at(NOW, "third", NOW + 2),
+        Err(WebhookError::TooManyCallbacks)
+    );
+
+    assert_eq!(
+        guard.check_at(NOW, "first", NOW + 3),
+        Err(WebhookError::Replayed)
+    );
+}
+
+#[test]
+fn replay_guard_forgets_nonces_outside_window() {
+    let guard = ReplayGuard::new(Duration::from_secs(300)).with_capacity(2);
+
+    assert!(guard.check_at(NOW, "first", NOW).is_ok());
+    assert!(guard.check_at(NOW + 100, "second", NOW + 100).is_ok());
+
+    assert!(guard.check_at(NOW + 301, "third", NOW + 301).is_ok());
+    assert_eq!(
+        guard.check_at(NOW + 100, "second", NOW + 301),
+        Err(WebhookError::Replayed)
+    );
+}
+
+#[test]
+fn replay_guard_check_headers() {
+    let guard = ReplayGuard::new(Duration::from_secs(300));
+    let headers = test_headers("1700000000", "some-signature");
+
+    assert!(guard.check_headers_at(&headers, NOW).is_ok());
+    assert_eq!(
+        guard.check_headers_at(&headers, NOW),
+        Err(WebhookError::Replayed)
+    );
+}
+
+#[test]
+fn replay_guard_check_headers_invalid_timestamp() {
+    let guard = ReplayGuard::new(Duration::from_secs(300));
+    let headers = test_headers("yesterday", "some-signature");
+
+    assert_eq!(
+        guard.check_headers_at(&headers, NOW),
+        Err(WebhookError::InvalidHeader(TIMESTAMP_HEADER.into()))
+    );
+}
+
+#[test]
+fn replay_guard_check_headers_missing_signature() {
+    let guard = ReplayGuard::new(Duration::from_secs(300));
+    let mut headers = HeaderMap::new();
+    headers.insert(TIMESTAMP_HEADER, "1700000000".parse().unwrap());
+
+    assert_eq!(
+        guard.check_headers_at(&headers, NOW),
+        Err(WebhookError::MissingHeader(SIGNATURE_HEADER.into()))
+    );
+}
+
+const SECRET: &str = "webhook-secret";
+const BODY: &[u8] = br#"{"results":[{"messageId":"some-message-id"}]}"#;
+
+fn signed_headers(signature: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+    headers
+}
+
+#[test]
+fn verify_signature_hex() {
+    let headers = signed_headers(&compute_signature(SECRET, BODY));
+
+    assert!(verify_signature(SECRET, &headers, BODY).is_ok());
+}
+
+#[test]
+fn verify_signature_base64_with_prefix() {
+    let digest = hex::decode(compute_signature(SECRET, BODY)).unwrap();
+    let encoded = base64::engine::general_purpose::STANDARD.encode(digest);
+    let headers = signed_headers(&format!("sha256={}", encoded));
+
+    assert!(verify_signature(SECRET, &headers, BODY).is_ok());
+}
+
+#[test]
+fn verify_signature_tampered_body() {
+    let headers = signed_headers(&compute_signature(SECRET, BODY));
+
+    assert_eq!(
+        verify_signature(SECRET, &headers, br#"{"results":[]}"#),
+        Err(WebhookError::InvalidSignature)
+    );
+}
+
+#[test]
+fn verify_signature_wrong_secret() {
+    let headers = signed_headers(&compute_signature("other-secret", BODY));
+
+    assert_eq!(
+        verify_signature(SECRET, &headers, BODY),
+        Err(WebhookError::InvalidSignature)
+    );
+}
+
+#[test]
+fn verify_signature_missing_header() {
+    assert_eq!(
+        verify_signature(SECRET, &HeaderMap::new(), BODY),
+        Err(WebhookError::MissingHeader(SIGNATURE_HEADER.into()))
+    );
+}
+
+#[test]
+fn verify_signature_malformed_header() {
+    let headers = signed_headers("not a signature!");
+
+    assert_eq!(
+        verify_signature(SECRET, &headers, BODY),
+        Err(WebhookError::InvalidHeader(SIGNATURE_HEADER.into()))
+    );
+}
+
+#[test]
+fn verify_signature_custom_header() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "X-Hub-Signature",
+        compute_signature(SECRET, BODY).parse().unwrap(),
+    );
+
+    assert!(verify_signature_with_header(SECRET, &headers, "X-Hub-Signature", BODY).is_ok());
+}
+
+const TIMESTAMP: &str = "1700000000";
+
+#[test]
+fn verify_signature_with_timestamp_valid() {
+    let signature = compute_signature_with_timestamp(SECRET, TIMESTAMP, BODY);
+    let headers = test_headers(TIMESTAMP, &signature);
+
+    assert!(verify_signature_with_timestamp(SECRET, &headers, BODY).is_ok());
+}
+
+#[test]
+fn verify_signature_with_timestamp_changed_timestamp() {
+    let signature = compute_signature_with_timestamp(SECRET, TIMESTAMP, BODY);
+    let headers = test_headers("1700000300", &signature);
+
+    assert_eq!(
+        verify_signature_with_timestamp(SECRET, &headers, BODY),
+        Err(WebhookError::InvalidSignature)
+    );
+}
+
+#[test]
+fn verify_signature_with_timestamp_body_only_signature() {
+    let headers = test_headers(TIMESTAMP, &compute_signature(SECRET, BODY));
+
+    assert_eq!(
+        verify_signature_with_timestamp(SECRET, &headers, BODY),
+        Err(WebhookError::InvalidSignature)
+    );
+}
+
+#[test]
+fn verify_signature_with_timestamp_missing_timestamp() {
+    let headers = signed_headers(&compute_signature_with_timestamp(SECRET, TIMESTAMP, BODY));
+
+    assert_eq!(
+        verify_signature_with_timestamp(SECRET, &headers, BODY),
+        Err(WebhookError::MissingHeader(TIMESTAMP_HEADER.into()))
+    );
+}
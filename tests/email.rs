@@ -194,12 +194,12 @@ async fn domain() {
 #[ignore]
 #[tokio::test]
 async fn delete_domain() {
-    let status = get_test_email_client()
+    let response = get_test_email_client()
         .delete_domain("test-domain-rust-001.com")
         .await
         .unwrap();
 
-    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
 }
 
 #[ignore]
@@ -220,10 +220,10 @@ async fn update_tracking() {
 #[ignore]
 #[tokio::test]
 async fn verify_domain() {
-    let status = get_test_email_client()
+    let response = get_test_email_client()
         .verify_domain("test-domain-rust-001.com")
         .await
         .unwrap();
 
-    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(response.status, StatusCode::ACCEPTED);
 }
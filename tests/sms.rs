@@ -282,214 +282,3 @@ async fn update_scheduled_status() {
         .unwrap();
     assert_eq!(response.status, StatusCode::OK);
 }
-
-#[ignore]
-#[tokio::test]
-async fn tfa_applications() {
-    let response = test_sms_client().tfa_applications().await.unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn create_tfa_application() {
-    let request_body = CreateTfaApplicationRequestBody::new("rust-application");
-
-    let response = test_sms_client()
-        .create_tfa_application(request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::CREATED);
-}
-
-#[ignore]
-#[tokio::test]
-async fn tfa_application() {
-    let response = test_sms_client()
-        .tfa_application("02CC3CAAFD733136AA15DFAC720A0C42")
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn update_tfa_application() {
-    let configuration = TfaApplicationConfiguration {
-        allow_multiple_pin_verifications: Some(true),
-        pin_attempts: None,
-        pin_time_to_live: None,
-        send_pin_per_application_limit: Some("5010/12h".to_string()),
-        send_pin_per_phone_number_limit: None,
-        verify_pin_limit: None,
-    };
-    let mut request_body = UpdateTfaApplicationRequestBody::new("rust-application-2");
-    request_body.configuration = Some(configuration);
-
-    let response = test_sms_client()
-        .update_tfa_application("02CC3CAAFD733136AA15DFAC720A0C42", request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn tfa_message_templates() {
-    let response = test_sms_client()
-        .tfa_message_templates("02CC3CAAFD733136AA15DFAC720A0C42")
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn create_tfa_message_template() {
-    let request_body =
-        CreateTfaMessageTemplateRequestBody::new("Your Rust PIN 2 is {{pin}}", PinType::Numeric, 6);
-
-    let response = test_sms_client()
-        .create_tfa_message_template("02CC3CAAFD733136AA15DFAC720A0C42", request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn tfa_message_template() {
-    let response = test_sms_client()
-        .tfa_message_template(
-            "02CC3CAAFD733136AA15DFAC720A0C42",
-            "44A45DA3067F882BB4D87D6A48F9681E",
-        )
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn update_tfa_message_template() {
-    let request_body =
-        UpdateTfaMessageTemplateRequestBody::new("Your Rust PIN 3 is {{pin}}", PinType::Numeric, 6);
-
-    let response = test_sms_client()
-        .update_tfa_message_template(
-            "02CC3CAAFD733136AA15DFAC720A0C42",
-            "44A45DA3067F882BB4D87D6A48F9681E",
-            request_body,
-        )
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn send_pin_over_sms() {
-    let query_parameters = SendPinOverSmsQueryParameters::new();
-    let request_body = SendPinOverSmsRequestBody::new(
-        "02CC3CAAFD733136AA15DFAC720A0C42",
-        "44A45DA3067F882BB4D87D6A48F9681E",
-        "555555555555",
-    );
-
-    let response = test_sms_client()
-        .send_pin_over_sms(query_parameters, request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn resend_pin_over_sms() {
-    let request_body = ResendPinOverSmsRequestBody::default();
-
-    let response = test_sms_client()
-        .resend_pin_over_sms("AAA30929B83F2ED86CC34781BCB7A546", request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn send_pin_over_voice() {
-    let request_body = SendPinOverVoiceRequestBody::new(
-        "02CC3CAAFD733136AA15DFAC720A0C42",
-        "44A45DA3067F882BB4D87D6A48F9681E",
-        "555555555555",
-    );
-
-    let response = test_sms_client()
-        .send_pin_over_voice(request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn resend_pin_over_voice() {
-    let request_body = ResendPinOverVoiceRequestBody::default();
-
-    let response = test_sms_client()
-        .resend_pin_over_voice("AAA30929B83F2ED86CC34781BCB7A546", request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn verify_phone_number() {
-    let request_body = VerifyPhoneNumberRequestBody::new("123456");
-
-    let response = test_sms_client()
-        .verify_phone_number("AAA30929B83F2ED86CC34781BCB7A546", request_body)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
-
-#[ignore]
-#[tokio::test]
-async fn tfa_verification_status() {
-    let query_parameters = TfaVerificationStatusQueryParameters::new("555555555555");
-    let response = test_sms_client()
-        .tfa_verification_status("02CC3CAAFD733136AA15DFAC720A0C42", query_parameters)
-        .await
-        .unwrap();
-
-    println!("{:?}", response.body);
-    assert_eq!(response.status, StatusCode::OK);
-}
@@ -237,6 +237,73 @@ async fn send_interactive_multiproduct() {
     assert!(!response.body.message_id.unwrap().is_empty());
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_interactive_flow() {
+    let mut action = InteractiveFlowAction::new("1234567890", "Open flow");
+    action.mode = Some(FlowMode::Draft);
+    action.flow_action_payload = Some(InteractiveFlowActionPayload::new("WELCOME"));
+    let request_body = SendInteractiveFlowRequestBody::new(
+        &test_sender_number(),
+        &test_destination_number(),
+        InteractiveFlowContent::new(InteractiveBody::new("Body text"), action),
+    );
+
+    let response = test_wa_client()
+        .send_interactive_flow(request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(!response.body.message_id.unwrap().is_empty());
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_interactive_order_details() {
+    let item = OrderItem::new("T-shirt", OrderAmount::new(50000, 100), 2);
+    let action = InteractiveOrderDetailsAction::new(
+        OrderPayment::new("payment-configuration", "rust-sdk-order"),
+        "INR",
+        OrderAmount::new(100000, 100),
+        OrderDetails::new(vec![item], OrderAmount::new(100000, 100)),
+    );
+    let request_body = SendInteractiveOrderDetailsRequestBody::new(
+        &test_sender_number(),
+        &test_destination_number(),
+        InteractiveOrderDetailsContent::new(InteractiveBody::new("Body text"), action),
+    );
+
+    let response = test_wa_client()
+        .send_interactive_order_details(request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(!response.body.message_id.unwrap().is_empty());
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_interactive_order_status() {
+    let request_body = SendInteractiveOrderStatusRequestBody::new(
+        &test_sender_number(),
+        &test_destination_number(),
+        InteractiveOrderStatusContent::new(
+            InteractiveBody::new("Body text"),
+            InteractiveOrderStatusAction::new("rust-sdk-order", OrderStatus::Processing),
+        ),
+    );
+
+    let response = test_wa_client()
+        .send_interactive_order_status(request_body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(!response.body.message_id.unwrap().is_empty());
+}
+
 #[ignore]
 #[tokio::test]
 async fn create_template() {
@@ -269,15 +336,31 @@ async fn templates() {
     assert!(!response.body.templates.unwrap().is_empty());
 }
 
+#[ignore]
+#[tokio::test]
+async fn template_by_name() {
+    let response = test_wa_client()
+        .template_by_name(
+            &test_sender_number(),
+            "rust_sdk_test_template",
+            TemplateLanguage::EnUs,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.body.is_some());
+}
+
 #[ignore]
 #[tokio::test]
 async fn delete_template() {
-    let status = test_wa_client()
+    let response = test_wa_client()
         .delete_template(&test_sender_number(), "rust_sdk_test_template")
         .await
         .unwrap();
 
-    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
 }
 
 #[ignore]